@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use aether_api::{args::Args, get_addr, init_logger, router::router, run_server, state::state};
+use aether_api::{
+    args::Args, get_addr, init_logger, purge::run_deployment_purge, reaper::run_action_reaper,
+    router::router, run_server, state::state,
+};
 use clap::Parser;
 
 #[tokio::main]
@@ -12,6 +15,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let app_state = state(args.clone()).await?;
 
+    tokio::spawn(run_action_reaper(
+        app_state.service.clone(),
+        args.reaper.clone(),
+    ));
+    tokio::spawn(run_deployment_purge(
+        app_state.service.clone(),
+        args.retention.clone(),
+    ));
+
     let router = router(app_state)?;
 
     let addr = get_addr(&args.server.host, args.server.port).await?;
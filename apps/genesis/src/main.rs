@@ -2,6 +2,7 @@ use clap::Parser;
 use genesis_core::application::dispatcher::EventDispatcher;
 use genesis_core::application::handlers::deployment::DeploymentEventHandler;
 use genesis_core::domain::ports::EventConsumer;
+use genesis_core::infrastructure::kubernetes::identity_instance_repository::KubeIdentityInstanceRepository;
 use genesis_core::infrastructure::rabbitmq::consumer::RabbitMqConsumer;
 use std::sync::Arc;
 use tracing::info;
@@ -22,19 +23,41 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenvy::dotenv()?;
 
     let args = Args::parse();
+    let exchange = args.amqp.amqp_exchange.clone();
     let queue = args.amqp.amqp_queue.clone();
     let amqp_url = args.amqp.amqp_url.clone();
 
-    info!(%amqp_url, %queue, "starting genesis");
+    info!(%amqp_url, %exchange, %queue, "starting genesis");
+
+    let client = kube::Client::try_default().await?;
+    let identity_instances = Arc::new(KubeIdentityInstanceRepository::new(client));
+    let base_domain = args.identity.base_domain.clone();
 
     let handlers: Vec<Arc<dyn genesis_core::domain::ports::EventHandler>> = vec![
-        Arc::new(DeploymentEventHandler::new("create")),
-        Arc::new(DeploymentEventHandler::new("delete")),
-        Arc::new(DeploymentEventHandler::new("update")),
+        Arc::new(
+            DeploymentEventHandler::new("create", identity_instances.clone())
+                .with_base_domain(base_domain.clone()),
+        ),
+        Arc::new(
+            DeploymentEventHandler::new("delete", identity_instances.clone())
+                .with_base_domain(base_domain.clone()),
+        ),
+        Arc::new(
+            DeploymentEventHandler::new("update", identity_instances.clone())
+                .with_base_domain(base_domain.clone()),
+        ),
+        Arc::new(
+            DeploymentEventHandler::new("suspend", identity_instances.clone())
+                .with_base_domain(base_domain.clone()),
+        ),
+        Arc::new(
+            DeploymentEventHandler::new("resume", identity_instances).with_base_domain(base_domain),
+        ),
     ];
 
     let dispatcher = Arc::new(EventDispatcher::new(handlers));
-    let consumer = RabbitMqConsumer::new(amqp_url, queue, dispatcher);
+    let consumer = RabbitMqConsumer::new(amqp_url, exchange, queue, dispatcher)
+        .with_retry_policy(args.amqp.retry_policy());
 
     consumer.run().await?;
 
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use aether_herald_core::domain::ports::HeraldService;
+use aether_herald_core::domain::services::HeraldServiceImpl;
+use aether_herald_core::infrastructure::control_plane::control_plane_repository::HttpControlPlaneRepository;
+use aether_herald_core::infrastructure::rabbitmq::message_bus_repository::RabbitMqMessageBusRepository;
+use clap::Parser;
+use tokio::signal::unix::{SignalKind, signal};
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::args::Args;
+
+pub mod args;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| "herald=info,aether_herald_core=info".into()),
+        )
+        .init();
+
+    dotenvy::dotenv().ok();
+
+    let args = Args::parse();
+    let shard = args.shard.shard_config()?;
+    let dataplane_id = args.control_plane.dataplane_id();
+    let poll_interval = args.poll_interval();
+
+    info!(
+        control_plane_url = %args.control_plane.control_plane_url,
+        %dataplane_id,
+        shard_index = shard.shard_index,
+        shard_count = shard.shard_count,
+        poll_interval_seconds = poll_interval.as_secs(),
+        "starting herald"
+    );
+
+    let mut control_plane = HttpControlPlaneRepository::new(&args.control_plane.control_plane_url);
+    if let Some(credentials) = args.control_plane.service_account() {
+        control_plane = control_plane.with_service_account(credentials);
+    }
+
+    let message_bus =
+        RabbitMqMessageBusRepository::connect(&args.amqp.amqp_url, &args.amqp.amqp_exchange)
+            .await?;
+
+    let service =
+        HeraldServiceImpl::new(Arc::new(control_plane), Arc::new(message_bus), dataplane_id);
+
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let shutdown = async move {
+        tokio::select! {
+            _ = sigterm.recv() => info!("received SIGTERM, shutting down"),
+            _ = tokio::signal::ctrl_c() => info!("received interrupt, shutting down"),
+        }
+    };
+    tokio::pin!(shutdown);
+
+    let mut ticker = interval(poll_interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut cycle: u64 = 0;
+
+    loop {
+        // A cycle in progress always runs to completion; shutdown is only observed between cycles
+        tokio::select! {
+            biased;
+            _ = &mut shutdown => break,
+            _ = ticker.tick() => {}
+        }
+
+        cycle += 1;
+        let started_at = Instant::now();
+
+        match service.sync_shard(&shard).await {
+            Ok(report) => info!(
+                cycle,
+                duration_ms = started_at.elapsed().as_millis() as u64,
+                deployments_listed = report.deployments_listed,
+                deployments_owned = report.deployments_owned,
+                deployments_failed = report.deployments_failed,
+                actions_published = report.actions_published,
+                "polling cycle completed"
+            ),
+            Err(e) => error!(
+                cycle,
+                duration_ms = started_at.elapsed().as_millis() as u64,
+                error = %e,
+                "polling cycle failed"
+            ),
+        }
+    }
+
+    info!(cycles = cycle, "herald stopped");
+
+    Ok(())
+}
@@ -2,7 +2,10 @@ use std::path::PathBuf;
 
 use clap::Parser;
 
-use aether_core::{AetherConfig, AuthConfig, DatabaseConfig};
+use aether_core::{
+    AetherConfig, AuthConfig, DatabaseConfig, InvitationConfig, IssuerConfig, RetentionConfig,
+    SchedulerConfig, dataplane::scheduler::SchedulingStrategy,
+};
 use url::Url;
 
 #[derive(Debug, Clone, Parser)]
@@ -18,6 +21,18 @@ pub struct Args {
 
     #[command(flatten)]
     pub server: ServerArgs,
+
+    #[command(flatten)]
+    pub reaper: ReaperArgs,
+
+    #[command(flatten)]
+    pub scheduler: SchedulerArgs,
+
+    #[command(flatten)]
+    pub retention: RetentionArgs,
+
+    #[command(flatten)]
+    pub invitation: InvitationArgs,
 }
 
 impl From<Args> for AetherConfig {
@@ -25,6 +40,9 @@ impl From<Args> for AetherConfig {
         Self {
             database: value.db.into(),
             auth: value.auth.into(),
+            scheduler: value.scheduler.into(),
+            retention: value.retention.into(),
+            invitation: value.invitation.into(),
         }
     }
 }
@@ -36,15 +54,70 @@ pub struct AuthArgs {
         env = "AUTH_ISSUER",
         name = "AUTH_ISSUER",
         default_value = "http://localhost:8888/realms/aether",
-        long_help = "The issuer URL to use for authentication"
+        num_args = 1..,
+        value_delimiter = ',',
+        value_parser = parse_issuer,
+        long_help = "Trusted issuer URLs, optionally followed by claim mappings: \
+            <url>[;username=<claim>][;client_id=<claim>][;roles=<claim>][;scopes=<claim>]\n\
+            Claims may be dotted paths, e.g. https://keycloak/realms/staff;roles=realm_access.roles\n\
+            Subjects of every issuer but the first are prefixed with their issuer, so keep the \
+            issuer existing accounts were created with first"
     )]
-    pub issuer: String,
+    pub issuers: Vec<IssuerConfig>,
+    #[arg(
+        long = "auth-audience",
+        env = "AUTH_AUDIENCE",
+        name = "AUTH_AUDIENCE",
+        num_args = 0..,
+        value_delimiter = ',',
+        long_help = "Audiences accepted in access tokens; the audience is not checked when empty"
+    )]
+    pub audiences: Vec<String>,
+    #[arg(
+        long = "auth-leeway-seconds",
+        env = "AUTH_LEEWAY_SECONDS",
+        name = "AUTH_LEEWAY_SECONDS",
+        default_value_t = AuthConfig::DEFAULT_LEEWAY_SECONDS,
+        long_help = "Clock skew tolerated when checking token expiry and not-before, in seconds"
+    )]
+    pub leeway_seconds: u64,
+}
+
+fn parse_issuer(value: &str) -> Result<IssuerConfig, String> {
+    let mut parts = value.split(';').map(str::trim);
+    let issuer = parts
+        .next()
+        .filter(|issuer| !issuer.is_empty())
+        .ok_or_else(|| "issuer URL is missing".to_string())?;
+    let mut config = IssuerConfig::new(issuer);
+
+    for part in parts.filter(|part| !part.is_empty()) {
+        let (name, claim) = part
+            .split_once('=')
+            .ok_or_else(|| format!("expected <name>=<claim>, got `{part}`"))?;
+        let claims = config.claims;
+        config.claims = match name.trim() {
+            "username" => claims.with_username(claim.trim()),
+            "client_id" => claims.with_client_id(claim.trim()),
+            "roles" => claims.with_roles(claim.trim()),
+            "scopes" => claims.with_scopes(claim.trim()),
+            other => return Err(format!("unknown claim mapping `{other}`")),
+        };
+    }
+
+    Ok(config)
 }
 
 impl From<AuthArgs> for AuthConfig {
     fn from(value: AuthArgs) -> Self {
         Self {
-            issuer: value.issuer,
+            issuers: value.issuers,
+            audiences: value
+                .audiences
+                .into_iter()
+                .filter(|audience| !audience.trim().is_empty())
+                .collect(),
+            leeway_seconds: value.leeway_seconds,
         }
     }
 }
@@ -142,6 +215,120 @@ impl Default for ServerArgs {
     }
 }
 
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct SchedulerArgs {
+    #[arg(
+        long = "scheduler-strategy",
+        env = "SCHEDULER_STRATEGY",
+        name = "SCHEDULER_STRATEGY",
+        default_value_t = SchedulingStrategy::LeastLoaded,
+        long_help = "How new deployments are placed on dataplanes: least-loaded, bin-packing or dedicated-per-organisation"
+    )]
+    pub strategy: SchedulingStrategy,
+}
+
+impl From<SchedulerArgs> for SchedulerConfig {
+    fn from(value: SchedulerArgs) -> Self {
+        Self {
+            strategy: value.strategy,
+        }
+    }
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct ReaperArgs {
+    #[arg(
+        long = "reaper-interval-seconds",
+        env = "REAPER_INTERVAL_SECONDS",
+        name = "REAPER_INTERVAL_SECONDS",
+        default_value_t = 30,
+        long_help = "How often expired action leases are reaped, in seconds"
+    )]
+    pub interval_seconds: u64,
+    #[arg(
+        long = "reaper-max-attempts",
+        env = "REAPER_MAX_ATTEMPTS",
+        name = "REAPER_MAX_ATTEMPTS",
+        default_value_t = 5,
+        long_help = "How many times an action can be leased before it is failed with a timeout"
+    )]
+    pub max_attempts: u32,
+}
+
+impl Default for ReaperArgs {
+    fn default() -> Self {
+        Self {
+            interval_seconds: 30,
+            max_attempts: 5,
+        }
+    }
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct RetentionArgs {
+    #[arg(
+        long = "deployment-grace-period-seconds",
+        env = "DEPLOYMENT_GRACE_PERIOD_SECONDS",
+        name = "DEPLOYMENT_GRACE_PERIOD_SECONDS",
+        default_value_t = 259200,
+        long_help = "How long a deleted deployment can be restored before it is purged, in seconds"
+    )]
+    pub deployment_grace_period_seconds: u64,
+    #[arg(
+        long = "purge-interval-seconds",
+        env = "PURGE_INTERVAL_SECONDS",
+        name = "PURGE_INTERVAL_SECONDS",
+        default_value_t = 300,
+        long_help = "How often deployments past their grace period are purged, in seconds"
+    )]
+    pub purge_interval_seconds: u64,
+}
+
+impl RetentionArgs {
+    pub fn deployment_grace_period(&self) -> chrono::Duration {
+        chrono::Duration::seconds(
+            i64::try_from(self.deployment_grace_period_seconds).unwrap_or(i64::MAX / 1000),
+        )
+    }
+}
+
+impl Default for RetentionArgs {
+    fn default() -> Self {
+        Self {
+            deployment_grace_period_seconds: 259200,
+            purge_interval_seconds: 300,
+        }
+    }
+}
+
+impl From<RetentionArgs> for RetentionConfig {
+    fn from(value: RetentionArgs) -> Self {
+        Self {
+            deployment_grace_period: value.deployment_grace_period(),
+        }
+    }
+}
+
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct InvitationArgs {
+    #[arg(
+        long = "invitation-log-tokens",
+        env = "INVITATION_LOG_TOKENS",
+        name = "INVITATION_LOG_TOKENS",
+        long_help = "Log invitation tokens so they can be handed over by hand. \
+            Anyone reading the logs can then accept the invitations: for local development only"
+    )]
+    pub log_tokens: bool,
+}
+
+impl From<InvitationArgs> for InvitationConfig {
+    fn from(value: InvitationArgs) -> Self {
+        Self {
+            log_tokens: value.log_tokens,
+        }
+    }
+}
+
 #[derive(clap::Args, Debug, Clone)]
 pub struct DatabaseArgs {
     #[arg(
@@ -246,6 +433,19 @@ mod tests {
         assert_eq!(server.host, "0.0.0.0");
         assert_eq!(server.port, 3333);
         assert!(server.allowed_origins.is_empty());
+
+        let reaper = ReaperArgs::default();
+        assert_eq!(reaper.interval_seconds, 30);
+        assert_eq!(reaper.max_attempts, 5);
+
+        let retention = RetentionArgs::default();
+        assert_eq!(
+            retention.deployment_grace_period(),
+            RetentionConfig::default().deployment_grace_period
+        );
+        assert_eq!(retention.purge_interval_seconds, 300);
+
+        assert!(!InvitationArgs::default().log_tokens);
     }
 
     #[test]
@@ -254,15 +454,51 @@ mod tests {
             log: LogArgs::default(),
             db: DatabaseArgs::default(),
             auth: AuthArgs {
-                issuer: "http://issuer.test".to_string(),
+                issuers: vec![IssuerConfig::new("http://issuer.test")],
+                audiences: vec!["aether-api".to_string(), String::new()],
+                leeway_seconds: 30,
             },
             server: ServerArgs::default(),
+            reaper: ReaperArgs::default(),
+            scheduler: SchedulerArgs {
+                strategy: SchedulingStrategy::BinPacking,
+            },
+            retention: RetentionArgs {
+                deployment_grace_period_seconds: 3600,
+                purge_interval_seconds: 60,
+            },
+            invitation: InvitationArgs { log_tokens: true },
         };
 
         let config: AetherConfig = args.clone().into();
         assert_eq!(config.database.host, args.db.host);
         assert_eq!(config.database.name, args.db.name);
         assert_eq!(config.database.username, args.db.user);
-        assert_eq!(config.auth.issuer, args.auth.issuer);
+        assert_eq!(config.auth.issuers, args.auth.issuers);
+        assert_eq!(config.auth.audiences, vec!["aether-api".to_string()]);
+        assert_eq!(config.auth.leeway_seconds, 30);
+        assert_eq!(config.scheduler.strategy, SchedulingStrategy::BinPacking);
+        assert_eq!(
+            config.retention.deployment_grace_period,
+            chrono::Duration::hours(1)
+        );
+        assert!(config.invitation.log_tokens);
+    }
+
+    #[test]
+    fn parse_issuer_reads_claim_mappings() {
+        let config = parse_issuer(
+            "https://keycloak.test/realms/staff;roles=realm_access.roles;client_id=azp",
+        )
+        .unwrap();
+
+        assert_eq!(config.issuer, "https://keycloak.test/realms/staff");
+        assert_eq!(config.claims.roles.as_deref(), Some("realm_access.roles"));
+        assert_eq!(config.claims.client_id, "azp");
+        assert_eq!(config.claims.username, "preferred_username");
+
+        assert!(parse_issuer("https://keycloak.test;groups=groups").is_err());
+        assert!(parse_issuer("https://keycloak.test;roles").is_err());
+        assert!(parse_issuer(";roles=roles").is_err());
     }
 }
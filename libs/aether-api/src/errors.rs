@@ -1,9 +1,16 @@
-use aether_core::CoreError;
-use axum::{Json, http::StatusCode, response::IntoResponse};
+use aether_core::{CoreError, ErrorKind, organisation::plan::LimitViolation};
+use axum::{
+    Json,
+    http::{StatusCode, header::CONTENT_TYPE},
+    response::IntoResponse,
+};
 use serde::Serialize;
 use thiserror::Error;
 use utoipa::ToSchema;
 
+/// Media type of every error body, see RFC 7807
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
 #[derive(Debug, Error, ToSchema)]
 pub enum ApiError {
     #[error("token not found")]
@@ -12,6 +19,22 @@ pub enum ApiError {
     #[error("bad request: {reason}")]
     BadRequest { reason: String },
 
+    #[error("not found: {reason}")]
+    NotFound { code: ErrorCode, reason: String },
+
+    #[error("conflict: {reason}")]
+    Conflict { code: ErrorCode, reason: String },
+
+    #[error("unprocessable entity: {reason}")]
+    UnprocessableEntity {
+        code: ErrorCode,
+        reason: String,
+        violations: Vec<LimitViolation>,
+    },
+
+    #[error("unauthorized: {reason}")]
+    Unauthorized { code: ErrorCode, reason: String },
+
     #[error("unknown error: {reason}")]
     Unknown { reason: String },
 
@@ -22,84 +45,294 @@ pub enum ApiError {
     Forbidden { reason: String },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct ApiErrorResponse {
-    pub code: String,
+/// Stable machine-readable error codes returned in the `code` member of a problem
+///
+/// Clients should branch on the code rather than on `detail`, which is meant for humans
+/// and may change wording between releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+pub enum ErrorCode {
+    #[serde(rename = "E_BAD_REQUEST")]
+    BadRequest,
+    #[serde(rename = "E_TOKEN_NOT_FOUND")]
+    TokenNotFound,
+    #[serde(rename = "E_MISSING_AUTH_HEADER")]
+    MissingAuthHeader,
+    #[serde(rename = "E_INVALID_AUTH_HEADER")]
+    InvalidAuthHeader,
+    #[serde(rename = "E_AUTHENTICATION_FAILED")]
+    AuthenticationFailed,
+    #[serde(rename = "E_INVALID_IDENTITY")]
+    InvalidIdentity,
+    #[serde(rename = "E_FORBIDDEN")]
+    Forbidden,
+    #[serde(rename = "E_UNKNOWN")]
+    Unknown,
+    #[serde(rename = "E_INTERNAL_SERVER_ERROR")]
+    InternalServerError,
+
+    #[serde(rename = "E_ORGANISATION_NOT_FOUND")]
+    OrganisationNotFound,
+    #[serde(rename = "E_ORGANISATION_CREATION_FAILED")]
+    OrganisationCreationFailed,
+    #[serde(rename = "E_INVALID_ORGANISATION_NAME")]
+    InvalidOrganisationName,
+    #[serde(rename = "E_INVALID_ORGANISATION_SLUG")]
+    InvalidOrganisationSlug,
+    #[serde(rename = "E_ORGANISATION_SLUG_TAKEN")]
+    OrganisationSlugTaken,
+    #[serde(rename = "E_ORGANISATION_SUSPENDED")]
+    OrganisationSuspended,
+    #[serde(rename = "E_ORGANISATION_LIMIT_REACHED")]
+    OrganisationLimitReached,
+    #[serde(rename = "E_USER_ORGANISATION_LIMIT_REACHED")]
+    UserOrganisationLimitReached,
+    #[serde(rename = "E_INVALID_ORGANISATION_STATUS")]
+    InvalidOrganisationStatus,
+    #[serde(rename = "E_INVALID_ORGANISATION_TRANSITION")]
+    InvalidOrganisationTransition,
+    #[serde(rename = "E_MISSING_STATUS_REASON")]
+    MissingStatusReason,
+    #[serde(rename = "E_INVALID_PLAN")]
+    InvalidPlan,
+    #[serde(rename = "E_INVALID_PLAN_LIMITS")]
+    InvalidPlanLimits,
+    #[serde(rename = "E_PLAN_LIMITS_EXCEEDED")]
+    PlanLimitsExceeded,
+
+    #[serde(rename = "E_USER_NOT_FOUND")]
+    UserNotFound,
+    #[serde(rename = "E_MEMBER_NOT_FOUND")]
+    MemberNotFound,
+    #[serde(rename = "E_MEMBER_ALREADY_EXISTS")]
+    MemberAlreadyExists,
+    #[serde(rename = "E_ROLE_NOT_FOUND")]
+    RoleNotFound,
+
+    #[serde(rename = "E_INVALID_EMAIL")]
+    InvalidEmail,
+    #[serde(rename = "E_INVALID_INVITATION_STATUS")]
+    InvalidInvitationStatus,
+    #[serde(rename = "E_INVITATION_NOT_FOUND")]
+    InvitationNotFound,
+    #[serde(rename = "E_INVITATION_ALREADY_PENDING")]
+    InvitationAlreadyPending,
+    #[serde(rename = "E_INVITATION_NOT_PENDING")]
+    InvitationNotPending,
+    #[serde(rename = "E_INVALID_INVITATION_TOKEN")]
+    InvalidInvitationToken,
+
+    #[serde(rename = "E_INVALID_API_KEY")]
+    InvalidApiKey,
+    #[serde(rename = "E_API_KEY_NOT_FOUND")]
+    ApiKeyNotFound,
+    #[serde(rename = "E_API_KEY_ALREADY_REVOKED")]
+    ApiKeyAlreadyRevoked,
+
+    #[serde(rename = "E_INVALID_DATAPLANE_CAPACITY")]
+    InvalidDataPlaneCapacity,
+    #[serde(rename = "E_DATAPLANE_NOT_FOUND")]
+    DataPlaneNotFound,
+    #[serde(rename = "E_INVALID_DATAPLANE_AGENT")]
+    InvalidDataPlaneAgent,
+    #[serde(rename = "E_DATAPLANE_AGENT_NOT_FOUND")]
+    DataPlaneAgentNotFound,
+    #[serde(rename = "E_NO_DATAPLANE_AVAILABLE")]
+    NoDataPlaneAvailable,
+    #[serde(rename = "E_NO_DATAPLANE_FIT")]
+    NoDataPlaneFit,
+    #[serde(rename = "E_INVALID_SCHEDULING_STRATEGY")]
+    InvalidSchedulingStrategy,
+
+    #[serde(rename = "E_ACTION_NOT_FOUND")]
+    ActionNotFound,
+    #[serde(rename = "E_INVALID_ACTION_TRANSITION")]
+    InvalidActionTransition,
+    #[serde(rename = "E_DEPLOYMENT_NOT_FOUND")]
+    DeploymentNotFound,
+    #[serde(rename = "E_INVALID_DEPLOYMENT_TRANSITION")]
+    InvalidDeploymentTransition,
+    #[serde(rename = "E_DEPLOYMENT_NOT_RESTORABLE")]
+    DeploymentNotRestorable,
+}
+
+impl From<&CoreError> for ErrorCode {
+    fn from(value: &CoreError) -> Self {
+        match value {
+            CoreError::FailedCreateOrganisation { .. } => ErrorCode::OrganisationCreationFailed,
+            CoreError::InvalidOrganisationName { .. } => ErrorCode::InvalidOrganisationName,
+            CoreError::InvalidOrganisationSlug { .. } => ErrorCode::InvalidOrganisationSlug,
+            CoreError::OrganisationNotFound { .. }
+            | CoreError::OrganisationNotFoundBySlug { .. } => ErrorCode::OrganisationNotFound,
+            CoreError::OrganisationSlugAlreadyExists { .. } => ErrorCode::OrganisationSlugTaken,
+            CoreError::OrganisationSuspended { .. } => ErrorCode::OrganisationSuspended,
+            CoreError::OrganisationLimitReached { .. } => ErrorCode::OrganisationLimitReached,
+            CoreError::UserOrganisationLimitReached { .. } => {
+                ErrorCode::UserOrganisationLimitReached
+            }
+            CoreError::InvalidOrganisationStatus { .. } => ErrorCode::InvalidOrganisationStatus,
+            CoreError::InvalidOrganisationTransition { .. } => {
+                ErrorCode::InvalidOrganisationTransition
+            }
+            CoreError::MissingStatusReason => ErrorCode::MissingStatusReason,
+            CoreError::InvalidPlan { .. } => ErrorCode::InvalidPlan,
+            CoreError::InvalidPlanLimits { .. } => ErrorCode::InvalidPlanLimits,
+            CoreError::PlanLimitsExceeded { .. } => ErrorCode::PlanLimitsExceeded,
+            CoreError::InvalidIdentity => ErrorCode::InvalidIdentity,
+            CoreError::UserNotFound { .. } => ErrorCode::UserNotFound,
+            CoreError::MemberNotFound { .. } => ErrorCode::MemberNotFound,
+            CoreError::MemberAlreadyExists { .. } => ErrorCode::MemberAlreadyExists,
+            CoreError::RoleNotFound { .. } => ErrorCode::RoleNotFound,
+            CoreError::InvalidEmail { .. } => ErrorCode::InvalidEmail,
+            CoreError::InvalidInvitationStatus { .. } => ErrorCode::InvalidInvitationStatus,
+            CoreError::InvitationNotFound { .. } => ErrorCode::InvitationNotFound,
+            CoreError::InvitationAlreadyPending { .. } => ErrorCode::InvitationAlreadyPending,
+            CoreError::InvitationNotPending { .. } => ErrorCode::InvitationNotPending,
+            CoreError::InvalidInvitationToken => ErrorCode::InvalidInvitationToken,
+            CoreError::InvalidApiKey { .. } => ErrorCode::InvalidApiKey,
+            CoreError::ApiKeyNotFound { .. } => ErrorCode::ApiKeyNotFound,
+            CoreError::ApiKeyAlreadyRevoked { .. } => ErrorCode::ApiKeyAlreadyRevoked,
+            CoreError::InvalidDataPlaneCapacity => ErrorCode::InvalidDataPlaneCapacity,
+            CoreError::DataPlaneNotFound { .. } => ErrorCode::DataPlaneNotFound,
+            CoreError::InvalidDataPlaneAgent { .. } => ErrorCode::InvalidDataPlaneAgent,
+            CoreError::DataPlaneAgentNotFound { .. } => ErrorCode::DataPlaneAgentNotFound,
+            CoreError::NoDataPlaneAvailable => ErrorCode::NoDataPlaneAvailable,
+            CoreError::NoDataPlaneFit { .. } => ErrorCode::NoDataPlaneFit,
+            CoreError::InvalidSchedulingStrategy { .. } => ErrorCode::InvalidSchedulingStrategy,
+            CoreError::ActionNotFound { .. } => ErrorCode::ActionNotFound,
+            CoreError::DeploymentNotFound { .. } => ErrorCode::DeploymentNotFound,
+            CoreError::InvalidActionTransition { .. } => ErrorCode::InvalidActionTransition,
+            CoreError::InvalidDeploymentTransition { .. } => ErrorCode::InvalidDeploymentTransition,
+            CoreError::DeploymentNotRestorable { .. } => ErrorCode::DeploymentNotRestorable,
+            CoreError::PermissionDenied { .. } => ErrorCode::Forbidden,
+            CoreError::DatabaseError { .. } | CoreError::InternalError(_) => ErrorCode::Unknown,
+        }
+    }
+}
+
+/// RFC 7807 problem details, served as `application/problem+json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ProblemDetails {
+    /// Problem type; always `about:blank`, the `code` member identifies the error
+    #[serde(rename = "type")]
+    #[schema(example = "about:blank")]
+    pub problem_type: String,
+    /// Reason phrase of the status code
+    #[schema(example = "Not Found")]
+    pub title: String,
     pub status: u16,
-    pub message: String,
+    /// Human readable explanation specific to this occurrence
+    pub detail: String,
+    pub code: ErrorCode,
+    /// Limits the request would exceed, set for `E_PLAN_LIMITS_EXCEEDED`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<LimitViolation>,
 }
 
-impl ApiErrorResponse {
-    pub fn new(code: impl Into<String>, status: StatusCode, message: impl Into<String>) -> Self {
+impl ProblemDetails {
+    pub fn new(status: StatusCode, code: ErrorCode, detail: impl Into<String>) -> Self {
         Self {
-            code: code.into(),
+            problem_type: "about:blank".to_string(),
+            title: status.canonical_reason().unwrap_or("Unknown").to_string(),
             status: status.as_u16(),
-            message: message.into(),
+            detail: detail.into(),
+            code,
+            violations: Vec::new(),
+        }
+    }
+
+    pub fn with_violations(mut self, violations: Vec<LimitViolation>) -> Self {
+        self.violations = violations;
+        self
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> axum::response::Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        (status, [(CONTENT_TYPE, PROBLEM_JSON)], Json(self)).into_response()
+    }
+}
+
+impl ApiError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ApiError::TokenNotFound | ApiError::Unauthorized { .. } => StatusCode::UNAUTHORIZED,
+            ApiError::BadRequest { .. } => StatusCode::BAD_REQUEST,
+            ApiError::NotFound { .. } => StatusCode::NOT_FOUND,
+            ApiError::Conflict { .. } => StatusCode::CONFLICT,
+            ApiError::UnprocessableEntity { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::Forbidden { .. } => StatusCode::FORBIDDEN,
+            ApiError::Unknown { .. } | ApiError::InternalServerError { .. } => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ApiError::TokenNotFound => ErrorCode::TokenNotFound,
+            ApiError::BadRequest { .. } => ErrorCode::BadRequest,
+            ApiError::NotFound { code, .. }
+            | ApiError::Conflict { code, .. }
+            | ApiError::UnprocessableEntity { code, .. }
+            | ApiError::Unauthorized { code, .. } => *code,
+            ApiError::Forbidden { .. } => ErrorCode::Forbidden,
+            ApiError::Unknown { .. } => ErrorCode::Unknown,
+            ApiError::InternalServerError { .. } => ErrorCode::InternalServerError,
         }
     }
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> axum::response::Response {
+        let problem = ProblemDetails::new(self.status(), self.code(), self.to_string());
+
         match self {
-            ApiError::InternalServerError { reason } => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiErrorResponse::new(
-                    "E_INTERNAL_SERVER_ERROR",
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    format!("internal server error: {reason}"),
-                )),
-            )
-                .into_response(),
-            ApiError::Unknown { reason } => (
-                StatusCode::BAD_REQUEST,
-                Json(ApiErrorResponse::new(
-                    "E_UNKNOWN",
-                    StatusCode::BAD_REQUEST,
-                    format!("unknown error: {reason}"),
-                )),
-            )
-                .into_response(),
-            ApiError::TokenNotFound => (
-                StatusCode::UNAUTHORIZED,
-                Json(ApiErrorResponse::new(
-                    "E_TOKEN_NOT_FOUND",
-                    StatusCode::UNAUTHORIZED,
-                    "token not found",
-                )),
-            )
-                .into_response(),
-            ApiError::BadRequest { reason } => (
-                StatusCode::BAD_REQUEST,
-                Json(ApiErrorResponse::new(
-                    "E_BAD_REQUEST",
-                    StatusCode::BAD_REQUEST,
-                    format!("bad request: {reason}"),
-                )),
-            )
-                .into_response(),
-            ApiError::Forbidden { reason } => (
-                StatusCode::FORBIDDEN,
-                Json(ApiErrorResponse::new(
-                    "E_FORBIDDEN",
-                    StatusCode::FORBIDDEN,
-                    format!("forbidden: {reason}"),
-                )),
-            )
-                .into_response(),
+            ApiError::UnprocessableEntity { violations, .. } => {
+                problem.with_violations(violations).into_response()
+            }
+            _ => problem.into_response(),
         }
     }
 }
 
 impl From<CoreError> for ApiError {
     fn from(value: CoreError) -> Self {
-        match value {
-            CoreError::FailedCreateOrganisation {
-                organisation_name: _,
-                reason,
-            } => ApiError::BadRequest { reason },
-            CoreError::PermissionDenied { reason } => ApiError::Forbidden { reason },
-            _ => ApiError::Unknown {
+        let code = ErrorCode::from(&value);
+
+        match value.kind() {
+            ErrorKind::NotFound => ApiError::NotFound {
+                code,
+                reason: value.to_string(),
+            },
+            ErrorKind::Conflict => ApiError::Conflict {
+                code,
+                reason: value.to_string(),
+            },
+            ErrorKind::UnprocessableEntity => {
+                let reason = value.to_string();
+                let violations = match value {
+                    CoreError::PlanLimitsExceeded { violations } => violations,
+                    _ => Vec::new(),
+                };
+                ApiError::UnprocessableEntity {
+                    code,
+                    reason,
+                    violations,
+                }
+            }
+            ErrorKind::Unauthorized => ApiError::Unauthorized {
+                code,
+                reason: value.to_string(),
+            },
+            ErrorKind::Forbidden => match value {
+                CoreError::PermissionDenied { reason } => ApiError::Forbidden { reason },
+                other => ApiError::Forbidden {
+                    reason: other.to_string(),
+                },
+            },
+            ErrorKind::Internal => ApiError::Unknown {
                 reason: "an unexpected error occurred".to_string(),
             },
         }
@@ -109,7 +342,9 @@ impl From<CoreError> for ApiError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aether_core::organisation::plan::LimitResource;
     use axum::response::IntoResponse;
+    use uuid::Uuid;
 
     #[test]
     fn api_error_into_response_status_codes() {
@@ -133,6 +368,14 @@ mod tests {
         }
         .into_response();
         assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let response = ApiError::NotFound {
+            code: ErrorCode::DeploymentNotFound,
+            reason: "gone".to_string(),
+        }
+        .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[CONTENT_TYPE], PROBLEM_JSON);
     }
 
     #[test]
@@ -141,16 +384,67 @@ mod tests {
             organisation_name: "org".to_string(),
             reason: "bad".to_string(),
         };
-        assert!(matches!(ApiError::from(err), ApiError::BadRequest { .. }));
+        assert!(matches!(
+            ApiError::from(err),
+            ApiError::UnprocessableEntity {
+                code: ErrorCode::OrganisationCreationFailed,
+                ..
+            }
+        ));
 
         let err = CoreError::PermissionDenied {
             reason: "no".to_string(),
         };
         assert!(matches!(ApiError::from(err), ApiError::Forbidden { .. }));
 
+        let err = CoreError::InvalidActionTransition {
+            from: "published".to_string(),
+            to: "failed".to_string(),
+        };
+        assert!(matches!(
+            ApiError::from(err),
+            ApiError::Conflict {
+                code: ErrorCode::InvalidActionTransition,
+                ..
+            }
+        ));
+
+        let err = CoreError::DeploymentNotFound { id: Uuid::new_v4() };
+        assert!(matches!(
+            ApiError::from(err),
+            ApiError::NotFound {
+                code: ErrorCode::DeploymentNotFound,
+                ..
+            }
+        ));
+
         let err = CoreError::DatabaseError {
             message: "db".to_string(),
         };
         assert!(matches!(ApiError::from(err), ApiError::Unknown { .. }));
     }
+
+    #[test]
+    fn problem_details_serializes_code_and_violations() {
+        let err = ApiError::from(CoreError::PlanLimitsExceeded {
+            violations: vec![LimitViolation {
+                resource: LimitResource::Members,
+                limit: 2,
+                current: 5,
+                excess: 3,
+            }],
+        });
+        let problem = ProblemDetails::new(err.status(), err.code(), err.to_string());
+        let ApiError::UnprocessableEntity { violations, .. } = err else {
+            panic!("expected UnprocessableEntity");
+        };
+
+        let body = serde_json::to_value(problem.with_violations(violations)).unwrap();
+
+        assert_eq!(body["type"], "about:blank");
+        assert_eq!(body["title"], "Unprocessable Entity");
+        assert_eq!(body["status"], 422);
+        assert_eq!(body["code"], "E_PLAN_LIMITS_EXCEEDED");
+        assert_eq!(body["violations"][0]["excess"], 3);
+    }
 }
@@ -1,4 +1,5 @@
 use aether_core::{
+    CoreError,
     action::{Action, ActionId, ports::ActionService},
     deployments::ports::DeploymentService,
     organisation::OrganisationId,
@@ -9,7 +10,11 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Serialize, ToSchema, PartialEq)]
 pub struct GetActionResponse {
@@ -33,8 +38,8 @@ pub struct GetActionRoute {
     params(GetActionRoute),
     responses(
         (status = 200, description = "Action details", body = GetActionResponse),
-        (status = 400, description = "Action not found", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 404, description = "Deployment or action not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     )
 )]
 pub async fn get_action_handler(
@@ -57,9 +62,7 @@ pub async fn get_action_handler(
         .service
         .get_action(deployment_id, ActionId(action_id))
         .await?
-        .ok_or(ApiError::BadRequest {
-            reason: "action not found".to_string(),
-        })?;
+        .ok_or(CoreError::ActionNotFound { id: action_id })?;
 
     Ok(Response::OK(GetActionResponse { data: action }))
 }
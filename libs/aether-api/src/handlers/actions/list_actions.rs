@@ -13,7 +13,12 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{errors::ApiError, handlers::default_limit, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    handlers::default_limit,
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Serialize, ToSchema, PartialEq)]
 pub struct ListActionsResponse {
@@ -45,8 +50,8 @@ pub struct ListActionsRoute {
     params(ListActionsRoute, ListActionsQuery),
     responses(
         (status = 200, description = "List of actions", body = ListActionsResponse),
-        (status = 400, description = "Invalid query parameters", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid query parameters", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     )
 )]
 pub async fn list_actions_handler(
@@ -0,0 +1,109 @@
+use aether_auth::Identity;
+use aether_core::api_key::{ApiKey, commands::CreateApiKeyCommand, ports::ApiKeyService};
+use axum::{
+    Json,
+    extract::{Extension, State},
+};
+use axum_extra::routing::TypedPath;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateApiKeyRequest {
+    /// Name to recognise the key by
+    pub name: String,
+    /// Permission bits granted to the key; must be a subset of the caller's permissions
+    pub permissions: u64,
+    /// Create a personal access token owned by the caller instead of an organisation key
+    #[serde(default)]
+    pub personal: bool,
+    /// When the key stops working; keys without an expiry last until revoked
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct CreateApiKeyResponse {
+    data: ApiKey,
+    /// The key to authenticate with; it is only returned once
+    secret: String,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/api-keys")]
+pub struct CreateApiKeyRoute {
+    pub organisation_id: Uuid,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{organisation_id}/api-keys",
+    summary = "create api key",
+    tag = "api-keys",
+    description = "Create an API key for the specified organisation. Organisation keys require permission to manage the organisation; any member may create a personal access token. The secret is returned in this response only and is sent as a bearer token.",
+    request_body = CreateApiKeyRequest,
+    params(CreateApiKeyRoute),
+    responses(
+        (status = 201, description = "API key created successfully", body = CreateApiKeyResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to manage API keys or to grant the requested permissions", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid name, permissions or expiry", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_api_key_handler(
+    CreateApiKeyRoute { organisation_id }: CreateApiKeyRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<CreateApiKeyRequest>,
+) -> Result<Response<CreateApiKeyResponse>, ApiError> {
+    let mut command =
+        CreateApiKeyCommand::new(organisation_id.into(), request.name, request.permissions)
+            .with_personal(request.personal);
+    if let Some(expires_at) = request.expires_at {
+        command = command.with_expires_at(expires_at);
+    }
+
+    let issued = state.service.create_api_key(identity, command).await?;
+
+    Ok(Response::Created(CreateApiKeyResponse {
+        data: issued.api_key,
+        secret: issued.secret,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn create_api_key_maps_service_error() {
+        let result = create_api_key_handler(
+            CreateApiKeyRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+            Json(CreateApiKeyRequest {
+                name: "ci".to_string(),
+                permissions: 1,
+                personal: true,
+                expires_at: None,
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
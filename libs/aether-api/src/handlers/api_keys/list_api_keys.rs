@@ -0,0 +1,81 @@
+use aether_auth::Identity;
+use aether_core::api_key::{ApiKey, ports::ApiKeyService};
+use axum::extract::{Extension, State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct ListApiKeysResponse {
+    data: Vec<ApiKey>,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/api-keys")]
+pub struct ListApiKeysRoute {
+    pub organisation_id: Uuid,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{organisation_id}/api-keys",
+    summary = "list api keys",
+    tag = "api-keys",
+    description = "List the API keys of the specified organisation, most recent first. Callers who cannot manage the organisation only see their own personal access tokens. Secrets are never returned.",
+    params(ListApiKeysRoute),
+    responses(
+        (status = 200, description = "List of API keys", body = ListApiKeysResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Only users can list API keys", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_api_keys_handler(
+    ListApiKeysRoute { organisation_id }: ListApiKeysRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<ListApiKeysResponse>, ApiError> {
+    let api_keys = state
+        .service
+        .list_api_keys(identity, organisation_id.into())
+        .await?;
+
+    Ok(Response::OK(ListApiKeysResponse { data: api_keys }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aether_auth::Client;
+
+    use crate::test_helpers::app_state;
+
+    #[tokio::test]
+    async fn list_api_keys_rejects_client_identity() {
+        let result = list_api_keys_handler(
+            ListApiKeysRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(Identity::Client(Client {
+                id: "client-1".to_string(),
+                client_id: "ci".to_string(),
+                roles: vec![],
+                scopes: vec![],
+            })),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Forbidden { .. })));
+    }
+}
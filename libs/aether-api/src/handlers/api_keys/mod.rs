@@ -0,0 +1,49 @@
+use axum::{Router, middleware::from_fn_with_state};
+use axum_extra::routing::RouterExt;
+use utoipa::OpenApi;
+
+use crate::{
+    handlers::api_keys::{
+        create_api_key::{__path_create_api_key_handler, create_api_key_handler},
+        list_api_keys::{__path_list_api_keys_handler, list_api_keys_handler},
+        revoke_api_key::{__path_revoke_api_key_handler, revoke_api_key_handler},
+    },
+    router::service_auth_middleware,
+    state::AppState,
+};
+
+pub mod create_api_key;
+pub mod list_api_keys;
+pub mod revoke_api_key;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(list_api_keys_handler, create_api_key_handler, revoke_api_key_handler),
+    tags(
+        (name = "api-keys", description = "Organisation API keys and personal access tokens for automation.")
+    )
+)]
+pub struct ApiKeyApiDoc;
+
+pub fn api_key_routes(app_state: AppState) -> Router<AppState> {
+    Router::new()
+        .typed_get(list_api_keys_handler)
+        .typed_post(create_api_key_handler)
+        .typed_delete(revoke_api_key_handler)
+        .layer(from_fn_with_state(
+            app_state.clone(),
+            service_auth_middleware,
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::api_key_routes;
+    use crate::test_helpers::app_state;
+
+    #[tokio::test]
+    async fn api_key_routes_builds() {
+        let state = app_state();
+        let _router = api_key_routes(state);
+    }
+}
@@ -0,0 +1,81 @@
+use aether_auth::Identity;
+use aether_core::api_key::{ApiKey, ports::ApiKeyService};
+use axum::extract::{Extension, State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/api-keys/{api_key_id}")]
+pub struct RevokeApiKeyRoute {
+    pub organisation_id: Uuid,
+    pub api_key_id: Uuid,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct RevokeApiKeyResponse {
+    data: ApiKey,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{organisation_id}/api-keys/{api_key_id}",
+    summary = "revoke api key",
+    tag = "api-keys",
+    description = "Revoke an API key so it can no longer authenticate. Owners may revoke their personal access tokens; other keys require permission to manage the organisation.",
+    params(RevokeApiKeyRoute),
+    responses(
+        (status = 200, description = "API key revoked successfully", body = RevokeApiKeyResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to manage API keys", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "API key not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "API key already revoked", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn revoke_api_key_handler(
+    RevokeApiKeyRoute {
+        organisation_id,
+        api_key_id,
+    }: RevokeApiKeyRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RevokeApiKeyResponse>, ApiError> {
+    let api_key = state
+        .service
+        .revoke_api_key(identity, organisation_id.into(), api_key_id.into())
+        .await?;
+
+    Ok(Response::OK(RevokeApiKeyResponse { data: api_key }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn revoke_api_key_maps_service_error() {
+        let result = revoke_api_key_handler(
+            RevokeApiKeyRoute {
+                organisation_id: Uuid::new_v4(),
+                api_key_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
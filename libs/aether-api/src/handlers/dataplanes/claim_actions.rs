@@ -9,7 +9,11 @@ use axum_extra::routing::TypedPath;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(TypedPath, Deserialize)]
 #[typed_path("/dataplanes/{dataplane_id}/deployments/{deployment_id}/actions:claim")]
@@ -35,11 +39,12 @@ pub struct ClaimActionsRequest {
     summary = "claim actions",
     tag = "dataplanes",
     request_body = ClaimActionsRequest,
-    description = "Claim actions for the specified deployment on the dataplane.",
+    description = "Claim actions for the specified deployment on the dataplane. Claimed actions are marked as pulled by the calling agent until their lease expires.",
     responses(
         (status = 200, description = "Claimed actions", body = ClaimActionsResponse),
-        (status = 400, description = "Invalid dataplane or deployment id", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid dataplane or deployment id", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Caller is not an agent of the dataplane", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     )
 )]
 pub async fn claim_actions_handler(
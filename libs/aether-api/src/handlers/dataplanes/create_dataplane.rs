@@ -9,7 +9,11 @@ use axum_extra::routing::TypedPath;
 use serde::Deserialize;
 use utoipa::ToSchema;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(TypedPath)]
 #[typed_path("/dataplanes")]
@@ -31,8 +35,8 @@ pub struct CreateDataPlaneRequest {
     description = "Create a new dataplane with the specified configuration.",
     responses(
         (status = 200, description = "Created dataplane", body = DataPlane),
-        (status = 400, description = "Invalid request parameters", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid request parameters", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     )
 )]
 pub async fn create_dataplane_handler(
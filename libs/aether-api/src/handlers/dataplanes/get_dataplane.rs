@@ -6,7 +6,11 @@ use axum::{Extension, extract::State};
 use axum_extra::routing::TypedPath;
 use serde::Deserialize;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(TypedPath, Deserialize)]
 #[typed_path("/dataplanes/{dataplane_id}")]
@@ -22,8 +26,9 @@ pub struct GetDataPlaneRoute {
     description = "Get details of the specified dataplane.",
     responses(
         (status = 200, description = "Dataplane details", body = DataPlane),
-        (status = 400, description = "Invalid dataplane id", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid dataplane id", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Data plane not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     )
 )]
 pub async fn get_dataplane_handler(
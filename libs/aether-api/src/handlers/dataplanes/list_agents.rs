@@ -0,0 +1,76 @@
+use aether_auth::Identity;
+use aether_core::dataplane::{
+    entities::DataPlaneAgent, ports::DataPlaneService, value_objects::DataPlaneId,
+};
+use axum::{Extension, extract::State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/dataplanes/{dataplane_id}/agents")]
+pub struct ListAgentsRoute {
+    pub dataplane_id: DataPlaneId,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct ListAgentsResponse {
+    pub data: Vec<DataPlaneAgent>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{dataplane_id}/agents",
+    summary = "list dataplane agents",
+    tag = "dataplanes",
+    description = "List the service accounts registered as agents of the dataplane.",
+    params(ListAgentsRoute),
+    responses(
+        (status = 200, description = "Registered agents", body = ListAgentsResponse),
+        (status = 403, description = "Caller is not a platform administrator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Dataplane not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    )
+)]
+pub async fn list_agents_handler(
+    ListAgentsRoute { dataplane_id }: ListAgentsRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<ListAgentsResponse>, ApiError> {
+    let agents = state.service.list_agents(identity, dataplane_id).await?;
+
+    Ok(Response::OK(ListAgentsResponse { data: agents }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::app_state;
+    use aether_auth::Client;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn list_agents_rejects_clients() {
+        let result = list_agents_handler(
+            ListAgentsRoute {
+                dataplane_id: DataPlaneId(Uuid::new_v4()),
+            },
+            State(app_state()),
+            Extension(Identity::Client(Client {
+                id: "id".to_string(),
+                client_id: "herald-service".to_string(),
+                roles: vec![],
+                scopes: vec![],
+            })),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Forbidden { .. })));
+    }
+}
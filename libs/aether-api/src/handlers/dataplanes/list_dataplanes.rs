@@ -5,7 +5,11 @@ use axum_extra::routing::TypedPath;
 use serde::Serialize;
 use utoipa::ToSchema;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Serialize, ToSchema, PartialEq)]
 pub struct ListDataplanesResponse {
@@ -24,8 +28,8 @@ pub struct ListDataplanesRoute;
     description = "List all dataplanes accessible to the authenticated user.",
     responses(
         (status = 200, description = "List of dataplanes", body = ListDataplanesResponse),
-        (status = 401, description = "Unauthorized", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     ),
     security(
         ("bearer_auth" = [])
@@ -15,11 +15,16 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Serialize, ToSchema, PartialEq)]
 pub struct ListDeploymentsForDataPlaneResponse {
     pub data: Vec<Deployment>,
+    pub next_cursor: Option<String>,
 }
 
 #[derive(TypedPath, Deserialize)]
@@ -45,8 +50,9 @@ pub struct ListDeploymentsForDataPlaneQueryParams {
     params(ListDeploymentsForDataPlaneQueryParams),
     responses(
         (status = 200, description = "List of deployments for dataplane", body = ListDeploymentsForDataPlaneResponse),
-        (status = 400, description = "Invalid dataplane id", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid dataplane id", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Caller is not an agent of the dataplane", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     )
 )]
 pub async fn list_deployments_for_dataplane_handler(
@@ -68,14 +74,24 @@ pub async fn list_deployments_for_dataplane_handler(
         query.cursor,
     )
     .map_err(|reason| ApiError::BadRequest { reason })?;
+    let limit = command.limit;
 
     let deployments = state
         .service
         .get_deployments_in_dataplane(identity, dataplane_id, command)
         .await?;
 
+    let next_cursor = if deployments.len() == limit {
+        deployments
+            .last()
+            .map(|deployment| deployment.id.to_string())
+    } else {
+        None
+    };
+
     Ok(Response::OK(ListDeploymentsForDataPlaneResponse {
         data: deployments,
+        next_cursor,
     }))
 }
 
@@ -6,18 +6,28 @@ use crate::handlers::dataplanes::{
     claim_actions::{__path_claim_actions_handler, claim_actions_handler},
     create_dataplane::{__path_create_dataplane_handler, create_dataplane_handler},
     get_dataplane::{__path_get_dataplane_handler, get_dataplane_handler},
+    list_agents::{__path_list_agents_handler, list_agents_handler},
     list_dataplanes::{__path_list_dataplanes_handler, list_dataplanes_handler},
     list_deployments_for_dataplane::{
         __path_list_deployments_for_dataplane_handler, list_deployments_for_dataplane_handler,
     },
+    register_agent::{__path_register_agent_handler, register_agent_handler},
+    report_action_outcome::{
+        __path_ack_action_handler, __path_nack_action_handler, action_command_handler,
+    },
+    unregister_agent::{__path_unregister_agent_handler, unregister_agent_handler},
 };
 use crate::{router::service_auth_middleware, state::AppState};
 
 pub mod claim_actions;
 pub mod create_dataplane;
 pub mod get_dataplane;
+pub mod list_agents;
 pub mod list_dataplanes;
 pub mod list_deployments_for_dataplane;
+pub mod register_agent;
+pub mod report_action_outcome;
+pub mod unregister_agent;
 
 #[derive(OpenApi)]
 #[openapi(paths(
@@ -25,7 +35,12 @@ pub mod list_deployments_for_dataplane;
     get_dataplane_handler,
     list_deployments_for_dataplane_handler,
     claim_actions_handler,
-    create_dataplane_handler
+    ack_action_handler,
+    nack_action_handler,
+    create_dataplane_handler,
+    register_agent_handler,
+    list_agents_handler,
+    unregister_agent_handler
 ))]
 pub struct DataPlaneApiDoc;
 
@@ -36,6 +51,10 @@ pub fn dataplanes_routes(app_state: AppState) -> Router<AppState> {
         .typed_get(get_dataplane_handler)
         .typed_get(list_deployments_for_dataplane_handler)
         .typed_post(claim_actions_handler)
+        .typed_post(action_command_handler)
+        .typed_post(register_agent_handler)
+        .typed_get(list_agents_handler)
+        .typed_delete(unregister_agent_handler)
         .layer(from_fn_with_state(
             app_state.clone(),
             service_auth_middleware,
@@ -0,0 +1,90 @@
+use aether_auth::Identity;
+use aether_core::dataplane::{
+    entities::DataPlaneAgent,
+    ports::DataPlaneService,
+    value_objects::{DataPlaneId, RegisterDataPlaneAgentCommand},
+};
+use axum::{Extension, Json, extract::State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/dataplanes/{dataplane_id}/agents")]
+pub struct RegisterAgentRoute {
+    pub dataplane_id: DataPlaneId,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct RegisterAgentRequest {
+    /// `client_id` of the agent's service account, as `<issuer>#<client_id>` when the
+    /// account belongs to any trusted issuer but the first
+    pub client_id: String,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct RegisterAgentResponse {
+    pub data: DataPlaneAgent,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{dataplane_id}/agents",
+    summary = "register dataplane agent",
+    tag = "dataplanes",
+    request_body = RegisterAgentRequest,
+    description = "Allow a service account to act as an agent of the dataplane: list its deployments, claim their actions and report outcomes. Registering an agent twice is a no-op.",
+    params(RegisterAgentRoute),
+    responses(
+        (status = 201, description = "Registered agent", body = RegisterAgentResponse),
+        (status = 403, description = "Caller is not a platform administrator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Dataplane not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid client id", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    )
+)]
+pub async fn register_agent_handler(
+    RegisterAgentRoute { dataplane_id }: RegisterAgentRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<RegisterAgentRequest>,
+) -> Result<Response<RegisterAgentResponse>, ApiError> {
+    let command = RegisterDataPlaneAgentCommand::new(request.client_id)?;
+
+    let agent = state
+        .service
+        .register_agent(identity, dataplane_id, command)
+        .await?;
+
+    Ok(Response::Created(RegisterAgentResponse { data: agent }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn register_agent_rejects_blank_client_id() {
+        let result = register_agent_handler(
+            RegisterAgentRoute {
+                dataplane_id: DataPlaneId(Uuid::new_v4()),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+            Json(RegisterAgentRequest {
+                client_id: " ".to_string(),
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::UnprocessableEntity { .. })));
+    }
+}
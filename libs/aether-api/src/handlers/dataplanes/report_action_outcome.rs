@@ -0,0 +1,239 @@
+use aether_auth::Identity;
+use aether_core::{
+    action::{
+        Action, ActionFailureReason, ActionId,
+        commands::{ActionOutcome, ReportActionOutcomeCommand},
+        ports::ActionService,
+    },
+    dataplane::value_objects::DataPlaneId,
+    deployments::DeploymentId,
+};
+use axum::{Extension, Json, extract::State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    handlers::split_verb,
+    response::Response,
+    state::AppState,
+};
+
+/// Matches both `{action_id}:ack` and `{action_id}:nack`, split by [`split_verb`].
+#[derive(TypedPath, Deserialize)]
+#[typed_path("/dataplanes/{dataplane_id}/deployments/{deployment_id}/actions/{action_command}")]
+pub struct ActionCommandRoute {
+    pub dataplane_id: DataPlaneId,
+    pub deployment_id: DeploymentId,
+    pub action_command: String,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct ReportActionOutcomeResponse {
+    pub data: Action,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NackActionRequest {
+    pub reason: ActionFailureReason,
+}
+
+pub async fn action_command_handler(
+    ActionCommandRoute {
+        dataplane_id,
+        deployment_id,
+        action_command,
+    }: ActionCommandRoute,
+    state: State<AppState>,
+    identity: Extension<Identity>,
+    request: Option<Json<NackActionRequest>>,
+) -> Result<Response<ReportActionOutcomeResponse>, ApiError> {
+    let (action_id, verb) = split_verb(&action_command, "'{action_id}:ack' or '{action_id}:nack'")?;
+
+    let action_id = Uuid::parse_str(action_id)
+        .map(ActionId)
+        .map_err(|_| ApiError::BadRequest {
+            reason: "action id must be a valid UUID".to_string(),
+        })?;
+
+    let route = ActionOutcomeRoute {
+        dataplane_id,
+        deployment_id,
+        action_id,
+    };
+
+    match verb {
+        "ack" => ack_action_handler(route, state, identity).await,
+        "nack" => {
+            let request = request.ok_or_else(|| ApiError::BadRequest {
+                reason: "a JSON body with a failure reason is required".to_string(),
+            })?;
+            nack_action_handler(route, state, identity, request).await
+        }
+        other => Err(ApiError::BadRequest {
+            reason: format!("unknown action command '{other}'"),
+        }),
+    }
+}
+
+pub struct ActionOutcomeRoute {
+    pub dataplane_id: DataPlaneId,
+    pub deployment_id: DeploymentId,
+    pub action_id: ActionId,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{dataplane_id}/deployments/{deployment_id}/actions/{action_id}:ack",
+    summary = "acknowledge action",
+    tag = "dataplanes",
+    description = "Report that an action was published. Only leased or pulled actions can be acknowledged.",
+    responses(
+        (status = 200, description = "Acknowledged action", body = ReportActionOutcomeResponse),
+        (status = 400, description = "Invalid action id or unknown command", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Caller is not an agent of the dataplane, or another agent pulled the action", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Action not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Illegal status transition", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    )
+)]
+pub async fn ack_action_handler(
+    route: ActionOutcomeRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<ReportActionOutcomeResponse>, ApiError> {
+    report_action_outcome(state, identity, route, ActionOutcome::Published).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/{dataplane_id}/deployments/{deployment_id}/actions/{action_id}:nack",
+    summary = "reject action",
+    tag = "dataplanes",
+    request_body = NackActionRequest,
+    description = "Report that an action failed. Only leased or pulled actions can be rejected.",
+    responses(
+        (status = 200, description = "Rejected action", body = ReportActionOutcomeResponse),
+        (status = 400, description = "Invalid action id or unknown command", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Caller is not an agent of the dataplane, or another agent pulled the action", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Action not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Illegal status transition", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    )
+)]
+pub async fn nack_action_handler(
+    route: ActionOutcomeRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<NackActionRequest>,
+) -> Result<Response<ReportActionOutcomeResponse>, ApiError> {
+    report_action_outcome(
+        state,
+        identity,
+        route,
+        ActionOutcome::Failed {
+            reason: request.reason,
+        },
+    )
+    .await
+}
+
+async fn report_action_outcome(
+    state: AppState,
+    identity: Identity,
+    route: ActionOutcomeRoute,
+    outcome: ActionOutcome,
+) -> Result<Response<ReportActionOutcomeResponse>, ApiError> {
+    let action = state
+        .service
+        .report_action_outcome(
+            identity,
+            ReportActionOutcomeCommand {
+                dataplane_id: route.dataplane_id,
+                deployment_id: route.deployment_id,
+                action_id: route.action_id,
+                outcome,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(ReportActionOutcomeResponse { data: action }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::app_state;
+    use aether_auth::Client;
+
+    fn identity() -> Extension<Identity> {
+        Extension(Identity::Client(Client {
+            id: "id".to_string(),
+            client_id: "herald-service".to_string(),
+            roles: vec![],
+            scopes: vec![],
+        }))
+    }
+
+    fn route(action_command: impl Into<String>) -> ActionCommandRoute {
+        ActionCommandRoute {
+            dataplane_id: DataPlaneId(Uuid::new_v4()),
+            deployment_id: DeploymentId(Uuid::new_v4()),
+            action_command: action_command.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn action_command_rejects_unknown_verb() {
+        let result = action_command_handler(
+            route(format!("{}:retry", Uuid::new_v4())),
+            State(app_state()),
+            identity(),
+            None,
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest { .. })));
+    }
+
+    #[tokio::test]
+    async fn action_command_rejects_invalid_action_id() {
+        let result = action_command_handler(
+            route("not-a-uuid:ack"),
+            State(app_state()),
+            identity(),
+            None,
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest { .. })));
+    }
+
+    #[tokio::test]
+    async fn nack_requires_failure_reason() {
+        let result = action_command_handler(
+            route(format!("{}:nack", Uuid::new_v4())),
+            State(app_state()),
+            identity(),
+            None,
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest { .. })));
+    }
+
+    #[tokio::test]
+    async fn ack_maps_service_error() {
+        let result = action_command_handler(
+            route(format!("{}:ack", Uuid::new_v4())),
+            State(app_state()),
+            identity(),
+            None,
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
@@ -0,0 +1,76 @@
+use aether_auth::Identity;
+use aether_core::dataplane::{ports::DataPlaneService, value_objects::DataPlaneId};
+use axum::{Extension, extract::State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/dataplanes/{dataplane_id}/agents/{client_id}")]
+pub struct UnregisterAgentRoute {
+    pub dataplane_id: DataPlaneId,
+    pub client_id: String,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct UnregisterAgentResponse {
+    success: bool,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{dataplane_id}/agents/{client_id}",
+    summary = "unregister dataplane agent",
+    tag = "dataplanes",
+    description = "Revoke a service account's access to the dataplane agent routes.",
+    params(UnregisterAgentRoute),
+    responses(
+        (status = 200, description = "Agent unregistered", body = UnregisterAgentResponse),
+        (status = 403, description = "Caller is not a platform administrator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Dataplane not found or agent not registered on it", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    )
+)]
+pub async fn unregister_agent_handler(
+    UnregisterAgentRoute {
+        dataplane_id,
+        client_id,
+    }: UnregisterAgentRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<UnregisterAgentResponse>, ApiError> {
+    state
+        .service
+        .unregister_agent(identity, dataplane_id, client_id)
+        .await?;
+
+    Ok(Response::OK(UnregisterAgentResponse { success: true }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn unregister_agent_rejects_plain_users() {
+        let result = unregister_agent_handler(
+            UnregisterAgentRoute {
+                dataplane_id: DataPlaneId(Uuid::new_v4()),
+                client_id: "forge".to_string(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Forbidden { .. })));
+    }
+}
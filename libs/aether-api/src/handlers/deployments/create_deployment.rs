@@ -1,5 +1,6 @@
 use aether_auth::Identity;
 use aether_core::{
+    dataplane::value_objects::Region,
     deployments::{
         Deployment, DeploymentKind, DeploymentName, DeploymentStatus, DeploymentVersion,
         commands::CreateDeploymentCommand, ports::DeploymentService,
@@ -12,7 +13,11 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Deserialize, ToSchema)]
 pub struct CreateDeploymentRequest {
@@ -21,6 +26,8 @@ pub struct CreateDeploymentRequest {
     pub version: String,
     pub status: Option<String>,
     pub namespace: String,
+    /// Region to place the deployment in; any region when omitted
+    pub region: Option<String>,
 }
 
 #[derive(Serialize, ToSchema, PartialEq)]
@@ -34,6 +41,7 @@ struct ParsedCreateDeploymentRequest {
     version: String,
     status: DeploymentStatus,
     namespace: String,
+    region: Option<Region>,
 }
 
 impl TryFrom<CreateDeploymentRequest> for ParsedCreateDeploymentRequest {
@@ -60,6 +68,7 @@ impl TryFrom<CreateDeploymentRequest> for ParsedCreateDeploymentRequest {
             version: request.version,
             status,
             namespace: request.namespace,
+            region: request.region.map(Region::new),
         })
     }
 }
@@ -80,9 +89,10 @@ pub struct CreateDeploymentRoute {
     params(CreateDeploymentRoute),
     responses(
         (status = 201, description = "Deployment created successfully", body = CreateDeploymentResponse),
-        (status = 400, description = "Invalid request data", body = ApiError),
-        (status = 401, description = "Unauthorized", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid request data", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Organisation limit reached or no data plane fits the deployment", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     ),
     security(
         ("bearer_auth" = [])
@@ -104,7 +114,7 @@ pub async fn create_deployment_handler(
             })?;
     let parsed = ParsedCreateDeploymentRequest::try_from(request)?;
 
-    let command = CreateDeploymentCommand::new(
+    let mut command = CreateDeploymentCommand::new(
         organisation_id,
         DeploymentName(parsed.name),
         parsed.kind,
@@ -113,6 +123,9 @@ pub async fn create_deployment_handler(
         parsed.namespace,
         created_by,
     );
+    if let Some(region) = parsed.region {
+        command = command.with_region(region);
+    }
 
     let deployment = state.service.create_deployment(command).await?;
 
@@ -136,6 +149,7 @@ mod tests {
             version: "1.0.0".to_string(),
             status: None,
             namespace: "default".to_string(),
+            region: None,
         };
 
         let result = create_deployment_handler(
@@ -161,6 +175,7 @@ mod tests {
             version: "1.0.0".to_string(),
             status: Some("bad".to_string()),
             namespace: "default".to_string(),
+            region: None,
         };
 
         let result = create_deployment_handler(
@@ -186,6 +201,7 @@ mod tests {
             version: "1.0.0".to_string(),
             status: None,
             namespace: "default".to_string(),
+            region: None,
         };
 
         let result = create_deployment_handler(
@@ -209,6 +225,7 @@ mod tests {
             version: "1.0.0".to_string(),
             status: None,
             namespace: "default".to_string(),
+            region: None,
         };
 
         let parsed = ParsedCreateDeploymentRequest::try_from(request).unwrap();
@@ -1,10 +1,15 @@
+use aether_auth::Identity;
 use aether_core::deployments::ports::DeploymentService;
-use axum::extract::State;
+use axum::{Extension, extract::State};
 use axum_extra::routing::TypedPath;
 use utoipa::IntoParams;
 use uuid::Uuid;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -30,9 +35,10 @@ pub struct DeleteDeploymentResponse {
     params(DeleteDeploymentRoute),
     responses(
         (status = 200, description = "Deployment deleted successfully", body = DeleteDeploymentResponse),
-        (status = 400, description = "Deployment not found", body = ApiError),
-        (status = 401, description = "Unauthorized", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Deployment not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Deployment already being deleted", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     ),
     security(
         ("bearer_auth" = [])
@@ -44,17 +50,15 @@ pub async fn delete_deployment_handler(
         deployment_id,
     }: DeleteDeploymentRoute,
     State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
 ) -> Result<Response<DeleteDeploymentResponse>, ApiError> {
     let organisation_id = organisation_id.into();
     let deployment_id = deployment_id.into();
 
     state
         .service
-        .delete_deployment_for_organisation(organisation_id, deployment_id)
-        .await
-        .map_err(|_| ApiError::BadRequest {
-            reason: "deployment not found".to_string(),
-        })?;
+        .delete_deployment_for_organisation(organisation_id, deployment_id, identity)
+        .await?;
 
     Ok(Response::OK(DeleteDeploymentResponse { success: true }))
 }
@@ -62,10 +66,10 @@ pub async fn delete_deployment_handler(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_helpers::app_state;
+    use crate::test_helpers::{app_state, user_identity};
 
     #[tokio::test]
-    async fn delete_deployment_maps_service_error_to_bad_request() {
+    async fn delete_deployment_maps_service_error() {
         let state = app_state();
 
         let result = delete_deployment_handler(
@@ -74,9 +78,10 @@ mod tests {
                 deployment_id: Uuid::new_v4(),
             },
             State(state),
+            Extension(user_identity("9f3f7a4d-52a3-4a1a-9b3f-0c1b9b7d9a6f")),
         )
         .await;
 
-        assert!(matches!(result, Err(ApiError::BadRequest { .. })));
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
     }
 }
@@ -5,7 +5,11 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Serialize, ToSchema, PartialEq)]
 pub struct GetDeploymentResponse {
@@ -28,9 +32,9 @@ pub struct GetDeploymentRoute {
     params(GetDeploymentRoute),
     responses(
         (status = 200, description = "Deployment details", body = GetDeploymentResponse),
-        (status = 400, description = "Deployment not found", body = ApiError),
-        (status = 401, description = "Unauthorized", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Deployment not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     ),
     security(
         ("bearer_auth" = [])
@@ -49,10 +53,7 @@ pub async fn get_deployment_handler(
     let deployment = state
         .service
         .get_deployment_for_organisation(organisation_id, deployment_id)
-        .await
-        .map_err(|_| ApiError::BadRequest {
-            reason: "deployment not found".to_string(),
-        })?;
+        .await?;
 
     Ok(Response::OK(GetDeploymentResponse { data: deployment }))
 }
@@ -63,7 +64,7 @@ mod tests {
     use crate::test_helpers::app_state;
 
     #[tokio::test]
-    async fn get_deployment_maps_service_error_to_bad_request() {
+    async fn get_deployment_maps_service_error() {
         let state = app_state();
 
         let result = get_deployment_handler(
@@ -75,6 +76,6 @@ mod tests {
         )
         .await;
 
-        assert!(matches!(result, Err(ApiError::BadRequest { .. })));
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
     }
 }
@@ -0,0 +1,81 @@
+use aether_core::deployments::{DeploymentStatusChange, ports::DeploymentService};
+use axum::extract::State;
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct GetDeploymentHistoryResponse {
+    data: Vec<DeploymentStatusChange>,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/deployments/{deployment_id}/history")]
+pub struct GetDeploymentHistoryRoute {
+    pub organisation_id: Uuid,
+    pub deployment_id: Uuid,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{organisation_id}/deployments/{deployment_id}/history",
+    summary = "get deployment history",
+    tag = "deployments",
+    description = "List the status changes of a deployment within the specified organisation, oldest first.",
+    params(GetDeploymentHistoryRoute),
+    responses(
+        (status = 200, description = "Deployment status history", body = GetDeploymentHistoryResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Deployment not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_deployment_history_handler(
+    GetDeploymentHistoryRoute {
+        organisation_id,
+        deployment_id,
+    }: GetDeploymentHistoryRoute,
+    State(state): State<AppState>,
+) -> Result<Response<GetDeploymentHistoryResponse>, ApiError> {
+    let organisation_id = organisation_id.into();
+    let deployment_id = deployment_id.into();
+
+    let history = state
+        .service
+        .get_deployment_status_history_for_organisation(organisation_id, deployment_id)
+        .await?;
+
+    Ok(Response::OK(GetDeploymentHistoryResponse { data: history }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::app_state;
+
+    #[tokio::test]
+    async fn get_deployment_history_maps_service_error() {
+        let state = app_state();
+
+        let result = get_deployment_history_handler(
+            GetDeploymentHistoryRoute {
+                organisation_id: Uuid::new_v4(),
+                deployment_id: Uuid::new_v4(),
+            },
+            State(state),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
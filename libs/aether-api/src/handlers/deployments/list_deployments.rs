@@ -1,17 +1,28 @@
 use aether_core::deployments::{Deployment, ports::DeploymentService};
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum_extra::routing::TypedPath;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Serialize, ToSchema, PartialEq)]
 pub struct ListDeploymentsResponse {
     data: Vec<Deployment>,
 }
 
+#[derive(Deserialize, IntoParams)]
+pub struct ListDeploymentsQuery {
+    /// Include deleted deployments that have not been purged yet
+    #[serde(default)]
+    include_deleted: bool,
+}
+
 #[derive(TypedPath, IntoParams, Deserialize)]
 #[typed_path("/organisations/{organisation_id}/deployments")]
 pub struct ListDeploymentsRoute {
@@ -23,23 +34,24 @@ pub struct ListDeploymentsRoute {
     path = "/{organisation_id}/deployments",
     summary = "list deployments",
     tag = "deployments",
-    description = "List deployments for the specified organisation.",
-    params(ListDeploymentsRoute),
+    description = "List deployments for the specified organisation. Deleted deployments are hidden unless `include_deleted` is set.",
+    params(ListDeploymentsRoute, ListDeploymentsQuery),
     responses(
         (status = 200, description = "List of deployments", body = ListDeploymentsResponse),
-        (status = 400, description = "Invalid organisation id", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid organisation id", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     )
 )]
 pub async fn list_deployments_handler(
     ListDeploymentsRoute { organisation_id }: ListDeploymentsRoute,
     State(state): State<AppState>,
+    Query(query): Query<ListDeploymentsQuery>,
 ) -> Result<Response<ListDeploymentsResponse>, ApiError> {
     let organisation_id = organisation_id.into();
 
     let deployments = state
         .service
-        .list_deployments_by_organisation(organisation_id)
+        .list_deployments_by_organisation(organisation_id, query.include_deleted)
         .await?;
 
     Ok(Response::OK(ListDeploymentsResponse { data: deployments }))
@@ -59,6 +71,9 @@ mod tests {
                 organisation_id: Uuid::new_v4(),
             },
             State(state),
+            Query(ListDeploymentsQuery {
+                include_deleted: false,
+            }),
         )
         .await;
 
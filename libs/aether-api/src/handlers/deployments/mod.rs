@@ -7,7 +7,11 @@ use crate::{
         create_deployment::{__path_create_deployment_handler, create_deployment_handler},
         delete_deployment::{__path_delete_deployment_handler, delete_deployment_handler},
         get_deployment::{__path_get_deployment_handler, get_deployment_handler},
+        get_deployment_history::{
+            __path_get_deployment_history_handler, get_deployment_history_handler,
+        },
         list_deployments::{__path_list_deployments_handler, list_deployments_handler},
+        restore_deployment::{__path_restore_deployment_handler, restore_deployment_handler},
         update_deployment::{__path_update_deployment_handler, update_deployment_handler},
     },
     router::service_auth_middleware,
@@ -17,7 +21,9 @@ use crate::{
 pub mod create_deployment;
 pub mod delete_deployment;
 pub mod get_deployment;
+pub mod get_deployment_history;
 pub mod list_deployments;
+pub mod restore_deployment;
 pub mod update_deployment;
 
 #[derive(OpenApi)]
@@ -28,6 +34,8 @@ pub mod update_deployment;
         get_deployment_handler,
         update_deployment_handler,
         delete_deployment_handler,
+        get_deployment_history_handler,
+        restore_deployment_handler,
     ),
     tags(
         (name = "deployments", description = "Deployment management endpoints scoped to organisations.")
@@ -42,6 +50,8 @@ pub fn deployment_routes(app_state: AppState) -> Router<AppState> {
         .typed_get(get_deployment_handler)
         .typed_patch(update_deployment_handler)
         .typed_delete(delete_deployment_handler)
+        .typed_get(get_deployment_history_handler)
+        .typed_post(restore_deployment_handler)
         .layer(from_fn_with_state(
             app_state.clone(),
             service_auth_middleware,
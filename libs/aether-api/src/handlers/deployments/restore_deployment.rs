@@ -0,0 +1,120 @@
+use aether_auth::Identity;
+use aether_core::deployments::{Deployment, DeploymentId, ports::DeploymentService};
+use axum::{Extension, extract::State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    handlers::split_verb,
+    response::Response,
+    state::AppState,
+};
+
+/// Matches `{deployment_id}:restore`, split by [`split_verb`].
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/deployments/{deployment_id}")]
+pub struct RestoreDeploymentRoute {
+    pub organisation_id: Uuid,
+    pub deployment_id: String,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct RestoreDeploymentResponse {
+    data: Deployment,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{organisation_id}/deployments/{deployment_id}:restore",
+    summary = "restore deployment",
+    tag = "deployments",
+    description = "Restore a deleted deployment that is still within its grace period. The deployment returns to the status it had before it was deleted.",
+    params(
+        ("organisation_id" = Uuid, Path, description = "Organisation id"),
+        ("deployment_id" = Uuid, Path, description = "Deployment id")
+    ),
+    responses(
+        (status = 200, description = "Deployment restored successfully", body = RestoreDeploymentResponse),
+        (status = 400, description = "Malformed deployment command", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Deployment not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Deployment not deleted, already purged or past its grace period", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_deployment_handler(
+    RestoreDeploymentRoute {
+        organisation_id,
+        deployment_id,
+    }: RestoreDeploymentRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RestoreDeploymentResponse>, ApiError> {
+    let (deployment_id, verb) = split_verb(&deployment_id, "'{deployment_id}:restore'")?;
+
+    if verb != "restore" {
+        return Err(ApiError::BadRequest {
+            reason: format!("unknown deployment command '{verb}'"),
+        });
+    }
+
+    let deployment_id = Uuid::parse_str(deployment_id)
+        .map(DeploymentId)
+        .map_err(|_| ApiError::BadRequest {
+            reason: "deployment id must be a valid UUID".to_string(),
+        })?;
+
+    let deployment = state
+        .service
+        .restore_deployment_for_organisation(organisation_id.into(), deployment_id, identity)
+        .await?;
+
+    Ok(Response::OK(RestoreDeploymentResponse { data: deployment }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    async fn restore(
+        deployment_id: String,
+    ) -> Result<Response<RestoreDeploymentResponse>, ApiError> {
+        restore_deployment_handler(
+            RestoreDeploymentRoute {
+                organisation_id: Uuid::new_v4(),
+                deployment_id,
+            },
+            State(app_state()),
+            Extension(user_identity("9f3f7a4d-52a3-4a1a-9b3f-0c1b9b7d9a6f")),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn restore_deployment_rejects_unknown_verb() {
+        let result = restore(format!("{}:resume", Uuid::new_v4())).await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest { .. })));
+    }
+
+    #[tokio::test]
+    async fn restore_deployment_rejects_invalid_id() {
+        let result = restore("not-a-uuid:restore".to_string()).await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest { .. })));
+    }
+
+    #[tokio::test]
+    async fn restore_deployment_maps_service_error() {
+        let result = restore(format!("{}:restore", Uuid::new_v4())).await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
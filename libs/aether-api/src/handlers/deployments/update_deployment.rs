@@ -1,15 +1,23 @@
-use aether_core::deployments::{
-    Deployment, DeploymentKind, DeploymentName, DeploymentStatus, DeploymentVersion,
-    commands::UpdateDeploymentCommand, ports::DeploymentService,
+use aether_auth::Identity;
+use aether_core::{
+    CoreError,
+    deployments::{
+        Deployment, DeploymentKind, DeploymentName, DeploymentStatus, DeploymentVersion,
+        commands::UpdateDeploymentCommand, ports::DeploymentService,
+    },
 };
-use axum::{Json, extract::State};
+use axum::{Extension, Json, extract::State};
 use axum_extra::routing::TypedPath;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Deserialize, ToSchema)]
 pub struct UpdateDeploymentRequest {
@@ -19,6 +27,8 @@ pub struct UpdateDeploymentRequest {
     pub status: Option<String>,
     pub namespace: Option<String>,
     pub deployed_at: Option<String>,
+    /// Why the status changes, recorded in the deployment history
+    pub reason: Option<String>,
 }
 
 #[derive(Serialize, ToSchema, PartialEq)]
@@ -43,9 +53,11 @@ pub struct UpdateDeploymentRoute {
     params(UpdateDeploymentRoute),
     responses(
         (status = 200, description = "Deployment updated successfully", body = UpdateDeploymentResponse),
-        (status = 400, description = "Invalid request data", body = ApiError),
-        (status = 401, description = "Unauthorized", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid request data", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Deployment not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Illegal status transition", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     ),
     security(
         ("bearer_auth" = [])
@@ -57,12 +69,13 @@ pub async fn update_deployment_handler(
         deployment_id,
     }: UpdateDeploymentRoute,
     State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
     Json(request): Json<UpdateDeploymentRequest>,
 ) -> Result<Response<UpdateDeploymentResponse>, ApiError> {
     let organisation_id = organisation_id.into();
     let deployment_id = deployment_id.into();
 
-    let mut command = UpdateDeploymentCommand::new();
+    let mut command = UpdateDeploymentCommand::new().with_actor(identity);
     if let Some(name) = request.name {
         command = command.with_name(DeploymentName(name));
     }
@@ -93,13 +106,19 @@ pub async fn update_deployment_handler(
             .with_timezone(&Utc);
         command = command.with_deployed_at(Some(parsed));
     }
+    if let Some(reason) = request.reason {
+        command = command.with_reason(reason);
+    }
 
     let deployment = state
         .service
         .update_deployment_for_organisation(organisation_id, deployment_id, command)
         .await
-        .map_err(|_| ApiError::BadRequest {
-            reason: "deployment not found".to_string(),
+        .map_err(|e| match e {
+            CoreError::InvalidDeploymentTransition { .. } => ApiError::from(e),
+            _ => ApiError::BadRequest {
+                reason: "deployment not found".to_string(),
+            },
         })?;
 
     Ok(Response::OK(UpdateDeploymentResponse { data: deployment }))
@@ -108,7 +127,7 @@ pub async fn update_deployment_handler(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_helpers::app_state;
+    use crate::test_helpers::{app_state, user_identity};
 
     #[tokio::test]
     async fn update_deployment_rejects_invalid_kind() {
@@ -120,6 +139,7 @@ mod tests {
             status: None,
             namespace: None,
             deployed_at: None,
+            reason: None,
         };
 
         let result = update_deployment_handler(
@@ -128,6 +148,7 @@ mod tests {
                 deployment_id: Uuid::new_v4(),
             },
             State(state),
+            Extension(user_identity("9f3f7a4d-52a3-4a1a-9b3f-0c1b9b7d9a6f")),
             Json(request),
         )
         .await;
@@ -145,6 +166,7 @@ mod tests {
             status: Some("bad".to_string()),
             namespace: None,
             deployed_at: None,
+            reason: None,
         };
 
         let result = update_deployment_handler(
@@ -153,6 +175,7 @@ mod tests {
                 deployment_id: Uuid::new_v4(),
             },
             State(state),
+            Extension(user_identity("9f3f7a4d-52a3-4a1a-9b3f-0c1b9b7d9a6f")),
             Json(request),
         )
         .await;
@@ -170,6 +193,7 @@ mod tests {
             status: None,
             namespace: None,
             deployed_at: Some("not-a-date".to_string()),
+            reason: None,
         };
 
         let result = update_deployment_handler(
@@ -178,6 +202,7 @@ mod tests {
                 deployment_id: Uuid::new_v4(),
             },
             State(state),
+            Extension(user_identity("9f3f7a4d-52a3-4a1a-9b3f-0c1b9b7d9a6f")),
             Json(request),
         )
         .await;
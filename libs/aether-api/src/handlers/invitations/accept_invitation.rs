@@ -0,0 +1,83 @@
+use aether_auth::Identity;
+use aether_core::{invitation::ports::InvitationService, organisation::member::Member};
+use axum::{
+    Json,
+    extract::{Extension, State},
+};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Deserialize, ToSchema)]
+pub struct AcceptInvitationRequest {
+    /// Token received with the invitation
+    pub token: String,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct AcceptInvitationResponse {
+    data: Member,
+}
+
+#[derive(TypedPath, Deserialize)]
+#[typed_path("/invitations/accept")]
+pub struct AcceptInvitationRoute;
+
+#[utoipa::path(
+    post,
+    path = "/accept",
+    summary = "accept invitation",
+    tag = "invitations",
+    description = "Accept an invitation with its token, joining the organisation as the authenticated user. The token can only be used once and only by the invited email address.",
+    request_body = AcceptInvitationRequest,
+    responses(
+        (status = 200, description = "Invitation accepted; the caller is now a member", body = AcceptInvitationResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The invitation was sent to another email address", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid, expired or already used token, or user limit reached", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn accept_invitation_handler(
+    _: AcceptInvitationRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<AcceptInvitationRequest>,
+) -> Result<Response<AcceptInvitationResponse>, ApiError> {
+    let member = state
+        .service
+        .accept_invitation(identity, request.token)
+        .await?;
+
+    Ok(Response::OK(AcceptInvitationResponse { data: member }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn accept_invitation_maps_service_error() {
+        let result = accept_invitation_handler(
+            AcceptInvitationRoute,
+            State(app_state()),
+            Extension(user_identity("user-123")),
+            Json(AcceptInvitationRequest {
+                token: "inv_token".to_string(),
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
@@ -0,0 +1,113 @@
+use aether_auth::Identity;
+use aether_core::{
+    invitation::{Invitation, commands::CreateInvitationCommand, ports::InvitationService},
+    role::RoleId,
+};
+use axum::{
+    Json,
+    extract::{Extension, State},
+};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateInvitationRequest {
+    /// Email address to send the invitation to
+    pub email: String,
+    /// Roles assigned to the invitee once the invitation is accepted
+    #[serde(default)]
+    pub role_ids: Vec<Uuid>,
+    /// Lifetime of the invitation, 7 days when omitted
+    pub ttl_seconds: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct CreateInvitationResponse {
+    data: Invitation,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/invitations")]
+pub struct CreateInvitationRoute {
+    pub organisation_id: Uuid,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{organisation_id}/invitations",
+    summary = "create invitation",
+    tag = "invitations",
+    description = "Invite an email address to join the specified organisation with preselected roles. A single-use token is sent to the invitee; it is never returned by the API.",
+    request_body = CreateInvitationRequest,
+    params(CreateInvitationRoute),
+    responses(
+        (status = 201, description = "Invitation created successfully", body = CreateInvitationResponse),
+        (status = 400, description = "Invalid expiry", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to invite members", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Unknown role", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Invitation already pending", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid email or user limit reached", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_invitation_handler(
+    CreateInvitationRoute { organisation_id }: CreateInvitationRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<CreateInvitationRequest>,
+) -> Result<Response<CreateInvitationResponse>, ApiError> {
+    let mut command = CreateInvitationCommand::new(organisation_id.into(), request.email)
+        .with_role_ids(request.role_ids.into_iter().map(RoleId).collect());
+    if let Some(ttl_seconds) = request.ttl_seconds {
+        let ttl = i64::try_from(ttl_seconds)
+            .ok()
+            .and_then(chrono::Duration::try_seconds)
+            .ok_or_else(|| ApiError::BadRequest {
+                reason: "ttl_seconds is out of range".to_string(),
+            })?;
+        command = command.with_ttl(ttl);
+    }
+
+    let invitation = state.service.create_invitation(identity, command).await?;
+
+    Ok(Response::Created(CreateInvitationResponse {
+        data: invitation,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn create_invitation_maps_service_error() {
+        let result = create_invitation_handler(
+            CreateInvitationRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+            Json(CreateInvitationRequest {
+                email: "invitee@example.com".to_string(),
+                role_ids: vec![],
+                ttl_seconds: None,
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
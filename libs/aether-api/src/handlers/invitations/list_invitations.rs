@@ -0,0 +1,74 @@
+use aether_auth::Identity;
+use aether_core::invitation::{Invitation, ports::InvitationService};
+use axum::extract::{Extension, State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct ListInvitationsResponse {
+    data: Vec<Invitation>,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/invitations")]
+pub struct ListInvitationsRoute {
+    pub organisation_id: Uuid,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{organisation_id}/invitations",
+    summary = "list invitations",
+    tag = "invitations",
+    description = "List the invitations of the specified organisation, most recent first.",
+    params(ListInvitationsRoute),
+    responses(
+        (status = 200, description = "List of invitations", body = ListInvitationsResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to view members", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_invitations_handler(
+    ListInvitationsRoute { organisation_id }: ListInvitationsRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<ListInvitationsResponse>, ApiError> {
+    let invitations = state
+        .service
+        .list_invitations(identity, organisation_id.into())
+        .await?;
+
+    Ok(Response::OK(ListInvitationsResponse { data: invitations }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn list_invitations_maps_service_error() {
+        let result = list_invitations_handler(
+            ListInvitationsRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
@@ -0,0 +1,60 @@
+use axum::{Router, middleware::from_fn_with_state};
+use axum_extra::routing::RouterExt;
+use utoipa::OpenApi;
+
+use crate::{
+    handlers::invitations::{
+        accept_invitation::{__path_accept_invitation_handler, accept_invitation_handler},
+        create_invitation::{__path_create_invitation_handler, create_invitation_handler},
+        list_invitations::{__path_list_invitations_handler, list_invitations_handler},
+        revoke_invitation::{__path_revoke_invitation_handler, revoke_invitation_handler},
+    },
+    router::service_auth_middleware,
+    state::AppState,
+};
+
+pub mod accept_invitation;
+pub mod create_invitation;
+pub mod list_invitations;
+pub mod revoke_invitation;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        list_invitations_handler,
+        create_invitation_handler,
+        revoke_invitation_handler,
+    ),
+    tags(
+        (name = "invitations", description = "Email invitations to join an organisation.")
+    )
+)]
+pub struct InvitationApiDoc;
+
+#[derive(OpenApi)]
+#[openapi(paths(accept_invitation_handler))]
+pub struct InvitationAcceptApiDoc;
+
+pub fn invitation_routes(app_state: AppState) -> Router<AppState> {
+    Router::new()
+        .typed_get(list_invitations_handler)
+        .typed_post(create_invitation_handler)
+        .typed_delete(revoke_invitation_handler)
+        .typed_post(accept_invitation_handler)
+        .layer(from_fn_with_state(
+            app_state.clone(),
+            service_auth_middleware,
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::invitation_routes;
+    use crate::test_helpers::app_state;
+
+    #[tokio::test]
+    async fn invitation_routes_builds() {
+        let state = app_state();
+        let _router = invitation_routes(state);
+    }
+}
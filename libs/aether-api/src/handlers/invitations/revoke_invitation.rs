@@ -0,0 +1,81 @@
+use aether_auth::Identity;
+use aether_core::invitation::{Invitation, ports::InvitationService};
+use axum::extract::{Extension, State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/invitations/{invitation_id}")]
+pub struct RevokeInvitationRoute {
+    pub organisation_id: Uuid,
+    pub invitation_id: Uuid,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct RevokeInvitationResponse {
+    data: Invitation,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{organisation_id}/invitations/{invitation_id}",
+    summary = "revoke invitation",
+    tag = "invitations",
+    description = "Revoke a pending invitation so its token can no longer be accepted.",
+    params(RevokeInvitationRoute),
+    responses(
+        (status = 200, description = "Invitation revoked successfully", body = RevokeInvitationResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to invite members", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Invitation not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Invitation no longer pending", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn revoke_invitation_handler(
+    RevokeInvitationRoute {
+        organisation_id,
+        invitation_id,
+    }: RevokeInvitationRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RevokeInvitationResponse>, ApiError> {
+    let invitation = state
+        .service
+        .revoke_invitation(identity, organisation_id.into(), invitation_id.into())
+        .await?;
+
+    Ok(Response::OK(RevokeInvitationResponse { data: invitation }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn revoke_invitation_maps_service_error() {
+        let result = revoke_invitation_handler(
+            RevokeInvitationRoute {
+                organisation_id: Uuid::new_v4(),
+                invitation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
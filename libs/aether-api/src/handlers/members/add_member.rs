@@ -0,0 +1,96 @@
+use aether_auth::Identity;
+use aether_core::{
+    organisation::{commands::AddMemberCommand, member::Member, ports::OrganisationService},
+    user::UserId,
+};
+use axum::{
+    Json,
+    extract::{Extension, State},
+};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Deserialize, ToSchema)]
+pub struct AddMemberRequest {
+    /// Id of an existing user to add to the organisation
+    pub user_id: Uuid,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct AddMemberResponse {
+    data: Member,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/members")]
+pub struct AddMemberRoute {
+    pub organisation_id: Uuid,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{organisation_id}/members",
+    summary = "add member",
+    tag = "members",
+    description = "Add an existing user to the specified organisation, within the `max_users` limit of its plan.",
+    request_body = AddMemberRequest,
+    params(AddMemberRoute),
+    responses(
+        (status = 201, description = "Member added successfully", body = AddMemberResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to add members", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Unknown user", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "User is already a member", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "User limit reached", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn add_member_handler(
+    AddMemberRoute { organisation_id }: AddMemberRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<AddMemberRequest>,
+) -> Result<Response<AddMemberResponse>, ApiError> {
+    let command = AddMemberCommand::new(UserId(request.user_id));
+
+    let member = state
+        .service
+        .add_member(identity, organisation_id.into(), command)
+        .await?;
+
+    Ok(Response::Created(AddMemberResponse { data: member }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn add_member_maps_service_error() {
+        let result = add_member_handler(
+            AddMemberRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+            Json(AddMemberRequest {
+                user_id: Uuid::new_v4(),
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
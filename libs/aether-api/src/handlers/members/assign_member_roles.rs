@@ -0,0 +1,99 @@
+use aether_auth::Identity;
+use aether_core::{
+    organisation::{
+        commands::AssignMemberRolesCommand, member::Member, ports::OrganisationService,
+    },
+    role::RoleId,
+};
+use axum::{
+    Json,
+    extract::{Extension, State},
+};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Deserialize, ToSchema)]
+pub struct AssignMemberRolesRequest {
+    /// Roles the member should hold; roles not listed are unassigned
+    pub role_ids: Vec<Uuid>,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct AssignMemberRolesResponse {
+    data: Member,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/members/{member_id}/roles")]
+pub struct AssignMemberRolesRoute {
+    pub organisation_id: Uuid,
+    pub member_id: Uuid,
+}
+
+#[utoipa::path(
+    put,
+    path = "/{organisation_id}/members/{member_id}/roles",
+    summary = "assign member roles",
+    tag = "members",
+    description = "Replace the roles assigned to a member. Only global roles and roles of the organisation can be assigned.",
+    request_body = AssignMemberRolesRequest,
+    params(AssignMemberRolesRoute),
+    responses(
+        (status = 200, description = "Roles assigned successfully", body = AssignMemberRolesResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to manage members", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Member or role not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn assign_member_roles_handler(
+    AssignMemberRolesRoute {
+        organisation_id,
+        member_id,
+    }: AssignMemberRolesRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<AssignMemberRolesRequest>,
+) -> Result<Response<AssignMemberRolesResponse>, ApiError> {
+    let command = AssignMemberRolesCommand::new(request.role_ids.into_iter().map(RoleId).collect());
+
+    let member = state
+        .service
+        .assign_member_roles(identity, organisation_id.into(), member_id.into(), command)
+        .await?;
+
+    Ok(Response::OK(AssignMemberRolesResponse { data: member }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn assign_member_roles_maps_service_error() {
+        let result = assign_member_roles_handler(
+            AssignMemberRolesRoute {
+                organisation_id: Uuid::new_v4(),
+                member_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+            Json(AssignMemberRolesRequest { role_ids: vec![] }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
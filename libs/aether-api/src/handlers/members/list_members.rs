@@ -0,0 +1,74 @@
+use aether_auth::Identity;
+use aether_core::organisation::{member::Member, ports::OrganisationService};
+use axum::extract::{Extension, State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct ListMembersResponse {
+    data: Vec<Member>,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/members")]
+pub struct ListMembersRoute {
+    pub organisation_id: Uuid,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{organisation_id}/members",
+    summary = "list members",
+    tag = "members",
+    description = "List the members of the specified organisation with their assigned roles.",
+    params(ListMembersRoute),
+    responses(
+        (status = 200, description = "List of members", body = ListMembersResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to view members", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_members_handler(
+    ListMembersRoute { organisation_id }: ListMembersRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<ListMembersResponse>, ApiError> {
+    let members = state
+        .service
+        .list_members(identity, organisation_id.into())
+        .await?;
+
+    Ok(Response::OK(ListMembersResponse { data: members }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn list_members_maps_service_error() {
+        let result = list_members_handler(
+            ListMembersRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
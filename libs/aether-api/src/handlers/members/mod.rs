@@ -0,0 +1,57 @@
+use axum::{Router, middleware::from_fn_with_state};
+use axum_extra::routing::RouterExt;
+use utoipa::OpenApi;
+
+use crate::{
+    handlers::members::{
+        add_member::{__path_add_member_handler, add_member_handler},
+        assign_member_roles::{__path_assign_member_roles_handler, assign_member_roles_handler},
+        list_members::{__path_list_members_handler, list_members_handler},
+        remove_member::{__path_remove_member_handler, remove_member_handler},
+    },
+    router::service_auth_middleware,
+    state::AppState,
+};
+
+pub mod add_member;
+pub mod assign_member_roles;
+pub mod list_members;
+pub mod remove_member;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        list_members_handler,
+        add_member_handler,
+        remove_member_handler,
+        assign_member_roles_handler,
+    ),
+    tags(
+        (name = "members", description = "Membership management endpoints scoped to organisations.")
+    )
+)]
+pub struct MemberApiDoc;
+
+pub fn member_routes(app_state: AppState) -> Router<AppState> {
+    Router::new()
+        .typed_get(list_members_handler)
+        .typed_post(add_member_handler)
+        .typed_delete(remove_member_handler)
+        .typed_put(assign_member_roles_handler)
+        .layer(from_fn_with_state(
+            app_state.clone(),
+            service_auth_middleware,
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::member_routes;
+    use crate::test_helpers::app_state;
+
+    #[tokio::test]
+    async fn member_routes_builds() {
+        let state = app_state();
+        let _router = member_routes(state);
+    }
+}
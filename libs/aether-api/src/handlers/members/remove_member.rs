@@ -0,0 +1,80 @@
+use aether_auth::Identity;
+use aether_core::organisation::ports::OrganisationService;
+use axum::extract::{Extension, State};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/members/{member_id}")]
+pub struct RemoveMemberRoute {
+    pub organisation_id: Uuid,
+    pub member_id: Uuid,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct RemoveMemberResponse {
+    success: bool,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{organisation_id}/members/{member_id}",
+    summary = "remove member",
+    tag = "members",
+    description = "Remove a member from the specified organisation. The organisation owner cannot be removed.",
+    params(RemoveMemberRoute),
+    responses(
+        (status = 200, description = "Member removed successfully", body = RemoveMemberResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to remove members, or the member is the owner", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Member not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn remove_member_handler(
+    RemoveMemberRoute {
+        organisation_id,
+        member_id,
+    }: RemoveMemberRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RemoveMemberResponse>, ApiError> {
+    state
+        .service
+        .remove_member(identity, organisation_id.into(), member_id.into())
+        .await?;
+
+    Ok(Response::OK(RemoveMemberResponse { success: true }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn remove_member_maps_service_error() {
+        let result = remove_member_handler(
+            RemoveMemberRoute {
+                organisation_id: Uuid::new_v4(),
+                member_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
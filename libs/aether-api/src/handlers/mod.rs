@@ -1,20 +1,54 @@
 pub mod actions;
+pub mod api_keys;
 pub mod dataplanes;
 pub mod deployments;
+pub mod invitations;
+pub mod members;
 pub mod organisations;
 pub mod roles;
 pub mod users;
 
+use crate::errors::ApiError;
+
 pub fn default_limit() -> usize {
     10
 }
 
+/// Splits a `{id}:{verb}` path segment into the id and the verb. The router cannot match a path
+/// parameter followed by a static suffix, so custom methods such as `{deployment_id}:restore`
+/// are routed on the whole segment and the verb is split off in the handler. `expected`
+/// describes the accepted forms in the error returned when the segment has no verb.
+pub fn split_verb<'a>(segment: &'a str, expected: &str) -> Result<(&'a str, &'a str), ApiError> {
+    segment.split_once(':').ok_or_else(|| ApiError::BadRequest {
+        reason: format!("expected {expected}"),
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use super::default_limit;
+    use super::{default_limit, split_verb};
+    use crate::errors::ApiError;
 
     #[test]
     fn default_limit_is_ten() {
         assert_eq!(default_limit(), 10);
     }
+
+    #[test]
+    fn split_verb_splits_on_first_colon() {
+        assert_eq!(
+            split_verb("abc:restore", "'{id}:restore'").unwrap(),
+            ("abc", "restore")
+        );
+    }
+
+    #[test]
+    fn split_verb_rejects_segment_without_verb() {
+        let result = split_verb("abc", "'{id}:restore'");
+
+        assert!(matches!(
+            result,
+            Err(ApiError::BadRequest { reason }) if reason == "expected '{id}:restore'"
+        ));
+    }
 }
@@ -0,0 +1,183 @@
+use aether_auth::Identity;
+use aether_core::organisation::{
+    commands::ChangePlanCommand,
+    plan::{PlanChange, PlanChangeReport},
+    ports::OrganisationService,
+    value_objects::{OrganisationLimits, Plan},
+};
+use axum::{
+    Json,
+    extract::{Extension, State},
+};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Deserialize, ToSchema)]
+pub struct PlanLimitsRequest {
+    pub max_instances: usize,
+    pub max_users: usize,
+    pub max_storage_gb: usize,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ChangePlanRequest {
+    /// Target plan: free, starter, business or enterprise
+    pub plan: String,
+    /// Custom limits, only accepted for the enterprise plan and from platform administrators
+    pub limits: Option<PlanLimitsRequest>,
+    /// Validate the change against current usage without applying it
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct ChangePlanResponse {
+    data: PlanChangeReport,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct GetPlanHistoryResponse {
+    data: Vec<PlanChange>,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/plan")]
+pub struct ChangePlanRoute {
+    pub organisation_id: Uuid,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/plan/history")]
+pub struct GetPlanHistoryRoute {
+    pub organisation_id: Uuid,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{organisation_id}/plan",
+    summary = "change plan",
+    tag = "organisation",
+    description = "Move the organisation to another plan. The change is checked against current instances, members and storage; a downgrade that no longer fits is rejected with the list of limits to get under. Upgrades and custom limits are reserved to platform administrators. Storage is reported as unmetered while it is not measured. Set `dry_run` to get the same report without applying it.",
+    request_body = ChangePlanRequest,
+    params(ChangePlanRoute),
+    responses(
+        (status = 200, description = "Plan change report", body = ChangePlanResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to manage billing, or an upgrade without the platform administrator role", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Organisation not active", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid plan or limits, or usage over the target limits", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn change_plan_handler(
+    ChangePlanRoute { organisation_id }: ChangePlanRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<ChangePlanRequest>,
+) -> Result<Response<ChangePlanResponse>, ApiError> {
+    let plan: Plan = request.plan.parse()?;
+    let mut command = ChangePlanCommand::new(plan).with_dry_run(request.dry_run);
+    if let Some(limits) = request.limits {
+        command = command.with_limits(OrganisationLimits::custom(
+            limits.max_instances,
+            limits.max_users,
+            limits.max_storage_gb,
+        ));
+    }
+
+    let report = state
+        .service
+        .change_plan(identity, organisation_id.into(), command)
+        .await?;
+
+    Ok(Response::OK(ChangePlanResponse { data: report }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{organisation_id}/plan/history",
+    summary = "get plan history",
+    tag = "organisation",
+    description = "List the plan changes of the organisation, oldest first.",
+    params(GetPlanHistoryRoute),
+    responses(
+        (status = 200, description = "Plan history", body = GetPlanHistoryResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to view billing", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_plan_history_handler(
+    GetPlanHistoryRoute { organisation_id }: GetPlanHistoryRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetPlanHistoryResponse>, ApiError> {
+    let history = state
+        .service
+        .get_plan_history(identity, organisation_id.into())
+        .await?;
+
+    Ok(Response::OK(GetPlanHistoryResponse { data: history }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::ErrorCode,
+        test_helpers::{app_state, user_identity},
+    };
+
+    #[tokio::test]
+    async fn change_plan_rejects_unknown_plan() {
+        let result = change_plan_handler(
+            ChangePlanRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+            Json(ChangePlanRequest {
+                plan: "platinum".to_string(),
+                limits: None,
+                dry_run: false,
+            }),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(ApiError::UnprocessableEntity {
+                code: ErrorCode::InvalidPlan,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_plan_history_maps_service_error() {
+        let result = get_plan_history_handler(
+            GetPlanHistoryRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
@@ -0,0 +1,166 @@
+use aether_auth::Identity;
+use aether_core::organisation::{
+    Organisation, commands::ChangeOrganisationStatusCommand, ports::OrganisationService,
+};
+use axum::{
+    Json,
+    extract::{Extension, State},
+};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Deserialize, ToSchema)]
+pub struct ChangeOrganisationStatusRequest {
+    /// Why the status changes; kept in the status history and sent to the dataplanes
+    pub reason: String,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct ChangeOrganisationStatusResponse {
+    data: Organisation,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/suspend")]
+pub struct SuspendOrganisationRoute {
+    pub organisation_id: Uuid,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/reactivate")]
+pub struct ReactivateOrganisationRoute {
+    pub organisation_id: Uuid,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{organisation_id}/suspend",
+    summary = "suspend organisation",
+    tag = "organisation",
+    description = "Suspend an active organisation. A `deployment.suspend` action is emitted for each of its deployments. Requires the `platform-admin` role.",
+    request_body = ChangeOrganisationStatusRequest,
+    params(SuspendOrganisationRoute),
+    responses(
+        (status = 200, description = "Organisation suspended successfully", body = ChangeOrganisationStatusResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Caller is not a platform administrator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Organisation not active", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Missing reason", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn suspend_organisation_handler(
+    SuspendOrganisationRoute { organisation_id }: SuspendOrganisationRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<ChangeOrganisationStatusRequest>,
+) -> Result<Response<ChangeOrganisationStatusResponse>, ApiError> {
+    let command = ChangeOrganisationStatusCommand::new(request.reason)?;
+
+    let organisation = state
+        .service
+        .suspend_organisation(identity, organisation_id.into(), command)
+        .await?;
+
+    Ok(Response::OK(ChangeOrganisationStatusResponse {
+        data: organisation,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{organisation_id}/reactivate",
+    summary = "reactivate organisation",
+    tag = "organisation",
+    description = "Reactivate a suspended organisation. A `deployment.resume` action is emitted for each of its deployments. Requires the `platform-admin` role.",
+    request_body = ChangeOrganisationStatusRequest,
+    params(ReactivateOrganisationRoute),
+    responses(
+        (status = 200, description = "Organisation reactivated successfully", body = ChangeOrganisationStatusResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Caller is not a platform administrator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Organisation not suspended", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Missing reason", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn reactivate_organisation_handler(
+    ReactivateOrganisationRoute { organisation_id }: ReactivateOrganisationRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<ChangeOrganisationStatusRequest>,
+) -> Result<Response<ChangeOrganisationStatusResponse>, ApiError> {
+    let command = ChangeOrganisationStatusCommand::new(request.reason)?;
+
+    let organisation = state
+        .service
+        .reactivate_organisation(identity, organisation_id.into(), command)
+        .await?;
+
+    Ok(Response::OK(ChangeOrganisationStatusResponse {
+        data: organisation,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::ErrorCode,
+        test_helpers::{app_state, user_identity},
+    };
+
+    #[tokio::test]
+    async fn suspend_organisation_requires_reason() {
+        let result = suspend_organisation_handler(
+            SuspendOrganisationRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+            Json(ChangeOrganisationStatusRequest {
+                reason: " ".to_string(),
+            }),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(ApiError::UnprocessableEntity {
+                code: ErrorCode::MissingStatusReason,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn reactivate_organisation_maps_service_error() {
+        let result = reactivate_organisation_handler(
+            ReactivateOrganisationRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+            Json(ChangeOrganisationStatusRequest {
+                reason: "payment received".to_string(),
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
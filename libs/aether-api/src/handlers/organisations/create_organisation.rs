@@ -11,7 +11,11 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use utoipa::ToSchema;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Deserialize, ToSchema)]
 pub struct CreateOrganisationRequest {
@@ -36,9 +40,11 @@ pub struct CreateOrganisationRoute;
     request_body = CreateOrganisationRequest,
     responses(
         (status = 201, description = "Organisation created successfully", body = CreateOrganisationResponse),
-        (status = 400, description = "Invalid request data", body = ApiError),
-        (status = 401, description = "Unauthorized", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid request data", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Organisation slug already taken", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Owner at their organisation limit", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     ),
     security(
         ("bearer_auth" = [])
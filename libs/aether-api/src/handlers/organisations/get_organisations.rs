@@ -8,7 +8,12 @@ use axum_extra::routing::TypedPath;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
-use crate::{errors::ApiError, handlers::default_limit, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    handlers::default_limit,
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Serialize, ToSchema, PartialEq)]
 pub struct GetOrganisationsResponse {
@@ -39,8 +44,8 @@ pub struct GetOrganisationsRoute;
     params(GetOrganisationsQuery),
     responses(
         (status = 200, description = "List of Organisations", body = GetOrganisationsResponse),
-        (status = 400, description = "Invalid query parameters", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid query parameters", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     )
 )]
 pub async fn get_organisations_handler(
@@ -4,19 +4,39 @@ use utoipa::OpenApi;
 
 use crate::{
     handlers::organisations::{
+        change_plan::{
+            __path_change_plan_handler, __path_get_plan_history_handler, change_plan_handler,
+            get_plan_history_handler,
+        },
+        change_status::{
+            __path_reactivate_organisation_handler, __path_suspend_organisation_handler,
+            reactivate_organisation_handler, suspend_organisation_handler,
+        },
         create_organisation::{__path_create_organisation_handler, create_organisation_handler},
         get_organisations::{__path_get_organisations_handler, get_organisations_handler},
+        transfer_ownership::{__path_transfer_ownership_handler, transfer_ownership_handler},
     },
     router::service_auth_middleware,
     state::AppState,
 };
 
+pub mod change_plan;
+pub mod change_status;
 pub mod create_organisation;
 pub mod get_organisations;
+pub mod transfer_ownership;
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(get_organisations_handler, create_organisation_handler),
+    paths(
+        get_organisations_handler,
+        create_organisation_handler,
+        transfer_ownership_handler,
+        suspend_organisation_handler,
+        reactivate_organisation_handler,
+        change_plan_handler,
+        get_plan_history_handler,
+    ),
     tags(
         (name = "organisation", description = "Organisation management endpoints.")
     )
@@ -27,6 +47,11 @@ pub fn organisation_routes(app_state: AppState) -> Router<AppState> {
     Router::new()
         .typed_get(get_organisations_handler)
         .typed_post(create_organisation_handler)
+        .typed_post(transfer_ownership_handler)
+        .typed_post(suspend_organisation_handler)
+        .typed_post(reactivate_organisation_handler)
+        .typed_post(change_plan_handler)
+        .typed_get(get_plan_history_handler)
         .layer(from_fn_with_state(
             app_state.clone(),
             service_auth_middleware,
@@ -0,0 +1,97 @@
+use aether_auth::Identity;
+use aether_core::organisation::{
+    Organisation, commands::TransferOwnershipCommand, member::MemberId, ports::OrganisationService,
+};
+use axum::{
+    Json,
+    extract::{Extension, State},
+};
+use axum_extra::routing::TypedPath;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
+
+#[derive(Deserialize, ToSchema)]
+pub struct TransferOwnershipRequest {
+    /// Member who becomes the new owner
+    pub member_id: Uuid,
+}
+
+#[derive(Serialize, ToSchema, PartialEq)]
+pub struct TransferOwnershipResponse {
+    data: Organisation,
+}
+
+#[derive(TypedPath, IntoParams, Deserialize)]
+#[typed_path("/organisations/{organisation_id}/transfer-ownership")]
+pub struct TransferOwnershipRoute {
+    pub organisation_id: Uuid,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{organisation_id}/transfer-ownership",
+    summary = "transfer ownership",
+    tag = "organisation",
+    description = "Hand the organisation over to one of its members. The current owner can always transfer ownership; anyone else needs the administrator permission.",
+    request_body = TransferOwnershipRequest,
+    params(TransferOwnershipRoute),
+    responses(
+        (status = 200, description = "Ownership transferred successfully", body = TransferOwnershipResponse),
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Missing permission to transfer ownership", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Member not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Organisation not active", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "New owner at their organisation limit", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn transfer_ownership_handler(
+    TransferOwnershipRoute { organisation_id }: TransferOwnershipRoute,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Json(request): Json<TransferOwnershipRequest>,
+) -> Result<Response<TransferOwnershipResponse>, ApiError> {
+    let command = TransferOwnershipCommand::new(MemberId(request.member_id));
+
+    let organisation = state
+        .service
+        .transfer_ownership(identity, organisation_id.into(), command)
+        .await?;
+
+    Ok(Response::OK(TransferOwnershipResponse {
+        data: organisation,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{app_state, user_identity};
+
+    #[tokio::test]
+    async fn transfer_ownership_maps_service_error() {
+        let result = transfer_ownership_handler(
+            TransferOwnershipRoute {
+                organisation_id: Uuid::new_v4(),
+            },
+            State(app_state()),
+            Extension(user_identity("user-123")),
+            Json(TransferOwnershipRequest {
+                member_id: Uuid::new_v4(),
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
+    }
+}
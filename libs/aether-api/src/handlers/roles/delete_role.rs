@@ -5,7 +5,11 @@ use axum_extra::routing::TypedPath;
 use utoipa::IntoParams;
 use uuid::Uuid;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -31,9 +35,9 @@ pub struct DeleteRoleResponse {
     params(DeleteRoleRoute),
     responses(
         (status = 200, description = "Role deleted successfully", body = DeleteRoleResponse),
-        (status = 400, description = "Role not found", body = ApiError),
-        (status = 401, description = "Unauthorized", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Role not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     ),
     security(
         ("bearer_auth" = [])
@@ -1,12 +1,19 @@
 use aether_auth::Identity;
-use aether_core::role::{Role, ports::RoleService};
+use aether_core::{
+    CoreError,
+    role::{Role, ports::RoleService},
+};
 use axum::extract::{Extension, State};
 use axum_extra::routing::TypedPath;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Serialize, ToSchema, PartialEq)]
 pub struct GetRoleResponse {
@@ -29,9 +36,9 @@ pub struct GetRoleRoute {
     params(GetRoleRoute),
     responses(
         (status = 200, description = "Role details", body = GetRoleResponse),
-        (status = 400, description = "Role not found", body = ApiError),
-        (status = 401, description = "Unauthorized", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Role not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     ),
     security(
         ("bearer_auth" = [])
@@ -46,15 +53,12 @@ pub async fn get_role_handler(
     Extension(identity): Extension<Identity>,
 ) -> Result<Response<GetRoleResponse>, ApiError> {
     let organisation_id = organisation_id.into();
-    let role_id = role_id.into();
 
     let role = state
         .service
-        .get_role(identity, organisation_id, role_id)
+        .get_role(identity, organisation_id, role_id.into())
         .await?
-        .ok_or(ApiError::BadRequest {
-            reason: "role not found".to_string(),
-        })?;
+        .ok_or(CoreError::RoleNotFound { id: role_id })?;
 
     Ok(Response::OK(GetRoleResponse { data: role }))
 }
@@ -79,6 +83,6 @@ mod tests {
         )
         .await;
 
-        assert!(matches!(result, Err(ApiError::Forbidden { .. })));
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
     }
 }
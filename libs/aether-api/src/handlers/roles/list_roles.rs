@@ -6,7 +6,11 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Serialize, ToSchema, PartialEq)]
 pub struct ListRolesResponse {
@@ -28,8 +32,8 @@ pub struct ListRolesRoute {
     params(ListRolesRoute),
     responses(
         (status = 200, description = "List of roles", body = ListRolesResponse),
-        (status = 400, description = "Invalid organisation id", body = ApiError),
-        (status = 500, description = "Internal Server Error", body = ApiError)
+        (status = 400, description = "Invalid organisation id", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal Server Error", body = ProblemDetails, content_type = "application/problem+json")
     )
 )]
 pub async fn list_roles_handler(
@@ -66,6 +70,6 @@ mod tests {
         )
         .await;
 
-        assert!(matches!(result, Err(ApiError::Forbidden { .. })));
+        assert!(matches!(result, Err(ApiError::Unknown { .. })));
     }
 }
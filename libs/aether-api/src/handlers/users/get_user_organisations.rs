@@ -5,7 +5,11 @@ use axum_extra::routing::TypedPath;
 use serde::Serialize;
 use utoipa::ToSchema;
 
-use crate::{errors::ApiError, response::Response, state::AppState};
+use crate::{
+    errors::{ApiError, ProblemDetails},
+    response::Response,
+    state::AppState,
+};
 
 #[derive(Serialize, ToSchema, PartialEq)]
 pub struct GetUserOrganisationsResponse {
@@ -23,8 +27,8 @@ pub struct GetUserOrganisationsRoute;
     description = "Retrieve a list of organisations the authenticated user is a member of.",
     responses(
         (status = 200, description = "List of user's organisations", body = GetUserOrganisationsResponse),
-        (status = 401, description = "Unauthorized", body = ApiError),
-        (status = 500, description = "Internal server error", body = ApiError)
+        (status = 401, description = "Unauthorized", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Internal server error", body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "users"
 )]
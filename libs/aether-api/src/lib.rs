@@ -3,11 +3,12 @@ use std::net::{SocketAddr, ToSocketAddrs};
 use aether_auth::{Identity, Token};
 use aether_core::{
     CoreError,
+    api_key::{API_KEY_PREFIX, ports::ApiKeyService},
     auth::ports::AuthService,
     user::{commands::CreateUserCommand, ports::UserService},
 };
 use axum::{
-    Json, Router,
+    Router,
     extract::{Request, State},
     http::{HeaderValue, StatusCode, header::AUTHORIZATION},
     middleware::Next,
@@ -18,7 +19,7 @@ use tracing_subscriber::EnvFilter;
 
 use crate::{
     args::LogArgs,
-    errors::{ApiError, ApiErrorResponse},
+    errors::{ApiError, ErrorCode, ProblemDetails},
 };
 
 pub mod args;
@@ -26,6 +27,8 @@ pub mod auth;
 pub mod errors;
 pub mod handlers;
 pub mod openapi;
+pub mod purge;
+pub mod reaper;
 pub mod response;
 pub mod router;
 pub mod state;
@@ -51,9 +54,17 @@ pub(crate) mod test_helpers {
                 log: args::LogArgs::default(),
                 db: args::DatabaseArgs::default(),
                 auth: args::AuthArgs {
-                    issuer: "http://localhost:8888/realms/aether".to_string(),
+                    issuers: vec![aether_core::IssuerConfig::new(
+                        "http://localhost:8888/realms/aether",
+                    )],
+                    audiences: Vec::new(),
+                    leeway_seconds: aether_core::AuthConfig::DEFAULT_LEEWAY_SECONDS,
                 },
                 server: args::ServerArgs::default(),
+                reaper: args::ReaperArgs::default(),
+                scheduler: args::SchedulerArgs::default(),
+                retention: args::RetentionArgs::default(),
+                invitation: args::InvitationArgs::default(),
             }),
             service: AetherService::new(pool),
         }
@@ -64,6 +75,7 @@ pub(crate) mod test_helpers {
             id: id.to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         })
@@ -137,22 +149,22 @@ impl IntoResponse for MiddlewareError {
         let (status, code, message) = match self {
             MiddlewareError::MissingAuthHeader => (
                 StatusCode::UNAUTHORIZED,
-                "E_MISSING_AUTH_HEADER",
+                ErrorCode::MissingAuthHeader,
                 "Authorization header is missing",
             ),
             MiddlewareError::InvalidAuthHeader => (
                 StatusCode::UNAUTHORIZED,
-                "E_INVALID_AUTH_HEADER",
+                ErrorCode::InvalidAuthHeader,
                 "Invalid authorization header",
             ),
             MiddlewareError::AuthenticationFailed(_) => (
                 StatusCode::UNAUTHORIZED,
-                "E_AUTHENTICATION_FAILED",
+                ErrorCode::AuthenticationFailed,
                 "Authentication failed",
             ),
         };
 
-        (status, Json(ApiErrorResponse::new(code, status, message))).into_response()
+        ProblemDetails::new(status, code, message).into_response()
     }
 }
 
@@ -176,7 +188,7 @@ pub async fn auth_middleware<T>(
     next: Next,
 ) -> Result<Response, MiddlewareError>
 where
-    T: AuthService + UserService + Clone + Send + Sync + 'static,
+    T: AuthService + UserService + ApiKeyService + Clone + Send + Sync + 'static,
 {
     auth_middleware_impl(State(state), req, |req| next.run(req)).await
 }
@@ -187,7 +199,7 @@ pub(crate) async fn auth_middleware_impl<T, F, Fut>(
     next: F,
 ) -> Result<Response, MiddlewareError>
 where
-    T: AuthService + UserService + Clone + Send + Sync + 'static,
+    T: AuthService + UserService + ApiKeyService + Clone + Send + Sync + 'static,
     F: FnOnce(Request) -> Fut,
     Fut: std::future::Future<Output = Response> + Send,
 {
@@ -200,7 +212,13 @@ where
         .await
         .map_err(|_| MiddlewareError::InvalidAuthHeader)?;
 
-    let identity = state.get_identity(token.as_str()).await.map_err(|e| {
+    // API keys are told apart from identity provider tokens by their prefix
+    let identity = if token.as_str().starts_with(API_KEY_PREFIX) {
+        state.authenticate_api_key(token.as_str()).await
+    } else {
+        state.get_identity(token.as_str()).await
+    }
+    .map_err(|e| {
         error!("Auth middleware: failed to identify user {:?}", e);
         MiddlewareError::AuthenticationFailed(e)
     })?;
@@ -216,6 +234,7 @@ where
             name,
             email,
             sub: user.id.clone(),
+            roles: user.roles.clone(),
         };
         if let Err(err) = state.create_user(command).await {
             error!("Auth middleware: failed to create user {:?}", err);
@@ -310,6 +329,7 @@ mod tests {
                         id: "user-1".to_string(),
                         username: "user".to_string(),
                         email: None,
+                        email_verified: false,
                         name: None,
                         roles: vec![],
                     }))
@@ -331,6 +351,55 @@ mod tests {
         }
     }
 
+    impl ApiKeyService for FakeAuthService {
+        fn create_api_key(
+            &self,
+            _identity: Identity,
+            _command: aether_core::api_key::commands::CreateApiKeyCommand,
+        ) -> impl Future<Output = Result<aether_core::api_key::IssuedApiKey, CoreError>> + Send
+        {
+            Box::pin(async move { Err(CoreError::InternalError("not implemented".to_string())) })
+        }
+
+        fn list_api_keys(
+            &self,
+            _identity: Identity,
+            _organisation_id: aether_core::organisation::OrganisationId,
+        ) -> impl Future<Output = Result<Vec<aether_core::api_key::ApiKey>, CoreError>> + Send
+        {
+            Box::pin(async move { Err(CoreError::InternalError("not implemented".to_string())) })
+        }
+
+        fn revoke_api_key(
+            &self,
+            _identity: Identity,
+            _organisation_id: aether_core::organisation::OrganisationId,
+            _api_key_id: aether_core::api_key::ApiKeyId,
+        ) -> impl Future<Output = Result<aether_core::api_key::ApiKey, CoreError>> + Send {
+            Box::pin(async move { Err(CoreError::InternalError("not implemented".to_string())) })
+        }
+
+        fn authenticate_api_key(
+            &self,
+            _secret: &str,
+        ) -> impl Future<Output = Result<Identity, CoreError>> + Send {
+            let fail = self.fail;
+            Box::pin(async move {
+                if fail {
+                    Err(CoreError::InvalidIdentity)
+                } else {
+                    Ok(Identity::ApiKey(aether_auth::ApiKey {
+                        id: "key-1".to_string(),
+                        name: "ci".to_string(),
+                        organisation_id: "org-1".to_string(),
+                        user_id: None,
+                        permissions: 0,
+                    }))
+                }
+            })
+        }
+    }
+
     #[tokio::test]
     async fn auth_middleware_impl_rejects_missing_header() {
         let state = FakeAuthService { fail: false };
@@ -403,4 +472,35 @@ mod tests {
 
         assert!(matches!(result, Ok(response) if response.status() == StatusCode::OK));
     }
+
+    #[tokio::test]
+    async fn auth_middleware_impl_resolves_prefixed_tokens_as_api_keys() {
+        for (token, api_key) in [("Bearer aek_secret", true), ("Bearer jwt", false)] {
+            let req = Request::builder()
+                .uri("/")
+                .header(AUTHORIZATION, token)
+                .body(Body::empty())
+                .unwrap();
+
+            let result = auth_middleware_impl(
+                State(FakeAuthService { fail: false }),
+                req,
+                |req| async move {
+                    let resolved = req.extensions().get::<Identity>().map(Identity::is_api_key);
+                    let status = if resolved == Some(api_key) {
+                        StatusCode::OK
+                    } else {
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    };
+                    status.into_response()
+                },
+            )
+            .await;
+
+            assert!(
+                matches!(result, Ok(response) if response.status() == StatusCode::OK),
+                "{token}"
+            );
+        }
+    }
 }
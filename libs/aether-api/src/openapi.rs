@@ -1,8 +1,18 @@
 use utoipa::OpenApi;
 
-use crate::handlers::{
-    actions::ActionApiDoc, dataplanes::DataPlaneApiDoc, deployments::DeploymentApiDoc,
-    organisations::OrganisationApiDoc, roles::RoleApiDoc, users::UserApiDoc,
+use crate::{
+    errors::{ErrorCode, ProblemDetails},
+    handlers::{
+        actions::ActionApiDoc,
+        api_keys::ApiKeyApiDoc,
+        dataplanes::DataPlaneApiDoc,
+        deployments::DeploymentApiDoc,
+        invitations::{InvitationAcceptApiDoc, InvitationApiDoc},
+        members::MemberApiDoc,
+        organisations::OrganisationApiDoc,
+        roles::RoleApiDoc,
+        users::UserApiDoc,
+    },
 };
 
 #[derive(OpenApi)]
@@ -10,13 +20,25 @@ use crate::handlers::{
     info(
         title = "Aether API",
         version = "0.1.0",
-        description = "API documentation for Aether services"
+        description = "API documentation for Aether services.\n\n\
+Errors are returned as RFC 7807 `application/problem+json` documents. The `code` member \
+carries a stable machine-readable error code (see the `ErrorCode` schema); `detail` is \
+meant for humans and may change. Status codes follow the kind of failure: 400 for \
+malformed requests, 401 when the caller cannot be identified, 403 for missing \
+permissions, 404 for unknown resources, 409 when the request conflicts with the current \
+state, 422 for requests that fail validation or exceed limits, and 500 for failures on \
+the server side."
     ),
+    components(schemas(ProblemDetails, ErrorCode)),
     nest(
         (path = "/organisations", api = OrganisationApiDoc),
         (path = "/organisations", api = RoleApiDoc),
+        (path = "/organisations", api = MemberApiDoc),
+        (path = "/organisations", api = InvitationApiDoc),
+        (path = "/organisations", api = ApiKeyApiDoc),
         (path = "/organisations", api = DeploymentApiDoc),
         (path = "/organisations", api = ActionApiDoc),
+        (path = "/invitations", api = InvitationAcceptApiDoc),
         (path = "/users", api = UserApiDoc),
         (path = "/dataplanes", api = DataPlaneApiDoc),
     )
@@ -33,4 +55,12 @@ mod tests {
         let doc = ApiDoc::openapi();
         assert_eq!(doc.info.title, "Aether API");
     }
+
+    #[test]
+    fn openapi_documents_problem_details() {
+        let doc = ApiDoc::openapi();
+        let schemas = doc.components.expect("components").schemas;
+        assert!(schemas.contains_key("ProblemDetails"));
+        assert!(schemas.contains_key("ErrorCode"));
+    }
 }
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use aether_core::deployments::ports::DeploymentService;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::args::RetentionArgs;
+
+const PURGE_BATCH_SIZE: usize = 100;
+
+/// Periodically purges deployments whose restore grace period has passed, queueing the
+/// teardown action that tells their dataplane to remove them.
+pub async fn run_deployment_purge<S>(service: S, args: RetentionArgs)
+where
+    S: DeploymentService,
+{
+    let mut ticker = interval(Duration::from_secs(args.purge_interval_seconds.max(1)));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        let deleted_before = chrono::Utc::now() - args.deployment_grace_period();
+
+        match service
+            .purge_deleted_deployments(deleted_before, PURGE_BATCH_SIZE)
+            .await
+        {
+            Ok(purged) if !purged.is_empty() => {
+                info!(purged = purged.len(), "purged deleted deployments")
+            }
+            Ok(_) => {}
+            Err(e) => error!(error = %e, "failed to purge deleted deployments"),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use aether_core::action::{commands::ReapActionsCommand, ports::ActionService};
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+use crate::args::ReaperArgs;
+
+/// Periodically returns expired leases to `pending` and fails actions that ran out of
+/// attempts or passed their deadline, so a crashed agent never strands its claims.
+pub async fn run_action_reaper<S>(service: S, args: ReaperArgs)
+where
+    S: ActionService,
+{
+    let mut ticker = interval(Duration::from_secs(args.interval_seconds.max(1)));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        let command = ReapActionsCommand {
+            now: chrono::Utc::now(),
+            max_attempts: args.max_attempts,
+        };
+
+        match service.reap_actions(command).await {
+            Ok(report) if report.requeued + report.timed_out + report.expired > 0 => info!(
+                requeued = report.requeued,
+                timed_out = report.timed_out,
+                expired = report.expired,
+                "reaped expired actions"
+            ),
+            Ok(_) => {}
+            Err(e) => error!(error = %e, "failed to reap expired actions"),
+        }
+    }
+}
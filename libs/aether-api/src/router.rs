@@ -18,7 +18,8 @@ use utoipa_swagger_ui::SwaggerUi;
 use crate::{
     errors::ApiError,
     handlers::{
-        actions::action_routes, dataplanes::dataplanes_routes, deployments::deployment_routes,
+        actions::action_routes, api_keys::api_key_routes, dataplanes::dataplanes_routes,
+        deployments::deployment_routes, invitations::invitation_routes, members::member_routes,
         organisations::organisation_routes, roles::role_routes, users::user_routes,
     },
     openapi::ApiDoc,
@@ -37,6 +38,7 @@ async fn service_auth<T, F, Fut>(State(state): State<T>, req: Request, next: F)
 where
     T: aether_core::auth::ports::AuthService
         + aether_core::user::ports::UserService
+        + aether_core::api_key::ports::ApiKeyService
         + Clone
         + Send
         + Sync
@@ -89,6 +91,9 @@ pub fn router(state: AppState) -> Result<Router, ApiError> {
         .merge(SwaggerUi::new("/swagger").url("/api-docs/openapi.json", openapi.clone()))
         .merge(organisation_routes(state.clone()))
         .merge(role_routes(state.clone()))
+        .merge(member_routes(state.clone()))
+        .merge(invitation_routes(state.clone()))
+        .merge(api_key_routes(state.clone()))
         .merge(deployment_routes(state.clone()))
         .merge(action_routes(state.clone()))
         .merge(user_routes(state.clone()))
@@ -108,7 +113,11 @@ mod tests {
     use crate::test_helpers::app_state;
     use aether_auth::{Identity, User};
     use aether_core::CoreError;
+    use aether_core::api_key::{
+        ApiKey, ApiKeyId, IssuedApiKey, commands::CreateApiKeyCommand, ports::ApiKeyService,
+    };
     use aether_core::auth::ports::AuthService;
+    use aether_core::organisation::OrganisationId;
     use aether_core::user::{commands::CreateUserCommand, ports::UserService};
     use axum::body::Body;
     use axum::extract::{Request, State};
@@ -148,6 +157,7 @@ mod tests {
                         id: "user-1".to_string(),
                         username: "user".to_string(),
                         email: None,
+                        email_verified: false,
                         name: None,
                         roles: vec![],
                     }))
@@ -169,6 +179,40 @@ mod tests {
         }
     }
 
+    impl ApiKeyService for FakeAuthService {
+        fn create_api_key(
+            &self,
+            _identity: Identity,
+            _command: CreateApiKeyCommand,
+        ) -> impl Future<Output = Result<IssuedApiKey, CoreError>> + Send {
+            Box::pin(async move { Err(CoreError::InternalError("not implemented".to_string())) })
+        }
+
+        fn list_api_keys(
+            &self,
+            _identity: Identity,
+            _organisation_id: OrganisationId,
+        ) -> impl Future<Output = Result<Vec<ApiKey>, CoreError>> + Send {
+            Box::pin(async move { Err(CoreError::InternalError("not implemented".to_string())) })
+        }
+
+        fn revoke_api_key(
+            &self,
+            _identity: Identity,
+            _organisation_id: OrganisationId,
+            _api_key_id: ApiKeyId,
+        ) -> impl Future<Output = Result<ApiKey, CoreError>> + Send {
+            Box::pin(async move { Err(CoreError::InternalError("not implemented".to_string())) })
+        }
+
+        fn authenticate_api_key(
+            &self,
+            _secret: &str,
+        ) -> impl Future<Output = Result<Identity, CoreError>> + Send {
+            Box::pin(async move { Err(CoreError::InvalidIdentity) })
+        }
+    }
+
     #[tokio::test]
     async fn service_auth_returns_unauthorized_on_missing_header() {
         let state = FakeAuthService { fail: false };
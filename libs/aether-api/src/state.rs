@@ -28,7 +28,11 @@ pub async fn state(args: Arc<Args>) -> Result<AppState, ApiError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::args::{Args, AuthArgs, DatabaseArgs, LogArgs, ServerArgs};
+    use crate::args::{
+        Args, AuthArgs, DatabaseArgs, InvitationArgs, LogArgs, ReaperArgs, RetentionArgs,
+        SchedulerArgs, ServerArgs,
+    };
+    use aether_core::{AuthConfig, IssuerConfig};
     use std::sync::Arc;
     use tokio::time::{Duration, timeout};
 
@@ -42,9 +46,15 @@ mod tests {
                 ..DatabaseArgs::default()
             },
             auth: AuthArgs {
-                issuer: "http://issuer.test".to_string(),
+                issuers: vec![IssuerConfig::new("http://issuer.test")],
+                audiences: Vec::new(),
+                leeway_seconds: AuthConfig::DEFAULT_LEEWAY_SECONDS,
             },
             server: ServerArgs::default(),
+            reaper: ReaperArgs::default(),
+            scheduler: SchedulerArgs::default(),
+            retention: RetentionArgs::default(),
+            invitation: InvitationArgs::default(),
         };
 
         let result = timeout(Duration::from_millis(200), state(Arc::new(args))).await;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Caller authenticated with an API key instead of an identity provider token
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ApiKey {
+    pub id: String,
+    pub name: String,
+    /// The only organisation the key can act in
+    pub organisation_id: String,
+    /// Owning user of a personal access token; unset for organisation keys
+    pub user_id: Option<String>,
+    /// Permission bits granted to the key, already capped at its creator's
+    pub permissions: u64,
+}
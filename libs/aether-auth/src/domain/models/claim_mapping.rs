@@ -0,0 +1,168 @@
+use serde_json::Value;
+
+use crate::domain::models::{claims::Claims, client::Client, identity::Identity, user::User};
+
+/// Where an issuer puts identity attributes in its tokens
+///
+/// Claim names may be dotted paths into nested objects, e.g. `realm_access.roles`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimMapping {
+    pub username: String,
+    /// Claim whose presence marks a client (service account) token
+    pub client_id: String,
+    /// Array of role names; no roles are read when unset
+    pub roles: Option<String>,
+    /// Space separated string or array of scopes granted to clients
+    pub scopes: Option<String>,
+}
+
+impl Default for ClaimMapping {
+    fn default() -> Self {
+        Self {
+            username: "preferred_username".to_string(),
+            client_id: "client_id".to_string(),
+            roles: None,
+            scopes: None,
+        }
+    }
+}
+
+impl ClaimMapping {
+    pub fn with_username(mut self, claim: impl Into<String>) -> Self {
+        self.username = claim.into();
+        self
+    }
+
+    pub fn with_client_id(mut self, claim: impl Into<String>) -> Self {
+        self.client_id = claim.into();
+        self
+    }
+
+    pub fn with_roles(mut self, claim: impl Into<String>) -> Self {
+        self.roles = Some(claim.into());
+        self
+    }
+
+    pub fn with_scopes(mut self, claim: impl Into<String>) -> Self {
+        self.scopes = Some(claim.into());
+        self
+    }
+
+    pub fn identity(&self, claims: Claims) -> Identity {
+        let value = serde_json::to_value(&claims).unwrap_or(Value::Null);
+        let roles = self
+            .roles
+            .as_deref()
+            .map(|path| strings(lookup(&value, path)))
+            .unwrap_or_default();
+
+        if let Some(client_id) = lookup(&value, &self.client_id).and_then(Value::as_str) {
+            Identity::Client(Client {
+                id: claims.sub.0,
+                client_id: client_id.to_string(),
+                roles,
+                scopes: self
+                    .scopes
+                    .as_deref()
+                    .map(|path| strings(lookup(&value, path)))
+                    .unwrap_or_default(),
+            })
+        } else {
+            let username = lookup(&value, &self.username)
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| claims.sub.0.clone());
+
+            Identity::User(User {
+                id: claims.sub.0,
+                username,
+                email: claims.email,
+                email_verified: claims.email_verified.unwrap_or(false),
+                name: claims.name,
+                roles,
+            })
+        }
+    }
+}
+
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |current, segment| current.get(segment))
+        .filter(|value| !value.is_null())
+}
+
+fn strings(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        Some(Value::String(items)) => items.split_whitespace().map(str::to_string).collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claims(json: serde_json::Value) -> Claims {
+        let mut base = serde_json::json!({
+            "sub": "user-123",
+            "iss": "http://issuer.test",
+            "scope": "openid profile",
+            "preferred_username": "johndoe",
+            "email": "john.doe@example.com",
+        });
+        base.as_object_mut()
+            .unwrap()
+            .extend(json.as_object().unwrap().clone());
+        serde_json::from_value(base).unwrap()
+    }
+
+    #[test]
+    fn default_mapping_matches_identity_from_claims() {
+        let claims = claims(serde_json::json!({ "realm_access": { "roles": ["admin"] } }));
+
+        let identity = ClaimMapping::default().identity(claims.clone());
+
+        assert_eq!(identity, Identity::from(claims));
+        assert_eq!(identity.username(), "johndoe");
+        assert!(identity.roles().is_empty());
+    }
+
+    #[test]
+    fn mapping_reads_nested_roles_and_custom_username() {
+        let mapping = ClaimMapping::default()
+            .with_username("email")
+            .with_roles("realm_access.roles");
+        let claims = claims(serde_json::json!({ "realm_access": { "roles": ["admin", "staff"] } }));
+
+        let identity = mapping.identity(claims);
+
+        assert!(identity.is_user());
+        assert_eq!(identity.username(), "john.doe@example.com");
+        assert!(identity.has_role("staff"));
+    }
+
+    #[test]
+    fn mapping_builds_client_with_scopes() {
+        let mapping = ClaimMapping::default()
+            .with_client_id("azp")
+            .with_roles("roles")
+            .with_scopes("scope");
+        let claims = claims(serde_json::json!({ "azp": "billing-bot", "roles": ["sync"] }));
+
+        let Identity::Client(client) = mapping.identity(claims) else {
+            panic!("expected a client identity");
+        };
+
+        assert_eq!(client.client_id, "billing-bot");
+        assert_eq!(client.roles, vec!["sync".to_string()]);
+        assert_eq!(
+            client.scopes,
+            vec!["openid".to_string(), "profile".to_string()]
+        );
+    }
+}
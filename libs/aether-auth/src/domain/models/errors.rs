@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum AuthError {
     #[error("invalid token: {message}")]
     InvalidToken { message: String },
@@ -16,4 +16,31 @@ pub enum AuthError {
 
     #[error("token expired")]
     Expired,
+
+    #[error("token not valid yet")]
+    NotYetValid,
+
+    #[error("token issuer is not trusted")]
+    InvalidIssuer,
+
+    #[error("unknown issuer: {issuer}")]
+    UnknownIssuer { issuer: String },
+
+    #[error("discovery failed for {issuer}: {message}")]
+    Discovery { issuer: String, message: String },
+
+    #[error("token audience does not match")]
+    InvalidAudience,
+
+    #[error("invalid token signature")]
+    InvalidSignature,
+
+    #[error("missing claim: {claim}")]
+    MissingClaim { claim: String },
+
+    #[error("unsupported algorithm: {algorithm}")]
+    UnsupportedAlgorithm { algorithm: String },
+
+    #[error("key {key} cannot verify {algorithm} tokens")]
+    KeyAlgorithmMismatch { key: String, algorithm: String },
 }
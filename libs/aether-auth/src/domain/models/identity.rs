@@ -1,11 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::models::{claims::Claims, client::Client, user::User};
+use crate::domain::models::{
+    api_key::ApiKey, claim_mapping::ClaimMapping, claims::Claims, client::Client, user::User,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Identity {
     User(User),
     Client(Client),
+    ApiKey(ApiKey),
 }
 
 impl Identity {
@@ -13,6 +16,7 @@ impl Identity {
         match self {
             Identity::User(u) => &u.id,
             Identity::Client(c) => &c.id,
+            Identity::ApiKey(k) => &k.id,
         }
     }
 
@@ -24,10 +28,15 @@ impl Identity {
         matches!(self, Identity::Client(_))
     }
 
+    pub fn is_api_key(&self) -> bool {
+        matches!(self, Identity::ApiKey(_))
+    }
+
     pub fn username(&self) -> &str {
         match self {
             Identity::User(u) => &u.username,
             Identity::Client(c) => &c.client_id,
+            Identity::ApiKey(k) => &k.name,
         }
     }
 
@@ -35,32 +44,38 @@ impl Identity {
         match self {
             Identity::User(u) => &u.roles,
             Identity::Client(c) => &c.roles,
+            // Keys carry their permissions directly rather than through roles
+            Identity::ApiKey(_) => &[],
         }
     }
 
     pub fn has_role(&self, role: &str) -> bool {
         self.roles().iter().any(|r| r == role)
     }
+
+    /// Prefixes the subject, and a client's `client_id`, with its issuer as `<issuer>#<sub>`,
+    /// so that equal identifiers from different identity providers never resolve to the same
+    /// account or agent. Issuer identifiers cannot contain a fragment, so the first `#` ends
+    /// the namespace
+    pub fn namespaced(self, issuer: &str) -> Self {
+        match self {
+            Identity::User(user) => Identity::User(User {
+                id: format!("{}#{}", issuer, user.id),
+                ..user
+            }),
+            Identity::Client(client) => Identity::Client(Client {
+                id: format!("{}#{}", issuer, client.id),
+                client_id: format!("{}#{}", issuer, client.client_id),
+                ..client
+            }),
+            Identity::ApiKey(key) => Identity::ApiKey(key),
+        }
+    }
 }
 
 impl From<Claims> for Identity {
     fn from(claims: Claims) -> Self {
-        if let Some(client_id) = claims.client_id {
-            Identity::Client(Client {
-                id: claims.sub.0,
-                client_id,
-                roles: Vec::new(),
-                scopes: Vec::new(),
-            })
-        } else {
-            Identity::User(User {
-                id: claims.sub.0.clone(),
-                email: claims.email,
-                name: claims.name,
-                roles: Vec::new(),
-                username: claims.preferred_username.unwrap_or(claims.sub.0),
-            })
-        }
+        ClaimMapping::default().identity(claims)
     }
 }
 
@@ -69,6 +84,7 @@ mod tests {
     use serde_json::json;
 
     use crate::domain::models::{
+        api_key::ApiKey,
         claims::{Audience, Claims},
         identity::Identity,
     };
@@ -137,9 +153,10 @@ mod tests {
                 assert_eq!(user.id, "user-123");
                 assert_eq!(user.username, "johndoe");
                 assert_eq!(user.email, Some("john.doe@example.com".to_string()));
+                assert!(user.email_verified);
                 assert_eq!(user.name, Some("John Doe".to_string()));
             }
-            Identity::Client(_) => panic!("Expected User, got Client"),
+            other => panic!("Expected User, got {other:?}"),
         }
     }
 
@@ -153,7 +170,7 @@ mod tests {
                 assert_eq!(client.id, "service-123");
                 assert_eq!(client.client_id, "ferriscord-bot");
             }
-            Identity::User(_) => panic!("Expected Client, got User"),
+            other => panic!("Expected Client, got {other:?}"),
         }
     }
 
@@ -182,4 +199,35 @@ mod tests {
         assert!(identity.roles().is_empty());
         assert!(!identity.has_role("service"));
     }
+
+    #[test]
+    fn test_namespaced_prefixes_subject_with_issuer() {
+        let user: Identity = create_user_claims().into();
+        let client: Identity = create_service_account_claims().into();
+
+        assert_eq!(
+            user.namespaced("https://idp.test").id(),
+            "https://idp.test#user-123"
+        );
+        let client = client.namespaced("https://idp.test");
+        assert_eq!(client.id(), "https://idp.test#service-123");
+        assert_eq!(client.username(), "https://idp.test#ferriscord-bot");
+    }
+
+    #[test]
+    fn test_identity_accessors_for_api_key() {
+        let identity = Identity::ApiKey(ApiKey {
+            id: "key-123".to_string(),
+            name: "ci".to_string(),
+            organisation_id: "org-123".to_string(),
+            user_id: None,
+            permissions: 1,
+        });
+
+        assert!(identity.is_api_key());
+        assert!(!identity.is_user());
+        assert_eq!(identity.id(), "key-123");
+        assert_eq!(identity.username(), "ci");
+        assert!(identity.roles().is_empty());
+    }
 }
@@ -1,3 +1,5 @@
+pub(crate) mod api_key;
+pub(crate) mod claim_mapping;
 pub(crate) mod claims;
 pub(crate) mod client;
 pub(crate) mod errors;
@@ -5,6 +7,8 @@ pub(crate) mod identity;
 pub(crate) mod token;
 pub(crate) mod user;
 
+pub use api_key::*;
+pub use claim_mapping::*;
 pub use claims::*;
 pub use client::*;
 pub use errors::*;
@@ -5,6 +5,9 @@ pub struct User {
     pub id: String,
     pub username: String,
     pub email: Option<String>,
+    /// Whether the issuer verified that the user owns `email`
+    #[serde(default)]
+    pub email_verified: bool,
     pub name: Option<String>,
     pub roles: Vec<String>,
 }
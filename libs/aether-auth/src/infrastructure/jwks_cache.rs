@@ -0,0 +1,557 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use jsonwebtoken::{Algorithm, DecodingKey};
+use reqwest::{Client, header::CACHE_CONTROL};
+use serde::{Deserialize, Serialize};
+use tokio::{sync::Mutex, task::JoinHandle};
+use tracing::{info, warn};
+
+use crate::AuthError;
+
+/// Time allowed to open a connection to an identity provider
+const IDP_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Time allowed for a whole discovery or JWKS request, body included
+const IDP_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Builds the client used to reach identity providers
+///
+/// Requests are bounded so an IdP that stops answering fails the fetch instead of holding
+/// the single-flight lock, and every request waiting on it, forever.
+pub fn idp_client() -> Client {
+    Client::builder()
+        .connect_timeout(IDP_CONNECT_TIMEOUT)
+        .timeout(IDP_REQUEST_TIMEOUT)
+        .build()
+        .expect("identity provider http client")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Jwks {
+    pub keys: Vec<Jwk>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Jwk {
+    pub kid: String,
+    pub kty: Option<String>,
+    pub alg: Option<String>,
+    pub crv: Option<String>,
+    pub n: Option<String>,
+    pub e: Option<String>,
+    pub x: Option<String>,
+    pub y: Option<String>,
+}
+
+impl Jwk {
+    /// Builds the key used to verify a token signed with `algorithm`, refusing keys whose
+    /// advertised type, curve or algorithm do not match
+    pub fn decoding_key(&self, algorithm: Algorithm) -> Result<DecodingKey, AuthError> {
+        let mismatch = || AuthError::KeyAlgorithmMismatch {
+            key: self.kid.clone(),
+            algorithm: format!("{algorithm:?}"),
+        };
+
+        if let Some(alg) = &self.alg
+            && *alg != format!("{algorithm:?}")
+        {
+            return Err(mismatch());
+        }
+
+        let (kty, crv) = match algorithm {
+            Algorithm::RS256
+            | Algorithm::RS384
+            | Algorithm::RS512
+            | Algorithm::PS256
+            | Algorithm::PS384
+            | Algorithm::PS512 => ("RSA", None),
+            Algorithm::ES256 => ("EC", Some("P-256")),
+            Algorithm::ES384 => ("EC", Some("P-384")),
+            Algorithm::EdDSA => ("OKP", Some("Ed25519")),
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => return Err(mismatch()),
+        };
+
+        if self.kty.as_deref().is_some_and(|value| value != kty)
+            || self.crv.as_deref().zip(crv).is_some_and(|(a, b)| a != b)
+        {
+            return Err(mismatch());
+        }
+
+        let key = match (kty, &self.n, &self.e, &self.x, &self.y) {
+            ("RSA", Some(n), Some(e), _, _) => DecodingKey::from_rsa_components(n, e),
+            ("EC", _, _, Some(x), Some(y)) => DecodingKey::from_ec_components(x, y),
+            ("OKP", _, _, Some(x), _) => DecodingKey::from_ed_components(x),
+            _ => return Err(mismatch()),
+        };
+
+        key.map_err(|e| {
+            warn!("failed to build decoding key from jwk {}: {}", self.kid, e);
+            AuthError::Internal {
+                message: e.to_string(),
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct JwksCacheConfig {
+    /// Lifetime of a key set when the IdP sends no `Cache-Control: max-age`
+    pub default_ttl: Duration,
+    /// Minimum delay between two fetches, whatever triggered them
+    pub min_refresh_interval: Duration,
+    /// How long expired keys keep being served while the IdP cannot be reached
+    pub max_stale: Duration,
+}
+
+impl Default for JwksCacheConfig {
+    fn default() -> Self {
+        Self {
+            default_ttl: Duration::from_secs(5 * 60),
+            min_refresh_interval: Duration::from_secs(30),
+            max_stale: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+struct CachedKeys {
+    keys: HashMap<String, Jwk>,
+    expires_at: Instant,
+}
+
+impl CachedKeys {
+    fn is_fresh(&self, now: Instant) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// Shared JWKS cache for one issuer
+///
+/// Keys are kept for the `max-age` announced by the IdP. Once expired they are still
+/// served for up to `max_stale` while a refresh runs in the background, so an IdP outage
+/// does not reject tokens signed with a known key. A token with an unknown `kid` triggers
+/// an immediate refetch to pick up rotated keys. All fetches are single-flight and spaced
+/// by at least `min_refresh_interval`, so a burst of requests costs the IdP one call.
+pub struct JwksCache {
+    http: Arc<Client>,
+    url: String,
+    config: JwksCacheConfig,
+    state: RwLock<Option<CachedKeys>>,
+    /// Held while fetching; remembers when the last fetch ran and how it ended
+    last_fetch: Mutex<LastFetch>,
+    /// Bumped after every fetch attempt so waiters know someone else already tried
+    generation: AtomicU64,
+    refreshing: AtomicBool,
+}
+
+impl JwksCache {
+    pub fn new(http: Arc<Client>, url: impl Into<String>) -> Self {
+        Self {
+            http,
+            url: url.into(),
+            config: JwksCacheConfig::default(),
+            state: RwLock::new(None),
+            last_fetch: Mutex::new(LastFetch::default()),
+            generation: AtomicU64::new(0),
+            refreshing: AtomicBool::new(false),
+        }
+    }
+
+    pub fn with_config(mut self, config: JwksCacheConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Returns the key for `kid`, fetching the key set only when the cache cannot answer
+    pub async fn get_key(self: &Arc<Self>, kid: &str) -> Result<Jwk, AuthError> {
+        match self.lookup(kid) {
+            Lookup::Fresh(key) => return Ok(key),
+            Lookup::Stale(key) => {
+                self.refresh_in_background();
+                return Ok(key);
+            }
+            Lookup::Miss => {}
+        }
+
+        let refreshed = self.refresh().await;
+
+        match self.lookup(kid) {
+            Lookup::Fresh(key) | Lookup::Stale(key) => Ok(key),
+            Lookup::Miss => match refreshed {
+                Ok(()) => Err(AuthError::KeyNotFound {
+                    key: kid.to_string(),
+                }),
+                Err(err) => Err(err),
+            },
+        }
+    }
+
+    fn lookup(&self, kid: &str) -> Lookup {
+        let now = Instant::now();
+        let state = self.state.read().unwrap_or_else(|e| e.into_inner());
+
+        let Some(cached) = state.as_ref() else {
+            return Lookup::Miss;
+        };
+        let Some(key) = cached.keys.get(kid) else {
+            return Lookup::Miss;
+        };
+
+        if cached.is_fresh(now) {
+            Lookup::Fresh(key.clone())
+        } else if now < cached.expires_at + self.config.max_stale {
+            Lookup::Stale(key.clone())
+        } else {
+            Lookup::Miss
+        }
+    }
+
+    fn refresh_in_background(self: &Arc<Self>) -> Option<JoinHandle<()>> {
+        if self
+            .refreshing
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return None;
+        }
+
+        let guard = RefreshingGuard(Arc::clone(self));
+        Some(tokio::spawn(async move {
+            if let Err(err) = guard.0.refresh().await {
+                warn!(
+                    "background jwks refresh failed, serving stale keys: {}",
+                    err
+                );
+            }
+        }))
+    }
+
+    /// Fetches the key set unless another caller just did or the rate limit applies, in
+    /// which case the outcome of that previous fetch is reported instead
+    async fn refresh(&self) -> Result<(), AuthError> {
+        let observed = self.generation.load(Ordering::Acquire);
+        let mut last_fetch = self.last_fetch.lock().await;
+
+        if self.generation.load(Ordering::Acquire) != observed {
+            return last_fetch.outcome();
+        }
+        if let Some(at) = last_fetch.at
+            && at.elapsed() < self.config.min_refresh_interval
+        {
+            return last_fetch.outcome();
+        }
+
+        let result = self.fetch().await;
+        *last_fetch = LastFetch {
+            at: Some(Instant::now()),
+            error: result.as_ref().err().cloned(),
+        };
+        self.generation.fetch_add(1, Ordering::AcqRel);
+
+        let (jwks, max_age) = result?;
+        let ttl = max_age
+            .unwrap_or(self.config.default_ttl)
+            .max(self.config.min_refresh_interval);
+
+        info!("fetched jwks with {} keys", jwks.keys.len());
+
+        let keys = jwks
+            .keys
+            .into_iter()
+            .map(|key| (key.kid.clone(), key))
+            .collect();
+        *self.state.write().unwrap_or_else(|e| e.into_inner()) = Some(CachedKeys {
+            keys,
+            expires_at: Instant::now() + ttl,
+        });
+
+        Ok(())
+    }
+
+    pub(crate) async fn fetch(&self) -> Result<(Jwks, Option<Duration>), AuthError> {
+        let resp = self.http.get(&self.url).send().await.map_err(|e| {
+            warn!("failed to fetch jwks: {}", e);
+            AuthError::Network {
+                message: e.to_string(),
+            }
+        })?;
+
+        if resp.status().is_client_error() || resp.status().is_server_error() {
+            warn!("failed to fetch jwks: {}", resp.status());
+            return Err(AuthError::Network {
+                message: format!("failed to fetch jwks: {}", resp.status()),
+            });
+        }
+
+        let max_age = resp
+            .headers()
+            .get(CACHE_CONTROL)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_max_age);
+
+        let bytes = resp.bytes().await.map_err(|e| {
+            warn!("failed to read jwks response body: {}", e);
+            AuthError::Network {
+                message: e.to_string(),
+            }
+        })?;
+
+        let jwks: Jwks = serde_json::from_slice(&bytes).map_err(|e| AuthError::Network {
+            message: e.to_string(),
+        })?;
+
+        Ok((jwks, max_age))
+    }
+}
+
+/// Clears the `refreshing` flag when a background refresh ends, even when its task panics or
+/// is cancelled, so a later stale lookup can start another one
+struct RefreshingGuard(Arc<JwksCache>);
+
+impl Drop for RefreshingGuard {
+    fn drop(&mut self) {
+        self.0.refreshing.store(false, Ordering::Release);
+    }
+}
+
+#[derive(Default)]
+struct LastFetch {
+    at: Option<Instant>,
+    error: Option<AuthError>,
+}
+
+impl LastFetch {
+    fn outcome(&self) -> Result<(), AuthError> {
+        match &self.error {
+            Some(err) => Err(err.clone()),
+            None => Ok(()),
+        }
+    }
+}
+
+enum Lookup {
+    Fresh(Jwk),
+    Stale(Jwk),
+    Miss,
+}
+
+/// Reads the lifetime from a `Cache-Control` header; `no-cache` and `no-store` mean zero
+fn parse_max_age(header: &str) -> Option<Duration> {
+    header.split(',').map(str::trim).find_map(|directive| {
+        if directive.eq_ignore_ascii_case("no-cache") || directive.eq_ignore_ascii_case("no-store")
+        {
+            return Some(Duration::ZERO);
+        }
+
+        let (name, value) = directive.split_once('=')?;
+        if !name.trim().eq_ignore_ascii_case("max-age") {
+            return None;
+        }
+        value
+            .trim()
+            .trim_matches('"')
+            .parse()
+            .ok()
+            .map(Duration::from_secs)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::{Method::GET, MockServer};
+
+    use super::*;
+
+    fn jwks_body(kid: &str) -> serde_json::Value {
+        serde_json::json!({ "keys": [{ "kid": kid, "n": "n", "e": "AQAB" }] })
+    }
+
+    fn cache(server: &MockServer, config: JwksCacheConfig) -> Arc<JwksCache> {
+        Arc::new(JwksCache::new(Arc::new(idp_client()), server.url("/certs")).with_config(config))
+    }
+
+    fn jwk(kty: Option<&str>, alg: Option<&str>) -> Jwk {
+        Jwk {
+            kid: "kid-1".to_string(),
+            kty: kty.map(str::to_string),
+            alg: alg.map(str::to_string),
+            crv: Some("P-256".to_string()),
+            n: None,
+            e: None,
+            x: Some("9sVhZ_AwjLB7ojkUSBuxrUViwP1kyLn0e3JmCNAbvGg".to_string()),
+            y: Some("yK7dKfu8B5gqk3Ft7MW-mHmkPWSj3Yy_QR-U-OPw4RE".to_string()),
+        }
+    }
+
+    #[test]
+    fn decoding_key_matches_key_type_to_algorithm() {
+        assert!(
+            jwk(Some("EC"), Some("ES256"))
+                .decoding_key(Algorithm::ES256)
+                .is_ok()
+        );
+        assert!(jwk(None, None).decoding_key(Algorithm::ES256).is_ok());
+
+        for (key, algorithm) in [
+            (jwk(Some("EC"), Some("ES256")), Algorithm::RS256),
+            (jwk(Some("EC"), Some("ES256")), Algorithm::ES384),
+            (jwk(Some("EC"), Some("ES384")), Algorithm::ES256),
+            (jwk(Some("RSA"), None), Algorithm::ES256),
+            (jwk(None, None), Algorithm::HS256),
+            (jwk(None, None), Algorithm::EdDSA),
+        ] {
+            let err = key.decoding_key(algorithm).unwrap_err();
+            assert!(
+                matches!(err, AuthError::KeyAlgorithmMismatch { .. }),
+                "{algorithm:?}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_max_age_reads_cache_control() {
+        assert_eq!(
+            parse_max_age("public, max-age=300, must-revalidate"),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(parse_max_age("no-store"), Some(Duration::ZERO));
+        assert_eq!(parse_max_age("public"), None);
+        assert_eq!(parse_max_age("max-age=soon"), None);
+    }
+
+    #[tokio::test]
+    async fn get_key_fetches_once_while_fresh() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/certs");
+            then.status(200)
+                .header("cache-control", "max-age=300")
+                .json_body(jwks_body("kid-1"));
+        });
+        let cache = cache(&server, JwksCacheConfig::default());
+
+        let (first, second, third) = tokio::join!(
+            cache.get_key("kid-1"),
+            cache.get_key("kid-1"),
+            cache.get_key("kid-1")
+        );
+        cache.get_key("kid-1").await.unwrap();
+
+        assert!(first.is_ok() && second.is_ok() && third.is_ok());
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn unknown_kid_refetches_at_most_once_per_interval() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/certs");
+            then.status(200).json_body(jwks_body("kid-1"));
+        });
+        let cache = cache(
+            &server,
+            JwksCacheConfig {
+                min_refresh_interval: Duration::ZERO,
+                ..JwksCacheConfig::default()
+            },
+        );
+        cache.get_key("kid-1").await.unwrap();
+
+        let err = cache.get_key("rotated").await.unwrap_err();
+        assert!(matches!(err, AuthError::KeyNotFound { key } if key == "rotated"));
+        mock.assert_hits(2);
+
+        let limited = Arc::new(
+            JwksCache::new(Arc::new(Client::new()), server.url("/certs"))
+                .with_config(JwksCacheConfig::default()),
+        );
+        limited.get_key("kid-1").await.unwrap();
+        assert!(limited.get_key("rotated").await.is_err());
+        assert!(limited.get_key("rotated").await.is_err());
+        mock.assert_hits(3);
+    }
+
+    #[tokio::test]
+    async fn picks_up_rotated_key_on_kid_miss() {
+        let server = MockServer::start();
+        let mut old = server.mock(|when, then| {
+            when.method(GET).path("/certs");
+            then.status(200).json_body(jwks_body("kid-1"));
+        });
+        let cache = cache(
+            &server,
+            JwksCacheConfig {
+                min_refresh_interval: Duration::ZERO,
+                ..JwksCacheConfig::default()
+            },
+        );
+        cache.get_key("kid-1").await.unwrap();
+
+        old.delete();
+        server.mock(|when, then| {
+            when.method(GET).path("/certs");
+            then.status(200).json_body(jwks_body("kid-2"));
+        });
+
+        let key = cache.get_key("kid-2").await.unwrap();
+        assert_eq!(key.kid, "kid-2");
+    }
+
+    #[tokio::test]
+    async fn serves_stale_keys_when_idp_is_down() {
+        let server = MockServer::start();
+        let mut up = server.mock(|when, then| {
+            when.method(GET).path("/certs");
+            then.status(200)
+                .header("cache-control", "no-cache")
+                .json_body(jwks_body("kid-1"));
+        });
+        let cache = cache(
+            &server,
+            JwksCacheConfig {
+                min_refresh_interval: Duration::ZERO,
+                ..JwksCacheConfig::default()
+            },
+        );
+        cache.get_key("kid-1").await.unwrap();
+
+        up.delete();
+        let down = server.mock(|when, then| {
+            when.method(GET).path("/certs");
+            then.status(503);
+        });
+
+        let key = cache.get_key("kid-1").await.unwrap();
+        assert_eq!(key.kid, "kid-1");
+
+        let err = cache.get_key("kid-2").await.unwrap_err();
+        assert!(matches!(err, AuthError::Network { .. }));
+        assert!(down.hits() >= 1);
+    }
+
+    #[tokio::test]
+    async fn cancelled_background_refresh_allows_another() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/certs");
+            then.status(200)
+                .delay(Duration::from_secs(5))
+                .json_body(jwks_body("kid-1"));
+        });
+        let cache = cache(&server, JwksCacheConfig::default());
+
+        let refresh = cache.refresh_in_background().expect("refresh started");
+        assert!(cache.refresh_in_background().is_none());
+
+        refresh.abort();
+        assert!(refresh.await.unwrap_err().is_cancelled());
+
+        assert!(!cache.refreshing.load(Ordering::Acquire));
+        assert!(cache.refresh_in_background().is_some());
+    }
+}
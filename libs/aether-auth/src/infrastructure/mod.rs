@@ -1,2 +1,3 @@
-pub mod ferriskey_repository;
-pub mod keycloak_repository;
+pub mod jwks_cache;
+pub mod multi_issuer_repository;
+pub mod token_validator;
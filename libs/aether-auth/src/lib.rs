@@ -4,5 +4,6 @@ pub(crate) mod infrastructure;
 pub use domain::models::*;
 pub use domain::ports::*;
 
-pub use infrastructure::ferriskey_repository::FerrisKeyRepository;
-pub use infrastructure::keycloak_repository::KeycloakAuthRepository;
+pub use infrastructure::jwks_cache::{JwksCache, JwksCacheConfig};
+pub use infrastructure::multi_issuer_repository::MultiIssuerRepository;
+pub use infrastructure::token_validator::TokenValidator;
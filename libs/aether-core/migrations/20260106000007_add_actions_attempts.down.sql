@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_actions_constraints_not_after;
+DROP INDEX IF EXISTS idx_actions_status_leased_until;
+
+ALTER TABLE actions DROP COLUMN IF EXISTS attempts;
@@ -0,0 +1,6 @@
+-- Add up migration script here
+ALTER TABLE actions ADD COLUMN attempts INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_actions_status_leased_until ON actions(status, leased_until);
+CREATE INDEX idx_actions_constraints_not_after ON actions(constraints_not_after)
+    WHERE constraints_not_after IS NOT NULL;
@@ -0,0 +1,3 @@
+-- Add up migration script here
+CREATE INDEX idx_actions_pending_claim ON actions(deployment_id, constraints_priority, created_at, id)
+    WHERE status = 'pending';
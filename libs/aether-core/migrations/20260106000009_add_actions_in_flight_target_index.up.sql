@@ -0,0 +1,3 @@
+-- Add up migration script here
+CREATE INDEX idx_actions_in_flight_target ON actions(target_kind, target_id)
+    WHERE status IN ('leased', 'pulled');
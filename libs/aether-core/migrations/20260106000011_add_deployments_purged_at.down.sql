@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_deployments_pending_purge;
+ALTER TABLE deployments DROP COLUMN IF EXISTS purged_at;
@@ -0,0 +1,5 @@
+-- Add up migration script here
+ALTER TABLE deployments ADD COLUMN purged_at TIMESTAMPTZ;
+
+CREATE INDEX idx_deployments_pending_purge ON deployments(deleted_at)
+    WHERE deleted_at IS NOT NULL AND purged_at IS NULL;
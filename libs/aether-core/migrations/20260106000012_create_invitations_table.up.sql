@@ -0,0 +1,23 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS invitations (
+    id UUID PRIMARY KEY,
+    organisation_id UUID NOT NULL REFERENCES organisations(id) ON DELETE CASCADE,
+    email TEXT NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    role_ids UUID[] NOT NULL DEFAULT '{}',
+    status VARCHAR(20) NOT NULL,
+    invited_by UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+
+    expires_at TIMESTAMPTZ NOT NULL,
+    accepted_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_invitations_organisation_id ON invitations(organisation_id, created_at);
+
+-- At most one pending invitation per address and organisation
+CREATE UNIQUE INDEX idx_invitations_pending_email
+    ON invitations(organisation_id, LOWER(email))
+    WHERE status = 'pending';
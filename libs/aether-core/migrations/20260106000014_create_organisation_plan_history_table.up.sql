@@ -0,0 +1,20 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS organisation_plan_history (
+    id UUID PRIMARY KEY,
+    organisation_id UUID NOT NULL REFERENCES organisations(id) ON DELETE CASCADE,
+    from_plan VARCHAR(20) NOT NULL,
+    to_plan VARCHAR(20) NOT NULL,
+
+    max_instances INTEGER NOT NULL,
+    max_users INTEGER NOT NULL,
+    max_storage_gb INTEGER NOT NULL,
+
+    actor_type VARCHAR(255) NOT NULL,
+    actor_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    actor_client_id VARCHAR(255),
+
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_organisation_plan_history_organisation
+    ON organisation_plan_history(organisation_id, changed_at);
@@ -0,0 +1,9 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS data_plane_agents (
+    dataplane_id UUID NOT NULL REFERENCES data_planes(id) ON DELETE CASCADE,
+    client_id VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (dataplane_id, client_id)
+);
+
+CREATE INDEX idx_data_plane_agents_client_id ON data_plane_agents(client_id);
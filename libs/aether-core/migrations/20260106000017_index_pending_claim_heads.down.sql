@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_actions_pending_claim;
+
+CREATE INDEX idx_actions_pending_claim ON actions(deployment_id, constraints_priority, created_at, id)
+    WHERE status = 'pending';
@@ -0,0 +1,7 @@
+-- Add up migration script here
+-- The claim order ages with NOW() and cannot come from an index; this one serves the
+-- per-target head lookup instead, and the aging sort only runs over those heads.
+DROP INDEX IF EXISTS idx_actions_pending_claim;
+
+CREATE INDEX idx_actions_pending_claim ON actions(deployment_id, dataplane_id, target_kind, target_id, created_at, id)
+    WHERE status = 'pending';
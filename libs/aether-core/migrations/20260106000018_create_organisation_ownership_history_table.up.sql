@@ -0,0 +1,16 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS organisation_ownership_history (
+    id UUID PRIMARY KEY,
+    organisation_id UUID NOT NULL REFERENCES organisations(id) ON DELETE CASCADE,
+    from_owner_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    to_owner_id UUID REFERENCES users(id) ON DELETE SET NULL,
+
+    actor_type VARCHAR(255) NOT NULL,
+    actor_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    actor_client_id VARCHAR(255),
+
+    transferred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_organisation_ownership_history_organisation
+    ON organisation_ownership_history(organisation_id, transferred_at);
@@ -1,5 +1,8 @@
 use aether_auth::Identity;
-use aether_domain::action::{Action, commands::ClaimActionsCommand};
+use aether_domain::action::{
+    Action, ActionReapReport,
+    commands::{ClaimActionsCommand, ReapActionsCommand, ReportActionOutcomeCommand},
+};
 
 use crate::{
     AetherService, CoreError,
@@ -9,6 +12,7 @@ use crate::{
         ports::ActionService,
         service::ActionServiceImpl,
     },
+    dataplane::ports::DataPlaneService,
     infrastructure::action::PostgresActionRepository,
 };
 
@@ -84,11 +88,74 @@ impl ActionService for AetherService {
         identity: Identity,
         command: ClaimActionsCommand,
     ) -> Result<Vec<Action>, CoreError> {
+        self.authorize_agent(identity.clone(), command.dataplane_id)
+            .await?;
+
         let action_repository = PostgresActionRepository::from_pool(self.pool());
         let action_service = ActionServiceImpl::new(action_repository);
 
         action_service.claim_actions(identity, command).await
     }
+
+    async fn report_action_outcome(
+        &self,
+        identity: Identity,
+        command: ReportActionOutcomeCommand,
+    ) -> Result<Action, CoreError> {
+        self.authorize_agent(identity.clone(), command.dataplane_id)
+            .await?;
+
+        let action_repository = PostgresActionRepository::from_pool(self.pool());
+        let action_service = ActionServiceImpl::new(action_repository);
+
+        action_service
+            .report_action_outcome(identity, command)
+            .await
+    }
+
+    async fn reap_actions(
+        &self,
+        command: ReapActionsCommand,
+    ) -> Result<ActionReapReport, CoreError> {
+        let tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| CoreError::DatabaseError {
+                message: e.to_string(),
+            })?;
+        let tx = tokio::sync::Mutex::new(Some(tx));
+
+        let result = {
+            let action_repository = PostgresActionRepository::from_tx(&tx);
+            let action_service = ActionServiceImpl::new(action_repository);
+
+            action_service.reap_actions(command).await
+        };
+
+        match result {
+            Ok(report) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .commit()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Ok(report)
+            }
+            Err(err) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .rollback()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Err(err)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -162,4 +229,28 @@ mod tests {
         let result = service().fetch_actions(command, identity()).await;
         assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
     }
+
+    #[tokio::test]
+    async fn report_action_outcome_maps_pool_error() {
+        let command = ReportActionOutcomeCommand {
+            dataplane_id: DataPlaneId(Uuid::new_v4()),
+            deployment_id: crate::domain::deployments::DeploymentId(Uuid::new_v4()),
+            action_id: crate::domain::action::ActionId(Uuid::new_v4()),
+            outcome: aether_domain::action::commands::ActionOutcome::Published,
+        };
+
+        let result = service().report_action_outcome(identity(), command).await;
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
+
+    #[tokio::test]
+    async fn reap_actions_maps_pool_error() {
+        let command = ReapActionsCommand {
+            now: chrono::Utc::now(),
+            max_attempts: 5,
+        };
+
+        let result = service().reap_actions(command).await;
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
 }
@@ -1,26 +1,47 @@
 use std::sync::OnceLock;
 
-use aether_auth::{FerrisKeyRepository, Identity};
+use aether_auth::{Identity, MultiIssuerRepository, TokenValidator};
 
-use crate::{AetherService, CoreError, auth::ports::AuthService, auth::service::AuthServiceImpl};
+use crate::{
+    AetherService, CoreError, auth::ports::AuthService, auth::service::AuthServiceImpl,
+    domain::AuthConfig,
+};
 
-static AUTH_ISSUER: OnceLock<String> = OnceLock::new();
+static AUTH_CONFIG: OnceLock<AuthConfig> = OnceLock::new();
 
-pub fn set_auth_issuer(issuer: String) {
-    let _ = AUTH_ISSUER.set(issuer);
+/// Built once so its JWKS cache is shared by every request
+static AUTH_REPOSITORY: OnceLock<MultiIssuerRepository> = OnceLock::new();
+
+pub fn set_auth_config(config: AuthConfig) {
+    let _ = AUTH_CONFIG.set(config);
 }
 
-fn auth_issuer() -> Result<&'static str, CoreError> {
-    AUTH_ISSUER
+fn auth_config() -> Result<&'static AuthConfig, CoreError> {
+    AUTH_CONFIG
         .get()
-        .map(|value| value.as_str())
         .ok_or_else(|| CoreError::InternalError("Auth issuer not configured".to_string()))
 }
 
+fn auth_repository() -> Result<&'static MultiIssuerRepository, CoreError> {
+    let config = auth_config()?;
+
+    Ok(AUTH_REPOSITORY.get_or_init(|| {
+        config
+            .issuers
+            .iter()
+            .fold(MultiIssuerRepository::new(), |repository, issuer| {
+                let validator = TokenValidator::new(issuer.issuer.clone())
+                    .with_audiences(config.audiences.clone())
+                    .with_leeway(config.leeway_seconds);
+
+                repository.with_issuer(validator, issuer.claims.clone())
+            })
+    }))
+}
+
 impl AuthService for AetherService {
     async fn get_identity(&self, token: &str) -> Result<Identity, CoreError> {
-        let issuer = auth_issuer()?;
-        let auth_repository = FerrisKeyRepository::new(issuer.to_string(), None);
+        let auth_repository = auth_repository()?.clone();
         let auth_service = AuthServiceImpl::new(std::sync::Arc::new(auth_repository));
 
         auth_service.get_identity(token).await
@@ -30,15 +51,24 @@ impl AuthService for AetherService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::IssuerConfig;
+
+    fn config(issuer: &str) -> AuthConfig {
+        AuthConfig {
+            issuers: vec![IssuerConfig::new(issuer)],
+            audiences: Vec::new(),
+            leeway_seconds: AuthConfig::DEFAULT_LEEWAY_SECONDS,
+        }
+    }
 
     #[test]
-    fn auth_issuer_returns_value_when_set() {
-        if auth_issuer().is_err() {
-            set_auth_issuer("issuer-test".to_string());
+    fn auth_config_returns_value_when_set() {
+        if auth_config().is_err() {
+            set_auth_config(config("issuer-test"));
         }
 
-        let issuer = auth_issuer().expect("issuer should be configured");
-        assert!(!issuer.is_empty());
+        let config = auth_config().expect("issuer should be configured");
+        assert!(!config.issuers.is_empty());
     }
 
     #[tokio::test]
@@ -49,17 +79,17 @@ mod tests {
             .expect("valid database url");
         let service = AetherService::new(pool);
 
-        set_auth_issuer("http://127.0.0.1:1/realms/aether".to_string());
+        set_auth_config(config("http://127.0.0.1:1/realms/aether"));
 
         let result = AuthService::get_identity(&service, "bad-token").await;
         assert!(matches!(result, Err(CoreError::InvalidIdentity)));
     }
 
     #[test]
-    fn auth_issuer_returns_error_when_unset() {
-        let result = auth_issuer();
+    fn auth_config_returns_error_when_unset() {
+        let result = auth_config();
         if let Ok(value) = result {
-            assert!(!value.is_empty());
+            assert!(!value.issuers.is_empty());
         } else {
             assert!(matches!(result, Err(CoreError::InternalError(_))));
         }
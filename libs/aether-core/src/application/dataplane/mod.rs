@@ -2,16 +2,26 @@ use aether_auth::Identity;
 use aether_domain::{
     CoreError,
     dataplane::{
-        entities::DataPlane,
+        entities::{DataPlane, DataPlaneAgent},
         ports::DataPlaneService,
         service::DataPlaneServiceImpl,
-        value_objects::{CreateDataplaneCommand, DataPlaneId, ListDataPlaneDeploymentsCommand},
+        value_objects::{
+            CreateDataplaneCommand, DataPlaneId, ListDataPlaneDeploymentsCommand,
+            RegisterDataPlaneAgentCommand,
+        },
     },
     deployments::Deployment,
 };
 use aether_postgres::deployments::PostgresDeploymentRepository;
 
-use crate::{AetherService, infrastructure::dataplane::PostgresDataPlaneRepository};
+use crate::{
+    AetherService,
+    infrastructure::{
+        dataplane::{PostgresDataPlaneAgentRepository, PostgresDataPlaneRepository},
+        role::{PostgresRoleRepository, RolePermissionProvider},
+    },
+    policy::AetherPolicy,
+};
 
 impl DataPlaneService for AetherService {
     async fn create_dataplane(
@@ -31,8 +41,16 @@ impl DataPlaneService for AetherService {
         let result = {
             let dataplane_repository = PostgresDataPlaneRepository::from_tx(&tx);
             let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
-            let dataplane_service =
-                DataPlaneServiceImpl::new(dataplane_repository, deployment_repository);
+            let agent_repository = PostgresDataPlaneAgentRepository::from_tx(&tx);
+            let dataplane_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let dataplane_service = DataPlaneServiceImpl::new(
+                dataplane_repository,
+                deployment_repository,
+                agent_repository,
+                dataplane_policy,
+            );
 
             dataplane_service.create_dataplane(identity, command).await
         };
@@ -64,8 +82,16 @@ impl DataPlaneService for AetherService {
     async fn list_dataplanes(&self, identity: Identity) -> Result<Vec<DataPlane>, CoreError> {
         let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
         let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
-        let dataplane_service =
-            DataPlaneServiceImpl::new(dataplane_repository, deployment_repository);
+        let agent_repository = PostgresDataPlaneAgentRepository::from_pool(self.pool());
+        let dataplane_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let dataplane_service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            deployment_repository,
+            agent_repository,
+            dataplane_policy,
+        );
 
         dataplane_service.list_dataplanes(identity).await
     }
@@ -77,8 +103,16 @@ impl DataPlaneService for AetherService {
     ) -> Result<DataPlane, CoreError> {
         let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
         let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
-        let dataplane_service =
-            DataPlaneServiceImpl::new(dataplane_repository, deployment_repository);
+        let agent_repository = PostgresDataPlaneAgentRepository::from_pool(self.pool());
+        let dataplane_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let dataplane_service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            deployment_repository,
+            agent_repository,
+            dataplane_policy,
+        );
 
         dataplane_service
             .get_dataplane(identity, dataplane_id)
@@ -93,11 +127,111 @@ impl DataPlaneService for AetherService {
     ) -> Result<Vec<Deployment>, CoreError> {
         let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
         let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
-        let dataplane_service =
-            DataPlaneServiceImpl::new(dataplane_repository, deployment_repository);
+        let agent_repository = PostgresDataPlaneAgentRepository::from_pool(self.pool());
+        let dataplane_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let dataplane_service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            deployment_repository,
+            agent_repository,
+            dataplane_policy,
+        );
 
         dataplane_service
             .get_deployments_in_dataplane(identity, dataplane_id, command)
             .await
     }
+
+    async fn register_agent(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+        command: RegisterDataPlaneAgentCommand,
+    ) -> Result<DataPlaneAgent, CoreError> {
+        let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
+        let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
+        let agent_repository = PostgresDataPlaneAgentRepository::from_pool(self.pool());
+        let dataplane_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let dataplane_service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            deployment_repository,
+            agent_repository,
+            dataplane_policy,
+        );
+
+        dataplane_service
+            .register_agent(identity, dataplane_id, command)
+            .await
+    }
+
+    async fn list_agents(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+    ) -> Result<Vec<DataPlaneAgent>, CoreError> {
+        let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
+        let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
+        let agent_repository = PostgresDataPlaneAgentRepository::from_pool(self.pool());
+        let dataplane_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let dataplane_service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            deployment_repository,
+            agent_repository,
+            dataplane_policy,
+        );
+
+        dataplane_service.list_agents(identity, dataplane_id).await
+    }
+
+    async fn unregister_agent(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+        client_id: String,
+    ) -> Result<(), CoreError> {
+        let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
+        let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
+        let agent_repository = PostgresDataPlaneAgentRepository::from_pool(self.pool());
+        let dataplane_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let dataplane_service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            deployment_repository,
+            agent_repository,
+            dataplane_policy,
+        );
+
+        dataplane_service
+            .unregister_agent(identity, dataplane_id, client_id)
+            .await
+    }
+
+    async fn authorize_agent(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+    ) -> Result<(), CoreError> {
+        let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
+        let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
+        let agent_repository = PostgresDataPlaneAgentRepository::from_pool(self.pool());
+        let dataplane_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let dataplane_service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            deployment_repository,
+            agent_repository,
+            dataplane_policy,
+        );
+
+        dataplane_service
+            .authorize_agent(identity, dataplane_id)
+            .await
+    }
 }
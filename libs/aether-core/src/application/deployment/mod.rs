@@ -1,28 +1,36 @@
 use crate::{
     AetherService, CoreError,
     action::{
-        ActionPayload, ActionSource, ActionTarget, ActionType, ActionVersion, TargetKind,
-        commands::RecordActionCommand, ports::ActionService, service::ActionServiceImpl,
+        ActionConstraints, ActionPayload, ActionSource, ActionTarget, ActionType, ActionVersion,
+        TargetKind, commands::RecordActionCommand, ports::ActionService,
+        service::ActionServiceImpl,
     },
+    dataplane::ports::{DataPlaneRepository, DataPlaneScheduler},
     deployments::{
-        Deployment, DeploymentId,
+        Deployment, DeploymentId, DeploymentStatusChange,
         commands::{CreateDeploymentCommand, UpdateDeploymentCommand},
-        ports::DeploymentService,
+        ports::{DeploymentRepository, DeploymentService},
         service::DeploymentServiceImpl,
     },
     infrastructure::{
         action::PostgresActionRepository, dataplane::PostgresDataPlaneRepository,
-        deployments::PostgresDeploymentRepository, user::PostgresUserRepository,
+        deployments::PostgresDeploymentRepository, organisation::PostgresOrganisationRepository,
+        user::PostgresUserRepository,
     },
-    organisation::OrganisationId,
+    organisation::{OrganisationId, ports::OrganisationRepository},
+    user::ports::UserRepository,
 };
-use serde_json::json;
+use aether_auth::Identity;
+use aether_persistence::PgTransaction;
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value, json};
 
 impl DeploymentService for AetherService {
     async fn create_deployment(
         &self,
         command: CreateDeploymentCommand,
     ) -> Result<Deployment, CoreError> {
+        let scheduling_strategy = self.scheduling_strategy();
         let tx = self
             .pool()
             .begin()
@@ -37,43 +45,28 @@ impl DeploymentService for AetherService {
                 let deployment_repository = PostgresDeploymentRepository::from_tx(tx);
                 let user_repository = PostgresUserRepository::from_tx(tx);
                 let dataplane_repository = PostgresDataPlaneRepository::from_tx(tx);
+                let organisation_repository = PostgresOrganisationRepository::from_tx(tx);
                 let deployment_service = DeploymentServiceImpl::new(
                     deployment_repository,
                     user_repository,
                     dataplane_repository,
-                );
+                    organisation_repository,
+                )
+                .with_scheduler(scheduling_strategy);
 
                 let deployment = deployment_service.create_deployment(command).await?;
 
-                let action_repository = PostgresActionRepository::from_tx(tx);
-                let action_service = ActionServiceImpl::new(action_repository);
-                let action_command = RecordActionCommand::new(
-                    deployment.id,
-                    deployment.dataplane_id,
-                    ActionType("deployment.create".to_string()),
-                    ActionTarget {
-                        kind: TargetKind::Deployment,
-                        id: deployment.id.0,
-                    },
-                    ActionPayload {
-                        data: json!({
-                            "deployment_id": deployment.id.0,
-                            "dataplane_id": deployment.dataplane_id.0,
-                            "organisation_id": deployment.organisation_id.0,
-                            "name": deployment.name.0.clone(),
-                            "kind": deployment.kind.to_string(),
-                            "version": deployment.version.0.clone(),
-                            "namespace": deployment.namespace.clone(),
-                            "created_by": deployment.created_by.0,
-                        }),
-                    },
-                    ActionVersion(1),
-                    ActionSource::User {
-                        user_id: deployment.created_by.0,
-                    },
-                );
-
-                action_service.record_action(action_command).await?;
+                let action_service = ActionServiceImpl::new(PostgresActionRepository::from_tx(tx));
+                action_service
+                    .record_action(deployment_action(
+                        &deployment,
+                        "deployment.create",
+                        ActionSource::User {
+                            user_id: deployment.created_by.0,
+                        },
+                        None,
+                    ))
+                    .await?;
 
                 Ok(deployment)
             })
@@ -96,13 +89,19 @@ impl DeploymentService for AetherService {
                 let deployment_repository = PostgresDeploymentRepository::from_tx(tx);
                 let user_repository = PostgresUserRepository::from_tx(tx);
                 let dataplane_repository = PostgresDataPlaneRepository::from_tx(tx);
+                let organisation_repository = PostgresOrganisationRepository::from_tx(tx);
                 let deployment_service = DeploymentServiceImpl::new(
                     deployment_repository,
                     user_repository,
                     dataplane_repository,
+                    organisation_repository,
                 );
 
-                deployment_service.delete_deployment(deployment_id).await
+                let previous = deployment_service.lock_deployment(deployment_id).await?;
+                deployment_service.delete_deployment(deployment_id).await?;
+
+                record_deployment_change(tx, &deployment_service, previous, None).await?;
+                Ok(())
             })
         })
         .await
@@ -112,6 +111,7 @@ impl DeploymentService for AetherService {
         &self,
         organisation_id: OrganisationId,
         deployment_id: DeploymentId,
+        identity: Identity,
     ) -> Result<(), CoreError> {
         let tx = self
             .pool()
@@ -127,15 +127,26 @@ impl DeploymentService for AetherService {
                 let deployment_repository = PostgresDeploymentRepository::from_tx(tx);
                 let user_repository = PostgresUserRepository::from_tx(tx);
                 let dataplane_repository = PostgresDataPlaneRepository::from_tx(tx);
+                let organisation_repository = PostgresOrganisationRepository::from_tx(tx);
                 let deployment_service = DeploymentServiceImpl::new(
                     deployment_repository,
                     user_repository,
                     dataplane_repository,
+                    organisation_repository,
                 );
 
+                let previous = deployment_service.lock_deployment(deployment_id).await?;
                 deployment_service
-                    .delete_deployment_for_organisation(organisation_id, deployment_id)
-                    .await
+                    .delete_deployment_for_organisation(
+                        organisation_id,
+                        deployment_id,
+                        identity.clone(),
+                    )
+                    .await?;
+
+                record_deployment_change(tx, &deployment_service, previous, Some(&identity))
+                    .await?;
+                Ok(())
             })
         })
         .await
@@ -148,10 +159,12 @@ impl DeploymentService for AetherService {
         let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
         let user_repository = PostgresUserRepository::from_pool(self.pool());
         let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
+        let organisation_repository = PostgresOrganisationRepository::from_pool(self.pool());
         let deployment_service = DeploymentServiceImpl::new(
             deployment_repository,
             user_repository,
             dataplane_repository,
+            organisation_repository,
         );
 
         deployment_service.get_deployment(deployment_id).await
@@ -165,10 +178,12 @@ impl DeploymentService for AetherService {
         let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
         let user_repository = PostgresUserRepository::from_pool(self.pool());
         let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
+        let organisation_repository = PostgresOrganisationRepository::from_pool(self.pool());
         let deployment_service = DeploymentServiceImpl::new(
             deployment_repository,
             user_repository,
             dataplane_repository,
+            organisation_repository,
         );
 
         deployment_service
@@ -179,18 +194,140 @@ impl DeploymentService for AetherService {
     async fn list_deployments_by_organisation(
         &self,
         organisation_id: OrganisationId,
+        include_deleted: bool,
     ) -> Result<Vec<Deployment>, CoreError> {
         let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
         let user_repository = PostgresUserRepository::from_pool(self.pool());
         let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
+        let organisation_repository = PostgresOrganisationRepository::from_pool(self.pool());
         let deployment_service = DeploymentServiceImpl::new(
             deployment_repository,
             user_repository,
             dataplane_repository,
+            organisation_repository,
         );
 
         deployment_service
-            .list_deployments_by_organisation(organisation_id)
+            .list_deployments_by_organisation(organisation_id, include_deleted)
+            .await
+    }
+
+    async fn restore_deployment_for_organisation(
+        &self,
+        organisation_id: OrganisationId,
+        deployment_id: DeploymentId,
+        identity: Identity,
+    ) -> Result<Deployment, CoreError> {
+        let grace_period = self.deployment_grace_period();
+        let tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| CoreError::DatabaseError {
+                message: e.to_string(),
+            })?;
+        let tx = std::sync::Arc::new(tokio::sync::Mutex::new(Some(tx)));
+
+        run_deployment_transaction(tx, |tx| {
+            Box::pin(async move {
+                let deployment_repository = PostgresDeploymentRepository::from_tx(tx);
+                let user_repository = PostgresUserRepository::from_tx(tx);
+                let dataplane_repository = PostgresDataPlaneRepository::from_tx(tx);
+                let organisation_repository = PostgresOrganisationRepository::from_tx(tx);
+                let deployment_service = DeploymentServiceImpl::new(
+                    deployment_repository,
+                    user_repository,
+                    dataplane_repository,
+                    organisation_repository,
+                )
+                .with_grace_period(grace_period);
+
+                let previous = deployment_service.lock_deployment(deployment_id).await?;
+                deployment_service
+                    .restore_deployment_for_organisation(
+                        organisation_id,
+                        deployment_id,
+                        identity.clone(),
+                    )
+                    .await?;
+
+                record_deployment_change(tx, &deployment_service, previous, Some(&identity)).await
+            })
+        })
+        .await
+    }
+
+    async fn purge_deleted_deployments(
+        &self,
+        deleted_before: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<Deployment>, CoreError> {
+        let tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| CoreError::DatabaseError {
+                message: e.to_string(),
+            })?;
+        let tx = std::sync::Arc::new(tokio::sync::Mutex::new(Some(tx)));
+
+        run_deployment_transaction(tx, |tx| {
+            Box::pin(async move {
+                let deployment_repository = PostgresDeploymentRepository::from_tx(tx);
+                let user_repository = PostgresUserRepository::from_tx(tx);
+                let dataplane_repository = PostgresDataPlaneRepository::from_tx(tx);
+                let organisation_repository = PostgresOrganisationRepository::from_tx(tx);
+                let deployment_service = DeploymentServiceImpl::new(
+                    deployment_repository,
+                    user_repository,
+                    dataplane_repository,
+                    organisation_repository,
+                );
+
+                let purged = deployment_service
+                    .purge_deleted_deployments(deleted_before, limit)
+                    .await?;
+
+                let action_service = ActionServiceImpl::new(PostgresActionRepository::from_tx(tx));
+                for deployment in &purged {
+                    let previous = Deployment {
+                        purged_at: None,
+                        ..deployment.clone()
+                    };
+                    action_service
+                        .record_action(deployment_action(
+                            deployment,
+                            "deployment.delete",
+                            ActionSource::System,
+                            Some(deployment.changes_since(&previous)),
+                        ))
+                        .await?;
+                }
+
+                Ok(purged)
+            })
+        })
+        .await
+    }
+
+    async fn get_deployment_status_history_for_organisation(
+        &self,
+        organisation_id: OrganisationId,
+        deployment_id: DeploymentId,
+    ) -> Result<Vec<DeploymentStatusChange>, CoreError> {
+        let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
+        let user_repository = PostgresUserRepository::from_pool(self.pool());
+        let dataplane_repository = PostgresDataPlaneRepository::from_pool(self.pool());
+        let organisation_repository = PostgresOrganisationRepository::from_pool(self.pool());
+        let deployment_service = DeploymentServiceImpl::new(
+            deployment_repository,
+            user_repository,
+            dataplane_repository,
+            organisation_repository,
+        );
+
+        deployment_service
+            .get_deployment_status_history_for_organisation(organisation_id, deployment_id)
             .await
     }
 
@@ -213,15 +350,21 @@ impl DeploymentService for AetherService {
                 let deployment_repository = PostgresDeploymentRepository::from_tx(tx);
                 let user_repository = PostgresUserRepository::from_tx(tx);
                 let dataplane_repository = PostgresDataPlaneRepository::from_tx(tx);
+                let organisation_repository = PostgresOrganisationRepository::from_tx(tx);
                 let deployment_service = DeploymentServiceImpl::new(
                     deployment_repository,
                     user_repository,
                     dataplane_repository,
+                    organisation_repository,
                 );
 
+                let previous = deployment_service.lock_deployment(deployment_id).await?;
+                let actor = command.actor.clone();
                 deployment_service
                     .update_deployment(deployment_id, command)
-                    .await
+                    .await?;
+
+                record_deployment_change(tx, &deployment_service, previous, actor.as_ref()).await
             })
         })
         .await
@@ -247,21 +390,116 @@ impl DeploymentService for AetherService {
                 let deployment_repository = PostgresDeploymentRepository::from_tx(tx);
                 let user_repository = PostgresUserRepository::from_tx(tx);
                 let dataplane_repository = PostgresDataPlaneRepository::from_tx(tx);
+                let organisation_repository = PostgresOrganisationRepository::from_tx(tx);
                 let deployment_service = DeploymentServiceImpl::new(
                     deployment_repository,
                     user_repository,
                     dataplane_repository,
+                    organisation_repository,
                 );
 
+                let previous = deployment_service.lock_deployment(deployment_id).await?;
+                let actor = command.actor.clone();
                 deployment_service
                     .update_deployment_for_organisation(organisation_id, deployment_id, command)
-                    .await
+                    .await?;
+
+                record_deployment_change(tx, &deployment_service, previous, actor.as_ref()).await
             })
         })
         .await
     }
 }
 
+/// Priority of `deployment.delete` actions: a purged deployment should release its dataplane
+/// resources ahead of routine creates and updates.
+const DELETE_ACTION_PRIORITY: u8 = 0;
+
+/// Builds the action telling the dataplane about a deployment. Update and delete actions carry
+/// the changed fields under `changes`, next to the full deployment genesis needs.
+pub(super) fn deployment_action(
+    deployment: &Deployment,
+    action_type: &str,
+    source: ActionSource,
+    changes: Option<Map<String, Value>>,
+) -> RecordActionCommand {
+    let mut data = json!({
+        "deployment_id": deployment.id.0,
+        "dataplane_id": deployment.dataplane_id.0,
+        "organisation_id": deployment.organisation_id.0,
+        "name": deployment.name.0.clone(),
+        "kind": deployment.kind.to_string(),
+        "version": deployment.version.0.clone(),
+        "namespace": deployment.namespace.clone(),
+        "created_by": deployment.created_by.0,
+    });
+    if let Some(changes) = changes {
+        data["changes"] = Value::Object(changes);
+    }
+
+    let command = RecordActionCommand::new(
+        deployment.id,
+        deployment.dataplane_id,
+        ActionType(action_type.to_string()),
+        ActionTarget {
+            kind: TargetKind::Deployment,
+            id: deployment.id.0,
+        },
+        ActionPayload { data },
+        ActionVersion(1),
+        source,
+    );
+
+    match action_type {
+        "deployment.delete" => command.with_constraints(ActionConstraints {
+            not_after: None,
+            priority: Some(DELETE_ACTION_PRIORITY),
+        }),
+        _ => command,
+    }
+}
+
+/// Reloads the deployment after an update, delete or restore and records a `deployment.update`
+/// action in the same transaction. Deleted deployments keep running until they are purged, so
+/// the dataplane only hears `deployment.delete` from [`DeploymentService::purge_deleted_deployments`].
+/// Returns the reloaded deployment; nothing is recorded when no field changed.
+async fn record_deployment_change<D, U, DP, O, S>(
+    tx: &PgTransaction<'_>,
+    deployment_service: &DeploymentServiceImpl<D, U, DP, O, S>,
+    previous: Deployment,
+    actor: Option<&Identity>,
+) -> Result<Deployment, CoreError>
+where
+    D: DeploymentRepository,
+    U: UserRepository,
+    DP: DataPlaneRepository,
+    O: OrganisationRepository,
+    S: DataPlaneScheduler,
+{
+    let deployment = deployment_service
+        .get_deployment(previous.id)
+        .await?
+        .ok_or(CoreError::DeploymentNotFound { id: previous.id.0 })?;
+
+    let changes = deployment.changes_since(&previous);
+    if changes.is_empty() {
+        return Ok(deployment);
+    }
+
+    let source = deployment_service.resolve_actor(actor).await?;
+
+    ActionServiceImpl::new(PostgresActionRepository::from_tx(tx))
+        .record_action(deployment_action(
+            &deployment,
+            "deployment.update",
+            source,
+            Some(changes),
+        ))
+        .await?;
+
+    Ok(deployment)
+}
+
 type TxFuture<'a, T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send + 'a>>;
 
 trait DeploymentTransaction {
@@ -396,6 +634,76 @@ mod tests {
         AetherService::new(pool)
     }
 
+    #[test]
+    fn deployment_action_carries_deployment_and_changes() {
+        let now = chrono::Utc::now();
+        let previous = Deployment {
+            id: DeploymentId(Uuid::new_v4()),
+            organisation_id: OrganisationId(Uuid::new_v4()),
+            dataplane_id: crate::dataplane::value_objects::DataPlaneId(Uuid::new_v4()),
+            name: DeploymentName("app".to_string()),
+            kind: DeploymentKind::Keycloak,
+            version: DeploymentVersion("1.0.0".to_string()),
+            status: DeploymentStatus::Successful,
+            namespace: "default".to_string(),
+            created_by: UserId(Uuid::new_v4()),
+            created_at: now,
+            updated_at: now,
+            deployed_at: None,
+            deleted_at: None,
+            purged_at: None,
+        };
+        let mut deployment = previous.clone();
+        deployment.version = DeploymentVersion("2.0.0".to_string());
+
+        let command = deployment_action(
+            &deployment,
+            "deployment.update",
+            ActionSource::System,
+            Some(deployment.changes_since(&previous)),
+        );
+
+        let data = &command.payload.data;
+        assert_eq!(command.action_type.0, "deployment.update");
+        assert_eq!(command.constraints.priority, None);
+        assert_eq!(data["deployment_id"], json!(deployment.id.0));
+        assert_eq!(data["organisation_id"], json!(deployment.organisation_id.0));
+        assert_eq!(data["name"], "app");
+        assert_eq!(data["kind"], "keycloak");
+        assert_eq!(data["version"], "2.0.0");
+        assert_eq!(data["namespace"], "default");
+        assert_eq!(
+            data["changes"],
+            json!({ "version": { "from": "1.0.0", "to": "2.0.0" } })
+        );
+    }
+
+    #[test]
+    fn deployment_action_prioritises_delete() {
+        let now = chrono::Utc::now();
+        let deployment = Deployment {
+            id: DeploymentId(Uuid::new_v4()),
+            organisation_id: OrganisationId(Uuid::new_v4()),
+            dataplane_id: crate::dataplane::value_objects::DataPlaneId(Uuid::new_v4()),
+            name: DeploymentName("app".to_string()),
+            kind: DeploymentKind::Keycloak,
+            version: DeploymentVersion("1.0.0".to_string()),
+            status: DeploymentStatus::Successful,
+            namespace: "default".to_string(),
+            created_by: UserId(Uuid::new_v4()),
+            created_at: now,
+            updated_at: now,
+            deployed_at: None,
+            deleted_at: Some(now),
+            purged_at: Some(now),
+        };
+
+        let command =
+            deployment_action(&deployment, "deployment.delete", ActionSource::System, None);
+
+        assert_eq!(command.constraints.priority, Some(DELETE_ACTION_PRIORITY));
+    }
+
     #[tokio::test]
     async fn create_deployment_maps_pool_error() {
         let command = CreateDeploymentCommand::new(
@@ -415,7 +723,7 @@ mod tests {
     #[tokio::test]
     async fn list_deployments_maps_pool_error() {
         let result = service()
-            .list_deployments_by_organisation(OrganisationId(Uuid::new_v4()))
+            .list_deployments_by_organisation(OrganisationId(Uuid::new_v4()), false)
             .await;
 
         assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
@@ -455,6 +763,51 @@ mod tests {
             .delete_deployment_for_organisation(
                 OrganisationId(Uuid::new_v4()),
                 DeploymentId(Uuid::new_v4()),
+                Identity::Client(aether_auth::Client {
+                    id: "id".to_string(),
+                    client_id: "client".to_string(),
+                    roles: vec![],
+                    scopes: vec![],
+                }),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
+
+    #[tokio::test]
+    async fn restore_deployment_for_organisation_maps_pool_error() {
+        let result = service()
+            .restore_deployment_for_organisation(
+                OrganisationId(Uuid::new_v4()),
+                DeploymentId(Uuid::new_v4()),
+                Identity::Client(aether_auth::Client {
+                    id: "id".to_string(),
+                    client_id: "client".to_string(),
+                    roles: vec![],
+                    scopes: vec![],
+                }),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
+
+    #[tokio::test]
+    async fn purge_deleted_deployments_maps_pool_error() {
+        let result = service()
+            .purge_deleted_deployments(chrono::Utc::now(), 10)
+            .await;
+
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
+
+    #[tokio::test]
+    async fn get_deployment_status_history_for_organisation_maps_pool_error() {
+        let result = service()
+            .get_deployment_status_history_for_organisation(
+                OrganisationId(Uuid::new_v4()),
+                DeploymentId(Uuid::new_v4()),
             )
             .await;
 
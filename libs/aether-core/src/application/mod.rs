@@ -1,12 +1,17 @@
 use sqlx::{PgPool, Postgres, Transaction};
 use tokio::sync::Mutex;
 
-use crate::{AetherConfig, CoreError, application::auth::set_auth_issuer};
+use crate::{
+    AetherConfig, CoreError, RetentionConfig, application::auth::set_auth_config,
+    dataplane::scheduler::SchedulingStrategy,
+};
 
 mod action;
+mod api_key;
 mod auth;
 mod dataplane;
 mod deployment;
+mod invitation;
 mod organisation;
 mod role;
 mod user;
@@ -14,16 +19,52 @@ mod user;
 #[derive(Clone)]
 pub struct AetherService {
     pool: PgPool,
+    scheduling_strategy: SchedulingStrategy,
+    deployment_grace_period: chrono::Duration,
+    log_invitation_tokens: bool,
 }
 
 impl AetherService {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            scheduling_strategy: SchedulingStrategy::default(),
+            deployment_grace_period: RetentionConfig::default().deployment_grace_period,
+            log_invitation_tokens: false,
+        }
+    }
+
+    pub fn with_scheduling_strategy(mut self, scheduling_strategy: SchedulingStrategy) -> Self {
+        self.scheduling_strategy = scheduling_strategy;
+        self
+    }
+
+    pub fn with_deployment_grace_period(mut self, grace_period: chrono::Duration) -> Self {
+        self.deployment_grace_period = grace_period;
+        self
+    }
+
+    /// Logs invitation tokens instead of only the invitation, see [`crate::InvitationConfig`]
+    pub fn with_invitation_token_logging(mut self, enabled: bool) -> Self {
+        self.log_invitation_tokens = enabled;
+        self
     }
 
     pub fn pool(&self) -> &PgPool {
         &self.pool
     }
+
+    pub fn scheduling_strategy(&self) -> SchedulingStrategy {
+        self.scheduling_strategy
+    }
+
+    pub fn deployment_grace_period(&self) -> chrono::Duration {
+        self.deployment_grace_period
+    }
+
+    pub fn log_invitation_tokens(&self) -> bool {
+        self.log_invitation_tokens
+    }
 }
 
 pub(crate) async fn take_transaction<'t>(
@@ -50,9 +91,12 @@ pub async fn create_service(config: AetherConfig) -> Result<AetherService, CoreE
         .map_err(|e| CoreError::DatabaseError {
             message: e.to_string(),
         })?;
-    set_auth_issuer(config.auth.issuer);
+    set_auth_config(config.auth);
 
-    Ok(AetherService::new(pg_pool))
+    Ok(AetherService::new(pg_pool)
+        .with_scheduling_strategy(config.scheduler.strategy)
+        .with_deployment_grace_period(config.retention.deployment_grace_period)
+        .with_invitation_token_logging(config.invitation.log_tokens))
 }
 
 #[cfg(test)]
@@ -82,8 +126,13 @@ mod tests {
                 name: "db".to_string(),
             },
             auth: crate::domain::AuthConfig {
-                issuer: "http://issuer.test".to_string(),
+                issuers: vec![crate::domain::IssuerConfig::new("http://issuer.test")],
+                audiences: Vec::new(),
+                leeway_seconds: crate::domain::AuthConfig::DEFAULT_LEEWAY_SECONDS,
             },
+            scheduler: crate::domain::SchedulerConfig::default(),
+            retention: crate::domain::RetentionConfig::default(),
+            invitation: crate::domain::InvitationConfig::default(),
         };
 
         let result = timeout(Duration::from_millis(200), create_service(config)).await;
@@ -1,17 +1,36 @@
 use aether_auth::Identity;
+use aether_persistence::PgTransaction;
+use serde_json::Value;
 use tracing::info;
 
+use super::deployment::deployment_action;
 use crate::{
     CoreError,
+    action::{ports::ActionService, service::ActionServiceImpl},
     application::AetherService,
-    infrastructure::{organisation::PostgresOrganisationRepository, user::PostgresUserRepository},
+    deployments::{ports::DeploymentService, service::DeploymentServiceImpl},
+    infrastructure::{
+        action::PostgresActionRepository,
+        dataplane::PostgresDataPlaneRepository,
+        deployments::PostgresDeploymentRepository,
+        organisation::PostgresOrganisationRepository,
+        role::{PostgresRoleRepository, RolePermissionProvider},
+        user::PostgresUserRepository,
+    },
     organisation::service::OrganisationServiceImpl,
     organisation::{
         Organisation, OrganisationId,
-        commands::{CreateOrganisationCommand, UpdateOrganisationCommand},
+        commands::{
+            AddMemberCommand, AssignMemberRolesCommand, ChangeOrganisationStatusCommand,
+            ChangePlanCommand, CreateOrganisationCommand, TransferOwnershipCommand,
+            UpdateOrganisationCommand,
+        },
+        member::{Member, MemberId},
+        plan::{PlanChange, PlanChangeReport},
         ports::OrganisationService,
         value_objects::OrganisationStatus,
     },
+    policy::AetherPolicy,
 };
 
 impl OrganisationService for AetherService {
@@ -31,8 +50,21 @@ impl OrganisationService for AetherService {
         let result = {
             let organisation_repository = PostgresOrganisationRepository::from_tx(&tx);
             let user_repository = PostgresUserRepository::from_tx(&tx);
-            let organisation_service =
-                OrganisationServiceImpl::new(organisation_repository, user_repository);
+            let role_repository = PostgresRoleRepository::from_tx(&tx);
+            let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
+            let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+            let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+            let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let organisation_service = OrganisationServiceImpl::new(
+                organisation_repository,
+                user_repository,
+                role_repository,
+                deployment_repository,
+                member_policy,
+                organisation_policy,
+            );
 
             organisation_service.create_organisation(command).await
         };
@@ -75,8 +107,21 @@ impl OrganisationService for AetherService {
         let result = {
             let organisation_repository = PostgresOrganisationRepository::from_tx(&tx);
             let user_repository = PostgresUserRepository::from_tx(&tx);
-            let organisation_service =
-                OrganisationServiceImpl::new(organisation_repository, user_repository);
+            let role_repository = PostgresRoleRepository::from_tx(&tx);
+            let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
+            let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+            let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+            let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let organisation_service = OrganisationServiceImpl::new(
+                organisation_repository,
+                user_repository,
+                role_repository,
+                deployment_repository,
+                member_policy,
+                organisation_policy,
+            );
 
             organisation_service.delete_organisation(id).await
         };
@@ -122,8 +167,21 @@ impl OrganisationService for AetherService {
         let result = {
             let organisation_repository = PostgresOrganisationRepository::from_tx(&tx);
             let user_repository = PostgresUserRepository::from_tx(&tx);
-            let organisation_service =
-                OrganisationServiceImpl::new(organisation_repository, user_repository);
+            let role_repository = PostgresRoleRepository::from_tx(&tx);
+            let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
+            let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+            let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+            let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let organisation_service = OrganisationServiceImpl::new(
+                organisation_repository,
+                user_repository,
+                role_repository,
+                deployment_repository,
+                member_policy,
+                organisation_policy,
+            );
 
             organisation_service.update_organisation(id, command).await
         };
@@ -160,8 +218,21 @@ impl OrganisationService for AetherService {
     ) -> Result<Vec<Organisation>, CoreError> {
         let organisation_repository = PostgresOrganisationRepository::from_pool(self.pool());
         let user_repository = PostgresUserRepository::from_pool(self.pool());
-        let organisation_service =
-            OrganisationServiceImpl::new(organisation_repository, user_repository);
+        let role_repository = PostgresRoleRepository::from_pool(self.pool());
+        let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
+        let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+        let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+        let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let organisation_service = OrganisationServiceImpl::new(
+            organisation_repository,
+            user_repository,
+            role_repository,
+            deployment_repository,
+            member_policy,
+            organisation_policy,
+        );
 
         organisation_service
             .get_organisations(status, limit, offset)
@@ -174,13 +245,586 @@ impl OrganisationService for AetherService {
     ) -> Result<Vec<Organisation>, CoreError> {
         let organisation_repository = PostgresOrganisationRepository::from_pool(self.pool());
         let user_repository = PostgresUserRepository::from_pool(self.pool());
-        let organisation_service =
-            OrganisationServiceImpl::new(organisation_repository, user_repository);
+        let role_repository = PostgresRoleRepository::from_pool(self.pool());
+        let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
+        let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+        let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+        let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let organisation_service = OrganisationServiceImpl::new(
+            organisation_repository,
+            user_repository,
+            role_repository,
+            deployment_repository,
+            member_policy,
+            organisation_policy,
+        );
 
         organisation_service
             .get_organisations_by_member(identity)
             .await
     }
+
+    async fn list_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<Vec<Member>, CoreError> {
+        let organisation_repository = PostgresOrganisationRepository::from_pool(self.pool());
+        let user_repository = PostgresUserRepository::from_pool(self.pool());
+        let role_repository = PostgresRoleRepository::from_pool(self.pool());
+        let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
+        let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+        let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+        let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let organisation_service = OrganisationServiceImpl::new(
+            organisation_repository,
+            user_repository,
+            role_repository,
+            deployment_repository,
+            member_policy,
+            organisation_policy,
+        );
+
+        organisation_service
+            .list_members(identity, organisation_id)
+            .await
+    }
+
+    async fn add_member(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: AddMemberCommand,
+    ) -> Result<Member, CoreError> {
+        let tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| CoreError::DatabaseError {
+                message: e.to_string(),
+            })?;
+        let tx = tokio::sync::Mutex::new(Some(tx));
+
+        let result = {
+            let organisation_repository = PostgresOrganisationRepository::from_tx(&tx);
+            let user_repository = PostgresUserRepository::from_tx(&tx);
+            let role_repository = PostgresRoleRepository::from_tx(&tx);
+            let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
+            let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+            let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+            let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let organisation_service = OrganisationServiceImpl::new(
+                organisation_repository,
+                user_repository,
+                role_repository,
+                deployment_repository,
+                member_policy,
+                organisation_policy,
+            );
+
+            organisation_service
+                .add_member(identity, organisation_id, command)
+                .await
+        };
+
+        match result {
+            Ok(member) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .commit()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Ok(member)
+            }
+            Err(err) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .rollback()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn remove_member(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        member_id: MemberId,
+    ) -> Result<(), CoreError> {
+        let tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| CoreError::DatabaseError {
+                message: e.to_string(),
+            })?;
+        let tx = tokio::sync::Mutex::new(Some(tx));
+
+        let result = {
+            let organisation_repository = PostgresOrganisationRepository::from_tx(&tx);
+            let user_repository = PostgresUserRepository::from_tx(&tx);
+            let role_repository = PostgresRoleRepository::from_tx(&tx);
+            let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
+            let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+            let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+            let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let organisation_service = OrganisationServiceImpl::new(
+                organisation_repository,
+                user_repository,
+                role_repository,
+                deployment_repository,
+                member_policy,
+                organisation_policy,
+            );
+
+            organisation_service
+                .remove_member(identity, organisation_id, member_id)
+                .await
+        };
+
+        match result {
+            Ok(()) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .commit()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Ok(())
+            }
+            Err(err) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .rollback()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn assign_member_roles(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        member_id: MemberId,
+        command: AssignMemberRolesCommand,
+    ) -> Result<Member, CoreError> {
+        let tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| CoreError::DatabaseError {
+                message: e.to_string(),
+            })?;
+        let tx = tokio::sync::Mutex::new(Some(tx));
+
+        let result = {
+            let organisation_repository = PostgresOrganisationRepository::from_tx(&tx);
+            let user_repository = PostgresUserRepository::from_tx(&tx);
+            let role_repository = PostgresRoleRepository::from_tx(&tx);
+            let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
+            let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+            let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+            let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let organisation_service = OrganisationServiceImpl::new(
+                organisation_repository,
+                user_repository,
+                role_repository,
+                deployment_repository,
+                member_policy,
+                organisation_policy,
+            );
+
+            organisation_service
+                .assign_member_roles(identity, organisation_id, member_id, command)
+                .await
+        };
+
+        match result {
+            Ok(member) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .commit()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Ok(member)
+            }
+            Err(err) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .rollback()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn transfer_ownership(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: TransferOwnershipCommand,
+    ) -> Result<Organisation, CoreError> {
+        let tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| CoreError::DatabaseError {
+                message: e.to_string(),
+            })?;
+        let tx = tokio::sync::Mutex::new(Some(tx));
+
+        let result = {
+            let organisation_repository = PostgresOrganisationRepository::from_tx(&tx);
+            let user_repository = PostgresUserRepository::from_tx(&tx);
+            let role_repository = PostgresRoleRepository::from_tx(&tx);
+            let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
+            let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+            let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+            let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let organisation_service = OrganisationServiceImpl::new(
+                organisation_repository,
+                user_repository,
+                role_repository,
+                deployment_repository,
+                member_policy,
+                organisation_policy,
+            );
+
+            organisation_service
+                .transfer_ownership(identity, organisation_id, command)
+                .await
+        };
+
+        match result {
+            Ok(organisation) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .commit()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Ok(organisation)
+            }
+            Err(err) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .rollback()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn suspend_organisation(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: ChangeOrganisationStatusCommand,
+    ) -> Result<Organisation, CoreError> {
+        let tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| CoreError::DatabaseError {
+                message: e.to_string(),
+            })?;
+        let tx = tokio::sync::Mutex::new(Some(tx));
+
+        let result = {
+            let organisation_repository = PostgresOrganisationRepository::from_tx(&tx);
+            let user_repository = PostgresUserRepository::from_tx(&tx);
+            let role_repository = PostgresRoleRepository::from_tx(&tx);
+            let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
+            let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+            let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+            let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let organisation_service = OrganisationServiceImpl::new(
+                organisation_repository,
+                user_repository,
+                role_repository,
+                deployment_repository,
+                member_policy,
+                organisation_policy,
+            );
+
+            let reason = command.reason.clone();
+            match organisation_service
+                .suspend_organisation(identity.clone(), organisation_id, command)
+                .await
+            {
+                Ok(organisation) => record_deployments_action(
+                    &tx,
+                    organisation_id,
+                    &identity,
+                    "deployment.suspend",
+                    &reason,
+                )
+                .await
+                .map(|()| organisation),
+                Err(err) => Err(err),
+            }
+        };
+
+        match result {
+            Ok(organisation) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .commit()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Ok(organisation)
+            }
+            Err(err) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .rollback()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn reactivate_organisation(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: ChangeOrganisationStatusCommand,
+    ) -> Result<Organisation, CoreError> {
+        let tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| CoreError::DatabaseError {
+                message: e.to_string(),
+            })?;
+        let tx = tokio::sync::Mutex::new(Some(tx));
+
+        let result = {
+            let organisation_repository = PostgresOrganisationRepository::from_tx(&tx);
+            let user_repository = PostgresUserRepository::from_tx(&tx);
+            let role_repository = PostgresRoleRepository::from_tx(&tx);
+            let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
+            let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+            let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+            let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let organisation_service = OrganisationServiceImpl::new(
+                organisation_repository,
+                user_repository,
+                role_repository,
+                deployment_repository,
+                member_policy,
+                organisation_policy,
+            );
+
+            let reason = command.reason.clone();
+            match organisation_service
+                .reactivate_organisation(identity.clone(), organisation_id, command)
+                .await
+            {
+                Ok(organisation) => record_deployments_action(
+                    &tx,
+                    organisation_id,
+                    &identity,
+                    "deployment.resume",
+                    &reason,
+                )
+                .await
+                .map(|()| organisation),
+                Err(err) => Err(err),
+            }
+        };
+
+        match result {
+            Ok(organisation) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .commit()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Ok(organisation)
+            }
+            Err(err) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .rollback()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn change_plan(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: ChangePlanCommand,
+    ) -> Result<PlanChangeReport, CoreError> {
+        let tx = self
+            .pool()
+            .begin()
+            .await
+            .map_err(|e| CoreError::DatabaseError {
+                message: e.to_string(),
+            })?;
+        let tx = tokio::sync::Mutex::new(Some(tx));
+
+        let result = {
+            let organisation_repository = PostgresOrganisationRepository::from_tx(&tx);
+            let user_repository = PostgresUserRepository::from_tx(&tx);
+            let role_repository = PostgresRoleRepository::from_tx(&tx);
+            let deployment_repository = PostgresDeploymentRepository::from_tx(&tx);
+            let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+            let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+            let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+                PostgresRoleRepository::from_pool(self.pool()),
+            ));
+            let organisation_service = OrganisationServiceImpl::new(
+                organisation_repository,
+                user_repository,
+                role_repository,
+                deployment_repository,
+                member_policy,
+                organisation_policy,
+            );
+
+            organisation_service
+                .change_plan(identity, organisation_id, command)
+                .await
+        };
+
+        match result {
+            Ok(report) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .commit()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                if report.applied {
+                    info!(
+                        "Organisation {} moved from {} to {} plan",
+                        organisation_id, report.from_plan, report.to_plan
+                    );
+                }
+                Ok(report)
+            }
+            Err(err) => {
+                super::take_transaction(&tx)
+                    .await?
+                    .rollback()
+                    .await
+                    .map_err(|e| CoreError::DatabaseError {
+                        message: e.to_string(),
+                    })?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn get_plan_history(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<Vec<PlanChange>, CoreError> {
+        let organisation_repository = PostgresOrganisationRepository::from_pool(self.pool());
+        let user_repository = PostgresUserRepository::from_pool(self.pool());
+        let role_repository = PostgresRoleRepository::from_pool(self.pool());
+        let deployment_repository = PostgresDeploymentRepository::from_pool(self.pool());
+        let policy_repo = PostgresRoleRepository::from_pool(self.pool());
+        let member_policy = AetherPolicy::new(RolePermissionProvider::new(policy_repo));
+        let organisation_policy = AetherPolicy::new(RolePermissionProvider::new(
+            PostgresRoleRepository::from_pool(self.pool()),
+        ));
+        let organisation_service = OrganisationServiceImpl::new(
+            organisation_repository,
+            user_repository,
+            role_repository,
+            deployment_repository,
+            member_policy,
+            organisation_policy,
+        );
+
+        organisation_service
+            .get_plan_history(identity, organisation_id)
+            .await
+    }
+}
+
+/// Records `action_type` for every deployment of the organisation that is not deleted, in the
+/// transaction that changed its status. Deleted deployments only hear `deployment.delete`.
+async fn record_deployments_action(
+    tx: &PgTransaction<'_>,
+    organisation_id: OrganisationId,
+    identity: &Identity,
+    action_type: &str,
+    reason: &str,
+) -> Result<(), CoreError> {
+    let deployment_service = DeploymentServiceImpl::new(
+        PostgresDeploymentRepository::from_tx(tx),
+        PostgresUserRepository::from_tx(tx),
+        PostgresDataPlaneRepository::from_tx(tx),
+        PostgresOrganisationRepository::from_tx(tx),
+    );
+    let source = deployment_service.resolve_actor(Some(identity)).await?;
+    let deployments = deployment_service
+        .list_deployments_by_organisation(organisation_id, false)
+        .await?;
+
+    let action_service = ActionServiceImpl::new(PostgresActionRepository::from_tx(tx));
+    for deployment in &deployments {
+        let mut command = deployment_action(deployment, action_type, source.clone(), None);
+        command.payload.data["reason"] = Value::String(reason.to_string());
+        action_service.record_action(command).await?;
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -224,6 +868,7 @@ mod tests {
             id: "not-a-uuid".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         });
@@ -248,4 +893,67 @@ mod tests {
             .await;
         assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
     }
+
+    fn member_identity() -> Identity {
+        Identity::User(aether_auth::User {
+            id: "user-1".to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        })
+    }
+
+    #[tokio::test]
+    async fn list_members_maps_pool_error() {
+        let result = service()
+            .list_members(member_identity(), OrganisationId(Uuid::new_v4()))
+            .await;
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
+
+    #[tokio::test]
+    async fn add_member_maps_pool_error() {
+        let result = service()
+            .add_member(
+                member_identity(),
+                OrganisationId(Uuid::new_v4()),
+                AddMemberCommand::new(crate::user::UserId(Uuid::new_v4())),
+            )
+            .await;
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
+
+    #[tokio::test]
+    async fn suspend_organisation_maps_pool_error() {
+        let result = service()
+            .suspend_organisation(
+                member_identity(),
+                OrganisationId(Uuid::new_v4()),
+                ChangeOrganisationStatusCommand::new("unpaid invoice").unwrap(),
+            )
+            .await;
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
+
+    #[tokio::test]
+    async fn change_plan_maps_pool_error() {
+        let result = service()
+            .change_plan(
+                member_identity(),
+                OrganisationId(Uuid::new_v4()),
+                ChangePlanCommand::new(Plan::Starter),
+            )
+            .await;
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
+
+    #[tokio::test]
+    async fn get_plan_history_maps_pool_error() {
+        let result = service()
+            .get_plan_history(member_identity(), OrganisationId(Uuid::new_v4()))
+            .await;
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
 }
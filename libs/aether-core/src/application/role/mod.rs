@@ -214,6 +214,7 @@ mod tests {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         })
@@ -229,11 +230,12 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn list_roles_rejects_permission() {
+    async fn list_roles_maps_pool_error() {
         let identity = Identity::User(User {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         });
@@ -242,15 +244,16 @@ mod tests {
             .list_roles_by_organisation(identity, OrganisationId(Uuid::new_v4()))
             .await;
 
-        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
     }
 
     #[tokio::test]
-    async fn get_role_rejects_permission() {
+    async fn get_role_maps_pool_error() {
         let identity = Identity::User(User {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         });
@@ -263,7 +266,7 @@ mod tests {
             )
             .await;
 
-        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
     }
 
     #[tokio::test]
@@ -272,6 +275,7 @@ mod tests {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         });
@@ -293,6 +297,7 @@ mod tests {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         });
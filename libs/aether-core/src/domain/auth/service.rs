@@ -95,6 +95,7 @@ mod tests {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec!["admin".to_string()],
         });
@@ -1,6 +1,7 @@
 pub use aether_domain::{
-    AetherConfig, AuthConfig, CoreError, DatabaseConfig, action, dataplane, deployments,
-    organisation, role, user,
+    AetherConfig, AuthConfig, CoreError, DatabaseConfig, ErrorKind, InvitationConfig, IssuerConfig,
+    RetentionConfig, SchedulerConfig, action, api_key, dataplane, deployments, invitation,
+    organisation, role, token, user,
 };
 
 pub mod auth;
@@ -3,7 +3,12 @@ use aether_permission::Permissions;
 
 use crate::domain::{
     CoreError,
-    organisation::OrganisationId,
+    api_key::ports::ApiKeyPolicy,
+    dataplane::ports::DataPlanePolicy,
+    organisation::{
+        OrganisationId,
+        ports::{MemberPolicy, OrganisationPolicy},
+    },
     role::ports::{PermissionProvider, RolePolicy},
 };
 
@@ -64,6 +69,21 @@ impl PolicyContext {
     }
 }
 
+/// Identity provider role of platform operators, who act across all organisations
+pub const PLATFORM_ADMIN_ROLE: &str = "platform-admin";
+
+/// Platform administration is granted by the identity provider, never by organisation roles,
+/// so an organisation administrator cannot grant it to themselves
+fn require_platform_admin(identity: &Identity) -> Result<(), CoreError> {
+    if identity.has_role(PLATFORM_ADMIN_ROLE) {
+        Ok(())
+    } else {
+        Err(CoreError::PermissionDenied {
+            reason: "platform administrator role required".to_string(),
+        })
+    }
+}
+
 pub struct AetherPolicy<R>
 where
     R: PermissionProvider,
@@ -82,27 +102,63 @@ where
     }
 }
 
-impl<R> RolePolicy for AetherPolicy<R>
+impl<R> AetherPolicy<R>
 where
     R: PermissionProvider,
 {
-    async fn can_view_roles(
+    async fn context(
         &self,
         identity: Identity,
         organisation_id: OrganisationId,
-    ) -> Result<(), CoreError> {
+    ) -> Result<PolicyContext, CoreError> {
+        // API keys carry their own permissions, valid only in the organisation they belong to
+        if let Identity::ApiKey(key) = &identity {
+            if key.organisation_id != organisation_id.to_string() {
+                return Ok(PolicyContext::new(Permissions::empty()));
+            }
+
+            let mut permissions = Permissions::from_bits_truncate(key.permissions);
+            // A personal access token never does more than its owner currently can, so it
+            // loses what the owner loses, and everything once the owner leaves
+            if key.user_id.is_some() {
+                let owner = self
+                    .role_permission_provider
+                    .permissions_for_organisation(identity.clone(), organisation_id)
+                    .await?;
+                if !owner.can(Permissions::ADMINISTRATOR) {
+                    permissions &= owner;
+                }
+            }
+
+            return Ok(PolicyContext::new(permissions));
+        }
+
         let permissions = self
             .role_permission_provider
             .permissions_for_organisation(identity, organisation_id)
             .await?;
 
-        let context = PolicyContext::new(permissions);
-        context.require_any(&[
-            Permissions::ADMINISTRATOR,
-            Permissions::VIEW_ROLES,
-            Permissions::MANAGE_ROLES,
-            Permissions::MANAGE_ORGANISATION,
-        ])
+        Ok(PolicyContext::new(permissions))
+    }
+}
+
+impl<R> RolePolicy for AetherPolicy<R>
+where
+    R: PermissionProvider,
+{
+    async fn can_view_roles(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        self.context(identity, organisation_id)
+            .await?
+            .require_any(&[
+                Permissions::ADMINISTRATOR,
+                Permissions::VIEW_ROLES,
+                Permissions::MANAGE_ROLES,
+                Permissions::MANAGE_ORGANISATION,
+            ])
     }
 
     async fn can_manage_roles(
@@ -110,17 +166,172 @@ where
         identity: Identity,
         organisation_id: OrganisationId,
     ) -> Result<(), CoreError> {
-        let permissions = self
-            .role_permission_provider
-            .permissions_for_organisation(identity, organisation_id)
-            .await?;
+        self.context(identity, organisation_id)
+            .await?
+            .require_any(&[
+                Permissions::ADMINISTRATOR,
+                Permissions::MANAGE_ROLES,
+                Permissions::MANAGE_ORGANISATION,
+            ])
+    }
+}
+
+impl<R> MemberPolicy for AetherPolicy<R>
+where
+    R: PermissionProvider,
+{
+    async fn can_view_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        self.context(identity, organisation_id)
+            .await?
+            .require_any(&[
+                Permissions::ADMINISTRATOR,
+                Permissions::VIEW_MEMBERS,
+                Permissions::MANAGE_MEMBERS,
+                Permissions::MANAGE_ORGANISATION,
+            ])
+    }
+
+    async fn can_add_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        self.context(identity, organisation_id)
+            .await?
+            .require_any(&[
+                Permissions::ADMINISTRATOR,
+                Permissions::INVITE_MEMBERS,
+                Permissions::MANAGE_MEMBERS,
+                Permissions::MANAGE_ORGANISATION,
+            ])
+    }
+
+    async fn can_manage_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        self.context(identity, organisation_id)
+            .await?
+            .require_any(&[
+                Permissions::ADMINISTRATOR,
+                Permissions::MANAGE_MEMBERS,
+                Permissions::MANAGE_ORGANISATION,
+            ])
+    }
+
+    async fn can_remove_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        self.context(identity, organisation_id)
+            .await?
+            .require_any(&[
+                Permissions::ADMINISTRATOR,
+                Permissions::KICK_MEMBERS,
+                Permissions::MANAGE_MEMBERS,
+                Permissions::MANAGE_ORGANISATION,
+            ])
+    }
+
+    async fn can_grant_permissions(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        permissions: Permissions,
+    ) -> Result<(), CoreError> {
+        let context = self.context(identity, organisation_id).await?;
+        if context.can(Permissions::ADMINISTRATOR) || context.can(permissions) {
+            Ok(())
+        } else {
+            Err(CoreError::PermissionDenied {
+                reason: "a role cannot be granted with permissions the caller lacks".to_string(),
+            })
+        }
+    }
+}
+
+impl<R> OrganisationPolicy for AetherPolicy<R>
+where
+    R: PermissionProvider,
+{
+    async fn can_transfer_ownership(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        self.context(identity, organisation_id)
+            .await?
+            .require_permission(Permissions::ADMINISTRATOR)
+    }
+
+    async fn can_change_status(
+        &self,
+        identity: Identity,
+        _organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        require_platform_admin(&identity)
+    }
+
+    async fn can_view_billing(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        self.context(identity, organisation_id)
+            .await?
+            .require_any(&[
+                Permissions::ADMINISTRATOR,
+                Permissions::VIEW_BILLING,
+                Permissions::MANAGE_BILLING,
+            ])
+    }
+
+    async fn can_manage_billing(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        self.context(identity, organisation_id)
+            .await?
+            .require_any(&[Permissions::ADMINISTRATOR, Permissions::MANAGE_BILLING])
+    }
 
-        let context = PolicyContext::new(permissions);
-        context.require_any(&[
-            Permissions::ADMINISTRATOR,
-            Permissions::MANAGE_ROLES,
-            Permissions::MANAGE_ORGANISATION,
-        ])
+    async fn can_upgrade_plan(
+        &self,
+        identity: Identity,
+        _organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        require_platform_admin(&identity)
+    }
+}
+
+impl<R> DataPlanePolicy for AetherPolicy<R>
+where
+    R: PermissionProvider,
+{
+    async fn can_manage_agents(&self, identity: Identity) -> Result<(), CoreError> {
+        require_platform_admin(&identity)
+    }
+}
+
+impl<R> ApiKeyPolicy for AetherPolicy<R>
+where
+    R: PermissionProvider,
+{
+    async fn can_manage_api_keys(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<(), CoreError> {
+        self.context(identity, organisation_id)
+            .await?
+            .require_any(&[Permissions::ADMINISTRATOR, Permissions::MANAGE_ORGANISATION])
     }
 }
 
@@ -181,6 +392,7 @@ mod tests {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         });
@@ -201,6 +413,7 @@ mod tests {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         });
@@ -221,6 +434,7 @@ mod tests {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         });
@@ -241,6 +455,7 @@ mod tests {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         });
@@ -251,4 +466,387 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn aether_policy_allows_removing_members_with_kick_permission() {
+        let policy = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::KICK_MEMBERS,
+        });
+        let identity = Identity::User(aether_auth::User {
+            id: "user-1".to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        });
+        let organisation_id = OrganisationId(Uuid::new_v4());
+
+        assert!(
+            policy
+                .can_remove_members(identity.clone(), organisation_id)
+                .await
+                .is_ok()
+        );
+        assert!(
+            policy
+                .can_manage_members(identity, organisation_id)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn aether_policy_allows_adding_members_with_invite_permission() {
+        let policy = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::INVITE_MEMBERS,
+        });
+        let identity = Identity::User(aether_auth::User {
+            id: "user-1".to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        });
+        let organisation_id = OrganisationId(Uuid::new_v4());
+
+        assert!(
+            policy
+                .can_add_members(identity.clone(), organisation_id)
+                .await
+                .is_ok()
+        );
+        assert!(
+            policy
+                .can_view_members(identity, organisation_id)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn aether_policy_restricts_ownership_transfer_to_administrators() {
+        let identity = Identity::User(aether_auth::User {
+            id: "user-1".to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        });
+        let manager = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::MANAGE_ORGANISATION,
+        });
+        let administrator = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::ADMINISTRATOR,
+        });
+
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        assert!(
+            manager
+                .can_transfer_ownership(identity.clone(), organisation_id)
+                .await
+                .is_err()
+        );
+        assert!(
+            administrator
+                .can_transfer_ownership(identity, organisation_id)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn aether_policy_caps_role_grants_at_the_caller_permissions() {
+        let identity = Identity::User(aether_auth::User {
+            id: "user-1".to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        });
+        let manager = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::MANAGE_MEMBERS | Permissions::VIEW_MEMBERS,
+        });
+        let administrator = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::ADMINISTRATOR,
+        });
+
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        assert!(
+            manager
+                .can_grant_permissions(identity.clone(), organisation_id, Permissions::VIEW_MEMBERS)
+                .await
+                .is_ok()
+        );
+        assert!(
+            manager
+                .can_grant_permissions(
+                    identity.clone(),
+                    organisation_id,
+                    Permissions::VIEW_MEMBERS | Permissions::MANAGE_ROLES,
+                )
+                .await
+                .is_err()
+        );
+        assert!(
+            manager
+                .can_grant_permissions(
+                    identity.clone(),
+                    organisation_id,
+                    Permissions::ADMINISTRATOR
+                )
+                .await
+                .is_err()
+        );
+        assert!(
+            administrator
+                .can_grant_permissions(identity, organisation_id, Permissions::all())
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn aether_policy_restricts_status_changes_to_platform_admins() {
+        let administrator = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::ADMINISTRATOR,
+        });
+        let member = aether_auth::User {
+            id: "user-1".to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        };
+        let operator = aether_auth::User {
+            roles: vec![PLATFORM_ADMIN_ROLE.to_string()],
+            ..member.clone()
+        };
+
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        assert!(
+            administrator
+                .can_change_status(Identity::User(member), organisation_id)
+                .await
+                .is_err()
+        );
+        assert!(
+            administrator
+                .can_change_status(Identity::User(operator), organisation_id)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn aether_policy_restricts_plan_upgrades_to_platform_admins() {
+        let administrator = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::ADMINISTRATOR,
+        });
+        let member = aether_auth::User {
+            id: "user-1".to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        };
+        let operator = aether_auth::User {
+            roles: vec![PLATFORM_ADMIN_ROLE.to_string()],
+            ..member.clone()
+        };
+
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        assert!(
+            administrator
+                .can_manage_billing(Identity::User(member.clone()), organisation_id)
+                .await
+                .is_ok()
+        );
+        assert!(
+            administrator
+                .can_upgrade_plan(Identity::User(member), organisation_id)
+                .await
+                .is_err()
+        );
+        assert!(
+            administrator
+                .can_upgrade_plan(Identity::User(operator), organisation_id)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn aether_policy_restricts_agent_management_to_platform_admins() {
+        let administrator = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::ADMINISTRATOR,
+        });
+        let member = aether_auth::User {
+            id: "user-1".to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        };
+        let operator = aether_auth::User {
+            roles: vec![PLATFORM_ADMIN_ROLE.to_string()],
+            ..member.clone()
+        };
+
+        assert!(
+            administrator
+                .can_manage_agents(Identity::User(member))
+                .await
+                .is_err()
+        );
+        assert!(
+            administrator
+                .can_manage_agents(Identity::User(operator))
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn aether_policy_separates_viewing_and_managing_billing() {
+        let identity = Identity::User(aether_auth::User {
+            id: "user-1".to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        });
+        let viewer = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::VIEW_BILLING,
+        });
+        let manager = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::MANAGE_BILLING,
+        });
+
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        assert!(
+            viewer
+                .can_view_billing(identity.clone(), organisation_id)
+                .await
+                .is_ok()
+        );
+        assert!(
+            viewer
+                .can_manage_billing(identity.clone(), organisation_id)
+                .await
+                .is_err()
+        );
+        assert!(
+            manager
+                .can_view_billing(identity.clone(), organisation_id)
+                .await
+                .is_ok()
+        );
+        assert!(
+            manager
+                .can_manage_billing(identity, organisation_id)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn aether_policy_uses_api_key_permissions_in_its_organisation_only() {
+        let policy = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::ADMINISTRATOR,
+        });
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        let identity = Identity::ApiKey(aether_auth::ApiKey {
+            id: "key-1".to_string(),
+            name: "ci".to_string(),
+            organisation_id: organisation_id.to_string(),
+            user_id: None,
+            permissions: Permissions::VIEW_ROLES.bits(),
+        });
+
+        assert!(
+            policy
+                .can_view_roles(identity.clone(), organisation_id)
+                .await
+                .is_ok()
+        );
+        assert!(
+            policy
+                .can_manage_roles(identity.clone(), organisation_id)
+                .await
+                .is_err()
+        );
+        assert!(
+            policy
+                .can_view_roles(identity, OrganisationId(Uuid::new_v4()))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn aether_policy_caps_personal_access_tokens_at_owner_permissions() {
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        let token = |user_id: Option<&str>| {
+            Identity::ApiKey(aether_auth::ApiKey {
+                id: "key-1".to_string(),
+                name: "laptop".to_string(),
+                organisation_id: organisation_id.to_string(),
+                user_id: user_id.map(str::to_string),
+                permissions: (Permissions::VIEW_ROLES | Permissions::MANAGE_ROLES).bits(),
+            })
+        };
+        let owner = Some("6f1c2a4e-0000-4000-8000-000000000001");
+
+        let viewer = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::VIEW_ROLES,
+        });
+        assert!(
+            viewer
+                .can_view_roles(token(owner), organisation_id)
+                .await
+                .is_ok()
+        );
+        assert!(
+            viewer
+                .can_manage_roles(token(owner), organisation_id)
+                .await
+                .is_err()
+        );
+
+        // The owner left the organisation
+        let former = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::empty(),
+        });
+        assert!(
+            former
+                .can_view_roles(token(owner), organisation_id)
+                .await
+                .is_err()
+        );
+        // Organisation keys are not tied to anyone's permissions
+        assert!(
+            former
+                .can_manage_roles(token(None), organisation_id)
+                .await
+                .is_ok()
+        );
+
+        let administrator = AetherPolicy::new(StaticPermissionProvider {
+            permissions: Permissions::ADMINISTRATOR,
+        });
+        assert!(
+            administrator
+                .can_manage_roles(token(owner), organisation_id)
+                .await
+                .is_ok()
+        );
+    }
 }
@@ -1 +1,3 @@
-pub use aether_postgres::dataplane::PostgresDataPlaneRepository;
+pub use aether_postgres::dataplane::{
+    PostgresDataPlaneAgentRepository, PostgresDataPlaneRepository,
+};
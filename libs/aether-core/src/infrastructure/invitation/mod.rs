@@ -0,0 +1 @@
+pub use aether_postgres::invitation::PostgresInvitationRepository;
@@ -1,6 +1,8 @@
 pub mod action;
+pub mod api_key;
 pub mod dataplane;
 pub mod deployments;
+pub mod invitation;
 pub mod organisation;
 pub mod role;
 pub mod user;
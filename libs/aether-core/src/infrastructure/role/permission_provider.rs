@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use aether_auth::Identity;
 use aether_permission::Permissions;
 
@@ -8,6 +10,7 @@ use crate::domain::{
         Role,
         ports::{PermissionProvider, RoleRepository},
     },
+    user::UserId,
 };
 
 #[derive(Clone)]
@@ -36,14 +39,30 @@ where
         identity: Identity,
         organisation_id: OrganisationId,
     ) -> Result<Permissions, CoreError> {
-        let role_names = identity.roles().to_vec();
-        if role_names.is_empty() {
-            return Ok(Permissions::empty());
+        // A personal access token acts for its owner and holds the roles their own tokens
+        // would; organisation keys have no owner and no roles
+        if let Identity::ApiKey(key) = &identity {
+            let Some(user_id) = key.user_id.as_deref() else {
+                return Ok(Permissions::empty());
+            };
+            let user_id = UserId::from_str(user_id).map_err(|_| CoreError::InvalidIdentity)?;
+            let roles = self
+                .role_repository
+                .list_granted_to_user(organisation_id, user_id)
+                .await?;
+
+            return Ok(permissions_from_roles(&roles));
         }
 
+        // Users hold the organisation roles named by their identity provider roles, plus the
+        // roles assigned to their membership
         let roles = self
             .role_repository
-            .list_by_names(organisation_id, role_names)
+            .list_granted_to_subject(
+                organisation_id,
+                identity.id().to_string(),
+                identity.roles().to_vec(),
+            )
             .await?;
 
         Ok(permissions_from_roles(&roles))
@@ -0,0 +1,109 @@
+use aether_auth::{Identity, User};
+use aether_core::AetherService;
+use aether_core::api_key::{commands::CreateApiKeyCommand, ports::ApiKeyService};
+use aether_core::organisation::OrganisationId;
+use aether_core::role::ports::RoleService;
+use aether_core::user::{commands::CreateUserCommand, ports::UserService};
+use aether_permission::Permissions;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Seeds an organisation owned by someone else, with a `role-viewer` role and Jane as a member
+/// holding no assigned roles
+async fn seed(pool: &PgPool, jane_id: Uuid) -> OrganisationId {
+    let owner_id = Uuid::new_v4();
+    let organisation_id = Uuid::new_v4();
+
+    sqlx::query(
+        "INSERT INTO users (id, email, name, sub) VALUES ($1, 'owner@example.com', 'Owner', 'owner')",
+    )
+    .bind(owner_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO organisations (id, name, slug, owner_id, status, plan, max_instances, max_users, max_storage_gb)
+         VALUES ($1, 'Acme', 'acme', $2, 'active', 'free', 1, 2, 1)",
+    )
+    .bind(organisation_id)
+    .bind(owner_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO members (id, user_id, organisation_id) VALUES ($1, $2, $3)")
+        .bind(Uuid::new_v4())
+        .bind(jane_id)
+        .bind(organisation_id)
+        .execute(pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO roles (id, name, permissions, organisation_id) VALUES ($1, 'role-viewer', $2, $3)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(Permissions::VIEW_ROLES.bits() as i64)
+    .bind(organisation_id)
+    .execute(pool)
+    .await
+    .unwrap();
+
+    OrganisationId(organisation_id)
+}
+
+#[sqlx::test(migrations = "./migrations")]
+#[ignore = "needs a Postgres server in DATABASE_URL"]
+async fn personal_access_token_keeps_the_identity_provider_roles_of_its_owner(pool: PgPool) {
+    let service = AetherService::new(pool.clone());
+    let identity = Identity::User(User {
+        id: "jane".to_string(),
+        username: "jane".to_string(),
+        email: Some("jane@example.com".to_string()),
+        email_verified: true,
+        name: Some("Jane".to_string()),
+        roles: vec!["role-viewer".to_string()],
+    });
+
+    // What the auth middleware does on every request carrying a token from the provider
+    let jane = service
+        .create_user(CreateUserCommand {
+            name: "Jane".to_string(),
+            email: "jane@example.com".to_string(),
+            sub: "jane".to_string(),
+            roles: vec!["role-viewer".to_string()],
+        })
+        .await
+        .unwrap();
+    let organisation_id = seed(&pool, jane.id.0).await;
+
+    service
+        .list_roles_by_organisation(identity.clone(), organisation_id)
+        .await
+        .unwrap();
+
+    let issued = service
+        .create_api_key(
+            identity,
+            CreateApiKeyCommand::new(
+                organisation_id,
+                "laptop".to_string(),
+                Permissions::VIEW_ROLES.bits(),
+            )
+            .with_personal(true),
+        )
+        .await
+        .unwrap();
+
+    let token = service.authenticate_api_key(&issued.secret).await.unwrap();
+    let roles = service
+        .list_roles_by_organisation(token, organisation_id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        roles
+            .iter()
+            .map(|role| role.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["role-viewer"]
+    );
+}
@@ -38,6 +38,10 @@ pub struct IdentityInstanceSpec {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ingress: Option<IngressConfig>,
+
+    /// Scales the instance down to zero replicas while true. The database is kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspended: Option<bool>,
 }
 
 /// Status of the IdentityInstance
@@ -231,6 +235,7 @@ mod tests {
             },
             ferriskey: None,
             ingress: None,
+            suspended: None,
         };
 
         assert_eq!(spec.provider, IdentityProvider::Keycloak);
@@ -315,6 +320,7 @@ mod tests {
                 },
                 ferriskey: None,
                 ingress: None,
+                suspended: None,
             },
             status: Some(super::IdentityInstanceStatus {
                 phase: Some(Phase::Running),
@@ -361,6 +367,7 @@ mod tests {
                 },
                 ferriskey: None,
                 ingress: None,
+                suspended: None,
             },
             status: None,
         };
@@ -1,6 +1,8 @@
+use chrono::{DateTime, Utc};
+
 use crate::action::{
-    ActionConstraints, ActionCursor, ActionPayload, ActionSource, ActionTarget, ActionType,
-    ActionVersion,
+    ActionConstraints, ActionCursor, ActionFailureReason, ActionId, ActionPayload, ActionSource,
+    ActionTarget, ActionType, ActionVersion,
 };
 use crate::{dataplane::value_objects::DataPlaneId, deployments::DeploymentId};
 
@@ -74,6 +76,29 @@ pub struct ClaimActionsCommand {
     pub lease_seconds: i64,
 }
 
+#[derive(Debug, Clone)]
+pub struct ReapActionsCommand {
+    /// Reference time used to decide whether leases and deadlines have expired
+    pub now: DateTime<Utc>,
+    /// Number of leases an action may go through before it is failed with `Timeout`
+    pub max_attempts: u32,
+}
+
+/// Result reported by an agent for an action it was handed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionOutcome {
+    Published,
+    Failed { reason: ActionFailureReason },
+}
+
+#[derive(Debug, Clone)]
+pub struct ReportActionOutcomeCommand {
+    pub dataplane_id: DataPlaneId,
+    pub deployment_id: DeploymentId,
+    pub action_id: ActionId,
+    pub outcome: ActionOutcome,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,6 +91,49 @@ pub enum ActionStatus {
     },
 }
 
+impl ActionStatus {
+    /// Stable lowercase name of the status, as stored and reported in errors
+    pub fn name(&self) -> &'static str {
+        match self {
+            ActionStatus::Pending => "pending",
+            ActionStatus::Leased { .. } => "leased",
+            ActionStatus::Pulled { .. } => "pulled",
+            ActionStatus::Published { .. } => "published",
+            ActionStatus::Failed { .. } => "failed",
+        }
+    }
+
+    /// Published and failed actions never change status again
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ActionStatus::Published { .. } | ActionStatus::Failed { .. }
+        )
+    }
+
+    /// Whether the action lifecycle allows moving from `self` to `next`.
+    ///
+    /// Pending actions are leased by herald, then either pulled by an agent or reported
+    /// directly as published or failed. Agents may also pull pending actions themselves,
+    /// under a lease. An expired lease goes back to pending, and a pending action past its
+    /// deadline can be failed without ever being leased.
+    pub fn can_transition_to(&self, next: &ActionStatus) -> bool {
+        matches!(
+            (self, next),
+            (ActionStatus::Pending, ActionStatus::Leased { .. })
+                | (ActionStatus::Pending, ActionStatus::Pulled { .. })
+                | (ActionStatus::Pending, ActionStatus::Failed { .. })
+                | (ActionStatus::Leased { .. }, ActionStatus::Pending)
+                | (ActionStatus::Leased { .. }, ActionStatus::Pulled { .. })
+                | (ActionStatus::Leased { .. }, ActionStatus::Published { .. })
+                | (ActionStatus::Leased { .. }, ActionStatus::Failed { .. })
+                | (ActionStatus::Pulled { .. }, ActionStatus::Pending)
+                | (ActionStatus::Pulled { .. }, ActionStatus::Published { .. })
+                | (ActionStatus::Pulled { .. }, ActionStatus::Failed { .. })
+        )
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 pub enum ActionFailureReason {
     InvalidPayload,
@@ -128,6 +171,15 @@ pub struct ActionConstraints {
     pub priority: Option<u8>,
 }
 
+impl ActionConstraints {
+    /// Priority assumed for actions that do not set one
+    pub const DEFAULT_PRIORITY: u8 = 128;
+
+    /// Waiting this long promotes a pending action by one priority level, so that
+    /// low-priority actions cannot starve (see `ActionRepository::claim_pending`)
+    pub const PRIORITY_AGING_SECONDS: i64 = 30;
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct ActionCursor(pub String);
 
@@ -143,6 +195,17 @@ pub struct ActionBatch {
     pub next_cursor: Option<ActionCursor>,
 }
 
+/// Counts of actions touched by one pass of the lease reaper
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ActionReapReport {
+    /// Expired leases returned to `Pending` for redelivery
+    pub requeued: u64,
+    /// Expired leases that used up their attempts and were failed with `Timeout`
+    pub timed_out: u64,
+    /// Actions failed with `Timeout` because their `not_after` deadline passed
+    pub expired: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,4 +246,45 @@ mod tests {
             _ => panic!("expected failed status"),
         }
     }
+
+    #[test]
+    fn action_status_allows_lifecycle_transitions() {
+        let now = Utc::now();
+        let leased = ActionStatus::Leased { until: now };
+        let pulled = ActionStatus::Pulled {
+            agent_id: "agent-1".to_string(),
+            at: now,
+        };
+        let published = ActionStatus::Published { at: now };
+        let failed = ActionStatus::Failed {
+            reason: ActionFailureReason::PublishFailed,
+            at: now,
+        };
+
+        assert!(ActionStatus::Pending.can_transition_to(&leased));
+        assert!(leased.can_transition_to(&pulled));
+        assert!(leased.can_transition_to(&published));
+        assert!(leased.can_transition_to(&ActionStatus::Pending));
+        assert!(ActionStatus::Pending.can_transition_to(&pulled));
+        assert!(pulled.can_transition_to(&ActionStatus::Pending));
+        assert!(pulled.can_transition_to(&failed));
+        assert!(pulled.can_transition_to(&published));
+    }
+
+    #[test]
+    fn action_status_rejects_illegal_transitions() {
+        let now = Utc::now();
+        let published = ActionStatus::Published { at: now };
+        let failed = ActionStatus::Failed {
+            reason: ActionFailureReason::Timeout,
+            at: now,
+        };
+
+        assert!(!ActionStatus::Pending.can_transition_to(&published));
+        assert!(!published.can_transition_to(&failed));
+        assert!(!failed.can_transition_to(&ActionStatus::Pending));
+        assert!(published.is_terminal());
+        assert!(failed.is_terminal());
+        assert!(!ActionStatus::Pending.is_terminal());
+    }
 }
@@ -4,8 +4,12 @@ use aether_auth::Identity;
 use chrono::{DateTime, Utc};
 
 use crate::CoreError;
-use crate::action::commands::{ClaimActionsCommand, FetchActionsCommand, RecordActionCommand};
-use crate::action::{Action, ActionBatch, ActionCursor, ActionId};
+use crate::action::commands::{
+    ClaimActionsCommand, FetchActionsCommand, ReapActionsCommand, RecordActionCommand,
+    ReportActionOutcomeCommand,
+};
+use crate::action::{Action, ActionBatch, ActionCursor, ActionId, ActionReapReport, ActionStatus};
+use crate::dataplane::value_objects::DataPlaneId;
 use crate::deployments::DeploymentId;
 
 #[cfg_attr(test, mockall::automock)]
@@ -25,12 +29,60 @@ pub trait ActionRepository: Send + Sync {
         limit: usize,
     ) -> impl Future<Output = Result<ActionBatch, CoreError>> + Send;
 
+    /// Hands up to `max` pending actions of the deployment on `dataplane_id` to `agent_id`,
+    /// most urgent first. Claimed actions are `Pulled` by the agent and leased until `lease_until`.
+    ///
+    /// Urgency is the action priority, promoted by one level per
+    /// [`PRIORITY_AGING_SECONDS`](crate::action::ActionConstraints::PRIORITY_AGING_SECONDS)
+    /// spent pending; ties go to the oldest action.
+    ///
+    /// Actions on the same [`ActionTarget`](crate::action::ActionTarget) are handed out one at a
+    /// time in creation order: the next one only becomes claimable once the previous one is
+    /// `Published` or `Failed`.
     fn claim_pending(
         &self,
         deployment_id: DeploymentId,
+        dataplane_id: DataPlaneId,
+        agent_id: String,
         max: usize,
         lease_until: DateTime<Utc>,
     ) -> impl Future<Output = Result<Vec<Action>, CoreError>> + Send;
+
+    /// Moves an action to `status` only if it is still in the `expected` status.
+    ///
+    /// Returns `None` when the action does not exist or was concurrently moved to another status.
+    fn transition_status(
+        &self,
+        deployment_id: DeploymentId,
+        action_id: ActionId,
+        expected: ActionStatus,
+        status: ActionStatus,
+        agent_id: String,
+    ) -> impl Future<Output = Result<Option<Action>, CoreError>> + Send;
+
+    /// Returns leased or pulled actions whose lease expired before `now` to `Pending`, as long
+    /// as they have been claimed fewer than `max_attempts` times. Returns the number of actions.
+    fn requeue_expired_leases(
+        &self,
+        now: DateTime<Utc>,
+        max_attempts: u32,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+
+    /// Fails leased or pulled actions whose lease expired before `now` after `max_attempts`
+    /// claims
+    fn fail_exhausted_leases(
+        &self,
+        now: DateTime<Utc>,
+        max_attempts: u32,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+
+    /// Fails actions whose `not_after` deadline is before `now`: pending ones right away, leased
+    /// or pulled ones only once their lease expired too, so an agent mid-execution can still
+    /// report its outcome
+    fn fail_past_deadline(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -57,4 +109,17 @@ pub trait ActionService: Send + Sync {
         identity: Identity,
         command: ClaimActionsCommand,
     ) -> impl Future<Output = Result<Vec<Action>, CoreError>> + Send;
+
+    /// Records the outcome reported by an agent, moving the action to published or failed
+    fn report_action_outcome(
+        &self,
+        identity: Identity,
+        command: ReportActionOutcomeCommand,
+    ) -> impl Future<Output = Result<Action, CoreError>> + Send;
+
+    /// Re-queues expired leases and times out exhausted or overdue actions
+    fn reap_actions(
+        &self,
+        command: ReapActionsCommand,
+    ) -> impl Future<Output = Result<ActionReapReport, CoreError>> + Send;
 }
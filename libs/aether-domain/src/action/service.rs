@@ -5,9 +5,11 @@ use uuid::Uuid;
 
 use crate::CoreError;
 use crate::action::ActionBatch;
-use crate::action::commands::ClaimActionsCommand;
+use crate::action::commands::{
+    ActionOutcome, ClaimActionsCommand, ReapActionsCommand, ReportActionOutcomeCommand,
+};
 use crate::action::{
-    Action, ActionId, ActionMetadata, ActionStatus,
+    Action, ActionId, ActionMetadata, ActionReapReport, ActionStatus,
     commands::{FetchActionsCommand, RecordActionCommand},
     ports::{ActionRepository, ActionService},
 };
@@ -92,24 +94,124 @@ where
         identity: Identity,
         command: ClaimActionsCommand,
     ) -> Result<Vec<Action>, CoreError> {
-        let client_id = identity.username();
+        let agent_id = agent_id(&identity, "only agents can claim actions")?;
+
+        info!(
+            "agent {} claims actions of deployment {} on dataplane {}",
+            agent_id, command.deployment_id.0, command.dataplane_id
+        );
+
+        let lease_until = Utc::now() + Duration::seconds(command.lease_seconds);
+
+        self.action_repository
+            .claim_pending(
+                command.deployment_id,
+                command.dataplane_id,
+                agent_id,
+                command.max,
+                lease_until,
+            )
+            .await
+    }
 
-        info!("the client: {} try to claim actions", client_id);
+    async fn report_action_outcome(
+        &self,
+        identity: Identity,
+        command: ReportActionOutcomeCommand,
+    ) -> Result<Action, CoreError> {
+        let agent_id = agent_id(&identity, "only agents can report action outcomes")?;
+
+        let action = self
+            .action_repository
+            .get_by_id(command.deployment_id, command.action_id)
+            .await?
+            .filter(|action| action.dataplane_id == command.dataplane_id)
+            .ok_or(CoreError::ActionNotFound {
+                id: command.action_id.0,
+            })?;
 
-        if !client_id.contains("herald-service") {
+        let at = Utc::now();
+        let status = match command.outcome {
+            ActionOutcome::Published => ActionStatus::Published { at },
+            ActionOutcome::Failed { reason } => ActionStatus::Failed { reason, at },
+        };
+
+        let invalid_transition = |from: &ActionStatus| CoreError::InvalidActionTransition {
+            from: from.name().to_string(),
+            to: status.name().to_string(),
+        };
+
+        if !action.status.can_transition_to(&status) {
+            return Err(invalid_transition(&action.status));
+        }
+
+        if let ActionStatus::Pulled {
+            agent_id: owner, ..
+        } = &action.status
+            && *owner != agent_id
+        {
             return Err(CoreError::PermissionDenied {
-                reason: "only herald can claim actions".to_string(),
+                reason: format!("action {} was pulled by another agent", action.id.0),
             });
         }
 
-        let lease_until = Utc::now() + Duration::seconds(command.lease_seconds);
+        info!(
+            "agent {} reports action {} as {}",
+            agent_id,
+            action.id.0,
+            status.name()
+        );
 
-        let actions = self
+        let updated = self
             .action_repository
-            .claim_pending(command.deployment_id, command.max, lease_until)
+            .transition_status(
+                action.deployment_id,
+                action.id,
+                action.status.clone(),
+                status.clone(),
+                agent_id,
+            )
             .await?;
 
-        Ok(actions)
+        updated.ok_or_else(|| invalid_transition(&action.status))
+    }
+
+    async fn reap_actions(
+        &self,
+        command: ReapActionsCommand,
+    ) -> Result<ActionReapReport, CoreError> {
+        // Deadlines are checked first so an overdue action is never handed out again
+        let expired = self
+            .action_repository
+            .fail_past_deadline(command.now)
+            .await?;
+
+        let timed_out = self
+            .action_repository
+            .fail_exhausted_leases(command.now, command.max_attempts)
+            .await?;
+
+        let requeued = self
+            .action_repository
+            .requeue_expired_leases(command.now, command.max_attempts)
+            .await?;
+
+        Ok(ActionReapReport {
+            requeued,
+            timed_out,
+            expired,
+        })
+    }
+}
+
+/// Agents authenticate as service accounts; their issuer-qualified `client_id` identifies
+/// them in the action lifecycle
+fn agent_id(identity: &Identity, reason: &str) -> Result<String, CoreError> {
+    match identity {
+        Identity::Client(client) => Ok(client.client_id.clone()),
+        _ => Err(CoreError::PermissionDenied {
+            reason: reason.to_string(),
+        }),
     }
 }
 
@@ -117,8 +219,9 @@ where
 mod tests {
     use super::*;
     use crate::action::{
-        ActionBatch, ActionConstraints, ActionCursor, ActionPayload, ActionSource, ActionTarget,
-        ActionType, ActionVersion, TargetKind, ports::MockActionRepository,
+        ActionBatch, ActionConstraints, ActionCursor, ActionFailureReason, ActionPayload,
+        ActionSource, ActionTarget, ActionType, ActionVersion, TargetKind,
+        ports::MockActionRepository,
     };
     use crate::dataplane::value_objects::DataPlaneId;
     use crate::deployments::DeploymentId;
@@ -253,4 +356,368 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap().unwrap().id, action_id);
     }
+
+    fn agent_identity() -> Identity {
+        Identity::Client(Client {
+            id: "client-1".to_string(),
+            client_id: "herald-service".to_string(),
+            roles: vec![],
+            scopes: vec![],
+        })
+    }
+
+    fn action_with_status(status: ActionStatus) -> Action {
+        let deployment_id = DeploymentId(Uuid::new_v4());
+        Action {
+            id: ActionId(Uuid::new_v4()),
+            deployment_id,
+            dataplane_id: DataPlaneId(Uuid::new_v4()),
+            action_type: ActionType("deployment.create".to_string()),
+            target: ActionTarget {
+                kind: TargetKind::Deployment,
+                id: deployment_id.0,
+            },
+            payload: ActionPayload {
+                data: json!({"id": "dep-1"}),
+            },
+            version: ActionVersion(1),
+            status,
+            metadata: ActionMetadata {
+                source: ActionSource::System,
+                created_at: Utc::now(),
+                constraints: ActionConstraints::default(),
+            },
+            leased_until: None,
+        }
+    }
+
+    fn outcome_command(action: &Action, outcome: ActionOutcome) -> ReportActionOutcomeCommand {
+        ReportActionOutcomeCommand {
+            dataplane_id: action.dataplane_id,
+            deployment_id: action.deployment_id,
+            action_id: action.id,
+            outcome,
+        }
+    }
+
+    #[tokio::test]
+    async fn report_action_outcome_publishes_leased_action() {
+        let mut mock_repo = MockActionRepository::new();
+        let action = action_with_status(ActionStatus::Leased { until: Utc::now() });
+        let stored = action.clone();
+
+        mock_repo
+            .expect_get_by_id()
+            .times(1)
+            .returning(move |_, _| {
+                let action = stored.clone();
+                Box::pin(async move { Ok(Some(action)) })
+            });
+        mock_repo
+            .expect_transition_status()
+            .times(1)
+            .withf(|_, _, expected, status, agent_id| {
+                matches!(expected, ActionStatus::Leased { .. })
+                    && matches!(status, ActionStatus::Published { .. })
+                    && agent_id == "herald-service"
+            })
+            .returning(|_, _, _, status, _| {
+                let mut action = action_with_status(status);
+                action.leased_until = None;
+                Box::pin(async move { Ok(Some(action)) })
+            });
+
+        let service = ActionServiceImpl::new(mock_repo);
+        let result = service
+            .report_action_outcome(
+                agent_identity(),
+                outcome_command(&action, ActionOutcome::Published),
+            )
+            .await;
+
+        assert!(matches!(
+            result.unwrap().status,
+            ActionStatus::Published { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn report_action_outcome_rejects_terminal_action() {
+        let mut mock_repo = MockActionRepository::new();
+        let action = action_with_status(ActionStatus::Published { at: Utc::now() });
+        let stored = action.clone();
+
+        mock_repo
+            .expect_get_by_id()
+            .times(1)
+            .returning(move |_, _| {
+                let action = stored.clone();
+                Box::pin(async move { Ok(Some(action)) })
+            });
+        mock_repo.expect_transition_status().never();
+
+        let service = ActionServiceImpl::new(mock_repo);
+        let result = service
+            .report_action_outcome(
+                agent_identity(),
+                outcome_command(
+                    &action,
+                    ActionOutcome::Failed {
+                        reason: ActionFailureReason::PublishFailed,
+                    },
+                ),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::InvalidActionTransition { ref from, ref to })
+                if from == "published" && to == "failed"
+        ));
+    }
+
+    #[tokio::test]
+    async fn report_action_outcome_rejects_action_from_other_dataplane() {
+        let mut mock_repo = MockActionRepository::new();
+        let action = action_with_status(ActionStatus::Leased { until: Utc::now() });
+        let stored = action.clone();
+
+        mock_repo
+            .expect_get_by_id()
+            .times(1)
+            .returning(move |_, _| {
+                let action = stored.clone();
+                Box::pin(async move { Ok(Some(action)) })
+            });
+
+        let service = ActionServiceImpl::new(mock_repo);
+        let mut command = outcome_command(&action, ActionOutcome::Published);
+        command.dataplane_id = DataPlaneId(Uuid::new_v4());
+
+        let result = service
+            .report_action_outcome(agent_identity(), command)
+            .await;
+
+        assert!(matches!(result, Err(CoreError::ActionNotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn report_action_outcome_reports_concurrent_change() {
+        let mut mock_repo = MockActionRepository::new();
+        let action = action_with_status(ActionStatus::Leased { until: Utc::now() });
+        let stored = action.clone();
+
+        mock_repo
+            .expect_get_by_id()
+            .times(1)
+            .returning(move |_, _| {
+                let action = stored.clone();
+                Box::pin(async move { Ok(Some(action)) })
+            });
+        mock_repo
+            .expect_transition_status()
+            .times(1)
+            .returning(|_, _, _, _, _| Box::pin(async { Ok(None) }));
+
+        let service = ActionServiceImpl::new(mock_repo);
+        let result = service
+            .report_action_outcome(
+                agent_identity(),
+                outcome_command(&action, ActionOutcome::Published),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::InvalidActionTransition { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn report_action_outcome_requires_client_identity() {
+        let mock_repo = MockActionRepository::new();
+        let action = action_with_status(ActionStatus::Pending);
+        let identity = Identity::User(aether_auth::User {
+            id: "user-1".to_string(),
+            username: "john".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        });
+
+        let service = ActionServiceImpl::new(mock_repo);
+        let result = service
+            .report_action_outcome(identity, outcome_command(&action, ActionOutcome::Published))
+            .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn reap_actions_collects_repository_counts() {
+        let mut mock_repo = MockActionRepository::new();
+        let now = Utc::now();
+
+        mock_repo
+            .expect_fail_past_deadline()
+            .times(1)
+            .withf(move |at| *at == now)
+            .returning(|_| Box::pin(async { Ok(1) }));
+        mock_repo
+            .expect_fail_exhausted_leases()
+            .times(1)
+            .withf(move |at, max_attempts| *at == now && *max_attempts == 3)
+            .returning(|_, _| Box::pin(async { Ok(2) }));
+        mock_repo
+            .expect_requeue_expired_leases()
+            .times(1)
+            .withf(move |at, max_attempts| *at == now && *max_attempts == 3)
+            .returning(|_, _| Box::pin(async { Ok(4) }));
+
+        let service = ActionServiceImpl::new(mock_repo);
+        let report = service
+            .reap_actions(ReapActionsCommand {
+                now,
+                max_attempts: 3,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report,
+            ActionReapReport {
+                requeued: 4,
+                timed_out: 2,
+                expired: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn reap_actions_stops_on_repository_error() {
+        let mut mock_repo = MockActionRepository::new();
+
+        mock_repo
+            .expect_fail_past_deadline()
+            .times(1)
+            .returning(|_| {
+                Box::pin(async {
+                    Err(CoreError::DatabaseError {
+                        message: "db".to_string(),
+                    })
+                })
+            });
+        mock_repo.expect_fail_exhausted_leases().never();
+        mock_repo.expect_requeue_expired_leases().never();
+
+        let service = ActionServiceImpl::new(mock_repo);
+        let result = service
+            .reap_actions(ReapActionsCommand {
+                now: Utc::now(),
+                max_attempts: 3,
+            })
+            .await;
+
+        assert!(matches!(result, Err(CoreError::DatabaseError { .. })));
+    }
+
+    fn pending_action(priority: Option<u8>, waited_seconds: i64) -> Action {
+        let mut action = action_with_status(ActionStatus::Pending);
+        action.metadata.created_at = Utc::now() - Duration::seconds(waited_seconds);
+        action.metadata.constraints.priority = priority;
+        action
+    }
+
+    #[tokio::test]
+    async fn claim_actions_keeps_repository_claim_order() {
+        let mut mock_repo = MockActionRepository::new();
+        let first = pending_action(Some(200), 60);
+        let second = pending_action(Some(0), 5);
+        let claimed = vec![first.clone(), second.clone()];
+
+        mock_repo
+            .expect_claim_pending()
+            .times(1)
+            .returning(move |_, _, _, _, _| {
+                let claimed = claimed.clone();
+                Box::pin(async move { Ok(claimed) })
+            });
+
+        let service = ActionServiceImpl::new(mock_repo);
+        let actions = service
+            .claim_actions(
+                agent_identity(),
+                ClaimActionsCommand {
+                    dataplane_id: first.dataplane_id,
+                    deployment_id: first.deployment_id,
+                    max: 10,
+                    lease_seconds: 30,
+                },
+            )
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = actions.iter().map(|action| action.id).collect();
+        assert_eq!(ids, vec![first.id, second.id]);
+    }
+
+    #[tokio::test]
+    async fn claim_actions_pulls_for_calling_agent_on_its_dataplane() {
+        let mut mock_repo = MockActionRepository::new();
+        let action = pending_action(None, 0);
+        let dataplane_id = action.dataplane_id;
+
+        mock_repo
+            .expect_claim_pending()
+            .times(1)
+            .withf(move |_, dataplane, agent_id, max, _| {
+                *dataplane == dataplane_id && agent_id == "herald-service" && *max == 5
+            })
+            .returning(|_, _, _, _, _| Box::pin(async { Ok(vec![]) }));
+
+        let service = ActionServiceImpl::new(mock_repo);
+        let result = service
+            .claim_actions(
+                agent_identity(),
+                ClaimActionsCommand {
+                    dataplane_id,
+                    deployment_id: action.deployment_id,
+                    max: 5,
+                    lease_seconds: 30,
+                },
+            )
+            .await;
+
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn report_action_outcome_rejects_action_pulled_by_another_agent() {
+        let mut mock_repo = MockActionRepository::new();
+        let action = action_with_status(ActionStatus::Pulled {
+            agent_id: "forge-eu".to_string(),
+            at: Utc::now(),
+        });
+        let stored = action.clone();
+
+        mock_repo
+            .expect_get_by_id()
+            .times(1)
+            .returning(move |_, _| {
+                let action = stored.clone();
+                Box::pin(async move { Ok(Some(action)) })
+            });
+        mock_repo.expect_transition_status().never();
+
+        let service = ActionServiceImpl::new(mock_repo);
+        let result = service
+            .report_action_outcome(
+                agent_identity(),
+                outcome_command(&action, ActionOutcome::Published),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
 }
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+
+use crate::organisation::OrganisationId;
+
+/// Command to create an API key in an organisation
+#[derive(Debug, Clone)]
+pub struct CreateApiKeyCommand {
+    pub organisation_id: OrganisationId,
+    pub name: String,
+    pub permissions: u64,
+    /// Issue a personal access token owned by the caller instead of an organisation key
+    pub personal: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl CreateApiKeyCommand {
+    /// Longest accepted key name
+    pub const MAX_NAME_LEN: usize = 100;
+
+    pub fn new(organisation_id: OrganisationId, name: String, permissions: u64) -> Self {
+        Self {
+            organisation_id,
+            name: name.trim().to_string(),
+            permissions,
+            personal: false,
+            expires_at: None,
+        }
+    }
+
+    pub fn with_personal(mut self, personal: bool) -> Self {
+        self.personal = personal;
+        self
+    }
+
+    pub fn with_expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    CoreError, action::ActionSource, organisation::OrganisationId, token::hash_token, user::UserId,
+};
+
+pub mod commands;
+pub mod ports;
+pub mod service;
+
+/// Prefix of API keys, used to tell them apart from identity provider tokens
+pub const API_KEY_PREFIX: &str = "aek_";
+
+/// Characters of a key kept in clear so it can be recognised in listings
+const DISPLAY_PREFIX_LEN: usize = API_KEY_PREFIX.len() + 8;
+
+/// API key ID value object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, ToSchema)]
+pub struct ApiKeyId(pub Uuid);
+
+impl From<Uuid> for ApiKeyId {
+    fn from(uuid: Uuid) -> Self {
+        ApiKeyId(uuid)
+    }
+}
+
+impl fmt::Display for ApiKeyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Who an API key belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ApiKeyOwner {
+    /// Shared automation key, managed by the organisation's administrators
+    Organisation,
+    /// Personal access token acting on behalf of a user
+    User { user_id: UserId },
+}
+
+/// A long-lived secret automation can authenticate with instead of an IdP token.
+///
+/// The key acts in a single organisation with a fixed set of permissions, capped at
+/// its creator's permissions when it was created. Only the hash of the secret is kept.
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub struct ApiKey {
+    pub id: ApiKeyId,
+    pub organisation_id: OrganisationId,
+    pub owner: ApiKeyOwner,
+    pub name: String,
+    /// Leading characters of the secret, safe to display
+    pub prefix: String,
+    #[serde(skip)]
+    pub key_hash: String,
+    pub permissions: u64,
+    pub created_by: UserId,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    /// Creates an active key for `secret`, keeping only its hash and display prefix
+    pub fn new(
+        organisation_id: OrganisationId,
+        owner: ApiKeyOwner,
+        name: String,
+        secret: &str,
+        permissions: u64,
+        created_by: UserId,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id: ApiKeyId(Uuid::new_v4()),
+            organisation_id,
+            owner,
+            name,
+            prefix: secret.chars().take(DISPLAY_PREFIX_LEN).collect(),
+            key_hash: hash_token(secret),
+            permissions,
+            created_by,
+            expires_at,
+            last_used_at: None,
+            revoked_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn user_id(&self) -> Option<UserId> {
+        match self.owner {
+            ApiKeyOwner::User { user_id } => Some(user_id),
+            ApiKeyOwner::Organisation => None,
+        }
+    }
+
+    /// Whether the key can still authenticate as of `now`
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+
+    /// Revokes the key; a revoked key cannot be revoked again
+    pub fn revoke(&mut self, now: DateTime<Utc>) -> Result<(), CoreError> {
+        if self.revoked_at.is_some() {
+            return Err(CoreError::ApiKeyAlreadyRevoked { id: self.id.0 });
+        }
+
+        self.revoked_at = Some(now);
+        Ok(())
+    }
+
+    /// The identity requests authenticated with this key run as
+    pub fn identity(&self) -> aether_auth::ApiKey {
+        aether_auth::ApiKey {
+            id: self.id.to_string(),
+            name: self.name.clone(),
+            organisation_id: self.organisation_id.to_string(),
+            user_id: self.user_id().map(|user_id| user_id.0.to_string()),
+            permissions: self.permissions,
+        }
+    }
+}
+
+/// A newly created key together with its secret, which is never shown again
+#[derive(Debug, Clone)]
+pub struct IssuedApiKey {
+    pub api_key: ApiKey,
+    pub secret: String,
+}
+
+/// Maps a key identity to the actor recorded in status histories: personal access
+/// tokens act as their user, organisation keys as an API client
+pub fn api_key_actor(key: &aether_auth::ApiKey) -> Result<ActionSource, CoreError> {
+    match &key.user_id {
+        Some(user_id) => Ok(ActionSource::User {
+            user_id: Uuid::parse_str(user_id).map_err(|_| CoreError::InvalidIdentity)?,
+        }),
+        None => Ok(ActionSource::Api {
+            client_id: key.id.clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_key(owner: ApiKeyOwner, expires_at: Option<DateTime<Utc>>) -> ApiKey {
+        ApiKey::new(
+            OrganisationId(Uuid::new_v4()),
+            owner,
+            "ci".to_string(),
+            "aek_0123456789abcdef",
+            1,
+            UserId(Uuid::new_v4()),
+            expires_at,
+        )
+    }
+
+    #[test]
+    fn new_key_keeps_only_a_display_prefix() {
+        let key = api_key(ApiKeyOwner::Organisation, None);
+
+        assert_eq!(key.prefix, "aek_01234567");
+        assert!(key.is_active(Utc::now()));
+    }
+
+    #[test]
+    fn expired_or_revoked_key_is_inactive() {
+        let now = Utc::now();
+        let expired = api_key(ApiKeyOwner::Organisation, Some(now));
+        assert!(!expired.is_active(now));
+
+        let mut revoked = api_key(ApiKeyOwner::Organisation, None);
+        revoked.revoke(now).unwrap();
+        assert!(!revoked.is_active(now));
+        assert!(matches!(
+            revoked.revoke(now),
+            Err(CoreError::ApiKeyAlreadyRevoked { .. })
+        ));
+    }
+
+    #[test]
+    fn personal_token_acts_as_its_user() {
+        let user_id = UserId(Uuid::new_v4());
+        let personal = api_key(ApiKeyOwner::User { user_id }, None);
+        let shared = api_key(ApiKeyOwner::Organisation, None);
+
+        assert_eq!(
+            api_key_actor(&personal.identity()).unwrap(),
+            ActionSource::User { user_id: user_id.0 }
+        );
+        assert_eq!(
+            api_key_actor(&shared.identity()).unwrap(),
+            ActionSource::Api {
+                client_id: shared.id.to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::future::Future;
+
+use aether_auth::Identity;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    CoreError,
+    api_key::{ApiKey, ApiKeyId, IssuedApiKey, commands::CreateApiKeyCommand},
+    organisation::OrganisationId,
+};
+
+/// Service trait for API keys and personal access tokens
+pub trait ApiKeyService: Send + Sync {
+    /// Creates a key and returns its secret, which cannot be retrieved afterwards
+    fn create_api_key(
+        &self,
+        identity: Identity,
+        command: CreateApiKeyCommand,
+    ) -> impl Future<Output = Result<IssuedApiKey, CoreError>> + Send;
+
+    /// Lists the keys of an organisation; callers who cannot manage keys only see their own
+    fn list_api_keys(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<Vec<ApiKey>, CoreError>> + Send;
+
+    /// Revokes a key so it can no longer authenticate
+    fn revoke_api_key(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        api_key_id: ApiKeyId,
+    ) -> impl Future<Output = Result<ApiKey, CoreError>> + Send;
+
+    /// Resolves a key secret to the identity it authenticates as
+    fn authenticate_api_key(
+        &self,
+        secret: &str,
+    ) -> impl Future<Output = Result<Identity, CoreError>> + Send;
+}
+
+/// Repository trait for API key persistence
+#[cfg_attr(test, mockall::automock)]
+pub trait ApiKeyRepository: Send + Sync {
+    fn insert(&self, api_key: &ApiKey) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn find_by_id(
+        &self,
+        organisation_id: &OrganisationId,
+        api_key_id: &ApiKeyId,
+    ) -> impl Future<Output = Result<Option<ApiKey>, CoreError>> + Send;
+
+    fn find_by_hash(
+        &self,
+        key_hash: &str,
+    ) -> impl Future<Output = Result<Option<ApiKey>, CoreError>> + Send;
+
+    fn list_by_organisation(
+        &self,
+        organisation_id: &OrganisationId,
+    ) -> impl Future<Output = Result<Vec<ApiKey>, CoreError>> + Send;
+
+    /// Persists the revocation of a key
+    fn update(&self, api_key: &ApiKey) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn touch_last_used(
+        &self,
+        api_key_id: &ApiKeyId,
+        used_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Authorisation rules for API keys
+#[cfg_attr(test, mockall::automock)]
+pub trait ApiKeyPolicy: Send + Sync {
+    /// Whether the identity may create organisation keys and see or revoke any key
+    fn can_manage_api_keys(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
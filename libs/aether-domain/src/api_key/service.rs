@@ -0,0 +1,529 @@
+use aether_auth::Identity;
+use aether_permission::{Permissions, require_permission};
+use chrono::Utc;
+use tracing::warn;
+
+use crate::{
+    CoreError,
+    api_key::{
+        API_KEY_PREFIX, ApiKey, ApiKeyId, ApiKeyOwner, IssuedApiKey,
+        commands::CreateApiKeyCommand,
+        ports::{ApiKeyPolicy, ApiKeyRepository, ApiKeyService},
+    },
+    organisation::OrganisationId,
+    role::ports::PermissionProvider,
+    token::{generate_token, hash_token},
+    user::{User, ports::UserRepository},
+};
+
+pub struct ApiKeyServiceImpl<K, U, R, P>
+where
+    K: ApiKeyRepository,
+    U: UserRepository,
+    R: PermissionProvider,
+    P: ApiKeyPolicy,
+{
+    api_key_repository: K,
+    user_repository: U,
+    permission_provider: R,
+    api_key_policy: P,
+}
+
+impl<K, U, R, P> ApiKeyServiceImpl<K, U, R, P>
+where
+    K: ApiKeyRepository,
+    U: UserRepository,
+    R: PermissionProvider,
+    P: ApiKeyPolicy,
+{
+    pub fn new(
+        api_key_repository: K,
+        user_repository: U,
+        permission_provider: R,
+        api_key_policy: P,
+    ) -> Self {
+        Self {
+            api_key_repository,
+            user_repository,
+            permission_provider,
+            api_key_policy,
+        }
+    }
+
+    /// Keys are created and owned by users; keys and clients cannot mint further keys
+    async fn caller(&self, identity: &Identity) -> Result<User, CoreError> {
+        let Identity::User(user) = identity else {
+            return Err(CoreError::PermissionDenied {
+                reason: "only users can manage API keys".to_string(),
+            });
+        };
+
+        self.user_repository
+            .find_by_sub(&user.id)
+            .await?
+            .ok_or(CoreError::InvalidIdentity)
+    }
+}
+
+fn validate_command(command: &CreateApiKeyCommand) -> Result<Permissions, CoreError> {
+    let invalid = |reason: &str| CoreError::InvalidApiKey {
+        reason: reason.to_string(),
+    };
+
+    if command.name.is_empty() || command.name.len() > CreateApiKeyCommand::MAX_NAME_LEN {
+        return Err(invalid("the name must be between 1 and 100 characters"));
+    }
+    if command
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= Utc::now())
+    {
+        return Err(invalid("the expiry must be in the future"));
+    }
+
+    let permissions = Permissions::from_bits(command.permissions)
+        .ok_or_else(|| invalid("unknown permissions"))?;
+    if permissions.is_empty() {
+        return Err(invalid("at least one permission is required"));
+    }
+
+    Ok(permissions)
+}
+
+impl<K, U, R, P> ApiKeyService for ApiKeyServiceImpl<K, U, R, P>
+where
+    K: ApiKeyRepository,
+    U: UserRepository,
+    R: PermissionProvider,
+    P: ApiKeyPolicy,
+{
+    async fn create_api_key(
+        &self,
+        identity: Identity,
+        command: CreateApiKeyCommand,
+    ) -> Result<IssuedApiKey, CoreError> {
+        let organisation_id = command.organisation_id;
+        let creator = self.caller(&identity).await?;
+        if !command.personal {
+            require_permission!(
+                self.api_key_policy
+                    .can_manage_api_keys(identity.clone(), organisation_id)
+                    .await
+            );
+        }
+
+        let requested = validate_command(&command)?;
+        let granted = self
+            .permission_provider
+            .permissions_for_organisation(identity, organisation_id)
+            .await?;
+        if !granted.can(Permissions::ADMINISTRATOR) && !granted.can(requested) {
+            return Err(CoreError::PermissionDenied {
+                reason: "an API key cannot have permissions its creator lacks".to_string(),
+            });
+        }
+
+        let owner = if command.personal {
+            ApiKeyOwner::User {
+                user_id: creator.id,
+            }
+        } else {
+            ApiKeyOwner::Organisation
+        };
+        let secret = generate_token(API_KEY_PREFIX);
+        let api_key = ApiKey::new(
+            organisation_id,
+            owner,
+            command.name,
+            &secret,
+            requested.bits(),
+            creator.id,
+            command.expires_at,
+        );
+
+        self.api_key_repository.insert(&api_key).await?;
+
+        Ok(IssuedApiKey { api_key, secret })
+    }
+
+    async fn list_api_keys(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<Vec<ApiKey>, CoreError> {
+        let caller = self.caller(&identity).await?;
+        let manager = self
+            .api_key_policy
+            .can_manage_api_keys(identity, organisation_id)
+            .await
+            .is_ok();
+
+        let api_keys = self
+            .api_key_repository
+            .list_by_organisation(&organisation_id)
+            .await?
+            .into_iter()
+            .filter(|api_key| manager || api_key.user_id() == Some(caller.id))
+            .collect();
+
+        Ok(api_keys)
+    }
+
+    async fn revoke_api_key(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        api_key_id: ApiKeyId,
+    ) -> Result<ApiKey, CoreError> {
+        let caller = self.caller(&identity).await?;
+        let not_found = CoreError::ApiKeyNotFound { id: api_key_id.0 };
+
+        let mut api_key = self
+            .api_key_repository
+            .find_by_id(&organisation_id, &api_key_id)
+            .await?
+            .ok_or(not_found)?;
+
+        if api_key.user_id() != Some(caller.id) {
+            require_permission!(
+                self.api_key_policy
+                    .can_manage_api_keys(identity, organisation_id)
+                    .await
+            );
+        }
+
+        api_key.revoke(Utc::now())?;
+        self.api_key_repository.update(&api_key).await?;
+
+        Ok(api_key)
+    }
+
+    async fn authenticate_api_key(&self, secret: &str) -> Result<Identity, CoreError> {
+        if !secret.starts_with(API_KEY_PREFIX) {
+            return Err(CoreError::InvalidIdentity);
+        }
+
+        let api_key = self
+            .api_key_repository
+            .find_by_hash(&hash_token(secret))
+            .await?
+            .ok_or(CoreError::InvalidIdentity)?;
+
+        let now = Utc::now();
+        if !api_key.is_active(now) {
+            warn!("rejected inactive api key {}", api_key.id);
+            return Err(CoreError::InvalidIdentity);
+        }
+
+        self.api_key_repository
+            .touch_last_used(&api_key.id, now)
+            .await?;
+
+        Ok(Identity::ApiKey(api_key.identity()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api_key::ports::{MockApiKeyPolicy, MockApiKeyRepository},
+        role::ports::MockPermissionProvider,
+        user::UserId,
+    };
+    use uuid::Uuid;
+
+    struct StubUserRepository {
+        user_id: UserId,
+    }
+
+    impl UserRepository for StubUserRepository {
+        async fn upsert_by_email(&self, _user: &User) -> Result<Option<User>, CoreError> {
+            Err(CoreError::InternalError("not used".to_string()))
+        }
+
+        async fn insert(&self, _user: &User) -> Result<Option<User>, CoreError> {
+            Err(CoreError::InternalError("not used".to_string()))
+        }
+
+        async fn find_by_sub(&self, sub: &str) -> Result<Option<User>, CoreError> {
+            Ok(Some(User {
+                id: self.user_id,
+                email: "owner@example.com".to_string(),
+                name: "Owner".to_string(),
+                sub: sub.to_string(),
+                roles: vec![],
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            }))
+        }
+
+        async fn find_by_id(&self, _id: &UserId) -> Result<Option<User>, CoreError> {
+            Ok(None)
+        }
+    }
+
+    fn identity() -> Identity {
+        Identity::User(aether_auth::User {
+            id: "owner-sub".to_string(),
+            username: "owner".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        })
+    }
+
+    fn permissions(granted: Permissions) -> MockPermissionProvider {
+        let mut provider = MockPermissionProvider::new();
+        provider
+            .expect_permissions_for_organisation()
+            .returning(move |_, _| Box::pin(async move { Ok(granted) }));
+        provider
+    }
+
+    fn policy(allowed: bool) -> MockApiKeyPolicy {
+        let mut policy = MockApiKeyPolicy::new();
+        policy.expect_can_manage_api_keys().returning(move |_, _| {
+            Box::pin(async move {
+                if allowed {
+                    Ok(())
+                } else {
+                    Err(CoreError::PermissionDenied {
+                        reason: "insufficient permissions".to_string(),
+                    })
+                }
+            })
+        });
+        policy
+    }
+
+    fn service(
+        repository: MockApiKeyRepository,
+        user_id: UserId,
+        granted: Permissions,
+        allowed: bool,
+    ) -> ApiKeyServiceImpl<
+        MockApiKeyRepository,
+        StubUserRepository,
+        MockPermissionProvider,
+        MockApiKeyPolicy,
+    > {
+        ApiKeyServiceImpl::new(
+            repository,
+            StubUserRepository { user_id },
+            permissions(granted),
+            policy(allowed),
+        )
+    }
+
+    fn stored_key(owner: ApiKeyOwner, secret: &str) -> ApiKey {
+        ApiKey::new(
+            OrganisationId::new(),
+            owner,
+            "ci".to_string(),
+            secret,
+            Permissions::VIEW_INSTANCES.bits(),
+            UserId(Uuid::new_v4()),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn create_api_key_stores_hash_and_returns_secret() {
+        let mut repository = MockApiKeyRepository::new();
+        repository
+            .expect_insert()
+            .times(1)
+            .withf(|api_key| api_key.key_hash.len() == 64)
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        let user_id = UserId(Uuid::new_v4());
+        let issued = service(repository, user_id, Permissions::VIEW_INSTANCES, false)
+            .create_api_key(
+                identity(),
+                CreateApiKeyCommand::new(
+                    OrganisationId::new(),
+                    " deploy bot ".to_string(),
+                    Permissions::VIEW_INSTANCES.bits(),
+                )
+                .with_personal(true),
+            )
+            .await
+            .unwrap();
+
+        assert!(issued.secret.starts_with(API_KEY_PREFIX));
+        assert_eq!(hash_token(&issued.secret), issued.api_key.key_hash);
+        assert_eq!(issued.api_key.name, "deploy bot");
+        assert_eq!(issued.api_key.owner, ApiKeyOwner::User { user_id });
+    }
+
+    #[tokio::test]
+    async fn create_api_key_caps_permissions_at_creator() {
+        let mut repository = MockApiKeyRepository::new();
+        repository.expect_insert().never();
+
+        let result = service(
+            repository,
+            UserId(Uuid::new_v4()),
+            Permissions::VIEW_INSTANCES,
+            true,
+        )
+        .create_api_key(
+            identity(),
+            CreateApiKeyCommand::new(
+                OrganisationId::new(),
+                "ci".to_string(),
+                (Permissions::VIEW_INSTANCES | Permissions::DELETE_INSTANCES).bits(),
+            ),
+        )
+        .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn create_organisation_key_requires_manage_permission() {
+        let result = service(
+            MockApiKeyRepository::new(),
+            UserId(Uuid::new_v4()),
+            Permissions::ADMINISTRATOR,
+            false,
+        )
+        .create_api_key(
+            identity(),
+            CreateApiKeyCommand::new(OrganisationId::new(), "ci".to_string(), 1),
+        )
+        .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn create_api_key_rejects_past_expiry_and_unknown_bits() {
+        for command in [
+            CreateApiKeyCommand::new(OrganisationId::new(), "ci".to_string(), 1)
+                .with_expires_at(Utc::now() - chrono::Duration::minutes(1)),
+            CreateApiKeyCommand::new(OrganisationId::new(), "ci".to_string(), 1 << 40),
+            CreateApiKeyCommand::new(OrganisationId::new(), " ".to_string(), 1),
+        ] {
+            let result = service(
+                MockApiKeyRepository::new(),
+                UserId(Uuid::new_v4()),
+                Permissions::ADMINISTRATOR,
+                true,
+            )
+            .create_api_key(identity(), command)
+            .await;
+
+            assert!(matches!(result, Err(CoreError::InvalidApiKey { .. })));
+        }
+    }
+
+    #[tokio::test]
+    async fn list_api_keys_shows_only_own_keys_to_members() {
+        let user_id = UserId(Uuid::new_v4());
+        let own = stored_key(ApiKeyOwner::User { user_id }, "aek_own");
+        let shared = stored_key(ApiKeyOwner::Organisation, "aek_shared");
+        let keys = vec![own.clone(), shared];
+
+        let mut repository = MockApiKeyRepository::new();
+        repository
+            .expect_list_by_organisation()
+            .returning(move |_| {
+                let keys = keys.clone();
+                Box::pin(async move { Ok(keys) })
+            });
+
+        let listed = service(repository, user_id, Permissions::empty(), false)
+            .list_api_keys(identity(), OrganisationId::new())
+            .await
+            .unwrap();
+
+        assert_eq!(listed, vec![own]);
+    }
+
+    #[tokio::test]
+    async fn revoke_api_key_of_someone_else_requires_manage_permission() {
+        let key = stored_key(ApiKeyOwner::Organisation, "aek_shared");
+        let mut repository = MockApiKeyRepository::new();
+        repository.expect_find_by_id().returning(move |_, _| {
+            let key = key.clone();
+            Box::pin(async move { Ok(Some(key)) })
+        });
+        repository.expect_update().never();
+
+        let result = service(
+            repository,
+            UserId(Uuid::new_v4()),
+            Permissions::empty(),
+            false,
+        )
+        .revoke_api_key(identity(), OrganisationId::new(), ApiKeyId(Uuid::new_v4()))
+        .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn authenticate_api_key_resolves_identity_and_records_use() {
+        let secret = generate_token(API_KEY_PREFIX);
+        let key = stored_key(ApiKeyOwner::Organisation, &secret);
+        let key_id = key.id;
+
+        let mut repository = MockApiKeyRepository::new();
+        let expected_hash = hash_token(&secret);
+        repository
+            .expect_find_by_hash()
+            .withf(move |hash| hash == expected_hash)
+            .returning(move |_| {
+                let key = key.clone();
+                Box::pin(async move { Ok(Some(key)) })
+            });
+        repository
+            .expect_touch_last_used()
+            .times(1)
+            .withf(move |id, _| *id == key_id)
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+
+        let identity = service(
+            repository,
+            UserId(Uuid::new_v4()),
+            Permissions::empty(),
+            false,
+        )
+        .authenticate_api_key(&secret)
+        .await
+        .unwrap();
+
+        let Identity::ApiKey(key) = identity else {
+            panic!("expected an api key identity");
+        };
+        assert_eq!(key.id, key_id.to_string());
+        assert_eq!(key.permissions, Permissions::VIEW_INSTANCES.bits());
+    }
+
+    #[tokio::test]
+    async fn authenticate_api_key_rejects_revoked_key() {
+        let secret = generate_token(API_KEY_PREFIX);
+        let mut key = stored_key(ApiKeyOwner::Organisation, &secret);
+        key.revoke(Utc::now()).unwrap();
+
+        let mut repository = MockApiKeyRepository::new();
+        repository.expect_find_by_hash().returning(move |_| {
+            let key = key.clone();
+            Box::pin(async move { Ok(Some(key)) })
+        });
+        repository.expect_touch_last_used().never();
+
+        let result = service(
+            repository,
+            UserId(Uuid::new_v4()),
+            Permissions::empty(),
+            false,
+        )
+        .authenticate_api_key(&secret)
+        .await;
+
+        assert!(matches!(result, Err(CoreError::InvalidIdentity)));
+    }
+}
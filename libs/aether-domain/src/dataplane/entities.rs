@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -26,3 +27,25 @@ impl DataPlane {
         }
     }
 }
+
+/// Service account allowed to act as the agent of a dataplane
+///
+/// A client may be registered on several dataplanes; it can only list deployments, claim
+/// actions and report outcomes on those.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct DataPlaneAgent {
+    pub dataplane_id: DataPlaneId,
+    /// `client_id` claim of the agent's service account token
+    pub client_id: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl DataPlaneAgent {
+    pub fn new(dataplane_id: DataPlaneId, client_id: impl Into<String>) -> Self {
+        Self {
+            dataplane_id,
+            client_id: client_id.into(),
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod entities;
 pub mod ports;
+pub mod scheduler;
 pub mod service;
 pub mod value_objects;
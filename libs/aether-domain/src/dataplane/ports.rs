@@ -3,9 +3,11 @@ use aether_auth::Identity;
 use crate::{
     CoreError,
     dataplane::{
-        entities::DataPlane,
+        entities::{DataPlane, DataPlaneAgent},
+        scheduler::{PlacementCandidate, PlacementRequest},
         value_objects::{
             CreateDataplaneCommand, DataPlaneId, ListDataPlaneDeploymentsCommand, Region,
+            RegisterDataPlaneAgentCommand,
         },
     },
     deployments::Deployment,
@@ -32,6 +34,30 @@ pub trait DataPlaneService: Send + Sync {
         dataplane_id: DataPlaneId,
         command: ListDataPlaneDeploymentsCommand,
     ) -> impl Future<Output = Result<Vec<Deployment>, CoreError>> + Send;
+    fn register_agent(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+        command: RegisterDataPlaneAgentCommand,
+    ) -> impl Future<Output = Result<DataPlaneAgent, CoreError>> + Send;
+    fn list_agents(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+    ) -> impl Future<Output = Result<Vec<DataPlaneAgent>, CoreError>> + Send;
+    fn unregister_agent(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+        client_id: String,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Fails with [`CoreError::PermissionDenied`] unless `identity` is a client registered
+    /// as an agent of `dataplane_id`
+    fn authorize_agent(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -50,7 +76,54 @@ pub trait DataPlaneRepository: Send + Sync {
         required_capacity: u32,
     ) -> impl Future<Output = Result<Option<DataPlane>, CoreError>> + Send;
     fn list_all(&self) -> impl Future<Output = Result<Vec<DataPlane>, CoreError>> + Send;
+    /// Every dataplane with its live deployment count and hosted organisations
+    fn list_placement_candidates(
+        &self,
+    ) -> impl Future<Output = Result<Vec<PlacementCandidate>, CoreError>> + Send;
     fn current_load(&self, id: &DataPlaneId)
     -> impl Future<Output = Result<u32, CoreError>> + Send;
     fn save(&self, dataplane: &DataPlane) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
+
+/// Registry of the service accounts acting as dataplane agents
+#[cfg_attr(test, mockall::automock)]
+pub trait DataPlaneAgentRepository: Send + Sync {
+    /// Registering an agent twice is a no-op
+    fn insert(&self, agent: &DataPlaneAgent) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Returns whether the agent was registered
+    fn delete(
+        &self,
+        dataplane_id: &DataPlaneId,
+        client_id: &str,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn list_by_dataplane(
+        &self,
+        dataplane_id: &DataPlaneId,
+    ) -> impl Future<Output = Result<Vec<DataPlaneAgent>, CoreError>> + Send;
+    fn list_dataplanes_for_client(
+        &self,
+        client_id: &str,
+    ) -> impl Future<Output = Result<Vec<DataPlaneId>, CoreError>> + Send;
+}
+
+/// Authorisation checks for dataplane administration
+#[cfg_attr(test, mockall::automock)]
+pub trait DataPlanePolicy: Send + Sync {
+    /// Required to bind service accounts to a dataplane, list them or remove them. An agent
+    /// claims the actions of every organisation placed on its dataplane
+    fn can_manage_agents(
+        &self,
+        identity: Identity,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Chooses the dataplane a new deployment is placed on
+pub trait DataPlaneScheduler: Send + Sync {
+    /// Returns the selected dataplane, or [`CoreError::NoDataPlaneFit`] listing why each
+    /// candidate was rejected
+    fn schedule(
+        &self,
+        request: &PlacementRequest,
+        candidates: &[PlacementCandidate],
+    ) -> Result<DataPlane, CoreError>;
+}
@@ -0,0 +1,445 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    CoreError,
+    dataplane::{
+        entities::DataPlane,
+        ports::DataPlaneScheduler,
+        value_objects::{DataPlaneId, DataPlaneMode, DataPlaneStatus, Region},
+    },
+    organisation::OrganisationId,
+};
+
+/// What a new deployment needs from the dataplane it lands on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlacementRequest {
+    pub organisation_id: OrganisationId,
+    /// Only dataplanes in this region are considered when set
+    pub region: Option<Region>,
+    pub required_capacity: u32,
+}
+
+/// A dataplane together with the usage the scheduler bases its decision on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlacementCandidate {
+    pub dataplane: DataPlane,
+    /// Number of live deployments currently placed on the dataplane
+    pub current_load: u32,
+    /// Organisations owning at least one live deployment on the dataplane
+    pub organisations: Vec<OrganisationId>,
+}
+
+impl PlacementCandidate {
+    pub fn free_capacity(&self) -> u32 {
+        self.dataplane
+            .capacity
+            .max()
+            .saturating_sub(self.current_load)
+    }
+
+    /// Compares the `current_load / capacity` ratio of two candidates
+    fn cmp_load_ratio(&self, other: &Self) -> Ordering {
+        let lhs = u64::from(self.current_load) * u64::from(other.dataplane.capacity.max());
+        let rhs = u64::from(other.current_load) * u64::from(self.dataplane.capacity.max());
+
+        lhs.cmp(&rhs)
+    }
+
+    fn hosts(&self, organisation_id: OrganisationId) -> bool {
+        self.organisations.contains(&organisation_id)
+    }
+}
+
+/// Why a dataplane was not selected for a deployment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum RejectionReason {
+    RegionMismatch {
+        requested: Region,
+        actual: Region,
+    },
+    NotActive {
+        status: DataPlaneStatus,
+    },
+    ModeMismatch {
+        required: DataPlaneMode,
+        actual: DataPlaneMode,
+    },
+    InsufficientCapacity {
+        capacity: u32,
+        current_load: u32,
+        required: u32,
+    },
+    DedicatedToAnotherOrganisation,
+}
+
+impl fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RegionMismatch { requested, actual } => write!(
+                f,
+                "in region '{}' instead of '{}'",
+                actual.as_str(),
+                requested.as_str()
+            ),
+            Self::NotActive { status } => write!(f, "not active ({status:?})"),
+            Self::ModeMismatch { required, actual } => {
+                write!(f, "{actual:?} instead of {required:?}")
+            }
+            Self::InsufficientCapacity {
+                capacity,
+                current_load,
+                required,
+            } => write!(
+                f,
+                "{current_load}/{capacity} used, {required} more required"
+            ),
+            Self::DedicatedToAnotherOrganisation => {
+                write!(f, "dedicated to another organisation")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct DataPlaneRejection {
+    pub dataplane_id: DataPlaneId,
+    #[serde(flatten)]
+    pub reason: RejectionReason,
+}
+
+impl fmt::Display for DataPlaneRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.dataplane_id, self.reason)
+    }
+}
+
+/// Built-in placement strategies
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SchedulingStrategy {
+    /// Spread deployments over shared dataplanes, lowest `load / capacity` first
+    #[default]
+    LeastLoaded,
+    /// Fill shared dataplanes up before touching emptier ones
+    BinPacking,
+    /// Give each organisation its own dedicated dataplane
+    DedicatedPerOrganisation,
+}
+
+impl fmt::Display for SchedulingStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LeastLoaded => write!(f, "least-loaded"),
+            Self::BinPacking => write!(f, "bin-packing"),
+            Self::DedicatedPerOrganisation => write!(f, "dedicated-per-organisation"),
+        }
+    }
+}
+
+impl FromStr for SchedulingStrategy {
+    type Err = CoreError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "least-loaded" => Ok(Self::LeastLoaded),
+            "bin-packing" => Ok(Self::BinPacking),
+            "dedicated-per-organisation" => Ok(Self::DedicatedPerOrganisation),
+            _ => Err(CoreError::InvalidSchedulingStrategy {
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl SchedulingStrategy {
+    fn required_mode(&self) -> DataPlaneMode {
+        match self {
+            Self::LeastLoaded | Self::BinPacking => DataPlaneMode::Shared,
+            Self::DedicatedPerOrganisation => DataPlaneMode::Dedicated,
+        }
+    }
+
+    fn check(
+        &self,
+        request: &PlacementRequest,
+        candidate: &PlacementCandidate,
+    ) -> Result<(), RejectionReason> {
+        let dataplane = &candidate.dataplane;
+
+        if let Some(requested) = &request.region
+            && requested != &dataplane.region
+        {
+            return Err(RejectionReason::RegionMismatch {
+                requested: requested.clone(),
+                actual: dataplane.region.clone(),
+            });
+        }
+
+        if dataplane.status != DataPlaneStatus::Active {
+            return Err(RejectionReason::NotActive {
+                status: dataplane.status,
+            });
+        }
+
+        let required_mode = self.required_mode();
+        if dataplane.mode != required_mode {
+            return Err(RejectionReason::ModeMismatch {
+                required: required_mode,
+                actual: dataplane.mode,
+            });
+        }
+
+        if required_mode == DataPlaneMode::Dedicated
+            && candidate
+                .organisations
+                .iter()
+                .any(|organisation_id| *organisation_id != request.organisation_id)
+        {
+            return Err(RejectionReason::DedicatedToAnotherOrganisation);
+        }
+
+        if candidate.free_capacity() < request.required_capacity {
+            return Err(RejectionReason::InsufficientCapacity {
+                capacity: dataplane.capacity.max(),
+                current_load: candidate.current_load,
+                required: request.required_capacity,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Orders fitting candidates, best first; ties are broken by id so placement is stable
+    fn compare(
+        &self,
+        request: &PlacementRequest,
+        lhs: &PlacementCandidate,
+        rhs: &PlacementCandidate,
+    ) -> Ordering {
+        let by_strategy = match self {
+            Self::LeastLoaded => lhs.cmp_load_ratio(rhs),
+            Self::BinPacking => rhs.cmp_load_ratio(lhs),
+            // Reuse the organisation's dataplane before claiming an empty one
+            Self::DedicatedPerOrganisation => rhs
+                .hosts(request.organisation_id)
+                .cmp(&lhs.hosts(request.organisation_id))
+                .then_with(|| lhs.cmp_load_ratio(rhs)),
+        };
+
+        by_strategy.then_with(|| lhs.dataplane.id.0.cmp(&rhs.dataplane.id.0))
+    }
+}
+
+impl DataPlaneScheduler for SchedulingStrategy {
+    fn schedule(
+        &self,
+        request: &PlacementRequest,
+        candidates: &[PlacementCandidate],
+    ) -> Result<DataPlane, CoreError> {
+        if candidates.is_empty() {
+            return Err(CoreError::NoDataPlaneAvailable);
+        }
+
+        let mut fitting = Vec::new();
+        let mut rejections = Vec::new();
+
+        for candidate in candidates {
+            match self.check(request, candidate) {
+                Ok(()) => fitting.push(candidate),
+                Err(reason) => rejections.push(DataPlaneRejection {
+                    dataplane_id: candidate.dataplane.id,
+                    reason,
+                }),
+            }
+        }
+
+        fitting
+            .into_iter()
+            .min_by(|lhs, rhs| self.compare(request, lhs, rhs))
+            .map(|candidate| candidate.dataplane.clone())
+            .ok_or(CoreError::NoDataPlaneFit { rejections })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataplane::value_objects::Capacity;
+    use uuid::Uuid;
+
+    fn candidate(
+        mode: DataPlaneMode,
+        region: &str,
+        capacity: u32,
+        current_load: u32,
+    ) -> PlacementCandidate {
+        PlacementCandidate {
+            dataplane: DataPlane {
+                id: DataPlaneId(Uuid::new_v4()),
+                mode,
+                region: Region::new(region),
+                status: DataPlaneStatus::Active,
+                capacity: Capacity::new(capacity).unwrap(),
+            },
+            current_load,
+            organisations: vec![],
+        }
+    }
+
+    fn request(region: Option<&str>) -> PlacementRequest {
+        PlacementRequest {
+            organisation_id: OrganisationId(Uuid::new_v4()),
+            region: region.map(Region::new),
+            required_capacity: 1,
+        }
+    }
+
+    #[test]
+    fn least_loaded_picks_lowest_load_ratio() {
+        let busy = candidate(DataPlaneMode::Shared, "eu-west", 10, 8);
+        let idle = candidate(DataPlaneMode::Shared, "eu-west", 100, 20);
+        let candidates = vec![busy, idle.clone()];
+
+        let selected = SchedulingStrategy::LeastLoaded
+            .schedule(&request(None), &candidates)
+            .unwrap();
+
+        assert_eq!(selected.id, idle.dataplane.id);
+    }
+
+    #[test]
+    fn bin_packing_picks_fullest_dataplane_that_fits() {
+        let almost_full = candidate(DataPlaneMode::Shared, "eu-west", 10, 9);
+        let full = candidate(DataPlaneMode::Shared, "eu-west", 10, 10);
+        let idle = candidate(DataPlaneMode::Shared, "eu-west", 10, 1);
+        let candidates = vec![idle, full, almost_full.clone()];
+
+        let selected = SchedulingStrategy::BinPacking
+            .schedule(&request(None), &candidates)
+            .unwrap();
+
+        assert_eq!(selected.id, almost_full.dataplane.id);
+    }
+
+    #[test]
+    fn region_preference_restricts_candidates() {
+        let eu = candidate(DataPlaneMode::Shared, "eu-west", 10, 9);
+        let us = candidate(DataPlaneMode::Shared, "us-east", 10, 0);
+        let candidates = vec![eu.clone(), us];
+
+        let selected = SchedulingStrategy::LeastLoaded
+            .schedule(&request(Some("eu-west")), &candidates)
+            .unwrap();
+
+        assert_eq!(selected.id, eu.dataplane.id);
+    }
+
+    #[test]
+    fn dedicated_strategy_reuses_organisation_dataplane() {
+        let request = request(None);
+        let empty = candidate(DataPlaneMode::Dedicated, "eu-west", 10, 0);
+        let mut owned = candidate(DataPlaneMode::Dedicated, "eu-west", 10, 3);
+        owned.organisations = vec![request.organisation_id];
+        let mut taken = candidate(DataPlaneMode::Dedicated, "eu-west", 10, 1);
+        taken.organisations = vec![OrganisationId(Uuid::new_v4())];
+        let candidates = vec![empty.clone(), taken, owned.clone()];
+
+        let selected = SchedulingStrategy::DedicatedPerOrganisation
+            .schedule(&request, &candidates)
+            .unwrap();
+        assert_eq!(selected.id, owned.dataplane.id);
+
+        let selected = SchedulingStrategy::DedicatedPerOrganisation
+            .schedule(&request, &candidates[..2])
+            .unwrap();
+        assert_eq!(selected.id, empty.dataplane.id);
+    }
+
+    #[test]
+    fn no_fit_lists_every_rejection() {
+        let wrong_region = candidate(DataPlaneMode::Shared, "us-east", 10, 0);
+        let mut draining = candidate(DataPlaneMode::Shared, "eu-west", 10, 0);
+        draining.dataplane.status = DataPlaneStatus::Draining;
+        let dedicated = candidate(DataPlaneMode::Dedicated, "eu-west", 10, 0);
+        let full = candidate(DataPlaneMode::Shared, "eu-west", 5, 5);
+        let candidates = vec![
+            wrong_region.clone(),
+            draining.clone(),
+            dedicated.clone(),
+            full.clone(),
+        ];
+
+        let error = SchedulingStrategy::LeastLoaded
+            .schedule(&request(Some("eu-west")), &candidates)
+            .unwrap_err();
+
+        let CoreError::NoDataPlaneFit { rejections } = error else {
+            panic!("expected a no fit error");
+        };
+        assert_eq!(
+            rejections,
+            vec![
+                DataPlaneRejection {
+                    dataplane_id: wrong_region.dataplane.id,
+                    reason: RejectionReason::RegionMismatch {
+                        requested: Region::new("eu-west"),
+                        actual: Region::new("us-east"),
+                    },
+                },
+                DataPlaneRejection {
+                    dataplane_id: draining.dataplane.id,
+                    reason: RejectionReason::NotActive {
+                        status: DataPlaneStatus::Draining,
+                    },
+                },
+                DataPlaneRejection {
+                    dataplane_id: dedicated.dataplane.id,
+                    reason: RejectionReason::ModeMismatch {
+                        required: DataPlaneMode::Shared,
+                        actual: DataPlaneMode::Dedicated,
+                    },
+                },
+                DataPlaneRejection {
+                    dataplane_id: full.dataplane.id,
+                    reason: RejectionReason::InsufficientCapacity {
+                        capacity: 5,
+                        current_load: 5,
+                        required: 1,
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_candidates_means_no_dataplane_available() {
+        let error = SchedulingStrategy::BinPacking
+            .schedule(&request(None), &[])
+            .unwrap_err();
+
+        assert!(matches!(error, CoreError::NoDataPlaneAvailable));
+    }
+
+    #[test]
+    fn strategy_parses_from_kebab_case() {
+        assert_eq!(
+            "bin-packing".parse::<SchedulingStrategy>().unwrap(),
+            SchedulingStrategy::BinPacking
+        );
+        assert_eq!(
+            SchedulingStrategy::DedicatedPerOrganisation
+                .to_string()
+                .parse::<SchedulingStrategy>()
+                .unwrap(),
+            SchedulingStrategy::DedicatedPerOrganisation
+        );
+        assert!(matches!(
+            "round-robin".parse::<SchedulingStrategy>(),
+            Err(CoreError::InvalidSchedulingStrategy { .. })
+        ));
+    }
+}
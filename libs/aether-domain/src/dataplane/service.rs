@@ -1,43 +1,80 @@
 use aether_auth::Identity;
+use aether_permission::require_permission;
 
 use crate::{
     CoreError,
     dataplane::{
-        entities::DataPlane,
-        ports::{DataPlaneRepository, DataPlaneService},
-        value_objects::{CreateDataplaneCommand, DataPlaneId, ListDataPlaneDeploymentsCommand},
+        entities::{DataPlane, DataPlaneAgent},
+        ports::{DataPlaneAgentRepository, DataPlanePolicy, DataPlaneRepository, DataPlaneService},
+        value_objects::{
+            CreateDataplaneCommand, DataPlaneId, ListDataPlaneDeploymentsCommand,
+            RegisterDataPlaneAgentCommand,
+        },
     },
     deployments::{Deployment, DeploymentId, ports::DeploymentRepository},
 };
 use uuid::Uuid;
 
 #[derive(Debug)]
-pub struct DataPlaneServiceImpl<DP, D>
+pub struct DataPlaneServiceImpl<DP, D, A, P>
 where
     DP: DataPlaneRepository,
     D: DeploymentRepository,
+    A: DataPlaneAgentRepository,
+    P: DataPlanePolicy,
 {
     dataplane_repository: DP,
     deployment_repository: D,
+    agent_repository: A,
+    dataplane_policy: P,
 }
 
-impl<DP, D> DataPlaneServiceImpl<DP, D>
+impl<DP, D, A, P> DataPlaneServiceImpl<DP, D, A, P>
 where
     DP: DataPlaneRepository,
     D: DeploymentRepository,
+    A: DataPlaneAgentRepository,
+    P: DataPlanePolicy,
 {
-    pub fn new(dataplane_repository: DP, deployment_repository: D) -> Self {
+    pub fn new(
+        dataplane_repository: DP,
+        deployment_repository: D,
+        agent_repository: A,
+        dataplane_policy: P,
+    ) -> Self {
         Self {
             dataplane_repository,
             deployment_repository,
+            agent_repository,
+            dataplane_policy,
+        }
+    }
+
+    /// Agents are provisioned by people allowed to manage them, never by another service
+    /// account
+    async fn ensure_agent_manager(&self, identity: &Identity) -> Result<(), CoreError> {
+        if !identity.is_user() {
+            return Err(CoreError::PermissionDenied {
+                reason: "only users can manage dataplane agents".to_string(),
+            });
         }
+
+        require_permission!(
+            self.dataplane_policy
+                .can_manage_agents(identity.clone())
+                .await
+        );
+
+        Ok(())
     }
 }
 
-impl<DP, D> DataPlaneService for DataPlaneServiceImpl<DP, D>
+impl<DP, D, A, P> DataPlaneService for DataPlaneServiceImpl<DP, D, A, P>
 where
     DP: DataPlaneRepository,
     D: DeploymentRepository,
+    A: DataPlaneAgentRepository,
+    P: DataPlanePolicy,
 {
     async fn create_dataplane(
         &self,
@@ -70,10 +107,12 @@ where
 
     async fn get_deployments_in_dataplane(
         &self,
-        _identity: Identity,
+        identity: Identity,
         dataplane_id: DataPlaneId,
         command: ListDataPlaneDeploymentsCommand,
     ) -> Result<Vec<Deployment>, CoreError> {
+        self.authorize_agent(identity, dataplane_id).await?;
+
         let mut deployments = self
             .deployment_repository
             .list_by_dataplane(&dataplane_id)
@@ -107,13 +146,92 @@ where
 
         Ok(shard_deployments.into_iter().take(command.limit).collect())
     }
+
+    async fn register_agent(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+        command: RegisterDataPlaneAgentCommand,
+    ) -> Result<DataPlaneAgent, CoreError> {
+        self.ensure_agent_manager(&identity).await?;
+        self.get_dataplane(identity, dataplane_id).await?;
+
+        let agent = DataPlaneAgent::new(dataplane_id, command.client_id);
+        self.agent_repository.insert(&agent).await?;
+
+        Ok(agent)
+    }
+
+    async fn list_agents(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+    ) -> Result<Vec<DataPlaneAgent>, CoreError> {
+        self.ensure_agent_manager(&identity).await?;
+        self.get_dataplane(identity, dataplane_id).await?;
+
+        self.agent_repository.list_by_dataplane(&dataplane_id).await
+    }
+
+    async fn unregister_agent(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+        client_id: String,
+    ) -> Result<(), CoreError> {
+        self.ensure_agent_manager(&identity).await?;
+        self.get_dataplane(identity, dataplane_id).await?;
+
+        if !self
+            .agent_repository
+            .delete(&dataplane_id, &client_id)
+            .await?
+        {
+            return Err(CoreError::DataPlaneAgentNotFound {
+                dataplane_id,
+                client_id,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn authorize_agent(
+        &self,
+        identity: Identity,
+        dataplane_id: DataPlaneId,
+    ) -> Result<(), CoreError> {
+        let Identity::Client(client) = identity else {
+            return Err(CoreError::PermissionDenied {
+                reason: "only dataplane agents can use this route".to_string(),
+            });
+        };
+
+        let dataplanes = self
+            .agent_repository
+            .list_dataplanes_for_client(&client.client_id)
+            .await?;
+
+        if !dataplanes.contains(&dataplane_id) {
+            return Err(CoreError::PermissionDenied {
+                reason: format!(
+                    "client {} is not an agent of data plane {}",
+                    client.client_id, dataplane_id
+                ),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        dataplane::ports::MockDataPlaneRepository,
+        dataplane::ports::{
+            MockDataPlaneAgentRepository, MockDataPlanePolicy, MockDataPlaneRepository,
+        },
         deployments::{
             DeploymentKind, DeploymentName, DeploymentStatus, DeploymentVersion,
             ports::MockDeploymentRepository,
@@ -138,12 +256,89 @@ mod tests {
             updated_at: created_at,
             deployed_at: None,
             deleted_at: None,
+            purged_at: None,
         }
     }
 
+    type TestService = DataPlaneServiceImpl<
+        MockDataPlaneRepository,
+        MockDeploymentRepository,
+        MockDataPlaneAgentRepository,
+        MockDataPlanePolicy,
+    >;
+
+    fn agent_identity() -> Identity {
+        Identity::Client(aether_auth::Client {
+            id: "id".to_string(),
+            client_id: "client".to_string(),
+            roles: vec![],
+            scopes: vec![],
+        })
+    }
+
+    fn user_identity() -> Identity {
+        Identity::User(aether_auth::User {
+            id: "user-1".to_string(),
+            username: "john".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        })
+    }
+
+    /// Stands in for the platform administrator check
+    fn agent_managers(allowed: bool) -> MockDataPlanePolicy {
+        let mut policy = MockDataPlanePolicy::new();
+        policy.expect_can_manage_agents().returning(move |_| {
+            Box::pin(async move {
+                if allowed {
+                    Ok(())
+                } else {
+                    Err(CoreError::PermissionDenied {
+                        reason: "platform administrator role required".to_string(),
+                    })
+                }
+            })
+        });
+        policy
+    }
+
+    fn existing_dataplane() -> (DataPlaneId, MockDataPlaneRepository) {
+        let dataplane = DataPlane::new(
+            crate::dataplane::value_objects::DataPlaneMode::Shared,
+            crate::dataplane::value_objects::Region::new("eu-west-1"),
+            crate::dataplane::value_objects::Capacity::new(10).unwrap(),
+        );
+        let dataplane_id = dataplane.id;
+        let mut dataplane_repository = MockDataPlaneRepository::new();
+        dataplane_repository
+            .expect_find_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(Some(dataplane)) }));
+
+        (dataplane_id, dataplane_repository)
+    }
+
+    /// Registers `client` as the only agent of `dataplanes`
+    fn agents_of(dataplanes: Vec<DataPlaneId>) -> MockDataPlaneAgentRepository {
+        let mut agent_repository = MockDataPlaneAgentRepository::new();
+        agent_repository
+            .expect_list_dataplanes_for_client()
+            .returning(move |client_id| {
+                let dataplanes = if client_id == "client" {
+                    dataplanes.clone()
+                } else {
+                    vec![]
+                };
+                Box::pin(async move { Ok(dataplanes) })
+            });
+        agent_repository
+    }
+
     fn service_with_deployments(
+        dataplane_id: DataPlaneId,
         deployments: Vec<Deployment>,
-    ) -> DataPlaneServiceImpl<MockDataPlaneRepository, MockDeploymentRepository> {
+    ) -> TestService {
         let dataplane_repository = MockDataPlaneRepository::new();
         let mut deployment_repository = MockDeploymentRepository::new();
         deployment_repository
@@ -153,7 +348,12 @@ mod tests {
                 Box::pin(async move { Ok(deployments) })
             });
 
-        DataPlaneServiceImpl::new(dataplane_repository, deployment_repository)
+        DataPlaneServiceImpl::new(
+            dataplane_repository,
+            deployment_repository,
+            agents_of(vec![dataplane_id]),
+            MockDataPlanePolicy::new(),
+        )
     }
 
     #[tokio::test]
@@ -167,16 +367,11 @@ mod tests {
                 )
             })
             .collect();
-        let service = service_with_deployments(deployments);
+        let service = service_with_deployments(dataplane_id, deployments);
 
         let shard_one = service
             .get_deployments_in_dataplane(
-                Identity::Client(aether_auth::Client {
-                    id: "id".to_string(),
-                    client_id: "client".to_string(),
-                    roles: vec![],
-                    scopes: vec![],
-                }),
+                agent_identity(),
                 dataplane_id,
                 ListDataPlaneDeploymentsCommand::new(Some(1), Some(4), Some(50), None).unwrap(),
             )
@@ -210,15 +405,10 @@ mod tests {
         });
         let cursor = deployments[1].id.to_string();
 
-        let service = service_with_deployments(deployments.clone());
+        let service = service_with_deployments(dataplane_id, deployments.clone());
         let page = service
             .get_deployments_in_dataplane(
-                Identity::Client(aether_auth::Client {
-                    id: "id".to_string(),
-                    client_id: "client".to_string(),
-                    roles: vec![],
-                    scopes: vec![],
-                }),
+                agent_identity(),
                 dataplane_id,
                 ListDataPlaneDeploymentsCommand::new(Some(0), Some(1), Some(2), Some(cursor))
                     .unwrap(),
@@ -230,4 +420,232 @@ mod tests {
         assert_eq!(page[0].id, deployments[2].id);
         assert_eq!(page[1].id, deployments[3].id);
     }
+
+    #[tokio::test]
+    async fn get_deployments_in_dataplane_rejects_agents_of_other_dataplanes() {
+        let service = DataPlaneServiceImpl::new(
+            MockDataPlaneRepository::new(),
+            MockDeploymentRepository::new(),
+            agents_of(vec![DataPlaneId(Uuid::new_v4())]),
+            MockDataPlanePolicy::new(),
+        );
+
+        let result = service
+            .get_deployments_in_dataplane(
+                agent_identity(),
+                DataPlaneId(Uuid::new_v4()),
+                ListDataPlaneDeploymentsCommand::new(None, None, None, None).unwrap(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn authorize_agent_accepts_any_registered_dataplane() {
+        let first = DataPlaneId(Uuid::new_v4());
+        let second = DataPlaneId(Uuid::new_v4());
+        let service: TestService = DataPlaneServiceImpl::new(
+            MockDataPlaneRepository::new(),
+            MockDeploymentRepository::new(),
+            agents_of(vec![first, second]),
+            MockDataPlanePolicy::new(),
+        );
+
+        assert!(
+            service
+                .authorize_agent(agent_identity(), first)
+                .await
+                .is_ok()
+        );
+        assert!(
+            service
+                .authorize_agent(agent_identity(), second)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn authorize_agent_rejects_same_client_id_of_another_issuer() {
+        let dataplane_id = DataPlaneId(Uuid::new_v4());
+        let service: TestService = DataPlaneServiceImpl::new(
+            MockDataPlaneRepository::new(),
+            MockDeploymentRepository::new(),
+            agents_of(vec![dataplane_id]),
+            MockDataPlanePolicy::new(),
+        );
+
+        let result = service
+            .authorize_agent(
+                agent_identity().namespaced("https://other.test"),
+                dataplane_id,
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn authorize_agent_rejects_users() {
+        let dataplane_id = DataPlaneId(Uuid::new_v4());
+        let service: TestService = DataPlaneServiceImpl::new(
+            MockDataPlaneRepository::new(),
+            MockDeploymentRepository::new(),
+            MockDataPlaneAgentRepository::new(),
+            MockDataPlanePolicy::new(),
+        );
+
+        let result = service.authorize_agent(user_identity(), dataplane_id).await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn register_agent_rejects_clients() {
+        let service: TestService = DataPlaneServiceImpl::new(
+            MockDataPlaneRepository::new(),
+            MockDeploymentRepository::new(),
+            MockDataPlaneAgentRepository::new(),
+            MockDataPlanePolicy::new(),
+        );
+
+        let result = service
+            .register_agent(
+                agent_identity(),
+                DataPlaneId(Uuid::new_v4()),
+                RegisterDataPlaneAgentCommand::new("client").unwrap(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn register_agent_rejects_plain_users() {
+        let (dataplane_id, dataplane_repository) = existing_dataplane();
+        let mut agent_repository = MockDataPlaneAgentRepository::new();
+        agent_repository.expect_insert().never();
+        let service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            MockDeploymentRepository::new(),
+            agent_repository,
+            agent_managers(false),
+        );
+
+        let result = service
+            .register_agent(
+                user_identity(),
+                dataplane_id,
+                RegisterDataPlaneAgentCommand::new("forge").unwrap(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn register_agent_stores_binding_for_existing_dataplane() {
+        let (dataplane_id, dataplane_repository) = existing_dataplane();
+        let mut agent_repository = MockDataPlaneAgentRepository::new();
+        agent_repository
+            .expect_insert()
+            .times(1)
+            .withf(move |agent| agent.dataplane_id == dataplane_id && agent.client_id == "forge")
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            MockDeploymentRepository::new(),
+            agent_repository,
+            agent_managers(true),
+        );
+
+        let agent = service
+            .register_agent(
+                user_identity(),
+                dataplane_id,
+                RegisterDataPlaneAgentCommand::new("  forge ").unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(agent.client_id, "forge");
+    }
+
+    #[tokio::test]
+    async fn unregister_agent_rejects_plain_users() {
+        let (dataplane_id, dataplane_repository) = existing_dataplane();
+        let mut agent_repository = MockDataPlaneAgentRepository::new();
+        agent_repository.expect_delete().never();
+        let service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            MockDeploymentRepository::new(),
+            agent_repository,
+            agent_managers(false),
+        );
+
+        let result = service
+            .unregister_agent(user_identity(), dataplane_id, "forge".to_string())
+            .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn unregister_agent_reports_unknown_dataplane() {
+        let mut dataplane_repository = MockDataPlaneRepository::new();
+        dataplane_repository
+            .expect_find_by_id()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        let mut agent_repository = MockDataPlaneAgentRepository::new();
+        agent_repository.expect_delete().never();
+        let service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            MockDeploymentRepository::new(),
+            agent_repository,
+            agent_managers(true),
+        );
+
+        let result = service
+            .unregister_agent(
+                user_identity(),
+                DataPlaneId(Uuid::new_v4()),
+                "forge".to_string(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::DataPlaneNotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn unregister_agent_reports_unknown_agent() {
+        let (dataplane_id, dataplane_repository) = existing_dataplane();
+        let mut agent_repository = MockDataPlaneAgentRepository::new();
+        agent_repository
+            .expect_delete()
+            .returning(|_, _| Box::pin(async { Ok(false) }));
+        let service = DataPlaneServiceImpl::new(
+            dataplane_repository,
+            MockDeploymentRepository::new(),
+            agent_repository,
+            agent_managers(true),
+        );
+
+        let result = service
+            .unregister_agent(user_identity(), dataplane_id, "forge".to_string())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::DataPlaneAgentNotFound { client_id, .. }) if client_id == "forge"
+        ));
+    }
+
+    #[test]
+    fn register_agent_command_rejects_blank_client_id() {
+        assert!(matches!(
+            RegisterDataPlaneAgentCommand::new("   "),
+            Err(CoreError::InvalidDataPlaneAgent { .. })
+        ));
+    }
 }
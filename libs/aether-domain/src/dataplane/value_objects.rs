@@ -66,6 +66,36 @@ pub struct CreateDataplaneCommand {
     pub capacity: Capacity,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterDataPlaneAgentCommand {
+    pub client_id: String,
+}
+
+impl RegisterDataPlaneAgentCommand {
+    pub const MAX_CLIENT_ID_LEN: usize = 255;
+
+    pub fn new(client_id: impl Into<String>) -> Result<Self, CoreError> {
+        let client_id = client_id.into().trim().to_string();
+
+        if client_id.is_empty() {
+            return Err(CoreError::InvalidDataPlaneAgent {
+                reason: "client_id must not be empty".to_string(),
+            });
+        }
+
+        if client_id.len() > Self::MAX_CLIENT_ID_LEN {
+            return Err(CoreError::InvalidDataPlaneAgent {
+                reason: format!(
+                    "client_id must be at most {} characters",
+                    Self::MAX_CLIENT_ID_LEN
+                ),
+            });
+        }
+
+        Ok(Self { client_id })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListDataPlaneDeploymentsCommand {
     pub shard_index: usize,
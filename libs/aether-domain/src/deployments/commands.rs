@@ -1,4 +1,6 @@
-use crate::{organisation::OrganisationId, user::UserId};
+use aether_auth::Identity;
+
+use crate::{dataplane::value_objects::Region, organisation::OrganisationId, user::UserId};
 
 use super::{DeploymentKind, DeploymentName, DeploymentStatus, DeploymentVersion};
 
@@ -12,6 +14,8 @@ pub struct CreateDeploymentCommand {
     pub status: DeploymentStatus,
     pub namespace: String,
     pub created_by: UserId,
+    /// Region the deployment should be placed in; any region when unset
+    pub region: Option<Region>,
 }
 
 impl CreateDeploymentCommand {
@@ -32,8 +36,14 @@ impl CreateDeploymentCommand {
             status,
             namespace,
             created_by,
+            region: None,
         }
     }
+
+    pub fn with_region(mut self, region: Region) -> Self {
+        self.region = Some(region);
+        self
+    }
 }
 
 /// Command to update an existing deployment
@@ -46,6 +56,10 @@ pub struct UpdateDeploymentCommand {
     pub namespace: Option<String>,
     pub deployed_at: Option<Option<chrono::DateTime<chrono::Utc>>>,
     pub deleted_at: Option<Option<chrono::DateTime<chrono::Utc>>>,
+    /// Who requests the update, recorded in the status history; the system when unset
+    pub actor: Option<Identity>,
+    /// Why the status changes, recorded in the status history
+    pub reason: Option<String>,
 }
 
 impl UpdateDeploymentCommand {
@@ -88,6 +102,16 @@ impl UpdateDeploymentCommand {
         self
     }
 
+    pub fn with_actor(mut self, actor: Identity) -> Self {
+        self.actor = Some(actor);
+        self
+    }
+
+    pub fn with_reason(mut self, reason: String) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
     pub fn is_empty(&self) -> bool {
         self.name.is_none()
             && self.kind.is_none()
@@ -122,6 +146,10 @@ mod tests {
         assert_eq!(command.version.0, "1.0.0");
         assert_eq!(command.status, DeploymentStatus::Pending);
         assert_eq!(command.namespace, "namespace");
+        assert!(command.region.is_none());
+
+        let command = command.with_region(Region::new("eu-west"));
+        assert_eq!(command.region, Some(Region::new("eu-west")));
     }
 
     #[test]
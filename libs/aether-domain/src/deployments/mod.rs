@@ -2,11 +2,13 @@ use std::{fmt, str::FromStr};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::{
-    CoreError, dataplane::value_objects::DataPlaneId, organisation::OrganisationId, user::UserId,
+    CoreError, action::ActionSource, dataplane::value_objects::DataPlaneId,
+    organisation::OrganisationId, user::UserId,
 };
 
 pub mod commands;
@@ -121,6 +123,33 @@ impl TryFrom<&str> for DeploymentStatus {
         }
     }
 }
+
+impl DeploymentStatus {
+    /// Whether the deployment lifecycle allows moving from `self` to `next`.
+    ///
+    /// Deployments are scheduled, rolled out and then either succeed or fail. A running
+    /// deployment can enter maintenance or be flagged for an upgrade, and a failed one can be
+    /// retried. Any deployment can be deleted. No transition leaves `Deleting`: only
+    /// [`Deployment::restore`] brings a deployment back, within its grace period.
+    pub fn can_transition_to(&self, next: &DeploymentStatus) -> bool {
+        use DeploymentStatus::*;
+
+        matches!(
+            (self, next),
+            (Pending, Scheduling | InProgress | Failed | Deleting)
+                | (Scheduling, Pending | InProgress | Failed | Deleting)
+                | (InProgress, Successful | Failed | Deleting)
+                | (
+                    Successful,
+                    Maintenance | UpgradeRequired | Upgrading | Failed | Deleting
+                )
+                | (Failed, Pending | Scheduling | InProgress | Deleting)
+                | (Maintenance, Successful | Upgrading | Failed | Deleting)
+                | (UpgradeRequired, Upgrading | Maintenance | Deleting)
+                | (Upgrading, Successful | Failed | Deleting)
+        )
+    }
+}
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, ToSchema)]
 pub struct DeploymentVersion(pub String);
 
@@ -143,6 +172,128 @@ pub struct Deployment {
 
     pub deployed_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
+    /// Set once the grace period after deletion expired and the teardown was requested
+    pub purged_at: Option<DateTime<Utc>>,
+}
+
+impl Deployment {
+    /// Moves the deployment to `next`, rejecting moves the lifecycle does not allow
+    pub fn transition_to(&mut self, next: DeploymentStatus) -> Result<(), CoreError> {
+        if !self.status.can_transition_to(&next) {
+            return Err(CoreError::InvalidDeploymentTransition {
+                from: self.status.to_string(),
+                to: next.to_string(),
+            });
+        }
+
+        self.status = next;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Brings a deleted deployment back in `status`, as long as it was deleted less than
+    /// `grace_period` ago. Restoring is the only way out of `Deleting`.
+    pub fn restore(
+        &mut self,
+        status: DeploymentStatus,
+        grace_period: chrono::Duration,
+    ) -> Result<(), CoreError> {
+        let not_restorable = |reason: &str| CoreError::DeploymentNotRestorable {
+            id: self.id.0,
+            reason: reason.to_string(),
+        };
+
+        let Some(deleted_at) = self.deleted_at else {
+            return Err(not_restorable("it is not deleted"));
+        };
+        if self.purged_at.is_some() || deleted_at + grace_period <= Utc::now() {
+            return Err(not_restorable("its grace period has expired"));
+        }
+
+        self.status = status;
+        self.deleted_at = None;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Lists the fields that differ from `previous` as `{ "<field>": { "from": .., "to": .. } }`
+    pub fn changes_since(&self, previous: &Deployment) -> Map<String, Value> {
+        fn diff<T: Serialize + PartialEq>(
+            changes: &mut Map<String, Value>,
+            field: &str,
+            from: &T,
+            to: &T,
+        ) {
+            if from != to {
+                changes.insert(field.to_string(), json!({ "from": from, "to": to }));
+            }
+        }
+
+        let mut changes = Map::new();
+        diff(&mut changes, "name", &previous.name, &self.name);
+        diff(&mut changes, "kind", &previous.kind, &self.kind);
+        diff(&mut changes, "version", &previous.version, &self.version);
+        diff(&mut changes, "status", &previous.status, &self.status);
+        diff(
+            &mut changes,
+            "namespace",
+            &previous.namespace,
+            &self.namespace,
+        );
+        diff(
+            &mut changes,
+            "deployed_at",
+            &previous.deployed_at,
+            &self.deployed_at,
+        );
+        diff(
+            &mut changes,
+            "deleted_at",
+            &previous.deleted_at,
+            &self.deleted_at,
+        );
+        diff(
+            &mut changes,
+            "purged_at",
+            &previous.purged_at,
+            &self.purged_at,
+        );
+        changes
+    }
+}
+
+/// One entry of a deployment's status history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct DeploymentStatusChange {
+    pub id: Uuid,
+    pub deployment_id: DeploymentId,
+    /// Status before the change; absent for the status the deployment was created with
+    pub from_status: Option<DeploymentStatus>,
+    pub to_status: DeploymentStatus,
+    /// Who requested the change
+    pub actor: ActionSource,
+    pub reason: Option<String>,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl DeploymentStatusChange {
+    pub fn new(
+        deployment_id: DeploymentId,
+        from_status: Option<DeploymentStatus>,
+        to_status: DeploymentStatus,
+        actor: ActionSource,
+        reason: Option<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            deployment_id,
+            from_status,
+            to_status,
+            actor,
+            reason,
+            changed_at: Utc::now(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -187,6 +338,137 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn deployment_status_transitions() {
+        use DeploymentStatus::*;
+
+        assert!(Pending.can_transition_to(&Scheduling));
+        assert!(InProgress.can_transition_to(&Successful));
+        assert!(Successful.can_transition_to(&Upgrading));
+        assert!(Failed.can_transition_to(&Pending));
+        assert!(Upgrading.can_transition_to(&Deleting));
+
+        assert!(!Deleting.can_transition_to(&Successful));
+        assert!(!Deleting.can_transition_to(&Deleting));
+        assert!(!Pending.can_transition_to(&Successful));
+        assert!(!Successful.can_transition_to(&Pending));
+    }
+
+    #[test]
+    fn deployment_transition_rejects_illegal_move() {
+        let now = Utc::now();
+        let mut deployment = Deployment {
+            id: DeploymentId(Uuid::new_v4()),
+            organisation_id: OrganisationId(Uuid::new_v4()),
+            dataplane_id: DataPlaneId(Uuid::new_v4()),
+            name: DeploymentName("app".to_string()),
+            kind: DeploymentKind::Keycloak,
+            version: DeploymentVersion("1.0.0".to_string()),
+            status: DeploymentStatus::Deleting,
+            namespace: "default".to_string(),
+            created_by: UserId(Uuid::new_v4()),
+            created_at: now,
+            updated_at: now,
+            deployed_at: None,
+            deleted_at: None,
+            purged_at: None,
+        };
+
+        let result = deployment.transition_to(DeploymentStatus::Successful);
+
+        assert!(matches!(
+            result,
+            Err(CoreError::InvalidDeploymentTransition { ref from, ref to })
+                if from == "deleting" && to == "successful"
+        ));
+        assert_eq!(deployment.status, DeploymentStatus::Deleting);
+
+        deployment.status = DeploymentStatus::InProgress;
+        deployment
+            .transition_to(DeploymentStatus::Successful)
+            .unwrap();
+        assert_eq!(deployment.status, DeploymentStatus::Successful);
+    }
+
+    #[test]
+    fn deployment_changes_since_lists_changed_fields() {
+        let now = Utc::now();
+        let previous = Deployment {
+            id: DeploymentId(Uuid::new_v4()),
+            organisation_id: OrganisationId(Uuid::new_v4()),
+            dataplane_id: DataPlaneId(Uuid::new_v4()),
+            name: DeploymentName("app".to_string()),
+            kind: DeploymentKind::Keycloak,
+            version: DeploymentVersion("1.0.0".to_string()),
+            status: DeploymentStatus::Successful,
+            namespace: "default".to_string(),
+            created_by: UserId(Uuid::new_v4()),
+            created_at: now,
+            updated_at: now,
+            deployed_at: None,
+            deleted_at: None,
+            purged_at: None,
+        };
+        let mut current = previous.clone();
+        current.version = DeploymentVersion("2.0.0".to_string());
+        current.status = DeploymentStatus::Upgrading;
+        current.updated_at = Utc::now();
+
+        let changes = current.changes_since(&previous);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes["version"],
+            json!({ "from": "1.0.0", "to": "2.0.0" })
+        );
+        assert_eq!(
+            changes["status"],
+            json!({ "from": "successful", "to": "upgrading" })
+        );
+        assert!(previous.changes_since(&previous).is_empty());
+    }
+
+    #[test]
+    fn deployment_restore_respects_grace_period() {
+        let now = Utc::now();
+        let mut deployment = Deployment {
+            id: DeploymentId(Uuid::new_v4()),
+            organisation_id: OrganisationId(Uuid::new_v4()),
+            dataplane_id: DataPlaneId(Uuid::new_v4()),
+            name: DeploymentName("app".to_string()),
+            kind: DeploymentKind::Keycloak,
+            version: DeploymentVersion("1.0.0".to_string()),
+            status: DeploymentStatus::Successful,
+            namespace: "default".to_string(),
+            created_by: UserId(Uuid::new_v4()),
+            created_at: now,
+            updated_at: now,
+            deployed_at: None,
+            deleted_at: None,
+            purged_at: None,
+        };
+        let grace_period = chrono::Duration::hours(1);
+
+        assert!(matches!(
+            deployment.restore(DeploymentStatus::Successful, grace_period),
+            Err(CoreError::DeploymentNotRestorable { .. })
+        ));
+
+        deployment.status = DeploymentStatus::Deleting;
+        deployment.deleted_at = Some(now - chrono::Duration::hours(2));
+        assert!(matches!(
+            deployment.restore(DeploymentStatus::Successful, grace_period),
+            Err(CoreError::DeploymentNotRestorable { .. })
+        ));
+
+        deployment.deleted_at = Some(now - chrono::Duration::minutes(5));
+        deployment
+            .restore(DeploymentStatus::Successful, grace_period)
+            .unwrap();
+        assert_eq!(deployment.status, DeploymentStatus::Successful);
+        assert!(deployment.deleted_at.is_none());
+    }
+
     #[test]
     fn deployment_id_from_str() {
         let id = Uuid::new_v4();
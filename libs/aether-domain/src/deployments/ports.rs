@@ -1,10 +1,13 @@
 use std::future::Future;
 
+use aether_auth::Identity;
+use chrono::{DateTime, Utc};
+
 use crate::{
     CoreError,
     dataplane::value_objects::DataPlaneId,
     deployments::{
-        Deployment, DeploymentId,
+        Deployment, DeploymentId, DeploymentStatusChange,
         commands::{CreateDeploymentCommand, UpdateDeploymentCommand},
     },
     organisation::OrganisationId,
@@ -31,10 +34,11 @@ pub trait DeploymentService: Send + Sync {
         deployment_id: DeploymentId,
     ) -> impl Future<Output = Result<Deployment, CoreError>> + Send;
 
-    /// Lists deployments for an organisation
+    /// Lists deployments for an organisation, including deleted ones when `include_deleted` is set
     fn list_deployments_by_organisation(
         &self,
         organisation_id: OrganisationId,
+        include_deleted: bool,
     ) -> impl Future<Output = Result<Vec<Deployment>, CoreError>> + Send;
 
     /// Updates an existing deployment
@@ -58,12 +62,35 @@ pub trait DeploymentService: Send + Sync {
         deployment_id: DeploymentId,
     ) -> impl Future<Output = Result<(), CoreError>> + Send;
 
-    /// Deletes a deployment scoped to an organisation
+    /// Deletes a deployment scoped to an organisation on behalf of `identity`
     fn delete_deployment_for_organisation(
         &self,
         organisation_id: OrganisationId,
         deployment_id: DeploymentId,
+        identity: Identity,
     ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Brings back a deleted deployment of an organisation within its grace period
+    fn restore_deployment_for_organisation(
+        &self,
+        organisation_id: OrganisationId,
+        deployment_id: DeploymentId,
+        identity: Identity,
+    ) -> impl Future<Output = Result<Deployment, CoreError>> + Send;
+
+    /// Marks up to `limit` deployments deleted before `deleted_before` as purged and returns them
+    fn purge_deleted_deployments(
+        &self,
+        deleted_before: DateTime<Utc>,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<Deployment>, CoreError>> + Send;
+
+    /// Lists the status changes of a deployment scoped to an organisation, oldest first
+    fn get_deployment_status_history_for_organisation(
+        &self,
+        organisation_id: OrganisationId,
+        deployment_id: DeploymentId,
+    ) -> impl Future<Output = Result<Vec<DeploymentStatusChange>, CoreError>> + Send;
 }
 
 /// Repository trait for managing Deployment entities.
@@ -78,12 +105,21 @@ pub trait DeploymentRepository: Send + Sync {
         deployment_id: DeploymentId,
     ) -> impl Future<Output = Result<Option<Deployment>, CoreError>> + Send;
 
+    /// Fetches a deployment and locks its row until the surrounding transaction ends,
+    /// so concurrent changes to the same deployment apply one at a time
+    fn get_by_id_for_update(
+        &self,
+        deployment_id: DeploymentId,
+    ) -> impl Future<Output = Result<Option<Deployment>, CoreError>> + Send;
+
     fn list_by_organisation(
         &self,
         organisation_id: OrganisationId,
+        include_deleted: bool,
     ) -> impl Future<Output = Result<Vec<Deployment>, CoreError>> + Send;
 
     fn update(&self, deployment: Deployment) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Soft deletes a deployment: the row is kept with `deleted_at` set until it is purged
     fn delete(
         &self,
         deployment_id: DeploymentId,
@@ -92,4 +128,30 @@ pub trait DeploymentRepository: Send + Sync {
         &self,
         dataplane_id: &DataPlaneId,
     ) -> impl Future<Output = Result<Vec<Deployment>, CoreError>> + Send;
+
+    /// Sets `purged_at` on up to `limit` deployments deleted before `deleted_before` that were
+    /// not purged yet, skipping rows locked by a concurrent purge, and returns them
+    fn mark_purged(
+        &self,
+        deleted_before: DateTime<Utc>,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<Deployment>, CoreError>> + Send;
+
+    /// Appends an entry to the status history of a deployment
+    fn record_status_change(
+        &self,
+        change: DeploymentStatusChange,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Lists the status history of a deployment, oldest first
+    fn list_status_history(
+        &self,
+        deployment_id: DeploymentId,
+    ) -> impl Future<Output = Result<Vec<DeploymentStatusChange>, CoreError>> + Send;
+
+    /// Counts the deployments of an organisation that have not been deleted
+    fn count_live_by_organisation(
+        &self,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<usize, CoreError>> + Send;
 }
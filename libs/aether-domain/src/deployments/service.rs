@@ -1,48 +1,177 @@
+use aether_auth::Identity;
+use chrono::{DateTime, Utc};
+
 use crate::{
-    CoreError,
-    dataplane::{ports::DataPlaneRepository, value_objects::Region},
+    CoreError, RetentionConfig,
+    action::ActionSource,
+    api_key::api_key_actor,
+    dataplane::{
+        ports::{DataPlaneRepository, DataPlaneScheduler},
+        scheduler::{PlacementRequest, SchedulingStrategy},
+    },
     deployments::{
-        Deployment, DeploymentId,
+        Deployment, DeploymentId, DeploymentStatus, DeploymentStatusChange,
         commands::{CreateDeploymentCommand, UpdateDeploymentCommand},
         ports::{DeploymentRepository, DeploymentService},
     },
-    organisation::OrganisationId,
+    organisation::{OrganisationId, ports::OrganisationRepository},
     user::ports::UserRepository,
 };
 use tracing::{error, info};
 
 #[derive(Debug)]
-pub struct DeploymentServiceImpl<D, U, DP>
+pub struct DeploymentServiceImpl<D, U, DP, O, S = SchedulingStrategy>
 where
     D: DeploymentRepository,
     U: UserRepository,
     DP: DataPlaneRepository,
+    O: OrganisationRepository,
+    S: DataPlaneScheduler,
 {
     deployment_repository: D,
     user_repository: U,
     dataplane_repository: DP,
+    organisation_repository: O,
+    scheduler: S,
+    grace_period: chrono::Duration,
 }
 
-impl<D, U, DP> DeploymentServiceImpl<D, U, DP>
+impl<D, U, DP, O> DeploymentServiceImpl<D, U, DP, O>
 where
     D: DeploymentRepository,
     U: UserRepository,
     DP: DataPlaneRepository,
+    O: OrganisationRepository,
 {
-    pub fn new(deployment_repository: D, user_repository: U, dataplane_repository: DP) -> Self {
+    pub fn new(
+        deployment_repository: D,
+        user_repository: U,
+        dataplane_repository: DP,
+        organisation_repository: O,
+    ) -> Self {
         Self {
             deployment_repository,
             user_repository,
             dataplane_repository,
+            organisation_repository,
+            scheduler: SchedulingStrategy::default(),
+            grace_period: RetentionConfig::default().deployment_grace_period,
         }
     }
 }
 
-impl<D, U, DP> DeploymentService for DeploymentServiceImpl<D, U, DP>
+impl<D, U, DP, O, S> DeploymentServiceImpl<D, U, DP, O, S>
 where
     D: DeploymentRepository,
     U: UserRepository,
     DP: DataPlaneRepository,
+    O: OrganisationRepository,
+    S: DataPlaneScheduler,
+{
+    pub fn with_scheduler<S2>(self, scheduler: S2) -> DeploymentServiceImpl<D, U, DP, O, S2>
+    where
+        S2: DataPlaneScheduler,
+    {
+        DeploymentServiceImpl {
+            deployment_repository: self.deployment_repository,
+            user_repository: self.user_repository,
+            dataplane_repository: self.dataplane_repository,
+            organisation_repository: self.organisation_repository,
+            scheduler,
+            grace_period: self.grace_period,
+        }
+    }
+
+    /// Sets how long a deleted deployment can still be restored
+    pub fn with_grace_period(mut self, grace_period: chrono::Duration) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+
+    /// Locks the organisation row and checks it can run one more deployment. The lock is held
+    /// until the surrounding transaction ends, so concurrent requests count one at a time.
+    async fn check_instance_limit(&self, organisation_id: OrganisationId) -> Result<(), CoreError> {
+        let organisation = self
+            .organisation_repository
+            .find_by_id_for_update(&organisation_id)
+            .await?
+            .ok_or(CoreError::OrganisationNotFound {
+                id: organisation_id.0,
+            })?;
+        let live_deployments = self
+            .deployment_repository
+            .count_live_by_organisation(organisation_id)
+            .await?;
+
+        organisation
+            .check_instance_limit(live_deployments)
+            .inspect_err(|e| error!("deployment rejected: {}", e))
+    }
+
+    /// Fetches a deployment and locks its row until the surrounding transaction ends
+    pub async fn lock_deployment(
+        &self,
+        deployment_id: DeploymentId,
+    ) -> Result<Deployment, CoreError> {
+        self.deployment_repository
+            .get_by_id_for_update(deployment_id)
+            .await?
+            .ok_or(CoreError::DeploymentNotFound {
+                id: deployment_id.0,
+            })
+    }
+
+    /// Maps the identity requesting a change to the actor stored in the status history
+    pub async fn resolve_actor(
+        &self,
+        identity: Option<&Identity>,
+    ) -> Result<ActionSource, CoreError> {
+        match identity {
+            None => Ok(ActionSource::System),
+            Some(Identity::User(user)) => {
+                let user = self
+                    .user_repository
+                    .find_by_sub(&user.id)
+                    .await?
+                    .ok_or(CoreError::InvalidIdentity)?;
+                Ok(ActionSource::User { user_id: user.id.0 })
+            }
+            Some(Identity::Client(client)) => Ok(ActionSource::Api {
+                client_id: client.client_id.clone(),
+            }),
+            Some(Identity::ApiKey(key)) => api_key_actor(key),
+        }
+    }
+
+    async fn mark_deleting(
+        &self,
+        mut deployment: Deployment,
+        identity: Option<&Identity>,
+    ) -> Result<(), CoreError> {
+        let previous_status = deployment.status.clone();
+        deployment.transition_to(DeploymentStatus::Deleting)?;
+        let actor = self.resolve_actor(identity).await?;
+
+        self.deployment_repository.delete(deployment.id).await?;
+        self.deployment_repository
+            .record_status_change(DeploymentStatusChange::new(
+                deployment.id,
+                Some(previous_status),
+                DeploymentStatus::Deleting,
+                actor,
+                None,
+            ))
+            .await
+    }
+}
+
+impl<D, U, DP, O, S> DeploymentService for DeploymentServiceImpl<D, U, DP, O, S>
+where
+    D: DeploymentRepository,
+    U: UserRepository,
+    DP: DataPlaneRepository,
+    O: OrganisationRepository,
+    S: DataPlaneScheduler,
 {
     async fn create_deployment(
         &self,
@@ -56,14 +185,21 @@ where
 
         info!("user {} try to create depliyment", user.email);
 
-        let dataplane = self
+        self.check_instance_limit(command.organisation_id).await?;
+
+        let candidates = self
             .dataplane_repository
-            .find_available(Some(Region::new("local")), 1)
-            .await?
-            .ok_or_else(|| {
-                error!("no dataplane found");
-                CoreError::NoDataPlaneAvailable
-            })?;
+            .list_placement_candidates()
+            .await?;
+        let request = PlacementRequest {
+            organisation_id: command.organisation_id,
+            region: command.region.clone(),
+            required_capacity: 1,
+        };
+        let dataplane = self
+            .scheduler
+            .schedule(&request, &candidates)
+            .inspect_err(|e| error!("no dataplane found: {}", e))?;
 
         let now = chrono::Utc::now();
         let deployment = Deployment {
@@ -80,6 +216,7 @@ where
             updated_at: now,
             deployed_at: None,
             deleted_at: None,
+            purged_at: None,
         };
 
         info!(
@@ -94,6 +231,15 @@ where
                 error!("failed to create deployment: {}", e);
                 e
             })?;
+        self.deployment_repository
+            .record_status_change(DeploymentStatusChange::new(
+                deployment.id,
+                None,
+                deployment.status.clone(),
+                ActionSource::User { user_id: user.id.0 },
+                None,
+            ))
+            .await?;
         Ok(deployment)
     }
 
@@ -113,10 +259,14 @@ where
             .deployment_repository
             .get_by_id(deployment_id)
             .await?
-            .ok_or(CoreError::InternalError("Deployment not found".to_string()))?;
+            .ok_or(CoreError::DeploymentNotFound {
+                id: deployment_id.0,
+            })?;
 
         if deployment.organisation_id != organisation_id {
-            return Err(CoreError::InternalError("Deployment not found".to_string()));
+            return Err(CoreError::DeploymentNotFound {
+                id: deployment_id.0,
+            });
         }
 
         Ok(deployment)
@@ -125,9 +275,10 @@ where
     async fn list_deployments_by_organisation(
         &self,
         organisation_id: OrganisationId,
+        include_deleted: bool,
     ) -> Result<Vec<Deployment>, CoreError> {
         self.deployment_repository
-            .list_by_organisation(organisation_id)
+            .list_by_organisation(organisation_id, include_deleted)
             .await
     }
 
@@ -142,11 +293,8 @@ where
             ));
         }
 
-        let mut deployment = self
-            .deployment_repository
-            .get_by_id(deployment_id)
-            .await?
-            .ok_or(CoreError::InternalError("Deployment not found".to_string()))?;
+        let mut deployment = self.lock_deployment(deployment_id).await?;
+        let previous_status = deployment.status.clone();
 
         if let Some(name) = command.name {
             deployment.name = name;
@@ -157,8 +305,10 @@ where
         if let Some(version) = command.version {
             deployment.version = version;
         }
-        if let Some(status) = command.status {
-            deployment.status = status;
+        if let Some(status) = command.status
+            && status != deployment.status
+        {
+            deployment.transition_to(status)?;
         }
         if let Some(namespace) = command.namespace {
             deployment.namespace = namespace;
@@ -172,9 +322,27 @@ where
 
         deployment.updated_at = chrono::Utc::now();
 
+        let status_change = if deployment.status != previous_status {
+            let actor = self.resolve_actor(command.actor.as_ref()).await?;
+            Some(DeploymentStatusChange::new(
+                deployment.id,
+                Some(previous_status),
+                deployment.status.clone(),
+                actor,
+                command.reason,
+            ))
+        } else {
+            None
+        };
+
         self.deployment_repository
             .update(deployment.clone())
             .await?;
+        if let Some(status_change) = status_change {
+            self.deployment_repository
+                .record_status_change(status_change)
+                .await?;
+        }
         Ok(deployment)
     }
 
@@ -192,19 +360,95 @@ where
     }
 
     async fn delete_deployment(&self, deployment_id: DeploymentId) -> Result<(), CoreError> {
-        self.deployment_repository.delete(deployment_id).await
+        let deployment = self.lock_deployment(deployment_id).await?;
+
+        self.mark_deleting(deployment, None).await
     }
 
     async fn delete_deployment_for_organisation(
         &self,
         organisation_id: OrganisationId,
         deployment_id: DeploymentId,
+        identity: Identity,
     ) -> Result<(), CoreError> {
+        // A concurrent delete waits for this one and then fails to leave `Deleting`
+        let deployment = self.lock_deployment(deployment_id).await?;
+        if deployment.organisation_id != organisation_id {
+            return Err(CoreError::DeploymentNotFound {
+                id: deployment_id.0,
+            });
+        }
+
+        self.mark_deleting(deployment, Some(&identity)).await
+    }
+
+    async fn restore_deployment_for_organisation(
+        &self,
+        organisation_id: OrganisationId,
+        deployment_id: DeploymentId,
+        identity: Identity,
+    ) -> Result<Deployment, CoreError> {
+        // Hold the row so a concurrent purge skips it, and check `purged_at` under the lock
+        let mut deployment = self.lock_deployment(deployment_id).await?;
+        if deployment.organisation_id != organisation_id {
+            return Err(CoreError::DeploymentNotFound {
+                id: deployment_id.0,
+            });
+        }
+
+        // Come back in the status the deployment had when it was deleted
+        let history = self
+            .deployment_repository
+            .list_status_history(deployment.id)
+            .await?;
+        let status = history
+            .iter()
+            .rev()
+            .find(|change| change.to_status == DeploymentStatus::Deleting)
+            .and_then(|change| change.from_status.clone())
+            .unwrap_or(DeploymentStatus::Pending);
+
+        deployment.restore(status.clone(), self.grace_period)?;
+        self.check_instance_limit(organisation_id).await?;
+        let actor = self.resolve_actor(Some(&identity)).await?;
+
+        self.deployment_repository
+            .update(deployment.clone())
+            .await?;
+        self.deployment_repository
+            .record_status_change(DeploymentStatusChange::new(
+                deployment.id,
+                Some(DeploymentStatus::Deleting),
+                status,
+                actor,
+                Some("restored".to_string()),
+            ))
+            .await?;
+        Ok(deployment)
+    }
+
+    async fn purge_deleted_deployments(
+        &self,
+        deleted_before: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<Deployment>, CoreError> {
+        self.deployment_repository
+            .mark_purged(deleted_before, limit)
+            .await
+    }
+
+    async fn get_deployment_status_history_for_organisation(
+        &self,
+        organisation_id: OrganisationId,
+        deployment_id: DeploymentId,
+    ) -> Result<Vec<DeploymentStatusChange>, CoreError> {
         let deployment = self
             .get_deployment_for_organisation(organisation_id, deployment_id)
             .await?;
 
-        self.deployment_repository.delete(deployment.id).await
+        self.deployment_repository
+            .list_status_history(deployment.id)
+            .await
     }
 }
 
@@ -215,10 +459,16 @@ mod tests {
         dataplane::{
             entities::DataPlane,
             ports::MockDataPlaneRepository,
+            scheduler::{PlacementCandidate, RejectionReason},
             value_objects::{Capacity, DataPlaneId, DataPlaneMode, DataPlaneStatus, Region},
         },
         deployments::ports::MockDeploymentRepository,
         deployments::{DeploymentKind, DeploymentName, DeploymentStatus, DeploymentVersion},
+        organisation::{
+            Organisation,
+            ports::MockOrganisationRepository,
+            value_objects::{OrganisationName, OrganisationSlug, Plan},
+        },
         user::UserId,
     };
     use chrono::Utc;
@@ -230,17 +480,25 @@ mod tests {
         fn upsert_by_email(
             &self,
             user: &crate::user::User,
-        ) -> impl std::future::Future<Output = Result<crate::user::User, CoreError>> + Send
+        ) -> impl std::future::Future<Output = Result<Option<crate::user::User>, CoreError>> + Send
         {
             let cloned = crate::user::User {
                 id: user.id,
                 email: user.email.clone(),
                 name: user.name.clone(),
                 sub: user.sub.clone(),
+                roles: user.roles.clone(),
                 created_at: user.created_at,
                 updated_at: user.updated_at,
             };
-            async move { Ok(cloned) }
+            async move { Ok(Some(cloned)) }
+        }
+
+        async fn insert(
+            &self,
+            _user: &crate::user::User,
+        ) -> Result<Option<crate::user::User>, CoreError> {
+            Err(CoreError::InternalError("not used".to_string()))
         }
 
         fn find_by_sub(
@@ -258,6 +516,26 @@ mod tests {
                     email: "user@example.com".to_string(),
                     name: "User".to_string(),
                     sub,
+                    roles: vec![],
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                }))
+            }
+        }
+
+        fn find_by_id(
+            &self,
+            id: &UserId,
+        ) -> impl std::future::Future<Output = Result<Option<crate::user::User>, CoreError>> + Send
+        {
+            let id = *id;
+            async move {
+                Ok(Some(crate::user::User {
+                    id,
+                    email: "user@example.com".to_string(),
+                    name: "User".to_string(),
+                    sub: id.to_string(),
+                    roles: vec![],
                     created_at: Utc::now(),
                     updated_at: Utc::now(),
                 }))
@@ -283,9 +561,28 @@ mod tests {
             updated_at: Utc::now(),
             deployed_at: None,
             deleted_at: None,
+            purged_at: None,
         }
     }
 
+    fn organisation_repository(plan: Plan) -> MockOrganisationRepository {
+        let mut mock_organisation_repo = MockOrganisationRepository::new();
+        mock_organisation_repo
+            .expect_find_by_id_for_update()
+            .times(1)
+            .returning(move |id| {
+                let mut organisation = Organisation::new(
+                    OrganisationName::new("Acme Corp").unwrap(),
+                    OrganisationSlug::new("acme-corp").unwrap(),
+                    UserId(Uuid::new_v4()),
+                    plan,
+                );
+                organisation.id = *id;
+                Box::pin(async move { Ok(Some(organisation)) })
+            });
+        mock_organisation_repo
+    }
+
     fn sample_dataplane() -> DataPlane {
         DataPlane {
             id: DataPlaneId(Uuid::new_v4()),
@@ -300,21 +597,40 @@ mod tests {
     async fn create_deployment_persists() {
         let mut mock_repo = MockDeploymentRepository::new();
         let mut mock_dataplane_repo = MockDataPlaneRepository::new();
+        mock_repo
+            .expect_count_live_by_organisation()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(0) }));
         mock_repo
             .expect_insert()
             .times(1)
             .withf(|deployment| deployment.name.0 == "app")
             .returning(|_| Box::pin(async { Ok(()) }));
+        mock_repo
+            .expect_record_status_change()
+            .times(1)
+            .withf(|change| {
+                change.from_status.is_none() && change.to_status == DeploymentStatus::Pending
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
         mock_dataplane_repo
-            .expect_find_available()
+            .expect_list_placement_candidates()
             .times(1)
-            .returning(|_, _| {
-                let dataplane = sample_dataplane();
-                Box::pin(async move { Ok(Some(dataplane)) })
+            .returning(|| {
+                let candidates = vec![PlacementCandidate {
+                    dataplane: sample_dataplane(),
+                    current_load: 0,
+                    organisations: vec![],
+                }];
+                Box::pin(async move { Ok(candidates) })
             });
 
-        let service =
-            DeploymentServiceImpl::new(mock_repo, StubUserRepository, mock_dataplane_repo);
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            mock_dataplane_repo,
+            organisation_repository(Plan::Starter),
+        );
         let command = CreateDeploymentCommand::new(
             OrganisationId(Uuid::new_v4()),
             DeploymentName("app".to_string()),
@@ -329,6 +645,101 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn create_deployment_reports_why_no_dataplane_fits() {
+        let mut mock_repo = MockDeploymentRepository::new();
+        let mut mock_dataplane_repo = MockDataPlaneRepository::new();
+        let dataplane = sample_dataplane();
+        let dataplane_id = dataplane.id;
+        mock_repo
+            .expect_count_live_by_organisation()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(0) }));
+        mock_repo.expect_insert().never();
+        mock_dataplane_repo
+            .expect_list_placement_candidates()
+            .times(1)
+            .returning(move || {
+                let candidates = vec![PlacementCandidate {
+                    dataplane: dataplane.clone(),
+                    current_load: 0,
+                    organisations: vec![],
+                }];
+                Box::pin(async move { Ok(candidates) })
+            });
+
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            mock_dataplane_repo,
+            organisation_repository(Plan::Starter),
+        )
+        .with_scheduler(SchedulingStrategy::BinPacking);
+        let command = CreateDeploymentCommand::new(
+            OrganisationId(Uuid::new_v4()),
+            DeploymentName("app".to_string()),
+            DeploymentKind::Keycloak,
+            DeploymentVersion("1.0.0".to_string()),
+            DeploymentStatus::Pending,
+            "default".to_string(),
+            UserId(Uuid::new_v4()),
+        )
+        .with_region(Region::new("eu-west"));
+
+        let result = service.create_deployment(command).await;
+
+        let Err(CoreError::NoDataPlaneFit { rejections }) = result else {
+            panic!("expected a no fit error");
+        };
+        assert_eq!(rejections.len(), 1);
+        assert_eq!(rejections[0].dataplane_id, dataplane_id);
+        assert!(matches!(
+            rejections[0].reason,
+            RejectionReason::RegionMismatch { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_deployment_rejects_when_instance_limit_reached() {
+        let mut mock_repo = MockDeploymentRepository::new();
+        let mut mock_dataplane_repo = MockDataPlaneRepository::new();
+        mock_repo
+            .expect_count_live_by_organisation()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(1) }));
+        mock_repo.expect_insert().never();
+        mock_dataplane_repo
+            .expect_list_placement_candidates()
+            .never();
+
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            mock_dataplane_repo,
+            organisation_repository(Plan::Free),
+        );
+        let command = CreateDeploymentCommand::new(
+            OrganisationId(Uuid::new_v4()),
+            DeploymentName("app".to_string()),
+            DeploymentKind::Keycloak,
+            DeploymentVersion("1.0.0".to_string()),
+            DeploymentStatus::Pending,
+            "default".to_string(),
+            UserId(Uuid::new_v4()),
+        );
+
+        let result = service.create_deployment(command).await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::OrganisationLimitReached {
+                max: 1,
+                current: 1,
+                ..
+            })
+        ));
+    }
+
     #[tokio::test]
     async fn get_deployment_for_organisation_rejects_mismatch() {
         let mut mock_repo = MockDeploymentRepository::new();
@@ -343,13 +754,17 @@ mod tests {
             Box::pin(async move { Ok(Some(deployment)) })
         });
 
-        let service =
-            DeploymentServiceImpl::new(mock_repo, StubUserRepository, mock_dataplane_repo);
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            mock_dataplane_repo,
+            MockOrganisationRepository::new(),
+        );
         let result = service
             .get_deployment_for_organisation(organisation_id, deployment_id)
             .await;
 
-        assert!(matches!(result, Err(CoreError::InternalError(_))));
+        assert!(matches!(result, Err(CoreError::DeploymentNotFound { .. })));
     }
 
     #[tokio::test]
@@ -358,6 +773,7 @@ mod tests {
             MockDeploymentRepository::new(),
             StubUserRepository,
             MockDataPlaneRepository::new(),
+            MockOrganisationRepository::new(),
         );
         let result = service
             .update_deployment(DeploymentId(Uuid::new_v4()), UpdateDeploymentCommand::new())
@@ -372,21 +788,38 @@ mod tests {
         let mock_dataplane_repo = MockDataPlaneRepository::new();
         let deployment_id = DeploymentId(Uuid::new_v4());
         let organisation_id = OrganisationId(Uuid::new_v4());
-        let deployment = sample_deployment(deployment_id, organisation_id);
+        let mut deployment = sample_deployment(deployment_id, organisation_id);
+        deployment.status = DeploymentStatus::InProgress;
 
-        mock_repo.expect_get_by_id().times(1).returning(move |_| {
-            let deployment = deployment.clone();
-            Box::pin(async move { Ok(Some(deployment)) })
-        });
+        mock_repo
+            .expect_get_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let deployment = deployment.clone();
+                Box::pin(async move { Ok(Some(deployment)) })
+            });
 
         mock_repo
             .expect_update()
             .times(1)
             .withf(|deployment| deployment.status == DeploymentStatus::Successful)
             .returning(|_| Box::pin(async { Ok(()) }));
+        mock_repo
+            .expect_record_status_change()
+            .times(1)
+            .withf(|change| {
+                change.from_status == Some(DeploymentStatus::InProgress)
+                    && change.to_status == DeploymentStatus::Successful
+                    && change.actor == ActionSource::System
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
 
-        let service =
-            DeploymentServiceImpl::new(mock_repo, StubUserRepository, mock_dataplane_repo);
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            mock_dataplane_repo,
+            MockOrganisationRepository::new(),
+        );
         let command = UpdateDeploymentCommand::new().with_status(DeploymentStatus::Successful);
 
         let result = service.update_deployment(deployment_id, command).await;
@@ -394,6 +827,289 @@ mod tests {
         assert_eq!(result.unwrap().status, DeploymentStatus::Successful);
     }
 
+    #[tokio::test]
+    async fn update_deployment_rejects_illegal_transition() {
+        let mut mock_repo = MockDeploymentRepository::new();
+        let deployment_id = DeploymentId(Uuid::new_v4());
+        let mut deployment = sample_deployment(deployment_id, OrganisationId(Uuid::new_v4()));
+        deployment.status = DeploymentStatus::Deleting;
+
+        mock_repo
+            .expect_get_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let deployment = deployment.clone();
+                Box::pin(async move { Ok(Some(deployment)) })
+            });
+        mock_repo.expect_update().never();
+        mock_repo.expect_record_status_change().never();
+
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            MockDataPlaneRepository::new(),
+            MockOrganisationRepository::new(),
+        );
+        let command = UpdateDeploymentCommand::new().with_status(DeploymentStatus::Successful);
+
+        let result = service.update_deployment(deployment_id, command).await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::InvalidDeploymentTransition { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn delete_deployment_records_actor() {
+        let mut mock_repo = MockDeploymentRepository::new();
+        let deployment_id = DeploymentId(Uuid::new_v4());
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        let deployment = sample_deployment(deployment_id, organisation_id);
+        let sub = Uuid::new_v4();
+
+        mock_repo
+            .expect_get_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let deployment = deployment.clone();
+                Box::pin(async move { Ok(Some(deployment)) })
+            });
+        mock_repo
+            .expect_delete()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_repo
+            .expect_record_status_change()
+            .times(1)
+            .withf(move |change| {
+                change.to_status == DeploymentStatus::Deleting
+                    && change.actor == ActionSource::User { user_id: sub }
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            MockDataPlaneRepository::new(),
+            MockOrganisationRepository::new(),
+        );
+        let identity = Identity::User(aether_auth::User {
+            id: sub.to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        });
+
+        let result = service
+            .delete_deployment_for_organisation(organisation_id, deployment_id, identity)
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn delete_deployment_rejects_deployment_deleted_before_lock() {
+        let mut mock_repo = MockDeploymentRepository::new();
+        let deployment_id = DeploymentId(Uuid::new_v4());
+        let mut deployment = sample_deployment(deployment_id, OrganisationId(Uuid::new_v4()));
+        deployment.status = DeploymentStatus::Deleting;
+        deployment.deleted_at = Some(Utc::now());
+
+        mock_repo
+            .expect_get_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let deployment = deployment.clone();
+                Box::pin(async move { Ok(Some(deployment)) })
+            });
+        mock_repo.expect_delete().never();
+        mock_repo.expect_record_status_change().never();
+
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            MockDataPlaneRepository::new(),
+            MockOrganisationRepository::new(),
+        );
+
+        let result = service.delete_deployment(deployment_id).await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::InvalidDeploymentTransition { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn restore_deployment_returns_to_status_before_deletion() {
+        let mut mock_repo = MockDeploymentRepository::new();
+        let deployment_id = DeploymentId(Uuid::new_v4());
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        let mut deployment = sample_deployment(deployment_id, organisation_id);
+        deployment.status = DeploymentStatus::Deleting;
+        deployment.deleted_at = Some(Utc::now());
+
+        mock_repo
+            .expect_get_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let deployment = deployment.clone();
+                Box::pin(async move { Ok(Some(deployment)) })
+            });
+        mock_repo
+            .expect_list_status_history()
+            .times(1)
+            .returning(move |deployment_id| {
+                let history = vec![
+                    DeploymentStatusChange::new(
+                        deployment_id,
+                        Some(DeploymentStatus::InProgress),
+                        DeploymentStatus::Successful,
+                        ActionSource::System,
+                        None,
+                    ),
+                    DeploymentStatusChange::new(
+                        deployment_id,
+                        Some(DeploymentStatus::Successful),
+                        DeploymentStatus::Deleting,
+                        ActionSource::System,
+                        None,
+                    ),
+                ];
+                Box::pin(async move { Ok(history) })
+            });
+        mock_repo
+            .expect_count_live_by_organisation()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(0) }));
+        mock_repo
+            .expect_update()
+            .times(1)
+            .withf(|deployment| {
+                deployment.status == DeploymentStatus::Successful && deployment.deleted_at.is_none()
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_repo
+            .expect_record_status_change()
+            .times(1)
+            .withf(|change| {
+                change.from_status == Some(DeploymentStatus::Deleting)
+                    && change.to_status == DeploymentStatus::Successful
+            })
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            MockDataPlaneRepository::new(),
+            organisation_repository(Plan::Starter),
+        );
+        let identity = Identity::Client(aether_auth::Client {
+            id: "id".to_string(),
+            client_id: "console".to_string(),
+            roles: vec![],
+            scopes: vec![],
+        });
+
+        let restored = service
+            .restore_deployment_for_organisation(organisation_id, deployment_id, identity)
+            .await
+            .unwrap();
+
+        assert_eq!(restored.status, DeploymentStatus::Successful);
+    }
+
+    #[tokio::test]
+    async fn restore_deployment_rejects_live_deployment() {
+        let mut mock_repo = MockDeploymentRepository::new();
+        let deployment_id = DeploymentId(Uuid::new_v4());
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        let deployment = sample_deployment(deployment_id, organisation_id);
+
+        mock_repo
+            .expect_get_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let deployment = deployment.clone();
+                Box::pin(async move { Ok(Some(deployment)) })
+            });
+        mock_repo
+            .expect_list_status_history()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo.expect_update().never();
+
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            MockDataPlaneRepository::new(),
+            MockOrganisationRepository::new(),
+        );
+        let identity = Identity::Client(aether_auth::Client {
+            id: "id".to_string(),
+            client_id: "console".to_string(),
+            roles: vec![],
+            scopes: vec![],
+        });
+
+        let result = service
+            .restore_deployment_for_organisation(organisation_id, deployment_id, identity)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::DeploymentNotRestorable { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn restore_deployment_rejects_deployment_purged_before_lock() {
+        let mut mock_repo = MockDeploymentRepository::new();
+        let deployment_id = DeploymentId(Uuid::new_v4());
+        let organisation_id = OrganisationId(Uuid::new_v4());
+        let mut deployment = sample_deployment(deployment_id, organisation_id);
+        deployment.status = DeploymentStatus::Deleting;
+        deployment.deleted_at = Some(Utc::now());
+        deployment.purged_at = Some(Utc::now());
+
+        mock_repo.expect_get_by_id().never();
+        mock_repo
+            .expect_get_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let deployment = deployment.clone();
+                Box::pin(async move { Ok(Some(deployment)) })
+            });
+        mock_repo
+            .expect_list_status_history()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_repo.expect_update().never();
+
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            MockDataPlaneRepository::new(),
+            MockOrganisationRepository::new(),
+        );
+        let identity = Identity::Client(aether_auth::Client {
+            id: "id".to_string(),
+            client_id: "console".to_string(),
+            roles: vec![],
+            scopes: vec![],
+        });
+
+        let result = service
+            .restore_deployment_for_organisation(organisation_id, deployment_id, identity)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::DeploymentNotRestorable { .. })
+        ));
+    }
+
     #[tokio::test]
     async fn list_deployments_delegates() {
         let mut mock_repo = MockDeploymentRepository::new();
@@ -407,15 +1123,20 @@ mod tests {
         mock_repo
             .expect_list_by_organisation()
             .times(1)
-            .returning(move |_| {
+            .withf(|_, include_deleted| !include_deleted)
+            .returning(move |_, _| {
                 let deployments = deployments.clone();
                 Box::pin(async move { Ok(deployments) })
             });
 
-        let service =
-            DeploymentServiceImpl::new(mock_repo, StubUserRepository, mock_dataplane_repo);
+        let service = DeploymentServiceImpl::new(
+            mock_repo,
+            StubUserRepository,
+            mock_dataplane_repo,
+            MockOrganisationRepository::new(),
+        );
         let result = service
-            .list_deployments_by_organisation(organisation_id)
+            .list_deployments_by_organisation(organisation_id, false)
             .await;
         assert!(result.is_ok());
         assert_eq!(result.unwrap().len(), 1);
@@ -0,0 +1,34 @@
+use crate::{organisation::OrganisationId, role::RoleId};
+
+/// Command to invite an email address to an organisation
+#[derive(Debug, Clone)]
+pub struct CreateInvitationCommand {
+    pub organisation_id: OrganisationId,
+    pub email: String,
+    pub role_ids: Vec<RoleId>,
+    pub ttl: chrono::Duration,
+}
+
+impl CreateInvitationCommand {
+    /// How long an invitation can be accepted when no TTL is given
+    pub const DEFAULT_TTL_HOURS: i64 = 168;
+
+    pub fn new(organisation_id: OrganisationId, email: String) -> Self {
+        Self {
+            organisation_id,
+            email: email.trim().to_string(),
+            role_ids: vec![],
+            ttl: chrono::Duration::hours(Self::DEFAULT_TTL_HOURS),
+        }
+    }
+
+    pub fn with_role_ids(mut self, role_ids: Vec<RoleId>) -> Self {
+        self.role_ids = role_ids;
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: chrono::Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+}
@@ -0,0 +1,232 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{CoreError, organisation::OrganisationId, role::RoleId, user::UserId};
+
+pub mod commands;
+pub mod notifier;
+pub mod ports;
+pub mod service;
+
+/// Invitation ID value object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, ToSchema)]
+pub struct InvitationId(pub Uuid);
+
+impl From<Uuid> for InvitationId {
+    fn from(uuid: Uuid) -> Self {
+        InvitationId(uuid)
+    }
+}
+
+impl fmt::Display for InvitationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Invitation status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationStatus {
+    /// Sent and waiting to be accepted
+    Pending,
+    /// Accepted; the invitee is now a member
+    Accepted,
+    /// Withdrawn before it was accepted
+    Revoked,
+    /// Not accepted before its TTL ran out
+    Expired,
+}
+
+impl fmt::Display for InvitationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => write!(f, "pending"),
+            Self::Accepted => write!(f, "accepted"),
+            Self::Revoked => write!(f, "revoked"),
+            Self::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+impl FromStr for InvitationStatus {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(Self::Pending),
+            "accepted" => Ok(Self::Accepted),
+            "revoked" => Ok(Self::Revoked),
+            "expired" => Ok(Self::Expired),
+            _ => Err(CoreError::InvalidInvitationStatus {
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// An invitation for an email address to join an organisation with preselected roles.
+///
+/// The invitation is accepted with a single-use secret token; only its hash is kept.
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub struct Invitation {
+    pub id: InvitationId,
+    pub organisation_id: OrganisationId,
+    pub email: String,
+    pub role_ids: Vec<RoleId>,
+    pub status: InvitationStatus,
+    pub invited_by: UserId,
+    #[serde(skip)]
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Invitation {
+    /// Creates a pending invitation that expires after `ttl`
+    pub fn new(
+        organisation_id: OrganisationId,
+        email: String,
+        role_ids: Vec<RoleId>,
+        invited_by: UserId,
+        token_hash: String,
+        ttl: chrono::Duration,
+    ) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: InvitationId(Uuid::new_v4()),
+            organisation_id,
+            email,
+            role_ids,
+            status: InvitationStatus::Pending,
+            invited_by,
+            token_hash,
+            expires_at: now + ttl,
+            accepted_at: None,
+            revoked_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// The status as of `now`: a pending invitation past its expiry reads as expired
+    pub fn status_at(&self, now: DateTime<Utc>) -> InvitationStatus {
+        match self.status {
+            InvitationStatus::Pending if self.expires_at <= now => InvitationStatus::Expired,
+            status => status,
+        }
+    }
+
+    /// Marks a pending invitation past its expiry as expired
+    pub fn expire(&mut self, now: DateTime<Utc>) {
+        if self.status == InvitationStatus::Pending && self.expires_at <= now {
+            self.status = InvitationStatus::Expired;
+            self.updated_at = now;
+        }
+    }
+
+    /// Accepts the invitation; only pending, unexpired invitations can be accepted
+    pub fn accept(&mut self, now: DateTime<Utc>) -> Result<(), CoreError> {
+        self.ensure_pending(now)?;
+
+        self.status = InvitationStatus::Accepted;
+        self.accepted_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Revokes the invitation; only pending, unexpired invitations can be revoked
+    pub fn revoke(&mut self, now: DateTime<Utc>) -> Result<(), CoreError> {
+        self.ensure_pending(now)?;
+
+        self.status = InvitationStatus::Revoked;
+        self.revoked_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    fn ensure_pending(&self, now: DateTime<Utc>) -> Result<(), CoreError> {
+        match self.status_at(now) {
+            InvitationStatus::Pending => Ok(()),
+            status => Err(CoreError::InvitationNotPending {
+                id: self.id.0,
+                status: status.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invitation(ttl: chrono::Duration) -> Invitation {
+        Invitation::new(
+            OrganisationId(Uuid::new_v4()),
+            "invitee@example.com".to_string(),
+            vec![],
+            UserId(Uuid::new_v4()),
+            "hash".to_string(),
+            ttl,
+        )
+    }
+
+    #[test]
+    fn invitation_status_round_trips() {
+        for status in [
+            InvitationStatus::Pending,
+            InvitationStatus::Accepted,
+            InvitationStatus::Revoked,
+            InvitationStatus::Expired,
+        ] {
+            assert_eq!(
+                status.to_string().parse::<InvitationStatus>().unwrap(),
+                status
+            );
+        }
+        assert!("unknown".parse::<InvitationStatus>().is_err());
+    }
+
+    #[test]
+    fn pending_invitation_reads_as_expired_after_ttl() {
+        let mut invitation = invitation(chrono::Duration::hours(1));
+        let later = invitation.expires_at + chrono::Duration::seconds(1);
+
+        assert_eq!(invitation.status_at(Utc::now()), InvitationStatus::Pending);
+        assert_eq!(invitation.status_at(later), InvitationStatus::Expired);
+
+        invitation.expire(later);
+        assert_eq!(invitation.status, InvitationStatus::Expired);
+    }
+
+    #[test]
+    fn accept_is_single_use() {
+        let mut invitation = invitation(chrono::Duration::hours(1));
+
+        assert!(invitation.accept(Utc::now()).is_ok());
+        assert_eq!(invitation.status, InvitationStatus::Accepted);
+        assert!(matches!(
+            invitation.accept(Utc::now()),
+            Err(CoreError::InvitationNotPending { .. })
+        ));
+    }
+
+    #[test]
+    fn expired_invitation_cannot_be_accepted_or_revoked() {
+        let mut invitation = invitation(chrono::Duration::zero());
+
+        assert!(matches!(
+            invitation.accept(Utc::now()),
+            Err(CoreError::InvitationNotPending { ref status, .. }) if status == "expired"
+        ));
+        assert!(invitation.revoke(Utc::now()).is_err());
+    }
+}
@@ -0,0 +1,45 @@
+use tracing::info;
+
+use crate::{
+    CoreError,
+    invitation::{Invitation, ports::Notifier},
+};
+
+/// Notifier that only logs invitations, for deployments without an outbound email channel.
+///
+/// The token is a credential and is left out unless [`LogNotifier::with_token`] opts in, so
+/// that invitations can still be handed over manually during local development.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogNotifier {
+    log_token: bool,
+}
+
+impl LogNotifier {
+    pub fn with_token(mut self, log_token: bool) -> Self {
+        self.log_token = log_token;
+        self
+    }
+}
+
+impl Notifier for LogNotifier {
+    async fn send_invitation(&self, invitation: &Invitation, token: &str) -> Result<(), CoreError> {
+        if self.log_token {
+            info!(
+                invitation_id = %invitation.id,
+                email = %invitation.email,
+                expires_at = %invitation.expires_at,
+                token = %token,
+                "invitation created"
+            );
+        } else {
+            info!(
+                invitation_id = %invitation.id,
+                email = %invitation.email,
+                expires_at = %invitation.expires_at,
+                "invitation created"
+            );
+        }
+
+        Ok(())
+    }
+}
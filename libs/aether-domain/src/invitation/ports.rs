@@ -0,0 +1,88 @@
+use std::future::Future;
+
+use aether_auth::Identity;
+
+use crate::{
+    CoreError,
+    invitation::{Invitation, InvitationId, commands::CreateInvitationCommand},
+    organisation::{OrganisationId, member::Member},
+};
+
+/// Service trait for organisation invitations
+pub trait InvitationService: Send + Sync {
+    /// Invites an email address to an organisation and delivers the acceptance token
+    fn create_invitation(
+        &self,
+        identity: Identity,
+        command: CreateInvitationCommand,
+    ) -> impl Future<Output = Result<Invitation, CoreError>> + Send;
+
+    /// Lists the invitations of an organisation
+    fn list_invitations(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<Vec<Invitation>, CoreError>> + Send;
+
+    /// Revokes a pending invitation
+    fn revoke_invitation(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        invitation_id: InvitationId,
+    ) -> impl Future<Output = Result<Invitation, CoreError>> + Send;
+
+    /// Accepts an invitation with its token, making the caller a member of the organisation
+    fn accept_invitation(
+        &self,
+        identity: Identity,
+        token: String,
+    ) -> impl Future<Output = Result<Member, CoreError>> + Send;
+}
+
+/// Repository trait for invitation persistence
+#[cfg_attr(test, mockall::automock)]
+pub trait InvitationRepository: Send + Sync {
+    fn insert(&self, invitation: &Invitation)
+    -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn find_by_id(
+        &self,
+        organisation_id: &OrganisationId,
+        invitation_id: &InvitationId,
+    ) -> impl Future<Output = Result<Option<Invitation>, CoreError>> + Send;
+
+    /// Finds an invitation by the hash of its token and locks it until the surrounding
+    /// transaction ends, so a token cannot be accepted twice concurrently
+    fn find_by_token_hash_for_update(
+        &self,
+        token_hash: &str,
+    ) -> impl Future<Output = Result<Option<Invitation>, CoreError>> + Send;
+
+    /// Finds the invitation still marked pending for an address, expired or not
+    fn find_pending_by_email(
+        &self,
+        organisation_id: &OrganisationId,
+        email: &str,
+    ) -> impl Future<Output = Result<Option<Invitation>, CoreError>> + Send;
+
+    fn list_by_organisation(
+        &self,
+        organisation_id: &OrganisationId,
+    ) -> impl Future<Output = Result<Vec<Invitation>, CoreError>> + Send;
+
+    /// Persists the status and timestamps of an invitation
+    fn update(&self, invitation: &Invitation)
+    -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Delivers invitations to their recipients
+#[cfg_attr(test, mockall::automock)]
+pub trait Notifier: Send + Sync {
+    /// Sends an invitation together with the plaintext token that accepts it
+    fn send_invitation(
+        &self,
+        invitation: &Invitation,
+        token: &str,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
@@ -0,0 +1,839 @@
+use aether_auth::Identity;
+use aether_permission::require_permission;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    CoreError,
+    invitation::{
+        Invitation, InvitationId,
+        commands::CreateInvitationCommand,
+        ports::{InvitationRepository, InvitationService, Notifier},
+    },
+    organisation::{
+        Organisation, OrganisationId,
+        member::Member,
+        ports::{MemberPolicy, OrganisationRepository},
+        service::ensure_assignable_roles,
+    },
+    role::ports::RoleRepository,
+    token::{generate_token, hash_token},
+    user::{User, UserId, ports::UserRepository},
+};
+
+/// Prefix of invitation tokens, so they are recognisable when leaked
+const INVITATION_TOKEN_PREFIX: &str = "inv_";
+
+pub struct InvitationServiceImpl<I, O, U, R, P, N>
+where
+    I: InvitationRepository,
+    O: OrganisationRepository,
+    U: UserRepository,
+    R: RoleRepository,
+    P: MemberPolicy,
+    N: Notifier,
+{
+    invitation_repository: I,
+    organisation_repository: O,
+    user_repository: U,
+    role_repository: R,
+    member_policy: P,
+    notifier: N,
+}
+
+impl<I, O, U, R, P, N> InvitationServiceImpl<I, O, U, R, P, N>
+where
+    I: InvitationRepository,
+    O: OrganisationRepository,
+    U: UserRepository,
+    R: RoleRepository,
+    P: MemberPolicy,
+    N: Notifier,
+{
+    pub fn new(
+        invitation_repository: I,
+        organisation_repository: O,
+        user_repository: U,
+        role_repository: R,
+        member_policy: P,
+        notifier: N,
+    ) -> Self {
+        Self {
+            invitation_repository,
+            organisation_repository,
+            user_repository,
+            role_repository,
+            member_policy,
+            notifier,
+        }
+    }
+
+    async fn active_organisation(
+        &self,
+        organisation_id: OrganisationId,
+    ) -> Result<Organisation, CoreError> {
+        // Locked so concurrent invitations and acceptances cannot both pass the user limit
+        let organisation = self
+            .organisation_repository
+            .find_by_id_for_update(&organisation_id)
+            .await?
+            .ok_or(CoreError::OrganisationNotFound {
+                id: *organisation_id.as_uuid(),
+            })?;
+
+        if !organisation.is_active() {
+            return Err(CoreError::OrganisationSuspended {
+                reason: "Cannot invite members to a non-active organisation".to_string(),
+            });
+        }
+
+        Ok(organisation)
+    }
+}
+
+fn validate_email(email: &str) -> Result<(), CoreError> {
+    let valid = match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !email.chars().any(char::is_whitespace)
+        }
+        None => false,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(CoreError::InvalidEmail {
+            value: email.to_string(),
+        })
+    }
+}
+
+impl<I, O, U, R, P, N> InvitationService for InvitationServiceImpl<I, O, U, R, P, N>
+where
+    I: InvitationRepository,
+    O: OrganisationRepository,
+    U: UserRepository,
+    R: RoleRepository,
+    P: MemberPolicy,
+    N: Notifier,
+{
+    async fn create_invitation(
+        &self,
+        identity: Identity,
+        command: CreateInvitationCommand,
+    ) -> Result<Invitation, CoreError> {
+        let organisation_id = command.organisation_id;
+        require_permission!(
+            self.member_policy
+                .can_add_members(identity.clone(), organisation_id)
+                .await
+        );
+
+        validate_email(&command.email)?;
+
+        let inviter = self
+            .user_repository
+            .find_by_sub(identity.id())
+            .await?
+            .ok_or(CoreError::InvalidIdentity)?;
+
+        let organisation = self.active_organisation(organisation_id).await?;
+        let granted =
+            ensure_assignable_roles(&self.role_repository, organisation_id, &command.role_ids)
+                .await?;
+        if !granted.is_empty() {
+            require_permission!(
+                self.member_policy
+                    .can_grant_permissions(identity.clone(), organisation_id, granted)
+                    .await
+            );
+        }
+
+        let now = Utc::now();
+        if let Some(mut existing) = self
+            .invitation_repository
+            .find_pending_by_email(&organisation_id, &command.email)
+            .await?
+        {
+            if existing.status_at(now) != existing.status {
+                // Only an expired invitation may be replaced
+                existing.expire(now);
+                self.invitation_repository.update(&existing).await?;
+            } else {
+                return Err(CoreError::InvitationAlreadyPending {
+                    email: command.email,
+                });
+            }
+        }
+
+        let count = self
+            .organisation_repository
+            .count_members(&organisation_id)
+            .await?;
+        organisation.check_user_limit(count)?;
+
+        let token = generate_token(INVITATION_TOKEN_PREFIX);
+        let invitation = Invitation::new(
+            organisation_id,
+            command.email,
+            command.role_ids,
+            inviter.id,
+            hash_token(&token),
+            command.ttl,
+        );
+
+        self.invitation_repository.insert(&invitation).await?;
+        self.notifier.send_invitation(&invitation, &token).await?;
+
+        Ok(invitation)
+    }
+
+    async fn list_invitations(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<Vec<Invitation>, CoreError> {
+        require_permission!(
+            self.member_policy
+                .can_view_members(identity, organisation_id)
+                .await
+        );
+
+        let now = Utc::now();
+        let invitations = self
+            .invitation_repository
+            .list_by_organisation(&organisation_id)
+            .await?
+            .into_iter()
+            .map(|mut invitation| {
+                invitation.expire(now);
+                invitation
+            })
+            .collect();
+
+        Ok(invitations)
+    }
+
+    async fn revoke_invitation(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        invitation_id: InvitationId,
+    ) -> Result<Invitation, CoreError> {
+        require_permission!(
+            self.member_policy
+                .can_add_members(identity, organisation_id)
+                .await
+        );
+
+        let mut invitation = self
+            .invitation_repository
+            .find_by_id(&organisation_id, &invitation_id)
+            .await?
+            .ok_or(CoreError::InvitationNotFound {
+                id: invitation_id.0,
+            })?;
+
+        invitation.revoke(Utc::now())?;
+        self.invitation_repository.update(&invitation).await?;
+
+        Ok(invitation)
+    }
+
+    async fn accept_invitation(
+        &self,
+        identity: Identity,
+        token: String,
+    ) -> Result<Member, CoreError> {
+        let Identity::User(invitee) = &identity else {
+            return Err(CoreError::InvalidIdentity);
+        };
+
+        let mut invitation = self
+            .invitation_repository
+            .find_by_token_hash_for_update(&hash_token(&token))
+            .await?
+            .ok_or(CoreError::InvalidInvitationToken)?;
+
+        let now = Utc::now();
+        invitation.accept(now)?;
+
+        // A token forwarded to someone else must not grant them the invitee's access
+        let Some(email) = invitee.email.as_deref().filter(|_| invitee.email_verified) else {
+            return Err(CoreError::PermissionDenied {
+                reason: "a verified email address is required to accept an invitation".to_string(),
+            });
+        };
+        if !email.eq_ignore_ascii_case(&invitation.email) {
+            return Err(CoreError::PermissionDenied {
+                reason: "the invitation was sent to another email address".to_string(),
+            });
+        }
+
+        let organisation = self.active_organisation(invitation.organisation_id).await?;
+
+        // An existing account keeps its subject; the email alone never rebinds it to the caller
+        let user = match self.user_repository.find_by_sub(&invitee.id).await? {
+            Some(user) => user,
+            None => self
+                .user_repository
+                .insert(&User {
+                    id: UserId(Uuid::new_v4()),
+                    email: invitation.email.clone(),
+                    name: invitee
+                        .name
+                        .clone()
+                        .unwrap_or_else(|| invitee.username.clone()),
+                    sub: invitee.id.clone(),
+                    roles: invitee.roles.clone(),
+                    created_at: now,
+                    updated_at: now,
+                })
+                .await?
+                .ok_or_else(|| CoreError::PermissionDenied {
+                    reason: "the invited email address belongs to another account".to_string(),
+                })?,
+        };
+
+        if self
+            .organisation_repository
+            .find_member_by_user(&organisation.id, &user.id)
+            .await?
+            .is_some()
+        {
+            return Err(CoreError::MemberAlreadyExists { user_id: user.id.0 });
+        }
+
+        let count = self
+            .organisation_repository
+            .count_members(&organisation.id)
+            .await?;
+        organisation.check_user_limit(count)?;
+
+        self.organisation_repository
+            .insert_member(&organisation.id, &user.id)
+            .await?;
+        let member = self
+            .organisation_repository
+            .find_member_by_user(&organisation.id, &user.id)
+            .await?
+            .ok_or_else(|| CoreError::InternalError("Inserted member not found".to_string()))?;
+
+        // Roles deleted since the invitation was sent are skipped rather than failing it
+        let mut role_ids = Vec::with_capacity(invitation.role_ids.len());
+        for role_id in &invitation.role_ids {
+            if self.role_repository.get_by_id(*role_id).await?.is_some() {
+                role_ids.push(*role_id);
+            }
+        }
+        if !role_ids.is_empty() {
+            self.organisation_repository
+                .set_member_roles(&member.id, role_ids.clone())
+                .await?;
+        }
+
+        self.invitation_repository.update(&invitation).await?;
+
+        Ok(Member { role_ids, ..member })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        invitation::{
+            InvitationStatus,
+            ports::{MockInvitationRepository, MockNotifier},
+        },
+        organisation::{
+            member::MemberId,
+            ports::{MockMemberPolicy, MockOrganisationRepository},
+            value_objects::{OrganisationName, OrganisationSlug, Plan},
+        },
+        role::{Role, RoleId, ports::MockRoleRepository},
+    };
+    use aether_permission::Permissions;
+
+    struct StubUserRepository {
+        user_id: UserId,
+        registered: bool,
+    }
+
+    impl UserRepository for StubUserRepository {
+        async fn upsert_by_email(&self, _user: &User) -> Result<Option<User>, CoreError> {
+            Err(CoreError::InternalError("not used".to_string()))
+        }
+
+        async fn insert(&self, user: &User) -> Result<Option<User>, CoreError> {
+            if self.registered {
+                return Err(CoreError::InternalError(
+                    "user already registered".to_string(),
+                ));
+            }
+            Ok(Some(User {
+                id: self.user_id,
+                email: user.email.clone(),
+                name: user.name.clone(),
+                sub: user.sub.clone(),
+                roles: user.roles.clone(),
+                created_at: user.created_at,
+                updated_at: user.updated_at,
+            }))
+        }
+
+        async fn find_by_sub(&self, sub: &str) -> Result<Option<User>, CoreError> {
+            Ok(self.registered.then(|| User {
+                id: self.user_id,
+                email: "owner@example.com".to_string(),
+                name: "Owner".to_string(),
+                sub: sub.to_string(),
+                roles: vec![],
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            }))
+        }
+
+        async fn find_by_id(&self, _id: &UserId) -> Result<Option<User>, CoreError> {
+            Ok(None)
+        }
+    }
+
+    fn allow_all() -> MockMemberPolicy {
+        let mut policy = MockMemberPolicy::new();
+        policy
+            .expect_can_view_members()
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+        policy
+            .expect_can_add_members()
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+        policy
+    }
+
+    fn identity(email: Option<&str>) -> Identity {
+        Identity::User(aether_auth::User {
+            id: "invitee-sub".to_string(),
+            username: "invitee".to_string(),
+            email: email.map(str::to_string),
+            email_verified: true,
+            name: None,
+            roles: vec![],
+        })
+    }
+
+    fn organisation(plan: Plan) -> Organisation {
+        Organisation::new(
+            OrganisationName::new("Acme").unwrap(),
+            OrganisationSlug::new("acme").unwrap(),
+            UserId(Uuid::new_v4()),
+            plan,
+        )
+    }
+
+    fn locked_organisation(
+        repo: &mut MockOrganisationRepository,
+        organisation: Organisation,
+        members: usize,
+    ) {
+        repo.expect_find_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let organisation = organisation.clone();
+                Box::pin(async move { Ok(Some(organisation)) })
+            });
+        repo.expect_count_members()
+            .returning(move |_| Box::pin(async move { Ok(members) }));
+    }
+
+    #[tokio::test]
+    async fn create_invitation_stores_hash_and_sends_token() {
+        let organisation = organisation(Plan::Starter);
+        let organisation_id = organisation.id;
+        let mut organisation_repo = MockOrganisationRepository::new();
+        locked_organisation(&mut organisation_repo, organisation, 1);
+
+        let mut invitation_repo = MockInvitationRepository::new();
+        invitation_repo
+            .expect_find_pending_by_email()
+            .times(1)
+            .returning(|_, _| Box::pin(async move { Ok(None) }));
+        invitation_repo
+            .expect_insert()
+            .times(1)
+            .withf(|invitation| invitation.token_hash.len() == 64)
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        let mut notifier = MockNotifier::new();
+        notifier
+            .expect_send_invitation()
+            .times(1)
+            .withf(|invitation, token| {
+                token.starts_with(INVITATION_TOKEN_PREFIX)
+                    && hash_token(token) == invitation.token_hash
+            })
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+
+        let service = InvitationServiceImpl::new(
+            invitation_repo,
+            organisation_repo,
+            StubUserRepository {
+                user_id: UserId(Uuid::new_v4()),
+                registered: true,
+            },
+            MockRoleRepository::new(),
+            allow_all(),
+            notifier,
+        );
+
+        let invitation = service
+            .create_invitation(
+                identity(None),
+                CreateInvitationCommand::new(organisation_id, " invitee@example.com ".to_string()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(invitation.email, "invitee@example.com");
+        assert_eq!(invitation.status, InvitationStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn create_invitation_rejects_invalid_email() {
+        let service = InvitationServiceImpl::new(
+            MockInvitationRepository::new(),
+            MockOrganisationRepository::new(),
+            StubUserRepository {
+                user_id: UserId(Uuid::new_v4()),
+                registered: true,
+            },
+            MockRoleRepository::new(),
+            allow_all(),
+            MockNotifier::new(),
+        );
+
+        let result = service
+            .create_invitation(
+                identity(None),
+                CreateInvitationCommand::new(OrganisationId::new(), "not-an-email".to_string()),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::InvalidEmail { .. })));
+    }
+
+    #[tokio::test]
+    async fn create_invitation_rejects_duplicate_pending_invitation() {
+        let organisation = organisation(Plan::Starter);
+        let organisation_id = organisation.id;
+        let mut organisation_repo = MockOrganisationRepository::new();
+        locked_organisation(&mut organisation_repo, organisation, 1);
+
+        let existing = Invitation::new(
+            organisation_id,
+            "invitee@example.com".to_string(),
+            vec![],
+            UserId(Uuid::new_v4()),
+            "hash".to_string(),
+            chrono::Duration::hours(1),
+        );
+        let mut invitation_repo = MockInvitationRepository::new();
+        invitation_repo
+            .expect_find_pending_by_email()
+            .times(1)
+            .returning(move |_, _| {
+                let existing = existing.clone();
+                Box::pin(async move { Ok(Some(existing)) })
+            });
+        invitation_repo.expect_insert().never();
+
+        let service = InvitationServiceImpl::new(
+            invitation_repo,
+            organisation_repo,
+            StubUserRepository {
+                user_id: UserId(Uuid::new_v4()),
+                registered: true,
+            },
+            MockRoleRepository::new(),
+            allow_all(),
+            MockNotifier::new(),
+        );
+
+        let result = service
+            .create_invitation(
+                identity(None),
+                CreateInvitationCommand::new(organisation_id, "invitee@example.com".to_string()),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::InvitationAlreadyPending { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_invitation_rejects_permissions_the_caller_lacks() {
+        let organisation = organisation(Plan::Starter);
+        let organisation_id = organisation.id;
+        let mut organisation_repo = MockOrganisationRepository::new();
+        locked_organisation(&mut organisation_repo, organisation, 1);
+
+        let mut role_repo = MockRoleRepository::new();
+        role_repo.expect_get_by_id().times(1).returning(move |id| {
+            Box::pin(async move {
+                Ok(Some(Role {
+                    id,
+                    name: "admin".to_string(),
+                    permissions: Permissions::ADMINISTRATOR.bits(),
+                    organisation_id: Some(organisation_id),
+                    color: None,
+                    created_at: Utc::now(),
+                }))
+            })
+        });
+
+        let mut policy = allow_all();
+        policy
+            .expect_can_grant_permissions()
+            .times(1)
+            .withf(|_, _, permissions| *permissions == Permissions::ADMINISTRATOR)
+            .returning(|_, _, _| {
+                Box::pin(async move {
+                    Err(CoreError::PermissionDenied {
+                        reason: "a role cannot be granted with permissions the caller lacks"
+                            .to_string(),
+                    })
+                })
+            });
+
+        let mut invitation_repo = MockInvitationRepository::new();
+        invitation_repo.expect_insert().never();
+
+        let service = InvitationServiceImpl::new(
+            invitation_repo,
+            organisation_repo,
+            StubUserRepository {
+                user_id: UserId(Uuid::new_v4()),
+                registered: true,
+            },
+            role_repo,
+            policy,
+            MockNotifier::new(),
+        );
+
+        let result = service
+            .create_invitation(
+                identity(None),
+                CreateInvitationCommand::new(organisation_id, "invitee@example.com".to_string())
+                    .with_role_ids(vec![RoleId(Uuid::new_v4())]),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    /// Accepts an invitation with one role as a caller whose account exists when `registered`
+    async fn accept_as(registered: bool) -> (Member, UserId, RoleId) {
+        let organisation = organisation(Plan::Starter);
+        let organisation_id = organisation.id;
+        let user_id = UserId(Uuid::new_v4());
+        let role_id = RoleId(Uuid::new_v4());
+        let token = generate_token(INVITATION_TOKEN_PREFIX);
+        let invitation = Invitation::new(
+            organisation_id,
+            "invitee@example.com".to_string(),
+            vec![role_id],
+            UserId(Uuid::new_v4()),
+            hash_token(&token),
+            chrono::Duration::hours(1),
+        );
+        let member = Member {
+            id: MemberId(Uuid::new_v4()),
+            organisation_id,
+            user_id,
+            email: "invitee@example.com".to_string(),
+            name: "invitee".to_string(),
+            role_ids: vec![],
+            created_at: Utc::now(),
+        };
+
+        let mut invitation_repo = MockInvitationRepository::new();
+        let expected_hash = hash_token(&token);
+        invitation_repo
+            .expect_find_by_token_hash_for_update()
+            .times(1)
+            .withf(move |hash| hash == expected_hash)
+            .returning(move |_| {
+                let invitation = invitation.clone();
+                Box::pin(async move { Ok(Some(invitation)) })
+            });
+        invitation_repo
+            .expect_update()
+            .times(1)
+            .withf(|invitation| invitation.status == InvitationStatus::Accepted)
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        let mut organisation_repo = MockOrganisationRepository::new();
+        locked_organisation(&mut organisation_repo, organisation, 1);
+        let mut lookups = 0;
+        organisation_repo
+            .expect_find_member_by_user()
+            .times(2)
+            .returning(move |_, _| {
+                lookups += 1;
+                let found = (lookups > 1).then(|| member.clone());
+                Box::pin(async move { Ok(found) })
+            });
+        organisation_repo
+            .expect_insert_member()
+            .times(1)
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+        organisation_repo
+            .expect_set_member_roles()
+            .times(1)
+            .withf(move |_, role_ids| *role_ids == vec![role_id])
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+
+        let mut role_repo = MockRoleRepository::new();
+        role_repo.expect_get_by_id().returning(move |id| {
+            Box::pin(async move {
+                Ok(Some(Role {
+                    id,
+                    name: "viewer".to_string(),
+                    permissions: 0,
+                    organisation_id: None,
+                    color: None,
+                    created_at: Utc::now(),
+                }))
+            })
+        });
+
+        let service = InvitationServiceImpl::new(
+            invitation_repo,
+            organisation_repo,
+            StubUserRepository {
+                user_id,
+                registered,
+            },
+            role_repo,
+            allow_all(),
+            MockNotifier::new(),
+        );
+
+        let member = service
+            .accept_invitation(identity(Some("Invitee@Example.com")), token)
+            .await
+            .unwrap();
+
+        (member, user_id, role_id)
+    }
+
+    #[tokio::test]
+    async fn accept_invitation_adds_member_with_roles() {
+        let (member, user_id, role_id) = accept_as(false).await;
+
+        assert_eq!(member.user_id, user_id);
+        assert_eq!(member.role_ids, vec![role_id]);
+    }
+
+    #[tokio::test]
+    async fn accept_invitation_keeps_existing_user() {
+        let (member, user_id, _) = accept_as(true).await;
+
+        assert_eq!(member.user_id, user_id);
+    }
+
+    /// Accepts a valid invitation for `invitee@example.com` as `invitee`, which must be rejected
+    async fn accept_rejected(invitee: Identity) -> Result<Member, CoreError> {
+        let token = generate_token(INVITATION_TOKEN_PREFIX);
+        let invitation = Invitation::new(
+            OrganisationId::new(),
+            "invitee@example.com".to_string(),
+            vec![],
+            UserId(Uuid::new_v4()),
+            hash_token(&token),
+            chrono::Duration::hours(1),
+        );
+
+        let mut invitation_repo = MockInvitationRepository::new();
+        invitation_repo
+            .expect_find_by_token_hash_for_update()
+            .times(1)
+            .returning(move |_| {
+                let invitation = invitation.clone();
+                Box::pin(async move { Ok(Some(invitation)) })
+            });
+        invitation_repo.expect_update().never();
+
+        let service = InvitationServiceImpl::new(
+            invitation_repo,
+            MockOrganisationRepository::new(),
+            StubUserRepository {
+                user_id: UserId(Uuid::new_v4()),
+                registered: true,
+            },
+            MockRoleRepository::new(),
+            allow_all(),
+            MockNotifier::new(),
+        );
+
+        service.accept_invitation(invitee, token).await
+    }
+
+    #[tokio::test]
+    async fn accept_invitation_rejects_other_email() {
+        let result = accept_rejected(identity(Some("someone@example.com"))).await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn accept_invitation_rejects_missing_email() {
+        let result = accept_rejected(identity(None)).await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn accept_invitation_rejects_unverified_email() {
+        let Identity::User(mut invitee) = identity(Some("invitee@example.com")) else {
+            unreachable!()
+        };
+        invitee.email_verified = false;
+
+        let result = accept_rejected(Identity::User(invitee)).await;
+
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn accept_invitation_rejects_unknown_token() {
+        let mut invitation_repo = MockInvitationRepository::new();
+        invitation_repo
+            .expect_find_by_token_hash_for_update()
+            .times(1)
+            .returning(|_| Box::pin(async move { Ok(None) }));
+
+        let service = InvitationServiceImpl::new(
+            invitation_repo,
+            MockOrganisationRepository::new(),
+            StubUserRepository {
+                user_id: UserId(Uuid::new_v4()),
+                registered: true,
+            },
+            MockRoleRepository::new(),
+            allow_all(),
+            MockNotifier::new(),
+        );
+
+        let result = service
+            .accept_invitation(identity(None), "inv_unknown".to_string())
+            .await;
+
+        assert!(matches!(result, Err(CoreError::InvalidInvitationToken)));
+    }
+}
@@ -2,19 +2,31 @@ use chrono::{DateTime, Utc};
 use thiserror::Error;
 use uuid::{NoContext, Timestamp, Uuid};
 
-use crate::dataplane::value_objects::DataPlaneId;
+use crate::{
+    dataplane::{
+        scheduler::{DataPlaneRejection, SchedulingStrategy},
+        value_objects::DataPlaneId,
+    },
+    organisation::plan::LimitViolation,
+};
 
 pub mod action;
+pub mod api_key;
 pub mod dataplane;
 pub mod deployments;
+pub mod invitation;
 pub mod organisation;
 pub mod role;
+pub mod token;
 pub mod user;
 
 #[derive(Clone, Debug)]
 pub struct AetherConfig {
     pub database: DatabaseConfig,
     pub auth: AuthConfig,
+    pub scheduler: SchedulerConfig,
+    pub retention: RetentionConfig,
+    pub invitation: InvitationConfig,
 }
 
 #[derive(Clone, Debug)]
@@ -28,7 +40,64 @@ pub struct DatabaseConfig {
 
 #[derive(Clone, Debug)]
 pub struct AuthConfig {
+    /// Identity providers whose tokens are accepted, matched on the `iss` claim
+    pub issuers: Vec<IssuerConfig>,
+    /// Audiences accepted in access tokens; the audience is not checked when empty
+    pub audiences: Vec<String>,
+    /// Clock skew tolerated when checking `exp` and `nbf`
+    pub leeway_seconds: u64,
+}
+
+impl AuthConfig {
+    pub const DEFAULT_LEEWAY_SECONDS: u64 = 60;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IssuerConfig {
     pub issuer: String,
+    /// How the issuer's claims map into an identity
+    pub claims: aether_auth::ClaimMapping,
+}
+
+impl IssuerConfig {
+    pub fn new(issuer: impl Into<String>) -> Self {
+        Self {
+            issuer: issuer.into(),
+            claims: aether_auth::ClaimMapping::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct InvitationConfig {
+    /// Logs invitation tokens so they can be handed over without an email channel.
+    /// Anyone reading the logs can then join the organisation, so only enable it locally.
+    pub log_tokens: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SchedulerConfig {
+    pub strategy: SchedulingStrategy,
+}
+
+#[derive(Clone, Debug)]
+pub struct RetentionConfig {
+    /// How long a deleted deployment can be restored before it is purged
+    pub deployment_grace_period: chrono::Duration,
+}
+
+impl RetentionConfig {
+    pub const DEFAULT_DEPLOYMENT_GRACE_PERIOD_HOURS: i64 = 72;
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            deployment_grace_period: chrono::Duration::hours(
+                Self::DEFAULT_DEPLOYMENT_GRACE_PERIOD_HOURS,
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -71,21 +140,105 @@ pub enum CoreError {
     #[error("Invalid organisation status: {value}")]
     InvalidOrganisationStatus { value: String },
 
+    #[error("Organisation cannot transition from '{from}' to '{to}'")]
+    InvalidOrganisationTransition { from: String, to: String },
+
+    #[error("A reason is required to change the organisation status")]
+    MissingStatusReason,
+
     #[error("Invalid plan: {value}")]
     InvalidPlan { value: String },
 
+    #[error("Invalid plan limits: {reason}")]
+    InvalidPlanLimits { reason: String },
+
+    #[error(
+        "Plan change blocked by current usage: {}",
+        join_violations(violations)
+    )]
+    PlanLimitsExceeded { violations: Vec<LimitViolation> },
+
     #[error("Invalid identity")]
     InvalidIdentity,
 
+    #[error("User not found with id: {id}")]
+    UserNotFound { id: Uuid },
+
+    #[error("Member not found with id: {id}")]
+    MemberNotFound { id: Uuid },
+
+    #[error("User {user_id} is already a member of the organisation")]
+    MemberAlreadyExists { user_id: Uuid },
+
+    #[error("Role not found with id: {id}")]
+    RoleNotFound { id: Uuid },
+
+    #[error("Invalid email address: {value}")]
+    InvalidEmail { value: String },
+
+    #[error("Invalid invitation status: {value}")]
+    InvalidInvitationStatus { value: String },
+
+    #[error("Invitation not found with id: {id}")]
+    InvitationNotFound { id: Uuid },
+
+    #[error("An invitation for '{email}' is already pending")]
+    InvitationAlreadyPending { email: String },
+
+    #[error("Invitation {id} is {status} and can no longer be used")]
+    InvitationNotPending { id: Uuid, status: String },
+
+    #[error("Invalid invitation token")]
+    InvalidInvitationToken,
+
+    #[error("Invalid API key: {reason}")]
+    InvalidApiKey { reason: String },
+
+    #[error("API key not found with id: {id}")]
+    ApiKeyNotFound { id: Uuid },
+
+    #[error("API key {id} is already revoked")]
+    ApiKeyAlreadyRevoked { id: Uuid },
+
     #[error("Invalid data plane capacity")]
     InvalidDataPlaneCapacity,
 
     #[error("Data plane not found with id: {id}")]
     DataPlaneNotFound { id: DataPlaneId },
 
+    #[error("Invalid data plane agent: {reason}")]
+    InvalidDataPlaneAgent { reason: String },
+
+    #[error("Client {client_id} is not an agent of data plane {dataplane_id}")]
+    DataPlaneAgentNotFound {
+        dataplane_id: DataPlaneId,
+        client_id: String,
+    },
+
     #[error("No data plane available for the organisation")]
     NoDataPlaneAvailable,
 
+    #[error("No data plane fits the deployment: {}", join_rejections(rejections))]
+    NoDataPlaneFit { rejections: Vec<DataPlaneRejection> },
+
+    #[error("Invalid scheduling strategy: {value}")]
+    InvalidSchedulingStrategy { value: String },
+
+    #[error("Action not found with id: {id}")]
+    ActionNotFound { id: Uuid },
+
+    #[error("Deployment not found with id: {id}")]
+    DeploymentNotFound { id: Uuid },
+
+    #[error("Action cannot transition from '{from}' to '{to}'")]
+    InvalidActionTransition { from: String, to: String },
+
+    #[error("Deployment cannot transition from '{from}' to '{to}'")]
+    InvalidDeploymentTransition { from: String, to: String },
+
+    #[error("Deployment {id} cannot be restored: {reason}")]
+    DeploymentNotRestorable { id: Uuid, reason: String },
+
     #[error("Permission denied: {reason}")]
     PermissionDenied { reason: String },
 
@@ -97,6 +250,94 @@ pub enum CoreError {
     InternalError(String),
 }
 
+/// Broad category of a [`CoreError`], used by adapters to pick a status code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The referenced resource does not exist or is not visible to the caller
+    NotFound,
+    /// The request conflicts with the current state of the resource
+    Conflict,
+    /// The request is well formed but fails domain validation or limits
+    UnprocessableEntity,
+    /// The caller could not be resolved to a known identity
+    Unauthorized,
+    /// The caller is known but lacks the required permission
+    Forbidden,
+    /// A failure on our side the caller cannot fix
+    Internal,
+}
+
+impl CoreError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            CoreError::OrganisationNotFound { .. }
+            | CoreError::OrganisationNotFoundBySlug { .. }
+            | CoreError::UserNotFound { .. }
+            | CoreError::MemberNotFound { .. }
+            | CoreError::RoleNotFound { .. }
+            | CoreError::InvitationNotFound { .. }
+            | CoreError::ApiKeyNotFound { .. }
+            | CoreError::DataPlaneNotFound { .. }
+            | CoreError::DataPlaneAgentNotFound { .. }
+            | CoreError::ActionNotFound { .. }
+            | CoreError::DeploymentNotFound { .. } => ErrorKind::NotFound,
+
+            CoreError::OrganisationSlugAlreadyExists { .. }
+            | CoreError::OrganisationSuspended { .. }
+            | CoreError::InvalidOrganisationTransition { .. }
+            | CoreError::MemberAlreadyExists { .. }
+            | CoreError::InvitationAlreadyPending { .. }
+            | CoreError::InvitationNotPending { .. }
+            | CoreError::ApiKeyAlreadyRevoked { .. }
+            | CoreError::InvalidActionTransition { .. }
+            | CoreError::InvalidDeploymentTransition { .. }
+            | CoreError::DeploymentNotRestorable { .. } => ErrorKind::Conflict,
+
+            CoreError::FailedCreateOrganisation { .. }
+            | CoreError::InvalidOrganisationName { .. }
+            | CoreError::InvalidOrganisationSlug { .. }
+            | CoreError::OrganisationLimitReached { .. }
+            | CoreError::UserOrganisationLimitReached { .. }
+            | CoreError::InvalidOrganisationStatus { .. }
+            | CoreError::MissingStatusReason
+            | CoreError::InvalidPlan { .. }
+            | CoreError::InvalidPlanLimits { .. }
+            | CoreError::PlanLimitsExceeded { .. }
+            | CoreError::InvalidEmail { .. }
+            | CoreError::InvalidInvitationStatus { .. }
+            | CoreError::InvalidInvitationToken
+            | CoreError::InvalidApiKey { .. }
+            | CoreError::InvalidDataPlaneCapacity
+            | CoreError::InvalidDataPlaneAgent { .. }
+            | CoreError::NoDataPlaneAvailable
+            | CoreError::NoDataPlaneFit { .. }
+            | CoreError::InvalidSchedulingStrategy { .. } => ErrorKind::UnprocessableEntity,
+
+            CoreError::InvalidIdentity => ErrorKind::Unauthorized,
+
+            CoreError::PermissionDenied { .. } => ErrorKind::Forbidden,
+
+            CoreError::DatabaseError { .. } | CoreError::InternalError(_) => ErrorKind::Internal,
+        }
+    }
+}
+
+fn join_violations(violations: &[LimitViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn join_rejections(rejections: &[DataPlaneRejection]) -> String {
+    rejections
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 pub fn generate_timestamp() -> (DateTime<Utc>, Timestamp) {
     let now = Utc::now();
     let seconds = now.timestamp().try_into().unwrap_or(0);
@@ -1,6 +1,10 @@
 use crate::{
     CoreError,
-    organisation::value_objects::{OrganisationLimits, OrganisationName, OrganisationSlug, Plan},
+    organisation::{
+        member::MemberId,
+        value_objects::{OrganisationLimits, OrganisationName, OrganisationSlug, Plan},
+    },
+    role::RoleId,
     user::UserId,
 };
 
@@ -118,6 +122,110 @@ impl Default for UpdateOrganisationCommand {
     }
 }
 
+/// Command to add an existing user to an organisation
+#[derive(Debug, Clone)]
+pub struct AddMemberCommand {
+    pub user_id: UserId,
+}
+
+impl AddMemberCommand {
+    pub fn new(user_id: UserId) -> Self {
+        Self { user_id }
+    }
+}
+
+/// Command to replace the roles assigned to a member
+#[derive(Debug, Clone)]
+pub struct AssignMemberRolesCommand {
+    pub role_ids: Vec<RoleId>,
+}
+
+impl AssignMemberRolesCommand {
+    pub fn new(role_ids: Vec<RoleId>) -> Self {
+        let mut unique = Vec::with_capacity(role_ids.len());
+        for role_id in role_ids {
+            if !unique.contains(&role_id) {
+                unique.push(role_id);
+            }
+        }
+
+        Self { role_ids: unique }
+    }
+}
+
+/// Command to hand an organisation over to one of its members
+#[derive(Debug, Clone)]
+pub struct TransferOwnershipCommand {
+    pub member_id: MemberId,
+}
+
+impl TransferOwnershipCommand {
+    pub fn new(member_id: MemberId) -> Self {
+        Self { member_id }
+    }
+}
+
+/// Command to suspend or reactivate an organisation
+#[derive(Debug, Clone)]
+pub struct ChangeOrganisationStatusCommand {
+    pub reason: String,
+}
+
+impl ChangeOrganisationStatusCommand {
+    pub fn new(reason: impl Into<String>) -> Result<Self, CoreError> {
+        let reason = reason.into().trim().to_string();
+        if reason.is_empty() {
+            return Err(CoreError::MissingStatusReason);
+        }
+
+        Ok(Self { reason })
+    }
+}
+
+/// Command to move an organisation to another plan
+#[derive(Debug, Clone)]
+pub struct ChangePlanCommand {
+    pub plan: Plan,
+    /// Custom limits, only accepted for [`Plan::Enterprise`]
+    pub limits: Option<OrganisationLimits>,
+    /// Only reports whether the change is possible, without applying it
+    pub dry_run: bool,
+}
+
+impl ChangePlanCommand {
+    pub fn new(plan: Plan) -> Self {
+        Self {
+            plan,
+            limits: None,
+            dry_run: false,
+        }
+    }
+
+    pub fn with_limits(mut self, limits: OrganisationLimits) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Limits the organisation gets on the new plan
+    pub fn target_limits(&self) -> Result<OrganisationLimits, CoreError> {
+        match &self.limits {
+            None => Ok(OrganisationLimits::from_plan(&self.plan)),
+            Some(_) if self.plan != Plan::Enterprise => Err(CoreError::InvalidPlanLimits {
+                reason: format!("custom limits are not available on the {} plan", self.plan),
+            }),
+            Some(limits) if limits.max_users == 0 => Err(CoreError::InvalidPlanLimits {
+                reason: "max_users must leave room for the owner".to_string(),
+            }),
+            Some(limits) => Ok(limits.clone()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,4 +278,48 @@ mod tests {
             UpdateOrganisationCommand::new().with_name(OrganisationName::new("Acme Corp").unwrap());
         assert!(!with_name.is_empty());
     }
+
+    #[test]
+    fn assign_member_roles_command_drops_duplicates() {
+        let role_id = RoleId(Uuid::new_v4());
+        let other = RoleId(Uuid::new_v4());
+
+        let command = AssignMemberRolesCommand::new(vec![role_id, other, role_id]);
+        assert_eq!(command.role_ids, vec![role_id, other]);
+    }
+
+    #[test]
+    fn change_status_command_requires_reason() {
+        assert!(matches!(
+            ChangeOrganisationStatusCommand::new("  "),
+            Err(CoreError::MissingStatusReason)
+        ));
+        assert_eq!(
+            ChangeOrganisationStatusCommand::new(" unpaid invoice ")
+                .unwrap()
+                .reason,
+            "unpaid invoice"
+        );
+    }
+
+    #[test]
+    fn change_plan_command_accepts_custom_limits_for_enterprise_only() {
+        let limits = OrganisationLimits::custom(500, 250, 1000);
+
+        let enterprise = ChangePlanCommand::new(Plan::Enterprise).with_limits(limits.clone());
+        assert_eq!(enterprise.target_limits().unwrap(), limits);
+
+        let business = ChangePlanCommand::new(Plan::Business).with_limits(limits);
+        assert!(matches!(
+            business.target_limits(),
+            Err(CoreError::InvalidPlanLimits { .. })
+        ));
+
+        assert_eq!(
+            ChangePlanCommand::new(Plan::Business)
+                .target_limits()
+                .unwrap(),
+            OrganisationLimits::from_plan(&Plan::Business)
+        );
+    }
 }
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{organisation::OrganisationId, role::RoleId, user::UserId};
+
+/// Member ID value object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, ToSchema)]
+pub struct MemberId(pub Uuid);
+
+impl From<Uuid> for MemberId {
+    fn from(uuid: Uuid) -> Self {
+        MemberId(uuid)
+    }
+}
+
+impl std::fmt::Display for MemberId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A user's membership of an organisation, with the roles assigned to it
+#[derive(Debug, Clone, Serialize, PartialEq, ToSchema)]
+pub struct Member {
+    pub id: MemberId,
+    pub organisation_id: OrganisationId,
+    pub user_id: UserId,
+    pub email: String,
+    pub name: String,
+    pub role_ids: Vec<RoleId>,
+    pub created_at: DateTime<Utc>,
+}
@@ -3,9 +3,11 @@ use serde::Serialize;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::{CoreError, user::UserId};
+use crate::{CoreError, action::ActionSource, user::UserId};
 
 pub mod commands;
+pub mod member;
+pub mod plan;
 pub mod ports;
 pub mod service;
 pub mod value_objects;
@@ -137,6 +139,13 @@ impl Organisation {
             ));
         }
 
+        if !self.status.is_active() {
+            return Err(CoreError::InvalidOrganisationTransition {
+                from: self.status.to_string(),
+                to: OrganisationStatus::Suspended.to_string(),
+            });
+        }
+
         self.status = OrganisationStatus::Suspended;
         self.updated_at = Utc::now();
         Ok(())
@@ -150,11 +159,31 @@ impl Organisation {
             ));
         }
 
+        if !self.status.is_suspended() {
+            return Err(CoreError::InvalidOrganisationTransition {
+                from: self.status.to_string(),
+                to: OrganisationStatus::Active.to_string(),
+            });
+        }
+
         self.status = OrganisationStatus::Active;
         self.updated_at = Utc::now();
         Ok(())
     }
 
+    /// Hands the organisation over to another user
+    pub fn transfer_ownership(&mut self, new_owner_id: UserId) -> Result<(), CoreError> {
+        if !self.is_active() {
+            return Err(CoreError::OrganisationSuspended {
+                reason: "Cannot transfer ownership of a non-active organisation".to_string(),
+            });
+        }
+
+        self.owner_id = new_owner_id;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
     /// Soft deletes the organisation
     pub fn delete(&mut self) -> Result<(), CoreError> {
         if self.is_deleted() {
@@ -178,14 +207,24 @@ impl Organisation {
 
     /// Upgrades the organisation plan
     pub fn upgrade_plan(&mut self, new_plan: Plan) -> Result<(), CoreError> {
+        self.change_plan(new_plan, OrganisationLimits::from_plan(&new_plan))
+    }
+
+    /// Moves the organisation to another plan with the given limits. Whether the current usage
+    /// fits the new limits is checked by the caller, see [`plan::PlanChangeReport`].
+    pub fn change_plan(
+        &mut self,
+        new_plan: Plan,
+        limits: OrganisationLimits,
+    ) -> Result<(), CoreError> {
         if !self.is_active() {
             return Err(CoreError::OrganisationSuspended {
-                reason: "Cannot upgrade plan for a non-active organisation".to_string(),
+                reason: "Cannot change plan for a non-active organisation".to_string(),
             });
         }
 
         self.plan = new_plan;
-        self.limits = OrganisationLimits::from_plan(&new_plan);
+        self.limits = limits;
         self.updated_at = Utc::now();
         Ok(())
     }
@@ -232,6 +271,69 @@ impl Organisation {
     }
 }
 
+/// One suspension or reactivation of an organisation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct OrganisationStatusChange {
+    pub id: Uuid,
+    pub organisation_id: OrganisationId,
+    pub from_status: OrganisationStatus,
+    pub to_status: OrganisationStatus,
+    /// Who requested the change
+    pub actor: ActionSource,
+    pub reason: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl OrganisationStatusChange {
+    pub fn new(
+        organisation_id: OrganisationId,
+        from_status: OrganisationStatus,
+        to_status: OrganisationStatus,
+        actor: ActionSource,
+        reason: String,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            organisation_id,
+            from_status,
+            to_status,
+            actor,
+            reason,
+            changed_at: Utc::now(),
+        }
+    }
+}
+
+/// One hand-over of an organisation to a new owner
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct OwnershipTransfer {
+    pub id: Uuid,
+    pub organisation_id: OrganisationId,
+    pub from_owner_id: UserId,
+    pub to_owner_id: UserId,
+    /// Who requested the transfer
+    pub actor: ActionSource,
+    pub transferred_at: DateTime<Utc>,
+}
+
+impl OwnershipTransfer {
+    pub fn new(
+        organisation_id: OrganisationId,
+        from_owner_id: UserId,
+        to_owner_id: UserId,
+        actor: ActionSource,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            organisation_id,
+            from_owner_id,
+            to_owner_id,
+            actor,
+            transferred_at: Utc::now(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -329,6 +431,44 @@ mod tests {
         assert!(matches!(result, Err(CoreError::InternalError(_))));
     }
 
+    #[test]
+    fn organisation_status_transitions_are_validated() {
+        let name = OrganisationName::new("Test Org").unwrap();
+        let slug = OrganisationSlug::new("test-org").unwrap();
+        let owner_id = UserId(Uuid::new_v4());
+
+        let mut org = Organisation::new(name, slug, owner_id, Plan::Free);
+
+        assert!(matches!(
+            org.activate(),
+            Err(CoreError::InvalidOrganisationTransition { .. })
+        ));
+        org.suspend().unwrap();
+        assert!(matches!(
+            org.suspend(),
+            Err(CoreError::InvalidOrganisationTransition { .. })
+        ));
+        org.activate().unwrap();
+        assert!(org.is_active());
+    }
+
+    #[test]
+    fn organisation_transfer_ownership_requires_active() {
+        let name = OrganisationName::new("Test Org").unwrap();
+        let slug = OrganisationSlug::new("test-org").unwrap();
+        let new_owner_id = UserId(Uuid::new_v4());
+
+        let mut org = Organisation::new(name, slug, UserId(Uuid::new_v4()), Plan::Free);
+        org.transfer_ownership(new_owner_id).unwrap();
+        assert_eq!(org.owner_id, new_owner_id);
+
+        org.suspend().unwrap();
+        assert!(matches!(
+            org.transfer_ownership(UserId(Uuid::new_v4())),
+            Err(CoreError::OrganisationSuspended { .. })
+        ));
+    }
+
     #[test]
     fn organisation_upgrade_rejects_non_active() {
         let name = OrganisationName::new("Test Org").unwrap();
@@ -0,0 +1,256 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    CoreError,
+    action::ActionSource,
+    organisation::{
+        OrganisationId,
+        ports::StorageMeter,
+        value_objects::{OrganisationLimits, Plan},
+    },
+};
+
+/// Resources an organisation currently consumes, as counted against its limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+pub struct OrganisationUsage {
+    /// Deployments that are not deleted
+    pub instances: usize,
+    pub members: usize,
+    /// `None` when storage is not metered
+    pub storage_gb: Option<usize>,
+}
+
+/// Limited resource of an organisation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitResource {
+    Instances,
+    Members,
+    Storage,
+}
+
+impl fmt::Display for LimitResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Instances => write!(f, "instances"),
+            Self::Members => write!(f, "members"),
+            Self::Storage => write!(f, "storage"),
+        }
+    }
+}
+
+/// A resource used beyond the limit of the requested plan
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct LimitViolation {
+    pub resource: LimitResource,
+    pub limit: usize,
+    pub current: usize,
+    /// How much has to be removed before the plan can change
+    pub excess: usize,
+}
+
+impl fmt::Display for LimitViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} uses {} of {}, remove {}",
+            self.resource, self.current, self.limit, self.excess
+        )
+    }
+}
+
+/// Outcome of checking a plan change against the current usage of an organisation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct PlanChangeReport {
+    pub from_plan: Plan,
+    pub to_plan: Plan,
+    pub limits: OrganisationLimits,
+    pub usage: OrganisationUsage,
+    /// What must be removed first; the change is only applied when this is empty
+    pub violations: Vec<LimitViolation>,
+    /// Resources whose usage is not measured, so their limits were not checked
+    pub unmetered: Vec<LimitResource>,
+    pub applied: bool,
+}
+
+impl PlanChangeReport {
+    pub fn new(
+        from_plan: Plan,
+        to_plan: Plan,
+        limits: OrganisationLimits,
+        usage: OrganisationUsage,
+    ) -> Self {
+        let measured = [
+            (
+                LimitResource::Instances,
+                limits.max_instances,
+                Some(usage.instances),
+            ),
+            (
+                LimitResource::Members,
+                limits.max_users,
+                Some(usage.members),
+            ),
+            (
+                LimitResource::Storage,
+                limits.max_storage_gb,
+                usage.storage_gb,
+            ),
+        ];
+        let unmetered = measured
+            .iter()
+            .filter(|(_, _, current)| current.is_none())
+            .map(|(resource, _, _)| *resource)
+            .collect();
+        let violations = measured
+            .into_iter()
+            .filter_map(|(resource, limit, current)| Some((resource, limit, current?)))
+            .filter(|(_, limit, current)| current > limit)
+            .map(|(resource, limit, current)| LimitViolation {
+                resource,
+                limit,
+                current,
+                excess: current - limit,
+            })
+            .collect();
+
+        Self {
+            from_plan,
+            to_plan,
+            limits,
+            usage,
+            violations,
+            unmetered,
+            applied: false,
+        }
+    }
+
+    pub fn is_allowed(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// One entry of an organisation's plan history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct PlanChange {
+    pub id: Uuid,
+    pub organisation_id: OrganisationId,
+    pub from_plan: Plan,
+    pub to_plan: Plan,
+    /// Limits in effect after the change
+    pub limits: OrganisationLimits,
+    /// Who requested the change
+    pub actor: ActionSource,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl PlanChange {
+    pub fn new(
+        organisation_id: OrganisationId,
+        from_plan: Plan,
+        to_plan: Plan,
+        limits: OrganisationLimits,
+        actor: ActionSource,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            organisation_id,
+            from_plan,
+            to_plan,
+            limits,
+            actor,
+            changed_at: Utc::now(),
+        }
+    }
+}
+
+/// Storage meter for deployments that do not meter storage yet. Storage is reported as
+/// unmetered instead of empty, so plan changes say that its limit was not checked.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnmeteredStorage;
+
+impl StorageMeter for UnmeteredStorage {
+    async fn storage_used_gb(
+        &self,
+        _organisation_id: OrganisationId,
+    ) -> Result<Option<usize>, CoreError> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_lists_every_exceeded_limit() {
+        let report = PlanChangeReport::new(
+            Plan::Business,
+            Plan::Starter,
+            OrganisationLimits::from_plan(&Plan::Starter),
+            OrganisationUsage {
+                instances: 7,
+                members: 10,
+                storage_gb: Some(12),
+            },
+        );
+
+        assert!(!report.is_allowed());
+        assert_eq!(
+            report.violations,
+            vec![
+                LimitViolation {
+                    resource: LimitResource::Instances,
+                    limit: 5,
+                    current: 7,
+                    excess: 2,
+                },
+                LimitViolation {
+                    resource: LimitResource::Storage,
+                    limit: 10,
+                    current: 12,
+                    excess: 2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn report_allows_usage_at_the_limit() {
+        let report = PlanChangeReport::new(
+            Plan::Starter,
+            Plan::Free,
+            OrganisationLimits::from_plan(&Plan::Free),
+            OrganisationUsage {
+                instances: 1,
+                members: 2,
+                storage_gb: Some(0),
+            },
+        );
+
+        assert!(report.is_allowed());
+        assert!(report.unmetered.is_empty());
+    }
+
+    #[test]
+    fn report_flags_unmetered_storage() {
+        let report = PlanChangeReport::new(
+            Plan::Business,
+            Plan::Free,
+            OrganisationLimits::from_plan(&Plan::Free),
+            OrganisationUsage {
+                instances: 1,
+                members: 2,
+                storage_gb: None,
+            },
+        );
+
+        assert!(report.is_allowed());
+        assert_eq!(report.unmetered, vec![LimitResource::Storage]);
+    }
+}
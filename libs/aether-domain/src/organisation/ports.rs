@@ -1,14 +1,22 @@
 use std::future::Future;
 
 use aether_auth::Identity;
+use aether_permission::Permissions;
 
 use crate::{
     CoreError,
     organisation::{
-        Organisation, OrganisationId,
-        commands::{CreateOrganisationCommand, CreateOrganisationData, UpdateOrganisationCommand},
+        Organisation, OrganisationId, OrganisationStatusChange, OwnershipTransfer,
+        commands::{
+            AddMemberCommand, AssignMemberRolesCommand, ChangeOrganisationStatusCommand,
+            ChangePlanCommand, CreateOrganisationCommand, CreateOrganisationData,
+            TransferOwnershipCommand, UpdateOrganisationCommand,
+        },
+        member::{Member, MemberId},
+        plan::{PlanChange, PlanChangeReport},
         value_objects::{OrganisationSlug, OrganisationStatus},
     },
+    role::RoleId,
     user::UserId,
 };
 
@@ -44,6 +52,77 @@ pub trait OrganisationService: Send + Sync {
         &self,
         identity: Identity,
     ) -> impl Future<Output = Result<Vec<Organisation>, CoreError>> + Send;
+
+    /// Lists the members of an organisation
+    fn list_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<Vec<Member>, CoreError>> + Send;
+
+    /// Adds an existing user to an organisation, within its `max_users` limit
+    fn add_member(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: AddMemberCommand,
+    ) -> impl Future<Output = Result<Member, CoreError>> + Send;
+
+    /// Removes a member from an organisation; the owner cannot be removed
+    fn remove_member(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        member_id: MemberId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Replaces the roles assigned to a member
+    fn assign_member_roles(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        member_id: MemberId,
+        command: AssignMemberRolesCommand,
+    ) -> impl Future<Output = Result<Member, CoreError>> + Send;
+
+    /// Hands the organisation over to one of its members
+    fn transfer_ownership(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: TransferOwnershipCommand,
+    ) -> impl Future<Output = Result<Organisation, CoreError>> + Send;
+
+    /// Suspends an active organisation and records why
+    fn suspend_organisation(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: ChangeOrganisationStatusCommand,
+    ) -> impl Future<Output = Result<Organisation, CoreError>> + Send;
+
+    /// Reactivates a suspended organisation and records why
+    fn reactivate_organisation(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: ChangeOrganisationStatusCommand,
+    ) -> impl Future<Output = Result<Organisation, CoreError>> + Send;
+
+    /// Moves the organisation to another plan once its usage fits the new limits
+    fn change_plan(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: ChangePlanCommand,
+    ) -> impl Future<Output = Result<PlanChangeReport, CoreError>> + Send;
+
+    /// Lists the plan changes of an organisation, oldest first
+    fn get_plan_history(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<Vec<PlanChange>, CoreError>> + Send;
 }
 
 /// Repository trait for organisation persistence
@@ -76,6 +155,13 @@ pub trait OrganisationRepository: Send + Sync {
         id: &OrganisationId,
     ) -> impl Future<Output = Result<Option<Organisation>, CoreError>> + Send;
 
+    /// Finds an organisation by its ID and locks its row until the surrounding
+    /// transaction ends, serializing concurrent writers that check its limits
+    fn find_by_id_for_update(
+        &self,
+        id: &OrganisationId,
+    ) -> impl Future<Output = Result<Option<Organisation>, CoreError>> + Send;
+
     /// Finds an organisation by its slug
     fn find_by_slug(
         &self,
@@ -125,4 +211,156 @@ pub trait OrganisationRepository: Send + Sync {
         &self,
         status: OrganisationStatus,
     ) -> impl Future<Output = Result<usize, CoreError>> + Send;
+
+    /// Lists the members of an organisation with their assigned roles
+    fn list_members(
+        &self,
+        organisation_id: &OrganisationId,
+    ) -> impl Future<Output = Result<Vec<Member>, CoreError>> + Send;
+
+    /// Finds a member of an organisation by its ID
+    fn find_member(
+        &self,
+        organisation_id: &OrganisationId,
+        member_id: &MemberId,
+    ) -> impl Future<Output = Result<Option<Member>, CoreError>> + Send;
+
+    /// Finds the membership of a user in an organisation
+    fn find_member_by_user(
+        &self,
+        organisation_id: &OrganisationId,
+        user_id: &UserId,
+    ) -> impl Future<Output = Result<Option<Member>, CoreError>> + Send;
+
+    /// Counts the members of an organisation
+    fn count_members(
+        &self,
+        organisation_id: &OrganisationId,
+    ) -> impl Future<Output = Result<usize, CoreError>> + Send;
+
+    /// Deletes a membership along with its role assignments, and revokes the personal access
+    /// tokens its user holds in the organisation
+    fn delete_member(
+        &self,
+        member_id: &MemberId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Replaces the roles assigned to a member
+    fn set_member_roles(
+        &self,
+        member_id: &MemberId,
+        role_ids: Vec<RoleId>,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Appends a suspension or reactivation to the organisation's status history
+    fn record_status_change(
+        &self,
+        change: &OrganisationStatusChange,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Appends an ownership transfer to the organisation's ownership history
+    fn record_ownership_transfer(
+        &self,
+        transfer: &OwnershipTransfer,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Appends an entry to the organisation's plan history
+    fn record_plan_change(
+        &self,
+        change: &PlanChange,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Lists the plan history of an organisation, oldest first
+    fn list_plan_history(
+        &self,
+        organisation_id: &OrganisationId,
+    ) -> impl Future<Output = Result<Vec<PlanChange>, CoreError>> + Send;
+}
+
+/// Authorisation checks for organisation membership management
+#[cfg_attr(test, mockall::automock)]
+pub trait MemberPolicy: Send + Sync {
+    fn can_view_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn can_add_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn can_manage_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn can_remove_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Required to hand out roles carrying `permissions`, by assignment or invitation. Nobody
+    /// grants more than they hold, so managing members does not lead to administrator
+    fn can_grant_permissions(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        permissions: Permissions,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Authorisation checks for operations on the organisation itself
+#[cfg_attr(test, mockall::automock)]
+pub trait OrganisationPolicy: Send + Sync {
+    /// Required to transfer ownership on behalf of the current owner
+    fn can_transfer_ownership(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Required to suspend or reactivate the organisation. Suspension is how the platform
+    /// enforces billing and abuse decisions, so members must not be able to lift it themselves
+    fn can_change_status(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Required to read the plan history
+    fn can_view_billing(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Required to change the plan
+    fn can_manage_billing(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Required on top of [`Self::can_manage_billing`] to move to a higher plan or set custom
+    /// limits. Upgrades are not paid for through the product, so the platform grants them
+    fn can_upgrade_plan(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+/// Measures the storage an organisation uses, in GB
+#[cfg_attr(test, mockall::automock)]
+pub trait StorageMeter: Send + Sync {
+    /// `None` when storage is not metered for the organisation
+    fn storage_used_gb(
+        &self,
+        organisation_id: OrganisationId,
+    ) -> impl Future<Output = Result<Option<usize>, CoreError>> + Send;
 }
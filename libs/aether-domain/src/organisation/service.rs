@@ -1,65 +1,141 @@
 use tracing::{error, info};
 
 use aether_auth::Identity;
+use aether_permission::{Permissions, require_permission};
 
 use crate::{
     CoreError,
+    action::ActionSource,
+    api_key::api_key_actor,
+    deployments::ports::DeploymentRepository,
     organisation::{
-        Organisation, OrganisationId,
-        commands::{CreateOrganisationCommand, CreateOrganisationData, UpdateOrganisationCommand},
-        ports::{OrganisationRepository, OrganisationService},
+        Organisation, OrganisationId, OrganisationStatusChange, OwnershipTransfer,
+        commands::{
+            AddMemberCommand, AssignMemberRolesCommand, ChangeOrganisationStatusCommand,
+            ChangePlanCommand, CreateOrganisationCommand, CreateOrganisationData,
+            TransferOwnershipCommand, UpdateOrganisationCommand,
+        },
+        member::{Member, MemberId},
+        plan::{OrganisationUsage, PlanChange, PlanChangeReport, UnmeteredStorage},
+        ports::{
+            MemberPolicy, OrganisationPolicy, OrganisationRepository, OrganisationService,
+            StorageMeter,
+        },
         value_objects::OrganisationStatus,
     },
-    user::ports::UserRepository,
+    role::{RoleId, ports::RoleRepository},
+    user::{UserId, ports::UserRepository},
 };
 
 /// Maximum number of organisations a user can own
 const MAX_ORGANISATIONS_PER_USER: usize = 10;
 
+/// Checks that every role exists and is either global or belongs to the organisation, and
+/// returns the permissions the roles grant together
+pub(crate) async fn ensure_assignable_roles<R>(
+    role_repository: &R,
+    organisation_id: OrganisationId,
+    role_ids: &[RoleId],
+) -> Result<Permissions, CoreError>
+where
+    R: RoleRepository,
+{
+    let mut permissions = Permissions::empty();
+    for role_id in role_ids {
+        match role_repository.get_by_id(*role_id).await? {
+            Some(role)
+                if role.organisation_id.is_none()
+                    || role.organisation_id == Some(organisation_id) =>
+            {
+                permissions |= Permissions::from_bits_truncate(role.permissions);
+            }
+            _ => return Err(CoreError::RoleNotFound { id: role_id.0 }),
+        }
+    }
+
+    Ok(permissions)
+}
+
 #[derive(Debug)]
-pub struct OrganisationServiceImpl<O, U>
+pub struct OrganisationServiceImpl<O, U, R, D, P, Q, S = UnmeteredStorage>
 where
     O: OrganisationRepository,
     U: UserRepository,
+    R: RoleRepository,
+    D: DeploymentRepository,
+    P: MemberPolicy,
+    Q: OrganisationPolicy,
+    S: StorageMeter,
 {
     organisation_repository: O,
     user_repository: U,
+    role_repository: R,
+    deployment_repository: D,
+    member_policy: P,
+    organisation_policy: Q,
+    storage_meter: S,
 }
 
-impl<O, U> OrganisationServiceImpl<O, U>
+impl<O, U, R, D, P, Q> OrganisationServiceImpl<O, U, R, D, P, Q>
 where
     O: OrganisationRepository,
     U: UserRepository,
+    R: RoleRepository,
+    D: DeploymentRepository,
+    P: MemberPolicy,
+    Q: OrganisationPolicy,
 {
-    pub fn new(organisation_repository: O, user_repository: U) -> Self {
+    pub fn new(
+        organisation_repository: O,
+        user_repository: U,
+        role_repository: R,
+        deployment_repository: D,
+        member_policy: P,
+        organisation_policy: Q,
+    ) -> Self {
         Self {
             organisation_repository,
             user_repository,
+            role_repository,
+            deployment_repository,
+            member_policy,
+            organisation_policy,
+            storage_meter: UnmeteredStorage,
         }
     }
 }
 
-impl<O, U> OrganisationService for OrganisationServiceImpl<O, U>
+impl<O, U, R, D, P, Q, S> OrganisationServiceImpl<O, U, R, D, P, Q, S>
 where
     O: OrganisationRepository,
     U: UserRepository,
+    R: RoleRepository,
+    D: DeploymentRepository,
+    P: MemberPolicy,
+    Q: OrganisationPolicy,
+    S: StorageMeter,
 {
-    async fn create_organisation(
-        &self,
-        command: CreateOrganisationCommand,
-    ) -> Result<Organisation, CoreError> {
-        let user = self
-            .user_repository
-            .find_by_sub(&command.owner_sub)
-            .await?
-            .ok_or(CoreError::InvalidIdentity)?;
-        let owner_id = user.id;
+    pub fn with_storage_meter<S2>(
+        self,
+        storage_meter: S2,
+    ) -> OrganisationServiceImpl<O, U, R, D, P, Q, S2>
+    where
+        S2: StorageMeter,
+    {
+        OrganisationServiceImpl {
+            organisation_repository: self.organisation_repository,
+            user_repository: self.user_repository,
+            role_repository: self.role_repository,
+            deployment_repository: self.deployment_repository,
+            member_policy: self.member_policy,
+            organisation_policy: self.organisation_policy,
+            storage_meter,
+        }
+    }
 
-        // 1. Check user organisation limit (max 10 organisations per user)
-        let user_organisations = self
-            .organisation_repository
-            .find_by_owner(&owner_id)
-            .await?;
+    /// Business rule: a user can own at most [`MAX_ORGANISATIONS_PER_USER`] active organisations
+    async fn check_owner_limit(&self, owner_id: &UserId) -> Result<(), CoreError> {
+        let user_organisations = self.organisation_repository.find_by_owner(owner_id).await?;
 
         let active_orgs_count = user_organisations
             .iter()
@@ -73,6 +149,108 @@ where
             });
         }
 
+        Ok(())
+    }
+
+    async fn find_organisation_for_update(
+        &self,
+        organisation_id: &OrganisationId,
+    ) -> Result<Organisation, CoreError> {
+        self.organisation_repository
+            .find_by_id_for_update(organisation_id)
+            .await?
+            .ok_or(CoreError::OrganisationNotFound {
+                id: *organisation_id.as_uuid(),
+            })
+    }
+
+    /// Maps the identity requesting a change to the actor stored in the status and plan history
+    async fn resolve_actor(&self, identity: &Identity) -> Result<ActionSource, CoreError> {
+        match identity {
+            Identity::User(user) => {
+                let user = self
+                    .user_repository
+                    .find_by_sub(&user.id)
+                    .await?
+                    .ok_or(CoreError::InvalidIdentity)?;
+                Ok(ActionSource::User { user_id: user.id.0 })
+            }
+            Identity::Client(client) => Ok(ActionSource::Api {
+                client_id: client.client_id.clone(),
+            }),
+            Identity::ApiKey(key) => api_key_actor(key),
+        }
+    }
+
+    /// Applies a status transition and appends it to the status history
+    async fn change_status(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: ChangeOrganisationStatusCommand,
+        transition: fn(&mut Organisation) -> Result<(), CoreError>,
+    ) -> Result<Organisation, CoreError> {
+        require_permission!(
+            self.organisation_policy
+                .can_change_status(identity.clone(), organisation_id)
+                .await
+        );
+
+        let mut organisation = self.find_organisation_for_update(&organisation_id).await?;
+        let from_status = organisation.status;
+        transition(&mut organisation)?;
+
+        let actor = self.resolve_actor(&identity).await?;
+        let organisation = self.organisation_repository.update(organisation).await?;
+        self.organisation_repository
+            .record_status_change(&OrganisationStatusChange::new(
+                organisation_id,
+                from_status,
+                organisation.status,
+                actor,
+                command.reason,
+            ))
+            .await?;
+
+        Ok(organisation)
+    }
+
+    async fn find_member(
+        &self,
+        organisation_id: &OrganisationId,
+        member_id: &MemberId,
+    ) -> Result<Member, CoreError> {
+        self.organisation_repository
+            .find_member(organisation_id, member_id)
+            .await?
+            .ok_or(CoreError::MemberNotFound { id: member_id.0 })
+    }
+}
+
+impl<O, U, R, D, P, Q, S> OrganisationService for OrganisationServiceImpl<O, U, R, D, P, Q, S>
+where
+    O: OrganisationRepository,
+    U: UserRepository,
+    R: RoleRepository,
+    D: DeploymentRepository,
+    P: MemberPolicy,
+    Q: OrganisationPolicy,
+    S: StorageMeter,
+{
+    async fn create_organisation(
+        &self,
+        command: CreateOrganisationCommand,
+    ) -> Result<Organisation, CoreError> {
+        let user = self
+            .user_repository
+            .find_by_sub(&command.owner_sub)
+            .await?
+            .ok_or(CoreError::InvalidIdentity)?;
+        let owner_id = user.id;
+
+        // 1. Check user organisation limit (max 10 organisations per user)
+        self.check_owner_limit(&owner_id).await?;
+
         // 2. Generate slug if not provided
         let slug = command.get_or_generate_slug()?;
 
@@ -222,18 +400,313 @@ where
 
         self.organisation_repository.find_by_member(&user.id).await
     }
+
+    async fn list_members(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<Vec<Member>, CoreError> {
+        require_permission!(
+            self.member_policy
+                .can_view_members(identity, organisation_id)
+                .await
+        );
+
+        self.organisation_repository
+            .list_members(&organisation_id)
+            .await
+    }
+
+    async fn add_member(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: AddMemberCommand,
+    ) -> Result<Member, CoreError> {
+        require_permission!(
+            self.member_policy
+                .can_add_members(identity, organisation_id)
+                .await
+        );
+
+        // Lock the organisation so concurrent additions cannot both pass the user limit
+        let organisation = self
+            .organisation_repository
+            .find_by_id_for_update(&organisation_id)
+            .await?
+            .ok_or(CoreError::OrganisationNotFound {
+                id: *organisation_id.as_uuid(),
+            })?;
+
+        if !organisation.is_active() {
+            return Err(CoreError::OrganisationSuspended {
+                reason: "Cannot add members to a non-active organisation".to_string(),
+            });
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(&command.user_id)
+            .await?
+            .ok_or(CoreError::UserNotFound {
+                id: command.user_id.0,
+            })?;
+
+        if self
+            .organisation_repository
+            .find_member_by_user(&organisation_id, &user.id)
+            .await?
+            .is_some()
+        {
+            return Err(CoreError::MemberAlreadyExists { user_id: user.id.0 });
+        }
+
+        let count = self
+            .organisation_repository
+            .count_members(&organisation_id)
+            .await?;
+        organisation.check_user_limit(count)?;
+
+        self.organisation_repository
+            .insert_member(&organisation_id, &user.id)
+            .await?;
+
+        self.organisation_repository
+            .find_member_by_user(&organisation_id, &user.id)
+            .await?
+            .ok_or_else(|| CoreError::InternalError("Inserted member not found".to_string()))
+    }
+
+    async fn remove_member(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        member_id: MemberId,
+    ) -> Result<(), CoreError> {
+        require_permission!(
+            self.member_policy
+                .can_remove_members(identity, organisation_id)
+                .await
+        );
+
+        let organisation = self
+            .organisation_repository
+            .find_by_id(&organisation_id)
+            .await?
+            .ok_or(CoreError::OrganisationNotFound {
+                id: *organisation_id.as_uuid(),
+            })?;
+        let member = self.find_member(&organisation_id, &member_id).await?;
+
+        if member.user_id == organisation.owner_id {
+            return Err(CoreError::PermissionDenied {
+                reason: "the organisation owner cannot be removed".to_string(),
+            });
+        }
+
+        self.organisation_repository.delete_member(&member.id).await
+    }
+
+    async fn assign_member_roles(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        member_id: MemberId,
+        command: AssignMemberRolesCommand,
+    ) -> Result<Member, CoreError> {
+        require_permission!(
+            self.member_policy
+                .can_manage_members(identity.clone(), organisation_id)
+                .await
+        );
+
+        let member = self.find_member(&organisation_id, &member_id).await?;
+
+        let granted =
+            ensure_assignable_roles(&self.role_repository, organisation_id, &command.role_ids)
+                .await?;
+        if !granted.is_empty() {
+            require_permission!(
+                self.member_policy
+                    .can_grant_permissions(identity, organisation_id, granted)
+                    .await
+            );
+        }
+
+        self.organisation_repository
+            .set_member_roles(&member.id, command.role_ids)
+            .await?;
+
+        self.find_member(&organisation_id, &member_id).await
+    }
+
+    async fn transfer_ownership(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: TransferOwnershipCommand,
+    ) -> Result<Organisation, CoreError> {
+        let mut organisation = self.find_organisation_for_update(&organisation_id).await?;
+
+        // The owner can always hand the organisation over; anyone else needs the permission
+        let caller = self.user_repository.find_by_sub(identity.id()).await?;
+        if caller.is_none_or(|caller| caller.id != organisation.owner_id) {
+            require_permission!(
+                self.organisation_policy
+                    .can_transfer_ownership(identity.clone(), organisation_id)
+                    .await
+            );
+        }
+
+        let new_owner = self
+            .find_member(&organisation_id, &command.member_id)
+            .await?;
+        if new_owner.user_id == organisation.owner_id {
+            return Ok(organisation);
+        }
+
+        self.check_owner_limit(&new_owner.user_id).await?;
+        let previous_owner_id = organisation.owner_id;
+        organisation.transfer_ownership(new_owner.user_id)?;
+
+        let actor = self.resolve_actor(&identity).await?;
+        let organisation = self.organisation_repository.update(organisation).await?;
+        self.organisation_repository
+            .record_ownership_transfer(&OwnershipTransfer::new(
+                organisation_id,
+                previous_owner_id,
+                new_owner.user_id,
+                actor,
+            ))
+            .await?;
+
+        Ok(organisation)
+    }
+
+    async fn suspend_organisation(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: ChangeOrganisationStatusCommand,
+    ) -> Result<Organisation, CoreError> {
+        self.change_status(identity, organisation_id, command, Organisation::suspend)
+            .await
+    }
+
+    async fn reactivate_organisation(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: ChangeOrganisationStatusCommand,
+    ) -> Result<Organisation, CoreError> {
+        self.change_status(identity, organisation_id, command, Organisation::activate)
+            .await
+    }
+
+    async fn change_plan(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+        command: ChangePlanCommand,
+    ) -> Result<PlanChangeReport, CoreError> {
+        require_permission!(
+            self.organisation_policy
+                .can_manage_billing(identity.clone(), organisation_id)
+                .await
+        );
+
+        let limits = command.target_limits()?;
+        // Locked so no deployment or member is added between the usage check and the change
+        let mut organisation = self.find_organisation_for_update(&organisation_id).await?;
+
+        let usage = OrganisationUsage {
+            instances: self
+                .deployment_repository
+                .count_live_by_organisation(organisation_id)
+                .await?,
+            members: self
+                .organisation_repository
+                .count_members(&organisation_id)
+                .await?,
+            storage_gb: self.storage_meter.storage_used_gb(organisation_id).await?,
+        };
+        let mut report =
+            PlanChangeReport::new(organisation.plan, command.plan, limits.clone(), usage);
+
+        if command.dry_run {
+            return Ok(report);
+        }
+        if command.plan > organisation.plan
+            || command.limits.is_some()
+            || limits.raises(&organisation.limits)
+        {
+            require_permission!(
+                self.organisation_policy
+                    .can_upgrade_plan(identity.clone(), organisation_id)
+                    .await
+            );
+        }
+        if !report.is_allowed() {
+            return Err(CoreError::PlanLimitsExceeded {
+                violations: report.violations,
+            });
+        }
+        if organisation.plan == command.plan && organisation.limits == limits {
+            return Ok(report);
+        }
+
+        let from_plan = organisation.plan;
+        organisation.change_plan(command.plan, limits.clone())?;
+
+        let actor = self.resolve_actor(&identity).await?;
+        self.organisation_repository.update(organisation).await?;
+        self.organisation_repository
+            .record_plan_change(&PlanChange::new(
+                organisation_id,
+                from_plan,
+                command.plan,
+                limits,
+                actor,
+            ))
+            .await?;
+
+        report.applied = true;
+        Ok(report)
+    }
+
+    async fn get_plan_history(
+        &self,
+        identity: Identity,
+        organisation_id: OrganisationId,
+    ) -> Result<Vec<PlanChange>, CoreError> {
+        require_permission!(
+            self.organisation_policy
+                .can_view_billing(identity, organisation_id)
+                .await
+        );
+
+        self.organisation_repository
+            .list_plan_history(&organisation_id)
+            .await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
+        deployments::ports::MockDeploymentRepository,
         organisation::{
-            ports::MockOrganisationRepository,
+            plan::LimitResource,
+            ports::{
+                MockMemberPolicy, MockOrganisationPolicy, MockOrganisationRepository,
+                MockStorageMeter,
+            },
             value_objects::{
                 OrganisationLimits, OrganisationName, OrganisationSlug, OrganisationStatus, Plan,
             },
         },
+        role::{Role, RoleId, ports::MockRoleRepository},
         user::{User, UserId, ports::UserRepository},
     };
     use chrono::Utc;
@@ -250,15 +723,28 @@ mod tests {
     }
 
     impl UserRepository for FakeUserRepository {
-        async fn upsert_by_email(&self, user: &User) -> Result<User, CoreError> {
-            Ok(User {
+        async fn upsert_by_email(&self, user: &User) -> Result<Option<User>, CoreError> {
+            Ok(Some(User {
                 id: user.id,
                 email: user.email.clone(),
                 name: user.name.clone(),
                 sub: user.sub.clone(),
+                roles: user.roles.clone(),
                 created_at: user.created_at,
                 updated_at: user.updated_at,
-            })
+            }))
+        }
+
+        async fn insert(&self, user: &User) -> Result<Option<User>, CoreError> {
+            Ok(Some(User {
+                id: user.id,
+                email: user.email.clone(),
+                name: user.name.clone(),
+                sub: user.sub.clone(),
+                roles: user.roles.clone(),
+                created_at: user.created_at,
+                updated_at: user.updated_at,
+            }))
         }
 
         async fn find_by_sub(&self, _sub: &str) -> Result<Option<User>, CoreError> {
@@ -267,10 +753,27 @@ mod tests {
                 email: user.email.clone(),
                 name: user.name.clone(),
                 sub: user.sub.clone(),
+                roles: user.roles.clone(),
                 created_at: user.created_at,
                 updated_at: user.updated_at,
             }))
         }
+
+        async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, CoreError> {
+            Ok(self
+                .user
+                .as_ref()
+                .filter(|user| user.id == *id)
+                .map(|user| User {
+                    id: user.id,
+                    email: user.email.clone(),
+                    name: user.name.clone(),
+                    sub: user.sub.clone(),
+                    roles: user.roles.clone(),
+                    created_at: user.created_at,
+                    updated_at: user.updated_at,
+                }))
+        }
     }
 
     fn create_test_organisation(
@@ -303,6 +806,7 @@ mod tests {
             email: "owner@example.com".to_string(),
             name: "Owner".to_string(),
             sub: owner_sub.clone(),
+            roles: vec![],
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -333,7 +837,14 @@ mod tests {
             })
             .returning(|_, _| Box::pin(async move { Ok(()) }));
 
-        let service = OrganisationServiceImpl::new(mock_repo, FakeUserRepository::new(Some(user)));
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(user)),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            MockMemberPolicy::new(),
+            MockOrganisationPolicy::new(),
+        );
         let command = CreateOrganisationCommand::new(name, owner_sub, Plan::Free);
 
         let result = service.create_organisation(command).await;
@@ -353,6 +864,7 @@ mod tests {
             email: "owner@example.com".to_string(),
             name: "Owner".to_string(),
             sub: owner_sub.clone(),
+            roles: vec![],
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -377,7 +889,14 @@ mod tests {
                 Box::pin(async move { Ok(orgs) })
             });
 
-        let service = OrganisationServiceImpl::new(mock_repo, FakeUserRepository::new(Some(user)));
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(user)),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            MockMemberPolicy::new(),
+            MockOrganisationPolicy::new(),
+        );
         let command = CreateOrganisationCommand::new(name, owner_sub, Plan::Free);
 
         let result = service.create_organisation(command).await;
@@ -401,6 +920,7 @@ mod tests {
             email: "owner@example.com".to_string(),
             name: "Owner".to_string(),
             sub: owner_sub.clone(),
+            roles: vec![],
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -416,7 +936,14 @@ mod tests {
             .times(1)
             .returning(|_| Box::pin(async move { Ok(true) }));
 
-        let service = OrganisationServiceImpl::new(mock_repo, FakeUserRepository::new(Some(user)));
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(user)),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            MockMemberPolicy::new(),
+            MockOrganisationPolicy::new(),
+        );
         let command = CreateOrganisationCommand::new(name, owner_sub, Plan::Free);
 
         let result = service.create_organisation(command).await;
@@ -439,6 +966,7 @@ mod tests {
             email: "owner@example.com".to_string(),
             name: "Owner".to_string(),
             sub: owner_sub.clone(),
+            roles: vec![],
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -471,7 +999,14 @@ mod tests {
             })
             .returning(|_, _| Box::pin(async move { Ok(()) }));
 
-        let service = OrganisationServiceImpl::new(mock_repo, FakeUserRepository::new(Some(user)));
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(user)),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            MockMemberPolicy::new(),
+            MockOrganisationPolicy::new(),
+        );
         let command =
             CreateOrganisationCommand::new(name, owner_sub, Plan::Free).with_slug(custom_slug);
 
@@ -504,7 +1039,14 @@ mod tests {
             Box::pin(async move { Ok(org) })
         });
 
-        let service = OrganisationServiceImpl::new(mock_repo, FakeUserRepository::new(None));
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            MockMemberPolicy::new(),
+            MockOrganisationPolicy::new(),
+        );
         let command = UpdateOrganisationCommand::new()
             .with_name(OrganisationName::new("New Name").unwrap())
             .with_slug(OrganisationSlug::new("new-slug").unwrap());
@@ -526,7 +1068,14 @@ mod tests {
             .times(1)
             .returning(|_| Box::pin(async move { Ok(None) }));
 
-        let service = OrganisationServiceImpl::new(mock_repo, FakeUserRepository::new(None));
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            MockMemberPolicy::new(),
+            MockOrganisationPolicy::new(),
+        );
         let command =
             UpdateOrganisationCommand::new().with_name(OrganisationName::new("New Name").unwrap());
 
@@ -549,7 +1098,14 @@ mod tests {
             Box::pin(async move { Ok(Some(org)) })
         });
 
-        let service = OrganisationServiceImpl::new(mock_repo, FakeUserRepository::new(None));
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            MockMemberPolicy::new(),
+            MockOrganisationPolicy::new(),
+        );
         let command =
             UpdateOrganisationCommand::new().with_name(OrganisationName::new("New Name").unwrap());
 
@@ -576,7 +1132,14 @@ mod tests {
             .times(1)
             .returning(|_| Box::pin(async move { Ok(()) }));
 
-        let service = OrganisationServiceImpl::new(mock_repo, FakeUserRepository::new(None));
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            MockMemberPolicy::new(),
+            MockOrganisationPolicy::new(),
+        );
         let result = service.delete_organisation(org_id).await;
         assert!(result.is_ok());
     }
@@ -591,7 +1154,14 @@ mod tests {
             .times(1)
             .returning(|_| Box::pin(async move { Ok(None) }));
 
-        let service = OrganisationServiceImpl::new(mock_repo, FakeUserRepository::new(None));
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            MockMemberPolicy::new(),
+            MockOrganisationPolicy::new(),
+        );
         let result = service.delete_organisation(org_id).await;
         assert!(result.is_err());
         matches!(result.unwrap_err(), CoreError::OrganisationNotFound { .. });
@@ -612,7 +1182,14 @@ mod tests {
             Box::pin(async move { Ok(Some(org)) })
         });
 
-        let service = OrganisationServiceImpl::new(mock_repo, FakeUserRepository::new(None));
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            MockMemberPolicy::new(),
+            MockOrganisationPolicy::new(),
+        );
         let result = service.delete_organisation(org_id).await;
         assert!(result.is_err());
         matches!(result.unwrap_err(), CoreError::InternalError { .. });
@@ -660,4 +1237,819 @@ mod tests {
         let command = UpdateOrganisationCommand::new();
         assert!(command.is_empty());
     }
+
+    fn allow_all() -> MockMemberPolicy {
+        let mut policy = MockMemberPolicy::new();
+        policy
+            .expect_can_view_members()
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+        policy
+            .expect_can_add_members()
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+        policy
+            .expect_can_manage_members()
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+        policy
+            .expect_can_remove_members()
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+        policy
+    }
+
+    fn identity() -> Identity {
+        Identity::User(aether_auth::User {
+            id: "user-sub".to_string(),
+            username: "user".to_string(),
+            email: None,
+            email_verified: false,
+            name: None,
+            roles: vec![],
+        })
+    }
+
+    fn test_user(id: UserId) -> User {
+        User {
+            id,
+            email: "member@example.com".to_string(),
+            name: "Member".to_string(),
+            sub: "member-sub".to_string(),
+            roles: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn test_member(organisation_id: OrganisationId, user_id: UserId) -> Member {
+        Member {
+            id: MemberId(Uuid::new_v4()),
+            organisation_id,
+            user_id,
+            email: "member@example.com".to_string(),
+            name: "Member".to_string(),
+            role_ids: vec![],
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn list_members_requires_permission() {
+        let mut policy = MockMemberPolicy::new();
+        policy.expect_can_view_members().returning(|_, _| {
+            Box::pin(async move {
+                Err(CoreError::PermissionDenied {
+                    reason: "insufficient permissions".to_string(),
+                })
+            })
+        });
+
+        let service = OrganisationServiceImpl::new(
+            MockOrganisationRepository::new(),
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            policy,
+            MockOrganisationPolicy::new(),
+        );
+
+        let result = service
+            .list_members(identity(), OrganisationId::new())
+            .await;
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn add_member_inserts_user() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation =
+            create_test_organisation("Test", "test", UserId(Uuid::new_v4()), Plan::Starter);
+        let organisation_id = organisation.id;
+        let user_id = UserId(Uuid::new_v4());
+        let member = test_member(organisation_id, user_id);
+
+        mock_repo
+            .expect_find_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let organisation = organisation.clone();
+                Box::pin(async move { Ok(Some(organisation)) })
+            });
+        let mut lookups = 0;
+        mock_repo
+            .expect_find_member_by_user()
+            .times(2)
+            .returning(move |_, _| {
+                lookups += 1;
+                let found = (lookups > 1).then(|| member.clone());
+                Box::pin(async move { Ok(found) })
+            });
+        mock_repo
+            .expect_count_members()
+            .times(1)
+            .returning(|_| Box::pin(async move { Ok(1) }));
+        mock_repo
+            .expect_insert_member()
+            .times(1)
+            .withf(move |org_id, id| *org_id == organisation_id && *id == user_id)
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(test_user(user_id))),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            allow_all(),
+            MockOrganisationPolicy::new(),
+        );
+
+        let result = service
+            .add_member(identity(), organisation_id, AddMemberCommand::new(user_id))
+            .await
+            .unwrap();
+        assert_eq!(result.user_id, user_id);
+    }
+
+    #[tokio::test]
+    async fn add_member_enforces_user_limit() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation =
+            create_test_organisation("Test", "test", UserId(Uuid::new_v4()), Plan::Free);
+        let organisation_id = organisation.id;
+        let max_users = organisation.limits.max_users;
+        let user_id = UserId(Uuid::new_v4());
+
+        mock_repo
+            .expect_find_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let organisation = organisation.clone();
+                Box::pin(async move { Ok(Some(organisation)) })
+            });
+        mock_repo
+            .expect_find_member_by_user()
+            .times(1)
+            .returning(|_, _| Box::pin(async move { Ok(None) }));
+        mock_repo
+            .expect_count_members()
+            .times(1)
+            .returning(move |_| Box::pin(async move { Ok(max_users) }));
+        mock_repo.expect_insert_member().never();
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(test_user(user_id))),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            allow_all(),
+            MockOrganisationPolicy::new(),
+        );
+
+        let result = service
+            .add_member(identity(), organisation_id, AddMemberCommand::new(user_id))
+            .await;
+        assert!(matches!(
+            result,
+            Err(CoreError::OrganisationLimitReached { ref limit_type, .. }) if limit_type == "users"
+        ));
+    }
+
+    #[tokio::test]
+    async fn add_member_rejects_existing_member() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation =
+            create_test_organisation("Test", "test", UserId(Uuid::new_v4()), Plan::Starter);
+        let organisation_id = organisation.id;
+        let user_id = UserId(Uuid::new_v4());
+        let member = test_member(organisation_id, user_id);
+
+        mock_repo
+            .expect_find_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let organisation = organisation.clone();
+                Box::pin(async move { Ok(Some(organisation)) })
+            });
+        mock_repo
+            .expect_find_member_by_user()
+            .times(1)
+            .returning(move |_, _| {
+                let member = member.clone();
+                Box::pin(async move { Ok(Some(member)) })
+            });
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(test_user(user_id))),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            allow_all(),
+            MockOrganisationPolicy::new(),
+        );
+
+        let result = service
+            .add_member(identity(), organisation_id, AddMemberCommand::new(user_id))
+            .await;
+        assert!(matches!(result, Err(CoreError::MemberAlreadyExists { .. })));
+    }
+
+    #[tokio::test]
+    async fn remove_member_rejects_owner() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let owner_id = UserId(Uuid::new_v4());
+        let organisation = create_test_organisation("Test", "test", owner_id, Plan::Starter);
+        let organisation_id = organisation.id;
+        let member = test_member(organisation_id, owner_id);
+        let member_id = member.id;
+
+        mock_repo.expect_find_by_id().times(1).returning(move |_| {
+            let organisation = organisation.clone();
+            Box::pin(async move { Ok(Some(organisation)) })
+        });
+        mock_repo
+            .expect_find_member()
+            .times(1)
+            .returning(move |_, _| {
+                let member = member.clone();
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        mock_repo.expect_delete_member().never();
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            allow_all(),
+            MockOrganisationPolicy::new(),
+        );
+
+        let result = service
+            .remove_member(identity(), organisation_id, member_id)
+            .await;
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn assign_member_roles_rejects_role_of_another_organisation() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation_id = OrganisationId::new();
+        let member = test_member(organisation_id, UserId(Uuid::new_v4()));
+        let member_id = member.id;
+        let role_id = RoleId(Uuid::new_v4());
+
+        mock_repo
+            .expect_find_member()
+            .times(1)
+            .returning(move |_, _| {
+                let member = member.clone();
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        mock_repo.expect_set_member_roles().never();
+
+        let mut role_repo = MockRoleRepository::new();
+        role_repo.expect_get_by_id().times(1).returning(move |id| {
+            Box::pin(async move {
+                Ok(Some(Role {
+                    id,
+                    name: "admin".to_string(),
+                    permissions: 0,
+                    organisation_id: Some(OrganisationId::new()),
+                    color: None,
+                    created_at: Utc::now(),
+                }))
+            })
+        });
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            role_repo,
+            MockDeploymentRepository::new(),
+            allow_all(),
+            MockOrganisationPolicy::new(),
+        );
+
+        let result = service
+            .assign_member_roles(
+                identity(),
+                organisation_id,
+                member_id,
+                AssignMemberRolesCommand::new(vec![role_id]),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(CoreError::RoleNotFound { id }) if id == role_id.0
+        ));
+    }
+
+    #[tokio::test]
+    async fn assign_member_roles_rejects_permissions_the_caller_lacks() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation_id = OrganisationId::new();
+        let member = test_member(organisation_id, UserId(Uuid::new_v4()));
+        let member_id = member.id;
+
+        mock_repo
+            .expect_find_member()
+            .times(1)
+            .returning(move |_, _| {
+                let member = member.clone();
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        mock_repo.expect_set_member_roles().never();
+
+        let mut role_repo = MockRoleRepository::new();
+        role_repo.expect_get_by_id().times(1).returning(move |id| {
+            Box::pin(async move {
+                Ok(Some(Role {
+                    id,
+                    name: "admin".to_string(),
+                    permissions: Permissions::ADMINISTRATOR.bits(),
+                    organisation_id: Some(organisation_id),
+                    color: None,
+                    created_at: Utc::now(),
+                }))
+            })
+        });
+
+        let mut policy = allow_all();
+        policy
+            .expect_can_grant_permissions()
+            .times(1)
+            .withf(|_, _, permissions| *permissions == Permissions::ADMINISTRATOR)
+            .returning(|_, _, _| {
+                Box::pin(async move {
+                    Err(CoreError::PermissionDenied {
+                        reason: "a role cannot be granted with permissions the caller lacks"
+                            .to_string(),
+                    })
+                })
+            });
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            role_repo,
+            MockDeploymentRepository::new(),
+            policy,
+            MockOrganisationPolicy::new(),
+        );
+
+        let result = service
+            .assign_member_roles(
+                identity(),
+                organisation_id,
+                member_id,
+                AssignMemberRolesCommand::new(vec![RoleId(Uuid::new_v4())]),
+            )
+            .await;
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    fn allow_status_change() -> MockOrganisationPolicy {
+        let mut policy = MockOrganisationPolicy::new();
+        policy
+            .expect_can_change_status()
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+        policy
+    }
+
+    fn expect_locked(mock_repo: &mut MockOrganisationRepository, organisation: Organisation) {
+        mock_repo
+            .expect_find_by_id_for_update()
+            .times(1)
+            .returning(move |_| {
+                let organisation = organisation.clone();
+                Box::pin(async move { Ok(Some(organisation)) })
+            });
+    }
+
+    #[tokio::test]
+    async fn transfer_ownership_by_owner_skips_policy() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let owner_id = UserId(Uuid::new_v4());
+        let new_owner_id = UserId(Uuid::new_v4());
+        let organisation = create_test_organisation("Test", "test", owner_id, Plan::Free);
+        let organisation_id = organisation.id;
+        let member = test_member(organisation_id, new_owner_id);
+        let member_id = member.id;
+
+        expect_locked(&mut mock_repo, organisation);
+        mock_repo
+            .expect_find_member()
+            .times(1)
+            .returning(move |_, _| {
+                let member = member.clone();
+                Box::pin(async move { Ok(Some(member)) })
+            });
+        mock_repo
+            .expect_find_by_owner()
+            .times(1)
+            .returning(|_| Box::pin(async move { Ok(vec![]) }));
+        mock_repo
+            .expect_update()
+            .times(1)
+            .withf(move |organisation| organisation.owner_id == new_owner_id)
+            .returning(|organisation| Box::pin(async move { Ok(organisation) }));
+        mock_repo
+            .expect_record_ownership_transfer()
+            .times(1)
+            .withf(move |transfer| {
+                transfer.organisation_id == organisation_id
+                    && transfer.from_owner_id == owner_id
+                    && transfer.to_owner_id == new_owner_id
+                    && transfer.actor
+                        == ActionSource::User {
+                            user_id: owner_id.0,
+                        }
+            })
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        let mut policy = MockOrganisationPolicy::new();
+        policy.expect_can_transfer_ownership().never();
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(test_user(owner_id))),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            allow_all(),
+            policy,
+        );
+
+        let result = service
+            .transfer_ownership(
+                identity(),
+                organisation_id,
+                TransferOwnershipCommand::new(member_id),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.owner_id, new_owner_id);
+    }
+
+    #[tokio::test]
+    async fn transfer_ownership_by_non_owner_requires_permission() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation =
+            create_test_organisation("Test", "test", UserId(Uuid::new_v4()), Plan::Free);
+        let organisation_id = organisation.id;
+
+        expect_locked(&mut mock_repo, organisation);
+        mock_repo.expect_update().never();
+        mock_repo.expect_record_ownership_transfer().never();
+
+        let mut policy = MockOrganisationPolicy::new();
+        policy
+            .expect_can_transfer_ownership()
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(async move {
+                    Err(CoreError::PermissionDenied {
+                        reason: "insufficient permissions".to_string(),
+                    })
+                })
+            });
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(test_user(UserId(Uuid::new_v4())))),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            allow_all(),
+            policy,
+        );
+
+        let result = service
+            .transfer_ownership(
+                identity(),
+                organisation_id,
+                TransferOwnershipCommand::new(MemberId(Uuid::new_v4())),
+            )
+            .await;
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn suspend_organisation_records_status_change() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let user_id = UserId(Uuid::new_v4());
+        let organisation =
+            create_test_organisation("Test", "test", UserId(Uuid::new_v4()), Plan::Free);
+        let organisation_id = organisation.id;
+
+        expect_locked(&mut mock_repo, organisation);
+        mock_repo
+            .expect_update()
+            .times(1)
+            .returning(|organisation| Box::pin(async move { Ok(organisation) }));
+        mock_repo
+            .expect_record_status_change()
+            .times(1)
+            .withf(move |change| {
+                change.organisation_id == organisation_id
+                    && change.from_status == OrganisationStatus::Active
+                    && change.to_status == OrganisationStatus::Suspended
+                    && change.actor == ActionSource::User { user_id: user_id.0 }
+                    && change.reason == "unpaid invoice"
+            })
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(test_user(user_id))),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            allow_all(),
+            allow_status_change(),
+        );
+
+        let result = service
+            .suspend_organisation(
+                identity(),
+                organisation_id,
+                ChangeOrganisationStatusCommand::new("unpaid invoice").unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(result.is_suspended());
+    }
+
+    #[tokio::test]
+    async fn reactivate_organisation_rejects_active_organisation() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation =
+            create_test_organisation("Test", "test", UserId(Uuid::new_v4()), Plan::Free);
+        let organisation_id = organisation.id;
+
+        expect_locked(&mut mock_repo, organisation);
+        mock_repo.expect_update().never();
+        mock_repo.expect_record_status_change().never();
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            MockDeploymentRepository::new(),
+            allow_all(),
+            allow_status_change(),
+        );
+
+        let result = service
+            .reactivate_organisation(
+                identity(),
+                organisation_id,
+                ChangeOrganisationStatusCommand::new("payment received").unwrap(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(CoreError::InvalidOrganisationTransition { .. })
+        ));
+    }
+
+    fn allow_billing() -> MockOrganisationPolicy {
+        let mut policy = MockOrganisationPolicy::new();
+        policy
+            .expect_can_manage_billing()
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+        policy
+    }
+
+    fn allow_upgrade() -> MockOrganisationPolicy {
+        let mut policy = allow_billing();
+        policy
+            .expect_can_upgrade_plan()
+            .returning(|_, _| Box::pin(async move { Ok(()) }));
+        policy
+    }
+
+    /// Returns the deployment repository counting the instances
+    fn expect_usage(
+        mock_repo: &mut MockOrganisationRepository,
+        instances: usize,
+        members: usize,
+    ) -> MockDeploymentRepository {
+        mock_repo
+            .expect_count_members()
+            .times(1)
+            .returning(move |_| Box::pin(async move { Ok(members) }));
+        let mut deployments = MockDeploymentRepository::new();
+        deployments
+            .expect_count_live_by_organisation()
+            .times(1)
+            .returning(move |_| Box::pin(async move { Ok(instances) }));
+        deployments
+    }
+
+    #[tokio::test]
+    async fn change_plan_downgrade_blocked_by_usage() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation =
+            create_test_organisation("Test", "test", UserId(Uuid::new_v4()), Plan::Business);
+        let organisation_id = organisation.id;
+
+        expect_locked(&mut mock_repo, organisation);
+        let deployments = expect_usage(&mut mock_repo, 3, 4);
+        mock_repo.expect_update().times(0);
+        mock_repo.expect_record_plan_change().times(0);
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            deployments,
+            MockMemberPolicy::new(),
+            allow_billing(),
+        );
+
+        let result = service
+            .change_plan(
+                identity(),
+                organisation_id,
+                ChangePlanCommand::new(Plan::Free),
+            )
+            .await;
+
+        let Err(CoreError::PlanLimitsExceeded { violations }) = result else {
+            panic!("expected PlanLimitsExceeded, got {result:?}");
+        };
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].excess, 2);
+        assert_eq!(violations[1].excess, 2);
+    }
+
+    #[tokio::test]
+    async fn change_plan_dry_run_reports_without_applying() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation =
+            create_test_organisation("Test", "test", UserId(Uuid::new_v4()), Plan::Business);
+        let organisation_id = organisation.id;
+
+        expect_locked(&mut mock_repo, organisation);
+        let deployments = expect_usage(&mut mock_repo, 3, 1);
+        mock_repo.expect_update().times(0);
+        mock_repo.expect_record_plan_change().times(0);
+
+        let mut storage_meter = MockStorageMeter::new();
+        storage_meter
+            .expect_storage_used_gb()
+            .times(1)
+            .returning(|_| Box::pin(async move { Ok(Some(4)) }));
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            deployments,
+            MockMemberPolicy::new(),
+            allow_billing(),
+        )
+        .with_storage_meter(storage_meter);
+
+        let report = service
+            .change_plan(
+                identity(),
+                organisation_id,
+                ChangePlanCommand::new(Plan::Free).with_dry_run(true),
+            )
+            .await
+            .unwrap();
+
+        assert!(!report.applied);
+        assert!(!report.is_allowed());
+        assert_eq!(report.usage.storage_gb, Some(4));
+        assert_eq!(report.violations.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn change_plan_upgrade_requires_upgrade_permission() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation =
+            create_test_organisation("Test", "test", UserId(Uuid::new_v4()), Plan::Starter);
+        let organisation_id = organisation.id;
+
+        expect_locked(&mut mock_repo, organisation);
+        let deployments = expect_usage(&mut mock_repo, 1, 1);
+        mock_repo.expect_update().never();
+        mock_repo.expect_record_plan_change().never();
+
+        let mut policy = allow_billing();
+        policy.expect_can_upgrade_plan().returning(|_, _| {
+            Box::pin(async move {
+                Err(CoreError::PermissionDenied {
+                    reason: "platform administrator role required".to_string(),
+                })
+            })
+        });
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(None),
+            MockRoleRepository::new(),
+            deployments,
+            MockMemberPolicy::new(),
+            policy,
+        );
+
+        let result = service
+            .change_plan(
+                identity(),
+                organisation_id,
+                ChangePlanCommand::new(Plan::Business),
+            )
+            .await;
+        assert!(matches!(result, Err(CoreError::PermissionDenied { .. })));
+    }
+
+    #[tokio::test]
+    async fn change_plan_downgrade_reports_unmetered_storage() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let organisation =
+            create_test_organisation("Test", "test", UserId(Uuid::new_v4()), Plan::Business);
+        let organisation_id = organisation.id;
+
+        expect_locked(&mut mock_repo, organisation);
+        let deployments = expect_usage(&mut mock_repo, 1, 1);
+        mock_repo
+            .expect_update()
+            .times(1)
+            .returning(|organisation| Box::pin(async move { Ok(organisation) }));
+        mock_repo
+            .expect_record_plan_change()
+            .times(1)
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        // Only can_manage_billing is allowed: a downgrade is not an upgrade
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(test_user(UserId(Uuid::new_v4())))),
+            MockRoleRepository::new(),
+            deployments,
+            MockMemberPolicy::new(),
+            allow_billing(),
+        );
+
+        let report = service
+            .change_plan(
+                identity(),
+                organisation_id,
+                ChangePlanCommand::new(Plan::Free),
+            )
+            .await
+            .unwrap();
+
+        assert!(report.applied);
+        assert_eq!(report.usage.storage_gb, None);
+        assert_eq!(report.unmetered, vec![LimitResource::Storage]);
+    }
+
+    #[tokio::test]
+    async fn change_plan_to_enterprise_records_history() {
+        let mut mock_repo = MockOrganisationRepository::new();
+        let user_id = UserId(Uuid::new_v4());
+        let organisation = create_test_organisation("Test", "test", user_id, Plan::Starter);
+        let organisation_id = organisation.id;
+        let limits = OrganisationLimits::custom(500, 200, 1000);
+        let expected_limits = limits.clone();
+
+        expect_locked(&mut mock_repo, organisation);
+        let deployments = expect_usage(&mut mock_repo, 5, 10);
+        mock_repo
+            .expect_update()
+            .times(1)
+            .withf(move |organisation| {
+                organisation.plan == Plan::Enterprise && organisation.limits == expected_limits
+            })
+            .returning(|organisation| Box::pin(async move { Ok(organisation) }));
+        mock_repo
+            .expect_record_plan_change()
+            .times(1)
+            .withf(move |change| {
+                change.from_plan == Plan::Starter
+                    && change.to_plan == Plan::Enterprise
+                    && change.actor == ActionSource::User { user_id: user_id.0 }
+            })
+            .returning(|_| Box::pin(async move { Ok(()) }));
+
+        let service = OrganisationServiceImpl::new(
+            mock_repo,
+            FakeUserRepository::new(Some(test_user(user_id))),
+            MockRoleRepository::new(),
+            deployments,
+            MockMemberPolicy::new(),
+            allow_upgrade(),
+        );
+
+        let report = service
+            .change_plan(
+                identity(),
+                organisation_id,
+                ChangePlanCommand::new(Plan::Enterprise).with_limits(limits),
+            )
+            .await
+            .unwrap();
+
+        assert!(report.applied);
+        assert!(report.violations.is_empty());
+    }
 }
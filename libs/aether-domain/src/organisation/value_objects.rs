@@ -183,8 +183,8 @@ impl FromStr for OrganisationStatus {
     }
 }
 
-/// Subscription plan for an organisation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+/// Subscription plan for an organisation, ordered from the smallest to the largest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, ToSchema)]
 pub enum Plan {
     /// Free tier with basic features
     Free,
@@ -276,6 +276,13 @@ impl OrganisationLimits {
             max_storage_gb,
         }
     }
+
+    /// Whether any limit is higher than in `other`
+    pub fn raises(&self, other: &Self) -> bool {
+        self.max_instances > other.max_instances
+            || self.max_users > other.max_users
+            || self.max_storage_gb > other.max_storage_gb
+    }
 }
 
 #[cfg(test)]
@@ -330,4 +337,13 @@ mod tests {
         assert_eq!(Plan::Free.max_users(), 2);
         assert_eq!(Plan::Enterprise.max_users(), 100);
     }
+
+    #[test]
+    fn test_limits_raises() {
+        let starter = OrganisationLimits::from_plan(&Plan::Starter);
+        assert!(OrganisationLimits::from_plan(&Plan::Business).raises(&starter));
+        assert!(!OrganisationLimits::from_plan(&Plan::Free).raises(&starter));
+        assert!(OrganisationLimits::custom(1, 1, 11).raises(&starter));
+        assert!(Plan::Free < Plan::Enterprise);
+    }
 }
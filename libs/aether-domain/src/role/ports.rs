@@ -8,6 +8,7 @@ use crate::{
     organisation::OrganisationId,
     role::commands::{CreateRoleCommand, UpdateRoleCommand},
     role::{Role, RoleId},
+    user::UserId,
 };
 
 /// Service trait for role business logic
@@ -71,6 +72,22 @@ pub trait RoleRepository: Send + Sync {
         organisation_id: OrganisationId,
         names: Vec<String>,
     ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+    /// Lists the roles a user holds in the organisation: those named by their identity provider
+    /// roles and those assigned to their membership. The user is found by subject
+    fn list_granted_to_subject(
+        &self,
+        organisation_id: OrganisationId,
+        sub: String,
+        role_names: Vec<String>,
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+    /// Lists the roles a user holds in the organisation, resolved like
+    /// [`RoleRepository::list_granted_to_subject`] from the identity provider roles stored on
+    /// their last sign-in. The user is found by id
+    fn list_granted_to_user(
+        &self,
+        organisation_id: OrganisationId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
     fn update(&self, role: Role) -> impl Future<Output = Result<(), CoreError>> + Send;
     fn delete(&self, role_id: RoleId) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
@@ -141,10 +141,10 @@ where
             .role_repository
             .get_by_id(role_id)
             .await?
-            .ok_or(CoreError::InternalError("Role not found".to_string()))?;
+            .ok_or(CoreError::RoleNotFound { id: role_id.0 })?;
 
         if role.organisation_id != Some(organisation_id) {
-            return Err(CoreError::InternalError("Role not found".to_string()));
+            return Err(CoreError::RoleNotFound { id: role_id.0 });
         }
 
         if let Some(name) = command.name {
@@ -186,6 +186,7 @@ mod tests {
             id: "user-1".to_string(),
             username: "user".to_string(),
             email: None,
+            email_verified: false,
             name: None,
             roles: vec![],
         })
@@ -309,7 +310,7 @@ mod tests {
                 command,
             )
             .await;
-        assert!(matches!(result, Err(CoreError::InternalError(_))));
+        assert!(matches!(result, Err(CoreError::RoleNotFound { .. })));
     }
 
     #[tokio::test]
@@ -0,0 +1,43 @@
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+
+/// Number of random bytes in a generated token
+const TOKEN_BYTES: usize = 32;
+
+/// Generates an opaque secret made of `prefix` followed by 32 random bytes, hex encoded.
+/// The prefix makes leaked tokens recognisable; only [`hash_token`] of it should be stored.
+pub fn generate_token(prefix: &str) -> String {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+
+    format!("{prefix}{}", hex::encode(bytes))
+}
+
+/// Hex encoded SHA-256 digest of a token, used to look it up without persisting the secret
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_tokens_are_prefixed_and_unique() {
+        let first = generate_token("inv_");
+        let second = generate_token("inv_");
+
+        assert!(first.starts_with("inv_"));
+        assert_eq!(first.len(), "inv_".len() + TOKEN_BYTES * 2);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn hash_token_is_stable_and_hides_the_token() {
+        let token = generate_token("inv_");
+
+        assert_eq!(hash_token(&token), hash_token(&token));
+        assert_eq!(hash_token(&token).len(), 64);
+        assert!(!hash_token(&token).contains(&token));
+    }
+}
@@ -3,4 +3,5 @@ pub struct CreateUserCommand {
     pub name: String,
     pub email: String,
     pub sub: String,
+    pub roles: Vec<String>,
 }
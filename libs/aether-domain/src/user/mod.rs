@@ -31,6 +31,9 @@ pub struct User {
     pub email: String,
     pub name: String,
     pub sub: String,
+    /// Identity provider roles seen on the user's last sign-in, so their personal access
+    /// tokens resolve the same organisation roles as their tokens from the provider
+    pub roles: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -1,6 +1,6 @@
 use crate::{
     CoreError,
-    user::{User, commands::CreateUserCommand},
+    user::{User, UserId, commands::CreateUserCommand},
 };
 
 pub trait UserService: Send + Sync {
@@ -11,9 +11,21 @@ pub trait UserService: Send + Sync {
 }
 
 pub trait UserRepository: Send + Sync {
-    fn upsert_by_email(&self, user: &User) -> impl Future<Output = Result<User, CoreError>> + Send;
+    /// Inserts a user, or refreshes the one registered with the same email when its subject
+    /// comes from the same issuer. Returns `None` when the email belongs to another issuer's
+    /// subject, which is never taken over
+    fn upsert_by_email(
+        &self,
+        user: &User,
+    ) -> impl Future<Output = Result<Option<User>, CoreError>> + Send;
+    /// Inserts a user, or returns `None` when its email or subject already belongs to a user
+    fn insert(&self, user: &User) -> impl Future<Output = Result<Option<User>, CoreError>> + Send;
     fn find_by_sub(
         &self,
         sub: &str,
     ) -> impl Future<Output = Result<Option<User>, CoreError>> + Send;
+    fn find_by_id(
+        &self,
+        id: &UserId,
+    ) -> impl Future<Output = Result<Option<User>, CoreError>> + Send;
 }
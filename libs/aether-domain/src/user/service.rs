@@ -38,12 +38,17 @@ where
             name: command.name,
             email: command.email,
             sub: command.sub,
+            roles: command.roles,
             created_at: now,
             updated_at: now,
         };
 
-        let user = self.user_repository.upsert_by_email(&user).await?;
-
-        Ok(user)
+        self.user_repository
+            .upsert_by_email(&user)
+            .await?
+            .ok_or_else(|| CoreError::PermissionDenied {
+                reason: "the email address belongs to an account of another identity provider"
+                    .to_string(),
+            })
     }
 }
@@ -2,3 +2,4 @@ pub mod action;
 pub mod dataplane;
 pub mod deployment;
 pub mod shard;
+pub mod sync_report;
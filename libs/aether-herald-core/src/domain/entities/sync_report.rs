@@ -0,0 +1,11 @@
+/// Outcome of one polling cycle over the deployments owned by a shard
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// Deployments returned by the control plane for the dataplane
+    pub deployments_listed: usize,
+    /// Deployments assigned to this shard
+    pub deployments_owned: usize,
+    pub actions_published: usize,
+    /// Owned deployments whose actions could not be claimed or published
+    pub deployments_failed: usize,
+}
@@ -14,3 +14,34 @@ pub enum HeraldError {
     #[error("Internal error: {message}")]
     Internal { message: String },
 }
+
+impl HeraldError {
+    /// Whether the same operation may succeed later.
+    ///
+    /// Broker and control plane outages are transient; an action the bus cannot
+    /// represent never will be.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::ControlPlane { .. } | Self::MessageBus { .. } => true,
+            Self::InvalidAction { .. } | Self::Internal { .. } => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infrastructure_errors_are_retryable() {
+        let error = HeraldError::MessageBus {
+            message: "connection refused".to_string(),
+        };
+        assert!(error.is_retryable());
+
+        let error = HeraldError::InvalidAction {
+            message: "payload is not serializable".to_string(),
+        };
+        assert!(!error.is_retryable());
+    }
+}
@@ -1,6 +1,8 @@
-use crate::domain::entities::action::{Action, ActionEvent};
+use crate::domain::entities::action::{Action, ActionEvent, ActionId};
 use crate::domain::entities::dataplane::DataPlaneId;
 use crate::domain::entities::deployment::{Deployment, DeploymentId};
+use crate::domain::entities::shard::ShardConfig;
+use crate::domain::entities::sync_report::SyncReport;
 use crate::domain::error::HeraldError;
 use std::future::Future;
 
@@ -10,6 +12,15 @@ pub trait HeraldService: Send + Sync {
         &self,
         deployment_id: &DeploymentId,
     ) -> impl Future<Output = Result<(), HeraldError>> + Send;
+
+    /// Processes every deployment owned by `shard`.
+    ///
+    /// Failing deployments are counted in the report instead of aborting the cycle, so one
+    /// broken deployment does not starve the others.
+    fn sync_shard(
+        &self,
+        shard: &ShardConfig,
+    ) -> impl Future<Output = Result<SyncReport, HeraldError>> + Send;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -24,6 +35,23 @@ pub trait ControlPlaneRepository: Send + Sync {
         dp_id: &DataPlaneId,
         deployment_id: &DeploymentId,
     ) -> impl Future<Output = Result<Vec<Action>, HeraldError>> + Send;
+
+    /// Reports a claimed action as published so it is not handed out again
+    fn ack_action(
+        &self,
+        dp_id: &DataPlaneId,
+        deployment_id: &DeploymentId,
+        action_id: &ActionId,
+    ) -> impl Future<Output = Result<(), HeraldError>> + Send;
+
+    /// Reports a claimed action as failed with the error that prevented publishing it
+    fn nack_action(
+        &self,
+        dp_id: &DataPlaneId,
+        deployment_id: &DeploymentId,
+        action_id: &ActionId,
+        error: &HeraldError,
+    ) -> impl Future<Output = Result<(), HeraldError>> + Send;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -1,8 +1,11 @@
 use crate::domain::entities::dataplane::DataPlaneId;
 use crate::domain::entities::deployment::DeploymentId;
+use crate::domain::entities::shard::ShardConfig;
+use crate::domain::entities::sync_report::SyncReport;
 use crate::domain::error::HeraldError;
 use crate::domain::ports::{ControlPlaneRepository, HeraldService, MessageBusRepository};
 use std::sync::Arc;
+use tracing::warn;
 
 pub struct HeraldServiceImpl<CP, MB>
 where
@@ -40,32 +43,95 @@ where
             .await?;
 
         for deployment in deployments {
-            let actions = self
-                .control_plane
-                .claim_actions(&self.dataplane_id, &deployment.id)
-                .await?;
-
-            for action in actions {
-                let event = action.into();
-                self.message_bus.publish(event).await?;
-            }
+            self.publish_claimed_actions(&deployment.id).await?;
         }
 
         Ok(())
     }
 
     async fn process_deployment(&self, deployment_id: &DeploymentId) -> Result<(), HeraldError> {
+        self.publish_claimed_actions(deployment_id).await?;
+
+        Ok(())
+    }
+
+    async fn sync_shard(&self, shard: &ShardConfig) -> Result<SyncReport, HeraldError> {
+        let deployments = self
+            .control_plane
+            .list_deployments(&self.dataplane_id)
+            .await?;
+
+        let mut report = SyncReport {
+            deployments_listed: deployments.len(),
+            ..Default::default()
+        };
+
+        for deployment in deployments
+            .iter()
+            .filter(|deployment| shard.owns_deployment(&deployment.id))
+        {
+            report.deployments_owned += 1;
+
+            match self.publish_claimed_actions(&deployment.id).await {
+                Ok(published) => report.actions_published += published,
+                Err(e) => {
+                    warn!(deployment_id = %deployment.id, error = %e, "failed to process deployment");
+                    report.deployments_failed += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+impl<CP, MB> HeraldServiceImpl<CP, MB>
+where
+    CP: ControlPlaneRepository,
+    MB: MessageBusRepository,
+{
+    /// Claims the pending actions of a deployment and publishes them, returning how many were published.
+    ///
+    /// Each action is acked once published so the control plane does not hand it out again when
+    /// its lease expires. An action the bus rejects for good is nacked with the error; on a
+    /// transient failure it stays claimed, and the control plane hands it out again once its
+    /// lease expires.
+    async fn publish_claimed_actions(
+        &self,
+        deployment_id: &DeploymentId,
+    ) -> Result<usize, HeraldError> {
         let actions = self
             .control_plane
             .claim_actions(&self.dataplane_id, deployment_id)
             .await?;
 
+        let count = actions.len();
         for action in actions {
-            let event = action.into();
-            self.message_bus.publish(event).await?;
+            let action_id = action.id;
+
+            if let Err(error) = self.message_bus.publish(action.into()).await {
+                if error.is_retryable() {
+                    warn!(%action_id, %error, "failed to publish action, leaving it to its lease");
+                    return Err(error);
+                }
+
+                if let Err(nack_error) = self
+                    .control_plane
+                    .nack_action(&self.dataplane_id, deployment_id, &action_id, &error)
+                    .await
+                {
+                    warn!(%action_id, error = %nack_error, "failed to nack action");
+                }
+
+                return Err(error);
+            }
+
+            self.control_plane
+                .ack_action(&self.dataplane_id, deployment_id, &action_id)
+                .await?;
         }
 
-        Ok(())
+        Ok(count)
     }
 }
 
@@ -177,6 +243,11 @@ mod tests {
                 Box::pin(async move { Ok(vec![(*a3).clone()]) })
             });
 
+        mock_control_plane
+            .expect_ack_action()
+            .times(3)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
         let mut mock_message_bus = MockMessageBusRepository::new();
         mock_message_bus
             .expect_publish()
@@ -277,6 +348,9 @@ mod tests {
                 Box::pin(async move { Ok(vec![(*a).clone()]) })
             });
 
+        mock_control_plane.expect_ack_action().never();
+        mock_control_plane.expect_nack_action().never();
+
         let mut mock_message_bus = MockMessageBusRepository::new();
         mock_message_bus.expect_publish().times(1).returning(|_| {
             Box::pin(async {
@@ -322,6 +396,15 @@ mod tests {
                 Box::pin(async move { Ok(vec![(*a1).clone(), (*a2).clone()]) })
             });
 
+        let acked = [action1.id, action2.id];
+        mock_control_plane
+            .expect_ack_action()
+            .times(2)
+            .withf(move |dp_id, dep_id, action_id| {
+                dp_id.0 == "test-dataplane" && dep_id.0 == "dep-1" && acked.contains(action_id)
+            })
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
         let mut mock_message_bus = MockMessageBusRepository::new();
         mock_message_bus
             .expect_publish()
@@ -417,6 +500,9 @@ mod tests {
                 Box::pin(async move { Ok(vec![(*a).clone()]) })
             });
 
+        mock_control_plane.expect_ack_action().never();
+        mock_control_plane.expect_nack_action().never();
+
         let mut mock_message_bus = MockMessageBusRepository::new();
         mock_message_bus.expect_publish().times(1).returning(|_| {
             Box::pin(async {
@@ -443,6 +529,165 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_process_deployment_nacks_unpublishable_action() {
+        // Arrange
+        let deployment_id = DeploymentId::new("dep-1");
+        let action = Arc::new(create_test_action("dep-1", "ferriskey", "create"));
+
+        let mut mock_control_plane = MockControlPlaneRepository::new();
+        let a = action.clone();
+        mock_control_plane
+            .expect_claim_actions()
+            .times(1)
+            .returning(move |_, _| {
+                let a = a.clone();
+                Box::pin(async move { Ok(vec![(*a).clone()]) })
+            });
+
+        let action_id = action.id;
+        mock_control_plane.expect_ack_action().never();
+        mock_control_plane
+            .expect_nack_action()
+            .times(1)
+            .withf(move |_, _, id, error| {
+                *id == action_id && matches!(error, HeraldError::InvalidAction { .. })
+            })
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+
+        let mut mock_message_bus = MockMessageBusRepository::new();
+        mock_message_bus.expect_publish().times(1).returning(|_| {
+            Box::pin(async {
+                Err(HeraldError::InvalidAction {
+                    message: "payload is not serializable".to_string(),
+                })
+            })
+        });
+
+        let service = HeraldServiceTestBuilder::new()
+            .with_control_plane(mock_control_plane)
+            .with_message_bus(mock_message_bus)
+            .build();
+
+        // Act
+        let result = service.process_deployment(&deployment_id).await;
+
+        // Assert
+        assert!(matches!(result, Err(HeraldError::InvalidAction { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_sync_shard_counts_failures_and_continues() {
+        // Arrange
+        let mut mock_control_plane = MockControlPlaneRepository::new();
+        mock_control_plane
+            .expect_list_deployments()
+            .times(1)
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![
+                        create_test_deployment("dep-1", "deployment-one"),
+                        create_test_deployment("dep-2", "deployment-two"),
+                    ])
+                })
+            });
+
+        mock_control_plane
+            .expect_claim_actions()
+            .withf(|_, dep_id| dep_id.0 == "dep-1")
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(async {
+                    Err(HeraldError::ControlPlane {
+                        message: "Cannot claim actions".to_string(),
+                    })
+                })
+            });
+
+        mock_control_plane
+            .expect_claim_actions()
+            .withf(|_, dep_id| dep_id.0 == "dep-2")
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![
+                        create_test_action("dep-2", "postgres", "create"),
+                        create_test_action("dep-2", "postgres", "update"),
+                    ])
+                })
+            });
+
+        mock_control_plane
+            .expect_ack_action()
+            .times(2)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let mut mock_message_bus = MockMessageBusRepository::new();
+        mock_message_bus
+            .expect_publish()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let service = HeraldServiceTestBuilder::new()
+            .with_control_plane(mock_control_plane)
+            .with_message_bus(mock_message_bus)
+            .build();
+
+        // Act
+        let report = service.sync_shard(&ShardConfig::new(0, 1)).await.unwrap();
+
+        // Assert
+        assert_eq!(
+            report,
+            SyncReport {
+                deployments_listed: 2,
+                deployments_owned: 2,
+                actions_published: 2,
+                deployments_failed: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_shard_only_processes_owned_deployments() {
+        // Arrange
+        let deployments: Vec<_> = (0..8)
+            .map(|i| create_test_deployment(&format!("dep-{i}"), "deployment"))
+            .collect();
+        let shard = ShardConfig::new(1, 2);
+        let owned = deployments
+            .iter()
+            .filter(|deployment| shard.owns_deployment(&deployment.id))
+            .count();
+
+        let mut mock_control_plane = MockControlPlaneRepository::new();
+        mock_control_plane
+            .expect_list_deployments()
+            .times(1)
+            .returning(move |_| {
+                let deployments = deployments.clone();
+                Box::pin(async move { Ok(deployments) })
+            });
+
+        mock_control_plane
+            .expect_claim_actions()
+            .withf(move |_, dep_id| shard.owns_deployment(dep_id))
+            .times(owned)
+            .returning(|_, _| Box::pin(async { Ok(vec![]) }));
+
+        let service = HeraldServiceTestBuilder::new()
+            .with_control_plane(mock_control_plane)
+            .build();
+
+        // Act
+        let report = service.sync_shard(&shard).await.unwrap();
+
+        // Assert
+        assert_eq!(report.deployments_listed, 8);
+        assert_eq!(report.deployments_owned, owned);
+        assert_eq!(report.deployments_failed, 0);
+    }
+
     #[test]
     fn test_herald_service_impl_creation() {
         let _service = HeraldServiceTestBuilder::new()
@@ -1,24 +1,162 @@
-use crate::domain::{
-    entities::{
-        action::Action,
-        dataplane::DataPlaneId,
-        deployment::{Deployment, DeploymentId},
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use tracing::warn;
+
+use crate::{
+    domain::{
+        entities::{
+            action::{Action, ActionId},
+            dataplane::DataPlaneId,
+            deployment::{Deployment, DeploymentId},
+        },
+        error::HeraldError,
+        ports::ControlPlaneRepository,
+    },
+    infrastructure::control_plane::{
+        dto::{
+            ClaimActionsRequest, ClaimActionsResponse, ListDeploymentsResponse, NackActionRequest,
+        },
+        retry::{AttemptError, RetryPolicy},
+        service_account::{ServiceAccountCredentials, ServiceAccountTokenProvider},
     },
-    error::HeraldError,
-    ports::ControlPlaneRepository,
 };
 
+const DEFAULT_PAGE_SIZE: usize = 100;
+const DEFAULT_CLAIM_MAX: usize = 50;
+const DEFAULT_LEASE_SECONDS: u64 = 30;
+
 /// HTTP client implementation for communicating with the control plane API
 pub struct HttpControlPlaneRepository {
-    // TODO: Add reqwest::Client field
-    // TODO: Add base_url field
+    http: Client,
+    base_url: String,
+    service_account: Option<ServiceAccountTokenProvider>,
+    retry_policy: RetryPolicy,
+    page_size: usize,
+    claim_max: usize,
+    lease_seconds: u64,
 }
 
 impl HttpControlPlaneRepository {
     /// Creates a new instance of the HTTP control plane repository
     pub fn new(base_url: impl Into<String>) -> Self {
-        let base_url = base_url.into();
-        todo!("Initialize the HTTP client and store the {base_url}")
+        Self {
+            http: Client::new(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            service_account: None,
+            retry_policy: RetryPolicy::default(),
+            page_size: DEFAULT_PAGE_SIZE,
+            claim_max: DEFAULT_CLAIM_MAX,
+            lease_seconds: DEFAULT_LEASE_SECONDS,
+        }
+    }
+
+    /// Authenticates every request with a service account token
+    pub fn with_service_account(mut self, credentials: ServiceAccountCredentials) -> Self {
+        self.service_account = Some(ServiceAccountTokenProvider::new(credentials));
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Number of deployments requested per page when listing deployments
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Maximum number of actions claimed per call and how long they stay leased
+    pub fn with_claim_options(mut self, max: usize, lease_seconds: u64) -> Self {
+        self.claim_max = max;
+        self.lease_seconds = lease_seconds;
+        self
+    }
+
+    /// Sends the request built by `build`, retrying transient failures with backoff
+    async fn send<T, F>(&self, operation: &str, build: F) -> Result<T, HeraldError>
+    where
+        T: DeserializeOwned,
+        F: Fn(&Client) -> RequestBuilder,
+    {
+        let mut attempt = 1;
+
+        loop {
+            let error = match self.attempt(operation, &build).await {
+                Ok(value) => return Ok(value),
+                Err(AttemptError::Fatal(error)) => return Err(error),
+                Err(AttemptError::Retryable(error)) => error,
+            };
+
+            if attempt >= self.retry_policy.max_attempts {
+                return Err(error);
+            }
+
+            let backoff = self.retry_policy.backoff(attempt);
+            warn!(
+                operation,
+                attempt,
+                backoff_ms = backoff.as_millis() as u64,
+                error = %error,
+                "control plane request failed, retrying"
+            );
+
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    async fn attempt<T, F>(&self, operation: &str, build: &F) -> Result<T, AttemptError>
+    where
+        T: DeserializeOwned,
+        F: Fn(&Client) -> RequestBuilder,
+    {
+        let mut request = build(&self.http);
+
+        if let Some(service_account) = &self.service_account {
+            let token = service_account.access_token(&self.http).await?;
+            request = request.bearer_auth(token);
+        }
+
+        let response = request.send().await.map_err(|e| {
+            AttemptError::Retryable(HeraldError::ControlPlane {
+                message: format!("{operation} failed: {e}"),
+            })
+        })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error = HeraldError::ControlPlane {
+                message: format!("{operation} failed with status {status}"),
+            };
+
+            // A rejected token may simply have been revoked or rotated: drop it and try again
+            if status == StatusCode::UNAUTHORIZED
+                && let Some(service_account) = &self.service_account
+            {
+                service_account.invalidate().await;
+                return Err(AttemptError::Retryable(error));
+            }
+
+            return if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+                Err(AttemptError::Retryable(error))
+            } else {
+                Err(AttemptError::Fatal(error))
+            };
+        }
+
+        let bytes = response.bytes().await.map_err(|e| {
+            AttemptError::Retryable(HeraldError::ControlPlane {
+                message: format!("{operation} failed to read response: {e}"),
+            })
+        })?;
+
+        serde_json::from_slice(&bytes).map_err(|e| {
+            AttemptError::Fatal(HeraldError::ControlPlane {
+                message: format!("{operation} returned an invalid response: {e}"),
+            })
+        })
     }
 }
 
@@ -27,7 +165,32 @@ impl ControlPlaneRepository for HttpControlPlaneRepository {
         &self,
         dataplane_id: &DataPlaneId,
     ) -> Result<Vec<Deployment>, HeraldError> {
-        todo!("Implement GET /dataplanes/{dataplane_id}/deployments")
+        let url = format!("{}/dataplanes/{dataplane_id}/deployments", self.base_url);
+        let limit = self.page_size.to_string();
+
+        let mut deployments = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let page: ListDeploymentsResponse = self
+                .send("list deployments", |http| {
+                    let mut query = vec![("limit", limit.as_str())];
+                    if let Some(cursor) = cursor.as_deref() {
+                        query.push(("cursor", cursor));
+                    }
+                    http.get(&url).query(&query)
+                })
+                .await?;
+
+            deployments.extend(page.data.into_iter().map(Deployment::from));
+
+            match page.next_cursor {
+                Some(next) if cursor.as_deref() != Some(next.as_str()) => cursor = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(deployments)
     }
 
     async fn claim_actions(
@@ -35,6 +198,377 @@ impl ControlPlaneRepository for HttpControlPlaneRepository {
         dataplane_id: &DataPlaneId,
         deployment_id: &DeploymentId,
     ) -> Result<Vec<Action>, HeraldError> {
-        todo!("Implement POST /dataplanes/{dataplane_id}/deployments/{deployment_id}/actions:claim")
+        let url = format!(
+            "{}/dataplanes/{dataplane_id}/deployments/{deployment_id}/actions:claim",
+            self.base_url
+        );
+        let body = ClaimActionsRequest {
+            max: self.claim_max,
+            lease_seconds: self.lease_seconds,
+        };
+
+        let response: ClaimActionsResponse = self
+            .send("claim actions", |http| http.post(&url).json(&body))
+            .await?;
+
+        response.data.into_iter().map(Action::try_from).collect()
+    }
+
+    async fn ack_action(
+        &self,
+        dataplane_id: &DataPlaneId,
+        deployment_id: &DeploymentId,
+        action_id: &ActionId,
+    ) -> Result<(), HeraldError> {
+        let url = format!(
+            "{}/dataplanes/{dataplane_id}/deployments/{deployment_id}/actions/{action_id}:ack",
+            self.base_url
+        );
+
+        let _: IgnoredAny = self.send("ack action", |http| http.post(&url)).await?;
+
+        Ok(())
+    }
+
+    async fn nack_action(
+        &self,
+        dataplane_id: &DataPlaneId,
+        deployment_id: &DeploymentId,
+        action_id: &ActionId,
+        error: &HeraldError,
+    ) -> Result<(), HeraldError> {
+        let url = format!(
+            "{}/dataplanes/{dataplane_id}/deployments/{deployment_id}/actions/{action_id}:nack",
+            self.base_url
+        );
+        let body = NackActionRequest {
+            reason: error.into(),
+        };
+
+        let _: IgnoredAny = self
+            .send("nack action", |http| http.post(&url).json(&body))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use super::*;
+
+    fn repository(server: &MockServer) -> HttpControlPlaneRepository {
+        HttpControlPlaneRepository::new(server.base_url()).with_retry_policy(RetryPolicy::new(
+            3,
+            Duration::from_millis(1),
+            Duration::from_millis(5),
+        ))
+    }
+
+    fn deployment_json(id: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "organisation_id": "9b7d9a4e-2c4b-4f0e-8f3c-5b1e0d1f2a3b",
+            "dataplane_id": "dp-1",
+            "name": format!("app-{id}"),
+            "kind": "ferriskey",
+            "version": "1.0.0",
+            "status": "running",
+            "namespace": "default",
+        })
+    }
+
+    fn action_json(action_type: &str) -> serde_json::Value {
+        json!({
+            "id": "7f0c4c1e-4b7a-4b6f-9a53-0c56f4d1c0de",
+            "deployment_id": "dep-1",
+            "dataplane_id": "dp-1",
+            "action_type": action_type,
+            "target": { "kind": "Deployment", "id": "dep-1" },
+            "payload": { "data": { "replicas": 2 } },
+            "version": 1,
+            "status": { "Leased": { "until": "2026-01-01T00:00:00Z" } },
+            "metadata": {
+                "source": "System",
+                "created_at": "2026-01-01T00:00:00Z",
+                "constraints": { "not_after": null, "priority": null }
+            },
+            "leased_until": "2026-01-01T00:00:00Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn list_deployments_follows_next_cursor() {
+        let server = MockServer::start();
+        let first_page = server.mock(|when, then| {
+            when.method(GET)
+                .path("/dataplanes/dp-1/deployments")
+                .query_param("limit", "2")
+                .matches(|req| {
+                    !req.query_params
+                        .as_ref()
+                        .is_some_and(|params| params.iter().any(|(key, _)| key == "cursor"))
+                });
+            then.status(200).json_body(json!({
+                "data": [deployment_json("dep-1"), deployment_json("dep-2")],
+                "next_cursor": "dep-2"
+            }));
+        });
+        let second_page = server.mock(|when, then| {
+            when.method(GET)
+                .path("/dataplanes/dp-1/deployments")
+                .query_param("limit", "2")
+                .query_param("cursor", "dep-2");
+            then.status(200).json_body(json!({
+                "data": [deployment_json("dep-3")],
+                "next_cursor": null
+            }));
+        });
+
+        let deployments = repository(&server)
+            .with_page_size(2)
+            .list_deployments(&DataPlaneId::new("dp-1"))
+            .await
+            .unwrap();
+
+        first_page.assert();
+        second_page.assert();
+        let ids: Vec<_> = deployments.iter().map(|d| d.id.0.as_str()).collect();
+        assert_eq!(ids, vec!["dep-1", "dep-2", "dep-3"]);
+        assert_eq!(deployments[0].dataplane_id, DataPlaneId::new("dp-1"));
+    }
+
+    #[tokio::test]
+    async fn claim_actions_posts_options_and_maps_actions() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/dataplanes/dp-1/deployments/dep-1/actions:claim")
+                .json_body(json!({ "max": 10, "lease_seconds": 60 }));
+            then.status(200)
+                .json_body(json!({ "data": [action_json("deployment.create")] }));
+        });
+
+        let actions = repository(&server)
+            .with_claim_options(10, 60)
+            .claim_actions(&DataPlaneId::new("dp-1"), &DeploymentId::new("dep-1"))
+            .await
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].resource, "deployment");
+        assert_eq!(actions[0].kind, "create");
+        assert_eq!(actions[0].payload, json!({ "replicas": 2 }));
+    }
+
+    #[tokio::test]
+    async fn ack_action_posts_to_ack_endpoint() {
+        let server = MockServer::start();
+        let action_id = ActionId(uuid::Uuid::new_v4());
+        let mock = server.mock(|when, then| {
+            when.method(POST).path(format!(
+                "/dataplanes/dp-1/deployments/dep-1/actions/{action_id}:ack"
+            ));
+            then.status(200)
+                .json_body(json!({ "data": action_json("deployment.create") }));
+        });
+
+        repository(&server)
+            .ack_action(
+                &DataPlaneId::new("dp-1"),
+                &DeploymentId::new("dep-1"),
+                &action_id,
+            )
+            .await
+            .unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn nack_action_reports_publish_failure() {
+        let server = MockServer::start();
+        let action_id = ActionId(uuid::Uuid::new_v4());
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path(format!(
+                    "/dataplanes/dp-1/deployments/dep-1/actions/{action_id}:nack"
+                ))
+                .json_body(json!({ "reason": "PublishFailed" }));
+            then.status(200)
+                .json_body(json!({ "data": action_json("deployment.create") }));
+        });
+
+        repository(&server)
+            .nack_action(
+                &DataPlaneId::new("dp-1"),
+                &DeploymentId::new("dep-1"),
+                &action_id,
+                &HeraldError::MessageBus {
+                    message: "channel closed".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors_until_attempts_are_exhausted() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/dataplanes/dp-1/deployments");
+            then.status(503);
+        });
+
+        let result = repository(&server)
+            .list_deployments(&DataPlaneId::new("dp-1"))
+            .await;
+
+        mock.assert_hits(3);
+        assert!(matches!(result, Err(HeraldError::ControlPlane { .. })));
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/dataplanes/dp-1/deployments/dep-1/actions:claim");
+            then.status(403);
+        });
+
+        let result = repository(&server)
+            .claim_actions(&DataPlaneId::new("dp-1"), &DeploymentId::new("dep-1"))
+            .await;
+
+        mock.assert_hits(1);
+        assert!(matches!(result, Err(HeraldError::ControlPlane { .. })));
+    }
+
+    #[tokio::test]
+    async fn invalid_response_body_is_a_control_plane_error() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/dataplanes/dp-1/deployments");
+            then.status(200).body("not json");
+        });
+
+        let result = repository(&server)
+            .list_deployments(&DataPlaneId::new("dp-1"))
+            .await;
+
+        assert!(matches!(result, Err(HeraldError::ControlPlane { .. })));
+    }
+
+    #[tokio::test]
+    async fn malformed_action_type_is_rejected() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/dataplanes/dp-1/deployments/dep-1/actions:claim");
+            then.status(200)
+                .json_body(json!({ "data": [action_json("create")] }));
+        });
+
+        let result = repository(&server)
+            .claim_actions(&DataPlaneId::new("dp-1"), &DeploymentId::new("dep-1"))
+            .await;
+
+        assert!(matches!(result, Err(HeraldError::InvalidAction { .. })));
+    }
+
+    #[tokio::test]
+    async fn authenticates_with_cached_service_account_token() {
+        let server = MockServer::start();
+        let token = server.mock(|when, then| {
+            when.method(POST)
+                .path("/token")
+                .body_contains("grant_type=client_credentials")
+                .body_contains("client_id=herald-service");
+            then.status(200)
+                .json_body(json!({ "access_token": "secret-token", "expires_in": 300 }));
+        });
+        let deployments = server.mock(|when, then| {
+            when.method(GET)
+                .path("/dataplanes/dp-1/deployments")
+                .header("authorization", "Bearer secret-token");
+            then.status(200)
+                .json_body(json!({ "data": [], "next_cursor": null }));
+        });
+
+        let repository = repository(&server).with_service_account(ServiceAccountCredentials::new(
+            server.url("/token"),
+            "herald-service",
+            "s3cr3t",
+        ));
+
+        let dataplane_id = DataPlaneId::new("dp-1");
+        repository.list_deployments(&dataplane_id).await.unwrap();
+        repository.list_deployments(&dataplane_id).await.unwrap();
+
+        token.assert_hits(1);
+        deployments.assert_hits(2);
+    }
+
+    #[tokio::test]
+    async fn rejected_service_account_credentials_are_not_retried() {
+        let server = MockServer::start();
+        let token = server.mock(|when, then| {
+            when.method(POST).path("/token");
+            then.status(401);
+        });
+        let deployments = server.mock(|when, then| {
+            when.method(GET).path("/dataplanes/dp-1/deployments");
+            then.status(200)
+                .json_body(json!({ "data": [], "next_cursor": null }));
+        });
+
+        let result = repository(&server)
+            .with_service_account(ServiceAccountCredentials::new(
+                server.url("/token"),
+                "herald-service",
+                "wrong",
+            ))
+            .list_deployments(&DataPlaneId::new("dp-1"))
+            .await;
+
+        token.assert_hits(1);
+        deployments.assert_hits(0);
+        assert!(matches!(result, Err(HeraldError::ControlPlane { .. })));
+    }
+
+    #[tokio::test]
+    async fn unauthorized_response_refreshes_the_token() {
+        let server = MockServer::start();
+        let token = server.mock(|when, then| {
+            when.method(POST).path("/token");
+            then.status(200)
+                .json_body(json!({ "access_token": "expired-token", "expires_in": 300 }));
+        });
+        let deployments = server.mock(|when, then| {
+            when.method(GET).path("/dataplanes/dp-1/deployments");
+            then.status(401);
+        });
+
+        let result = repository(&server)
+            .with_service_account(ServiceAccountCredentials::new(
+                server.url("/token"),
+                "herald-service",
+                "s3cr3t",
+            ))
+            .list_deployments(&DataPlaneId::new("dp-1"))
+            .await;
+
+        token.assert_hits(3);
+        deployments.assert_hits(3);
+        assert!(matches!(result, Err(HeraldError::ControlPlane { .. })));
     }
 }
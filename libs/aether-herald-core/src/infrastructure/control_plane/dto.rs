@@ -0,0 +1,177 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        action::{Action, ActionId},
+        dataplane::DataPlaneId,
+        deployment::{Deployment, DeploymentId},
+    },
+    error::HeraldError,
+};
+
+/// Page returned by `GET /dataplanes/{id}/deployments`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListDeploymentsResponse {
+    pub data: Vec<DeploymentDto>,
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeploymentDto {
+    pub id: String,
+    pub dataplane_id: String,
+    pub name: String,
+}
+
+impl From<DeploymentDto> for Deployment {
+    fn from(dto: DeploymentDto) -> Self {
+        Deployment {
+            id: DeploymentId::new(dto.id),
+            dataplane_id: DataPlaneId::new(dto.dataplane_id),
+            name: dto.name,
+        }
+    }
+}
+
+/// Body sent to `POST /dataplanes/{id}/deployments/{deployment_id}/actions:claim`
+#[derive(Debug, Clone, Serialize)]
+pub struct ClaimActionsRequest {
+    pub max: usize,
+    pub lease_seconds: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClaimActionsResponse {
+    pub data: Vec<ActionDto>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionDto {
+    pub id: Uuid,
+    pub deployment_id: String,
+    pub action_type: String,
+    pub payload: ActionPayloadDto,
+    pub metadata: ActionMetadataDto,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionPayloadDto {
+    pub data: Value,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionMetadataDto {
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<ActionDto> for Action {
+    type Error = HeraldError;
+
+    fn try_from(dto: ActionDto) -> Result<Self, Self::Error> {
+        let (resource, kind) =
+            dto.action_type
+                .split_once('.')
+                .ok_or_else(|| HeraldError::InvalidAction {
+                    message: format!(
+                        "action {} has malformed type '{}', expected '<resource>.<kind>'",
+                        dto.id, dto.action_type
+                    ),
+                })?;
+
+        Ok(Action {
+            id: ActionId(dto.id),
+            deployment_id: DeploymentId::new(dto.deployment_id),
+            resource: resource.to_string(),
+            kind: kind.to_string(),
+            payload: dto.payload.data,
+            occurred_at: dto.metadata.created_at,
+        })
+    }
+}
+
+/// Body sent to `POST /dataplanes/{id}/deployments/{deployment_id}/actions/{action_id}:nack`
+#[derive(Debug, Clone, Serialize)]
+pub struct NackActionRequest {
+    pub reason: FailureReasonDto,
+}
+
+/// Mirrors the control plane's `ActionFailureReason`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum FailureReasonDto {
+    InvalidPayload,
+    PublishFailed,
+    InternalError(String),
+}
+
+impl From<&HeraldError> for FailureReasonDto {
+    fn from(error: &HeraldError) -> Self {
+        match error {
+            HeraldError::InvalidAction { .. } => FailureReasonDto::InvalidPayload,
+            HeraldError::MessageBus { .. } => FailureReasonDto::PublishFailed,
+            other => FailureReasonDto::InternalError(other.to_string()),
+        }
+    }
+}
+
+/// Response of an OAuth2 `client_credentials` token request
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    #[serde(default)]
+    pub expires_in: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn action_dto_splits_action_type() {
+        let dto: ActionDto = serde_json::from_value(json!({
+            "id": "7f0c4c1e-4b7a-4b6f-9a53-0c56f4d1c0de",
+            "deployment_id": "dep-1",
+            "dataplane_id": "dp-1",
+            "action_type": "deployment.create",
+            "target": { "kind": "Deployment", "id": "7f0c4c1e-4b7a-4b6f-9a53-0c56f4d1c0de" },
+            "payload": { "data": { "name": "app" } },
+            "version": 1,
+            "status": { "Leased": { "until": "2026-01-01T00:00:00Z" } },
+            "metadata": {
+                "source": "System",
+                "created_at": "2026-01-01T00:00:00Z",
+                "constraints": { "not_after": null, "priority": null }
+            },
+            "leased_until": "2026-01-01T00:00:00Z"
+        }))
+        .unwrap();
+
+        let action = Action::try_from(dto).unwrap();
+
+        assert_eq!(action.resource, "deployment");
+        assert_eq!(action.kind, "create");
+        assert_eq!(action.deployment_id, DeploymentId::new("dep-1"));
+        assert_eq!(action.payload, json!({ "name": "app" }));
+    }
+
+    #[test]
+    fn action_dto_rejects_type_without_resource() {
+        let dto = ActionDto {
+            id: Uuid::new_v4(),
+            deployment_id: "dep-1".to_string(),
+            action_type: "create".to_string(),
+            payload: ActionPayloadDto { data: Value::Null },
+            metadata: ActionMetadataDto {
+                created_at: Utc::now(),
+            },
+        };
+
+        let result = Action::try_from(dto);
+
+        assert!(matches!(result, Err(HeraldError::InvalidAction { .. })));
+    }
+}
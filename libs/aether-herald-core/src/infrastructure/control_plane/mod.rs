@@ -1,2 +1,4 @@
 pub mod control_plane_repository;
 pub mod dto;
+pub mod retry;
+pub mod service_account;
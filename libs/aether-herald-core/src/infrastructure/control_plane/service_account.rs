@@ -0,0 +1,134 @@
+use std::time::{Duration, Instant};
+
+use reqwest::{Client, StatusCode};
+use tokio::sync::Mutex;
+
+use crate::{
+    domain::error::HeraldError,
+    infrastructure::control_plane::{dto::TokenResponse, retry::AttemptError},
+};
+
+/// Tokens are refreshed this long before the IdP says they expire.
+const EXPIRY_SKEW: Duration = Duration::from_secs(30);
+
+/// Lifetime assumed when the IdP does not return `expires_in`.
+const DEFAULT_TOKEN_TTL: Duration = Duration::from_secs(60);
+
+/// OAuth2 client credentials used by herald to authenticate against the control plane
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceAccountCredentials {
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+impl ServiceAccountCredentials {
+    pub fn new(
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            token_url: token_url.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+        }
+    }
+}
+
+struct CachedToken {
+    value: String,
+    refresh_at: Instant,
+}
+
+/// Fetches and caches access tokens using the `client_credentials` grant
+pub(crate) struct ServiceAccountTokenProvider {
+    credentials: ServiceAccountCredentials,
+    cached: Mutex<Option<CachedToken>>,
+}
+
+impl ServiceAccountTokenProvider {
+    pub(crate) fn new(credentials: ServiceAccountCredentials) -> Self {
+        Self {
+            credentials,
+            cached: Mutex::new(None),
+        }
+    }
+
+    pub(crate) async fn access_token(&self, http: &Client) -> Result<String, AttemptError> {
+        let mut cached = self.cached.lock().await;
+
+        if let Some(token) = cached.as_ref()
+            && Instant::now() < token.refresh_at
+        {
+            return Ok(token.value.clone());
+        }
+
+        let token = self.fetch(http).await?;
+        let value = token.value.clone();
+        *cached = Some(token);
+
+        Ok(value)
+    }
+
+    /// Drops the cached token so the next request fetches a fresh one
+    pub(crate) async fn invalidate(&self) {
+        *self.cached.lock().await = None;
+    }
+
+    async fn fetch(&self, http: &Client) -> Result<CachedToken, AttemptError> {
+        let response = http
+            .post(&self.credentials.token_url)
+            .form(&[
+                ("grant_type", "client_credentials"),
+                ("client_id", self.credentials.client_id.as_str()),
+                ("client_secret", self.credentials.client_secret.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| {
+                AttemptError::Retryable(HeraldError::ControlPlane {
+                    message: format!("failed to request service account token: {e}"),
+                })
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error = HeraldError::ControlPlane {
+                message: format!("failed to request service account token: {status}"),
+            };
+
+            // Bad credentials or a misconfigured client will not fix themselves
+            return if matches!(
+                status,
+                StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+            ) {
+                Err(AttemptError::Fatal(error))
+            } else {
+                Err(AttemptError::Retryable(error))
+            };
+        }
+
+        let bytes = response.bytes().await.map_err(|e| {
+            AttemptError::Retryable(HeraldError::ControlPlane {
+                message: format!("failed to read service account token response: {e}"),
+            })
+        })?;
+
+        let token: TokenResponse = serde_json::from_slice(&bytes).map_err(|e| {
+            AttemptError::Retryable(HeraldError::ControlPlane {
+                message: format!("invalid service account token response: {e}"),
+            })
+        })?;
+
+        let ttl = token
+            .expires_in
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_TOKEN_TTL);
+
+        Ok(CachedToken {
+            value: token.access_token,
+            refresh_at: Instant::now() + ttl.saturating_sub(EXPIRY_SKEW),
+        })
+    }
+}
@@ -0,0 +1,203 @@
+use lapin::{
+    BasicProperties, Channel, Connection, ConnectionProperties, ExchangeKind,
+    options::{BasicPublishOptions, ConfirmSelectOptions, ExchangeDeclareOptions},
+    publisher_confirm::Confirmation,
+    types::{FieldTable, ShortString},
+};
+use tokio::sync::Mutex;
+use tracing::{debug, info};
+
+use crate::domain::{
+    entities::action::ActionEvent, error::HeraldError, ports::MessageBusRepository,
+};
+
+/// AMQP 0-9-1 `delivery_mode` value asking the broker to persist the message to disk
+const PERSISTENT_DELIVERY_MODE: u8 = 2;
+
+/// RabbitMQ implementation of the message bus.
+///
+/// Events are published on a durable topic exchange using `<resource>.<kind>` as the
+/// routing key. The channel runs in confirm mode and every message is published as
+/// mandatory, so `publish` only returns once the broker has routed the message to at
+/// least one queue and taken responsibility for it.
+pub struct RabbitMqMessageBusRepository {
+    amqp_url: String,
+    exchange: String,
+    channel: Mutex<Option<Channel>>,
+}
+
+impl RabbitMqMessageBusRepository {
+    /// Connects to the broker and declares the exchange
+    pub async fn connect(
+        amqp_url: impl Into<String>,
+        exchange: impl Into<String>,
+    ) -> Result<Self, HeraldError> {
+        let repository = Self {
+            amqp_url: amqp_url.into(),
+            exchange: exchange.into(),
+            channel: Mutex::new(None),
+        };
+
+        let channel = repository.open_channel().await?;
+        *repository.channel.lock().await = Some(channel);
+
+        Ok(repository)
+    }
+
+    async fn open_channel(&self) -> Result<Channel, HeraldError> {
+        let conn = Connection::connect(&self.amqp_url, ConnectionProperties::default())
+            .await
+            .map_err(|e| HeraldError::MessageBus {
+                message: format!("failed to connect to RabbitMQ: {e}"),
+            })?;
+
+        let channel = conn
+            .create_channel()
+            .await
+            .map_err(|e| HeraldError::MessageBus {
+                message: format!("failed to create channel: {e}"),
+            })?;
+
+        channel
+            .confirm_select(ConfirmSelectOptions::default())
+            .await
+            .map_err(|e| HeraldError::MessageBus {
+                message: format!("failed to enable publisher confirms: {e}"),
+            })?;
+
+        channel
+            .exchange_declare(
+                &self.exchange,
+                ExchangeKind::Topic,
+                ExchangeDeclareOptions {
+                    durable: true,
+                    ..Default::default()
+                },
+                FieldTable::default(),
+            )
+            .await
+            .map_err(|e| HeraldError::MessageBus {
+                message: format!("failed to declare exchange '{}': {e}", self.exchange),
+            })?;
+
+        info!(exchange = %self.exchange, "connected to RabbitMQ");
+
+        Ok(channel)
+    }
+
+    /// Returns the current channel, reopening it if the broker closed it
+    async fn channel(&self) -> Result<Channel, HeraldError> {
+        let mut guard = self.channel.lock().await;
+
+        if let Some(channel) = guard.as_ref()
+            && channel.status().connected()
+        {
+            return Ok(channel.clone());
+        }
+
+        let channel = self.open_channel().await?;
+        *guard = Some(channel.clone());
+
+        Ok(channel)
+    }
+}
+
+/// Builds the AMQP properties for an event; the action id doubles as message id so
+/// consumers can deduplicate redeliveries.
+fn event_properties(event: &ActionEvent) -> BasicProperties {
+    BasicProperties::default()
+        .with_message_id(ShortString::from(event.action_id.to_string()))
+        .with_delivery_mode(PERSISTENT_DELIVERY_MODE)
+        .with_content_type(ShortString::from("application/json"))
+        .with_timestamp(event.timestamp.timestamp().max(0) as u64)
+}
+
+impl MessageBusRepository for RabbitMqMessageBusRepository {
+    async fn publish(&self, event: ActionEvent) -> Result<(), HeraldError> {
+        let payload = serde_json::to_vec(&event).map_err(|e| HeraldError::InvalidAction {
+            message: format!("failed to serialize event {}: {e}", event.action_id),
+        })?;
+
+        let channel = self.channel().await?;
+
+        let confirmation = channel
+            .basic_publish(
+                &self.exchange,
+                &event.routing_key,
+                BasicPublishOptions {
+                    mandatory: true,
+                    ..Default::default()
+                },
+                &payload,
+                event_properties(&event),
+            )
+            .await
+            .map_err(|e| HeraldError::MessageBus {
+                message: format!("failed to publish event {}: {e}", event.action_id),
+            })?
+            .await
+            .map_err(|e| HeraldError::MessageBus {
+                message: format!("failed to confirm event {}: {e}", event.action_id),
+            })?;
+
+        match confirmation {
+            Confirmation::Nack(_) => {
+                return Err(HeraldError::MessageBus {
+                    message: format!("broker rejected event {}", event.action_id),
+                });
+            }
+            // The broker returns mandatory messages it could not route before confirming them
+            Confirmation::Ack(Some(_)) => {
+                return Err(HeraldError::MessageBus {
+                    message: format!(
+                        "no queue is bound to '{}' for event {}",
+                        event.routing_key, event.action_id
+                    ),
+                });
+            }
+            Confirmation::Ack(None) | Confirmation::NotRequested => {}
+        }
+
+        debug!(
+            action_id = %event.action_id,
+            routing_key = %event.routing_key,
+            "published action event"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::entities::action::ActionId;
+
+    #[test]
+    fn event_properties_are_persistent_and_identified_by_action_id() {
+        let action_id = ActionId(Uuid::new_v4());
+        let event = ActionEvent {
+            action_id,
+            routing_key: "deployment.create".to_string(),
+            payload: json!({ "name": "app" }),
+            timestamp: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+        };
+
+        let properties = event_properties(&event);
+
+        assert_eq!(
+            properties.message_id().as_ref().map(|id| id.as_str()),
+            Some(action_id.to_string().as_str())
+        );
+        assert_eq!(*properties.delivery_mode(), Some(PERSISTENT_DELIVERY_MODE));
+        assert_eq!(
+            properties.content_type().as_ref().map(|ct| ct.as_str()),
+            Some("application/json")
+        );
+        assert_eq!(*properties.timestamp(), Some(1_767_225_600));
+    }
+}
@@ -74,6 +74,7 @@ mod tests {
                 },
                 ferriskey: None,
                 ingress: None,
+                suspended: None,
             },
             status,
         }
@@ -153,6 +153,7 @@ mod tests {
                 },
                 ferriskey: None,
                 ingress: None,
+                suspended: None,
             },
             status,
         }
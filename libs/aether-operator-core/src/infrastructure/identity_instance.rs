@@ -466,8 +466,7 @@ impl KeycloakProviderHandler {
             return Ok(false);
         };
 
-        // IdentityInstance currently drives a single replica deployment.
-        let desired_replicas = 1;
+        let desired_replicas = instance_replicas(instance);
         let generation = deployment.metadata.generation.unwrap_or_default();
         let observed_generation = deployment
             .status
@@ -1156,6 +1155,7 @@ impl FerriskeyProviderHandler {
             &db_host,
             &webapp_url,
             &allowed_origins,
+            instance_replicas(instance),
             owner_reference.clone(),
         )?;
         let api_service = build_ferriskey_service(
@@ -1172,6 +1172,7 @@ impl FerriskeyProviderHandler {
             &web_labels,
             &web_image,
             &api_base_url,
+            instance_replicas(instance),
             owner_reference.clone(),
         )?;
         let web_service =
@@ -1732,6 +1733,15 @@ fn ferriskey_allowed_origins(webapp_url: &str) -> String {
     }
 }
 
+/// Replicas the instance runs with: none while it is suspended, one otherwise.
+fn instance_replicas(instance: &IdentityInstance) -> i32 {
+    if instance.spec.suspended.unwrap_or(false) {
+        0
+    } else {
+        1
+    }
+}
+
 fn ingress_enabled(instance: &IdentityInstance) -> bool {
     instance
         .spec
@@ -2014,7 +2024,7 @@ fn build_keycloak_deployment(
             ..Default::default()
         },
         spec: Some(DeploymentSpec {
-            replicas: Some(1),
+            replicas: Some(instance_replicas(instance)),
             selector,
             template: PodTemplateSpec {
                 metadata: Some(ObjectMeta {
@@ -2209,6 +2219,7 @@ fn build_ferriskey_api_deployment(
     db_host: &str,
     webapp_url: &str,
     allowed_origins: &str,
+    replicas: i32,
     owner_reference: Option<OwnerReference>,
 ) -> Result<Deployment, OperatorError> {
     Ok(Deployment {
@@ -2220,7 +2231,7 @@ fn build_ferriskey_api_deployment(
             ..Default::default()
         },
         spec: Some(DeploymentSpec {
-            replicas: Some(1),
+            replicas: Some(replicas),
             selector: LabelSelector {
                 match_labels: Some(labels.clone()),
                 ..Default::default()
@@ -2399,6 +2410,7 @@ fn build_ferriskey_webapp_deployment(
     labels: &BTreeMap<String, String>,
     image: &str,
     api_base_url: &str,
+    replicas: i32,
     owner_reference: Option<OwnerReference>,
 ) -> Result<Deployment, OperatorError> {
     Ok(Deployment {
@@ -2410,7 +2422,7 @@ fn build_ferriskey_webapp_deployment(
             ..Default::default()
         },
         spec: Some(DeploymentSpec {
-            replicas: Some(1),
+            replicas: Some(replicas),
             selector: LabelSelector {
                 match_labels: Some(labels.clone()),
                 ..Default::default()
@@ -2639,6 +2651,7 @@ mod tests {
                 },
                 ferriskey: None,
                 ingress: None,
+                suspended: None,
             },
             status: None,
         }
@@ -2866,6 +2879,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_keycloak_deployment_scales_down_suspended_instances() {
+        let mut instance = instance();
+        let labels = keycloak_labels(&instance);
+        let replicas = |instance: &IdentityInstance| {
+            build_keycloak_deployment(
+                instance,
+                "instance-1",
+                "default",
+                &labels,
+                "instance-1-admin",
+                None,
+            )
+            .unwrap()
+            .spec
+            .and_then(|spec| spec.replicas)
+        };
+
+        assert_eq!(replicas(&instance), Some(1));
+
+        instance.spec.suspended = Some(true);
+        assert_eq!(replicas(&instance), Some(0));
+
+        instance.spec.suspended = Some(false);
+        assert_eq!(replicas(&instance), Some(1));
+    }
+
     #[test]
     fn ferriskey_webapp_url_uses_override_or_fallback() {
         let mut instance = instance();
@@ -527,27 +527,218 @@ impl ActionRepository for PostgresActionRepository<'_, '_> {
     async fn claim_pending(
         &self,
         deployment_id: DeploymentId,
+        dataplane_id: DataPlaneId,
+        agent_id: String,
         max: usize,
         lease_until: DateTime<Utc>,
     ) -> Result<Vec<Action>, CoreError> {
+        // UPDATE ... RETURNING has no order of its own, so the claimed rows are sorted again
         let rows = match &self.executor {
             PgExecutor::Pool(pool) => {
                 sqlx::query_as!(
                     ActionRow,
                     r#"
-                    WITH claimed AS (
-                        SELECT id
+                    WITH heads AS (
+                        SELECT DISTINCT ON (target_kind, target_id) id
                         FROM actions
                         WHERE deployment_id = $1
+                          AND dataplane_id = $6
                           AND status = 'pending'
-                        ORDER BY created_at ASC, id ASC
+                          AND (constraints_not_after IS NULL OR constraints_not_after > NOW())
+                        ORDER BY target_kind, target_id, created_at ASC, id ASC
+                    ),
+                    claimed AS (
+                        SELECT candidate.id,
+                               GREATEST(
+                                   COALESCE(candidate.constraints_priority, $4::SMALLINT)
+                                       - EXTRACT(EPOCH FROM (NOW() - candidate.created_at))::BIGINT / $5::BIGINT,
+                                   0
+                               ) AS claim_priority
+                        FROM actions candidate
+                        JOIN heads ON heads.id = candidate.id
+                        WHERE candidate.status = 'pending'
+                          AND NOT EXISTS (
+                            SELECT 1
+                            FROM actions in_flight
+                            WHERE in_flight.target_kind = candidate.target_kind
+                              AND in_flight.target_id = candidate.target_id
+                              AND in_flight.status IN ('leased', 'pulled')
+                        )
+                        ORDER BY claim_priority ASC,
+                                 candidate.created_at ASC,
+                                 candidate.id ASC
+                        LIMIT $2
+                        FOR UPDATE OF candidate SKIP LOCKED
+                    ),
+                    pulled AS (
+                        UPDATE actions
+                        SET status = 'pulled',
+                            status_at = NOW(),
+                            status_agent_id = $7,
+                            leased_until = $3,
+                            attempts = attempts + 1
+                        WHERE id IN (SELECT id FROM claimed)
+                        RETURNING *
+                    )
+                    SELECT pulled.id,
+                           pulled.deployment_id,
+                           pulled.dataplane_id,
+                           pulled.action_type,
+                           pulled.target_kind,
+                           pulled.target_id,
+                           pulled.payload,
+                           pulled.version,
+                           pulled.status,
+                           pulled.status_at,
+                           pulled.status_agent_id,
+                           pulled.status_reason,
+                           pulled.source_type,
+                           pulled.source_user_id,
+                           pulled.source_client_id,
+                           pulled.constraints_not_after,
+                           pulled.constraints_priority,
+                           pulled.created_at,
+                           pulled.leased_until
+                    FROM pulled
+                    JOIN claimed ON claimed.id = pulled.id
+                    ORDER BY claimed.claim_priority ASC,
+                             pulled.created_at ASC,
+                             pulled.id ASC
+                    "#,
+                    deployment_id.0,
+                    max as i64,
+                    lease_until,
+                    i16::from(ActionConstraints::DEFAULT_PRIORITY),
+                    ActionConstraints::PRIORITY_AGING_SECONDS,
+                    dataplane_id.0,
+                    agent_id
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    ActionRow,
+                    r#"
+                    WITH heads AS (
+                        SELECT DISTINCT ON (target_kind, target_id) id
+                        FROM actions
+                        WHERE deployment_id = $1
+                          AND dataplane_id = $6
+                          AND status = 'pending'
+                          AND (constraints_not_after IS NULL OR constraints_not_after > NOW())
+                        ORDER BY target_kind, target_id, created_at ASC, id ASC
+                    ),
+                    claimed AS (
+                        SELECT candidate.id,
+                               GREATEST(
+                                   COALESCE(candidate.constraints_priority, $4::SMALLINT)
+                                       - EXTRACT(EPOCH FROM (NOW() - candidate.created_at))::BIGINT / $5::BIGINT,
+                                   0
+                               ) AS claim_priority
+                        FROM actions candidate
+                        JOIN heads ON heads.id = candidate.id
+                        WHERE candidate.status = 'pending'
+                          AND NOT EXISTS (
+                            SELECT 1
+                            FROM actions in_flight
+                            WHERE in_flight.target_kind = candidate.target_kind
+                              AND in_flight.target_id = candidate.target_id
+                              AND in_flight.status IN ('leased', 'pulled')
+                        )
+                        ORDER BY claim_priority ASC,
+                                 candidate.created_at ASC,
+                                 candidate.id ASC
                         LIMIT $2
-                        FOR UPDATE SKIP LOCKED
+                        FOR UPDATE OF candidate SKIP LOCKED
+                    ),
+                    pulled AS (
+                        UPDATE actions
+                        SET status = 'pulled',
+                            status_at = NOW(),
+                            status_agent_id = $7,
+                            leased_until = $3,
+                            attempts = attempts + 1
+                        WHERE id IN (SELECT id FROM claimed)
+                        RETURNING *
                     )
+                    SELECT pulled.id,
+                           pulled.deployment_id,
+                           pulled.dataplane_id,
+                           pulled.action_type,
+                           pulled.target_kind,
+                           pulled.target_id,
+                           pulled.payload,
+                           pulled.version,
+                           pulled.status,
+                           pulled.status_at,
+                           pulled.status_agent_id,
+                           pulled.status_reason,
+                           pulled.source_type,
+                           pulled.source_user_id,
+                           pulled.source_client_id,
+                           pulled.constraints_not_after,
+                           pulled.constraints_priority,
+                           pulled.created_at,
+                           pulled.leased_until
+                    FROM pulled
+                    JOIN claimed ON claimed.id = pulled.id
+                    ORDER BY claimed.claim_priority ASC,
+                             pulled.created_at ASC,
+                             pulled.id ASC
+                    "#,
+                    deployment_id.0,
+                    max as i64,
+                    lease_until,
+                    i16::from(ActionConstraints::DEFAULT_PRIORITY),
+                    ActionConstraints::PRIORITY_AGING_SECONDS,
+                    dataplane_id.0,
+                    agent_id
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to claim pending actions: {}", e),
+        })?;
+
+        rows.into_iter().map(|row| row.into_action()).collect()
+    }
+
+    async fn transition_status(
+        &self,
+        deployment_id: DeploymentId,
+        action_id: ActionId,
+        expected: ActionStatus,
+        status: ActionStatus,
+        agent_id: String,
+    ) -> Result<Option<Action>, CoreError> {
+        let (expected_status, _, _, _) = status_to_row(&expected);
+        let leased_until = match &status {
+            ActionStatus::Leased { until } => Some(*until),
+            _ => None,
+        };
+        let (status, status_at, _, status_reason) = status_to_row(&status);
+
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    ActionRow,
+                    r#"
                     UPDATE actions
-                    SET status = 'leased',
-                        leased_until = $3
-                    WHERE id IN (SELECT id FROM claimed)
+                    SET status = $4,
+                        status_at = $5,
+                        status_agent_id = $6,
+                        status_reason = $7,
+                        leased_until = $8
+                    WHERE deployment_id = $1
+                      AND id = $2
+                      AND status = $3
                     RETURNING id,
                               deployment_id,
                               dataplane_id,
@@ -569,10 +760,15 @@ impl ActionRepository for PostgresActionRepository<'_, '_> {
                               leased_until
                     "#,
                     deployment_id.0,
-                    max as i64,
-                    lease_until
+                    action_id.0,
+                    expected_status,
+                    status,
+                    status_at,
+                    agent_id,
+                    status_reason,
+                    leased_until
                 )
-                .fetch_all(*pool)
+                .fetch_optional(*pool)
                 .await
             }
             PgExecutor::Tx(tx) => {
@@ -583,19 +779,15 @@ impl ActionRepository for PostgresActionRepository<'_, '_> {
                 sqlx::query_as!(
                     ActionRow,
                     r#"
-                    WITH claimed AS (
-                        SELECT id
-                        FROM actions
-                        WHERE deployment_id = $1
-                          AND status = 'pending'
-                        ORDER BY created_at ASC, id ASC
-                        LIMIT $2
-                        FOR UPDATE SKIP LOCKED
-                    )
                     UPDATE actions
-                    SET status = 'leased',
-                        leased_until = $3
-                    WHERE id IN (SELECT id FROM claimed)
+                    SET status = $4,
+                        status_at = $5,
+                        status_agent_id = $6,
+                        status_reason = $7,
+                        leased_until = $8
+                    WHERE deployment_id = $1
+                      AND id = $2
+                      AND status = $3
                     RETURNING id,
                               deployment_id,
                               dataplane_id,
@@ -617,18 +809,187 @@ impl ActionRepository for PostgresActionRepository<'_, '_> {
                               leased_until
                     "#,
                     deployment_id.0,
-                    max as i64,
-                    lease_until
+                    action_id.0,
+                    expected_status,
+                    status,
+                    status_at,
+                    agent_id,
+                    status_reason,
+                    leased_until
                 )
-                .fetch_all(transaction.as_mut())
+                .fetch_optional(transaction.as_mut())
                 .await
             }
         }
         .map_err(|e| CoreError::DatabaseError {
-            message: format!("Failed to claim pending actions: {}", e),
+            message: format!("Failed to update action status: {}", e),
         })?;
 
-        rows.into_iter().map(|row| row.into_action()).collect()
+        row.map(|row| row.into_action()).transpose()
+    }
+
+    async fn requeue_expired_leases(
+        &self,
+        now: DateTime<Utc>,
+        max_attempts: u32,
+    ) -> Result<u64, CoreError> {
+        let result = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    UPDATE actions
+                    SET status = 'pending',
+                        status_at = NULL,
+                        status_agent_id = NULL,
+                        leased_until = NULL
+                    WHERE status IN ('leased', 'pulled')
+                      AND leased_until < $1
+                      AND attempts < $2
+                    "#,
+                    now,
+                    max_attempts as i32
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    UPDATE actions
+                    SET status = 'pending',
+                        status_at = NULL,
+                        status_agent_id = NULL,
+                        leased_until = NULL
+                    WHERE status IN ('leased', 'pulled')
+                      AND leased_until < $1
+                      AND attempts < $2
+                    "#,
+                    now,
+                    max_attempts as i32
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to requeue expired leases: {}", e),
+        })?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn fail_exhausted_leases(
+        &self,
+        now: DateTime<Utc>,
+        max_attempts: u32,
+    ) -> Result<u64, CoreError> {
+        let result = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    UPDATE actions
+                    SET status = 'failed',
+                        status_at = $1,
+                        status_reason = $3,
+                        leased_until = NULL
+                    WHERE status IN ('leased', 'pulled')
+                      AND leased_until < $1
+                      AND attempts >= $2
+                    "#,
+                    now,
+                    max_attempts as i32,
+                    failure_reason_to_string(&ActionFailureReason::Timeout)
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    UPDATE actions
+                    SET status = 'failed',
+                        status_at = $1,
+                        status_reason = $3,
+                        leased_until = NULL
+                    WHERE status IN ('leased', 'pulled')
+                      AND leased_until < $1
+                      AND attempts >= $2
+                    "#,
+                    now,
+                    max_attempts as i32,
+                    failure_reason_to_string(&ActionFailureReason::Timeout)
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to time out exhausted leases: {}", e),
+        })?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn fail_past_deadline(&self, now: DateTime<Utc>) -> Result<u64, CoreError> {
+        let result = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    UPDATE actions
+                    SET status = 'failed',
+                        status_at = $1,
+                        status_reason = $2,
+                        leased_until = NULL
+                    WHERE constraints_not_after < $1
+                      AND (
+                          status = 'pending'
+                          OR (status IN ('leased', 'pulled') AND leased_until < $1)
+                      )
+                    "#,
+                    now,
+                    failure_reason_to_string(&ActionFailureReason::Timeout)
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    UPDATE actions
+                    SET status = 'failed',
+                        status_at = $1,
+                        status_reason = $2,
+                        leased_until = NULL
+                    WHERE constraints_not_after < $1
+                      AND (
+                          status = 'pending'
+                          OR (status IN ('leased', 'pulled') AND leased_until < $1)
+                      )
+                    "#,
+                    now,
+                    failure_reason_to_string(&ActionFailureReason::Timeout)
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to time out overdue actions: {}", e),
+        })?;
+
+        Ok(result.rows_affected())
     }
 }
 
@@ -734,7 +1095,7 @@ fn parse_failure_reason(raw: &str) -> ActionFailureReason {
     }
 }
 
-fn source_to_row(source: &ActionSource) -> (String, Option<Uuid>, Option<String>) {
+pub(crate) fn source_to_row(source: &ActionSource) -> (String, Option<Uuid>, Option<String>) {
     match source {
         ActionSource::User { user_id } => ("user".to_string(), Some(*user_id), None),
         ActionSource::System => ("system".to_string(), None, None),
@@ -742,7 +1103,7 @@ fn source_to_row(source: &ActionSource) -> (String, Option<Uuid>, Option<String>
     }
 }
 
-fn parse_source(
+pub(crate) fn parse_source(
     raw: &str,
     user_id: Option<Uuid>,
     client_id: Option<&str>,
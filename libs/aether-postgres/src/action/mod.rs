@@ -1,3 +1,4 @@
 mod action_repository;
 
 pub use action_repository::PostgresActionRepository;
+pub(crate) use action_repository::{parse_source, source_to_row};
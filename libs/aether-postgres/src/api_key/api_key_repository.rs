@@ -0,0 +1,413 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use aether_domain::{
+    CoreError,
+    api_key::{ApiKey, ApiKeyId, ApiKeyOwner, ports::ApiKeyRepository},
+    organisation::OrganisationId,
+    user::UserId,
+};
+use aether_persistence::{PgExecutor, PgTransaction};
+
+#[derive(FromRow)]
+struct ApiKeyRow {
+    id: Uuid,
+    organisation_id: Uuid,
+    user_id: Option<Uuid>,
+    name: String,
+    prefix: String,
+    key_hash: String,
+    permissions: i64,
+    created_by: Uuid,
+    expires_at: Option<DateTime<Utc>>,
+    last_used_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl ApiKeyRow {
+    fn into_api_key(self) -> ApiKey {
+        let owner = match self.user_id {
+            Some(user_id) => ApiKeyOwner::User {
+                user_id: UserId(user_id),
+            },
+            None => ApiKeyOwner::Organisation,
+        };
+
+        ApiKey {
+            id: ApiKeyId(self.id),
+            organisation_id: OrganisationId(self.organisation_id),
+            owner,
+            name: self.name,
+            prefix: self.prefix,
+            key_hash: self.key_hash,
+            permissions: self.permissions as u64,
+            created_by: UserId(self.created_by),
+            expires_at: self.expires_at,
+            last_used_at: self.last_used_at,
+            revoked_at: self.revoked_at,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub struct PostgresApiKeyRepository<'e, 't> {
+    executor: PgExecutor<'e, 't>,
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<'e, 't> PostgresApiKeyRepository<'e, 't> {
+    pub fn new(executor: PgExecutor<'e, 't>) -> Self {
+        Self { executor }
+    }
+
+    pub fn from_tx(tx: &'e PgTransaction<'t>) -> Self {
+        Self::new(PgExecutor::from_tx(tx))
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<'e> PostgresApiKeyRepository<'e, 'e> {
+    pub fn from_pool(pool: &'e sqlx::PgPool) -> Self {
+        Self::new(PgExecutor::from_pool(pool))
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl ApiKeyRepository for PostgresApiKeyRepository<'_, '_> {
+    async fn insert(&self, api_key: &ApiKey) -> Result<(), CoreError> {
+        let user_id = api_key.user_id().map(|user_id| user_id.0);
+
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO api_keys (
+                        id, organisation_id, user_id, name, prefix, key_hash, permissions,
+                        created_by, expires_at, last_used_at, revoked_at, created_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                    "#,
+                    api_key.id.0,
+                    api_key.organisation_id.0,
+                    user_id,
+                    api_key.name,
+                    api_key.prefix,
+                    api_key.key_hash,
+                    api_key.permissions as i64,
+                    api_key.created_by.0,
+                    api_key.expires_at,
+                    api_key.last_used_at,
+                    api_key.revoked_at,
+                    api_key.created_at,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    INSERT INTO api_keys (
+                        id, organisation_id, user_id, name, prefix, key_hash, permissions,
+                        created_by, expires_at, last_used_at, revoked_at, created_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                    "#,
+                    api_key.id.0,
+                    api_key.organisation_id.0,
+                    user_id,
+                    api_key.name,
+                    api_key.prefix,
+                    api_key.key_hash,
+                    api_key.permissions as i64,
+                    api_key.created_by.0,
+                    api_key.expires_at,
+                    api_key.last_used_at,
+                    api_key.revoked_at,
+                    api_key.created_at,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to insert api key: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        organisation_id: &OrganisationId,
+        api_key_id: &ApiKeyId,
+    ) -> Result<Option<ApiKey>, CoreError> {
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    ApiKeyRow,
+                    r#"
+                    SELECT id, organisation_id, user_id, name, prefix, key_hash, permissions,
+                           created_by, expires_at, last_used_at, revoked_at, created_at
+                    FROM api_keys
+                    WHERE organisation_id = $1 AND id = $2
+                    "#,
+                    organisation_id.0,
+                    api_key_id.0,
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    ApiKeyRow,
+                    r#"
+                    SELECT id, organisation_id, user_id, name, prefix, key_hash, permissions,
+                           created_by, expires_at, last_used_at, revoked_at, created_at
+                    FROM api_keys
+                    WHERE organisation_id = $1 AND id = $2
+                    "#,
+                    organisation_id.0,
+                    api_key_id.0,
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to find api key: {}", e),
+        })?;
+
+        Ok(row.map(ApiKeyRow::into_api_key))
+    }
+
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, CoreError> {
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    ApiKeyRow,
+                    r#"
+                    SELECT id, organisation_id, user_id, name, prefix, key_hash, permissions,
+                           created_by, expires_at, last_used_at, revoked_at, created_at
+                    FROM api_keys
+                    WHERE key_hash = $1
+                    "#,
+                    key_hash,
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    ApiKeyRow,
+                    r#"
+                    SELECT id, organisation_id, user_id, name, prefix, key_hash, permissions,
+                           created_by, expires_at, last_used_at, revoked_at, created_at
+                    FROM api_keys
+                    WHERE key_hash = $1
+                    "#,
+                    key_hash,
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to find api key by hash: {}", e),
+        })?;
+
+        Ok(row.map(ApiKeyRow::into_api_key))
+    }
+
+    async fn list_by_organisation(
+        &self,
+        organisation_id: &OrganisationId,
+    ) -> Result<Vec<ApiKey>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    ApiKeyRow,
+                    r#"
+                    SELECT id, organisation_id, user_id, name, prefix, key_hash, permissions,
+                           created_by, expires_at, last_used_at, revoked_at, created_at
+                    FROM api_keys
+                    WHERE organisation_id = $1
+                    ORDER BY created_at DESC
+                    "#,
+                    organisation_id.0,
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    ApiKeyRow,
+                    r#"
+                    SELECT id, organisation_id, user_id, name, prefix, key_hash, permissions,
+                           created_by, expires_at, last_used_at, revoked_at, created_at
+                    FROM api_keys
+                    WHERE organisation_id = $1
+                    ORDER BY created_at DESC
+                    "#,
+                    organisation_id.0,
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to list api keys: {}", e),
+        })?;
+
+        Ok(rows.into_iter().map(ApiKeyRow::into_api_key).collect())
+    }
+
+    async fn update(&self, api_key: &ApiKey) -> Result<(), CoreError> {
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    UPDATE api_keys
+                    SET revoked_at = $2
+                    WHERE id = $1
+                    "#,
+                    api_key.id.0,
+                    api_key.revoked_at,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    UPDATE api_keys
+                    SET revoked_at = $2
+                    WHERE id = $1
+                    "#,
+                    api_key.id.0,
+                    api_key.revoked_at,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to update api key: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn touch_last_used(
+        &self,
+        api_key_id: &ApiKeyId,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), CoreError> {
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    UPDATE api_keys
+                    SET last_used_at = $2
+                    WHERE id = $1
+                    "#,
+                    api_key_id.0,
+                    used_at,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    UPDATE api_keys
+                    SET last_used_at = $2
+                    WHERE id = $1
+                    "#,
+                    api_key_id.0,
+                    used_at,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to record api key use: {}", e),
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn sample_row() -> ApiKeyRow {
+        ApiKeyRow {
+            id: Uuid::parse_str("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa").unwrap(),
+            organisation_id: Uuid::parse_str("dddddddd-dddd-dddd-dddd-dddddddddddd").unwrap(),
+            user_id: None,
+            name: "ci".to_string(),
+            prefix: "aek_01234567".to_string(),
+            key_hash: "hash".to_string(),
+            permissions: i64::MIN | 4,
+            created_by: Uuid::parse_str("eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee").unwrap(),
+            expires_at: Some(sample_time()),
+            last_used_at: None,
+            revoked_at: None,
+            created_at: sample_time(),
+        }
+    }
+
+    #[test]
+    fn api_key_row_into_api_key_maps_fields() {
+        let api_key = sample_row().into_api_key();
+
+        assert_eq!(api_key.owner, ApiKeyOwner::Organisation);
+        assert_eq!(api_key.permissions, (1 << 63) | 4);
+        assert_eq!(api_key.key_hash, "hash");
+        assert_eq!(api_key.expires_at, Some(sample_time()));
+    }
+
+    #[test]
+    fn api_key_row_with_user_is_a_personal_token() {
+        let user_id = Uuid::parse_str("bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb").unwrap();
+        let mut row = sample_row();
+        row.user_id = Some(user_id);
+
+        assert_eq!(row.into_api_key().user_id(), Some(UserId(user_id)));
+    }
+}
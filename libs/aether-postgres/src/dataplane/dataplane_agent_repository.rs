@@ -0,0 +1,226 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use aether_domain::{
+    CoreError,
+    dataplane::{
+        entities::DataPlaneAgent, ports::DataPlaneAgentRepository, value_objects::DataPlaneId,
+    },
+};
+use aether_persistence::{PgExecutor, PgTransaction};
+
+#[derive(FromRow)]
+struct DataPlaneAgentRow {
+    dataplane_id: Uuid,
+    client_id: String,
+    created_at: DateTime<Utc>,
+}
+
+impl DataPlaneAgentRow {
+    fn into_agent(self) -> DataPlaneAgent {
+        DataPlaneAgent {
+            dataplane_id: DataPlaneId(self.dataplane_id),
+            client_id: self.client_id,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub struct PostgresDataPlaneAgentRepository<'e, 't> {
+    executor: PgExecutor<'e, 't>,
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<'e, 't> PostgresDataPlaneAgentRepository<'e, 't> {
+    pub fn new(executor: PgExecutor<'e, 't>) -> Self {
+        Self { executor }
+    }
+
+    pub fn from_tx(tx: &'e PgTransaction<'t>) -> Self {
+        Self::new(PgExecutor::from_tx(tx))
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<'e> PostgresDataPlaneAgentRepository<'e, 'e> {
+    pub fn from_pool(pool: &'e sqlx::PgPool) -> Self {
+        Self::new(PgExecutor::from_pool(pool))
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl DataPlaneAgentRepository for PostgresDataPlaneAgentRepository<'_, '_> {
+    async fn insert(&self, agent: &DataPlaneAgent) -> Result<(), CoreError> {
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO data_plane_agents (dataplane_id, client_id, created_at)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT (dataplane_id, client_id) DO NOTHING
+                    "#,
+                    agent.dataplane_id.0,
+                    agent.client_id,
+                    agent.created_at
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    INSERT INTO data_plane_agents (dataplane_id, client_id, created_at)
+                    VALUES ($1, $2, $3)
+                    ON CONFLICT (dataplane_id, client_id) DO NOTHING
+                    "#,
+                    agent.dataplane_id.0,
+                    agent.client_id,
+                    agent.created_at
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to register data plane agent: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, dataplane_id: &DataPlaneId, client_id: &str) -> Result<bool, CoreError> {
+        let result = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    DELETE FROM data_plane_agents
+                    WHERE dataplane_id = $1
+                      AND client_id = $2
+                    "#,
+                    dataplane_id.0,
+                    client_id
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    DELETE FROM data_plane_agents
+                    WHERE dataplane_id = $1
+                      AND client_id = $2
+                    "#,
+                    dataplane_id.0,
+                    client_id
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to unregister data plane agent: {}", e),
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_by_dataplane(
+        &self,
+        dataplane_id: &DataPlaneId,
+    ) -> Result<Vec<DataPlaneAgent>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    DataPlaneAgentRow,
+                    r#"
+                    SELECT dataplane_id,
+                           client_id,
+                           created_at
+                    FROM data_plane_agents
+                    WHERE dataplane_id = $1
+                    ORDER BY created_at ASC, client_id ASC
+                    "#,
+                    dataplane_id.0
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    DataPlaneAgentRow,
+                    r#"
+                    SELECT dataplane_id,
+                           client_id,
+                           created_at
+                    FROM data_plane_agents
+                    WHERE dataplane_id = $1
+                    ORDER BY created_at ASC, client_id ASC
+                    "#,
+                    dataplane_id.0
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to list data plane agents: {}", e),
+        })?;
+
+        Ok(rows.into_iter().map(|row| row.into_agent()).collect())
+    }
+
+    async fn list_dataplanes_for_client(
+        &self,
+        client_id: &str,
+    ) -> Result<Vec<DataPlaneId>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT dataplane_id
+                    FROM data_plane_agents
+                    WHERE client_id = $1
+                    "#,
+                    client_id
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT dataplane_id
+                    FROM data_plane_agents
+                    WHERE client_id = $1
+                    "#,
+                    client_id
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to list data planes of agent: {}", e),
+        })?;
+
+        Ok(rows.into_iter().map(DataPlaneId).collect())
+    }
+}
@@ -2,11 +2,13 @@ use chrono::Utc;
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use aether_domain::organisation::OrganisationId;
 use aether_domain::{
     CoreError,
     dataplane::{
         entities::DataPlane,
         ports::DataPlaneRepository,
+        scheduler::PlacementCandidate,
         value_objects::{Capacity, DataPlaneId, DataPlaneMode, DataPlaneStatus, Region},
     },
 };
@@ -37,6 +39,43 @@ impl DataPlaneRow {
     }
 }
 
+#[derive(FromRow)]
+struct PlacementCandidateRow {
+    id: Uuid,
+    mode: String,
+    region: String,
+    status: String,
+    capacity: i32,
+    current_load: i64,
+    organisations: Vec<Uuid>,
+}
+
+impl PlacementCandidateRow {
+    fn into_candidate(self) -> Result<PlacementCandidate, CoreError> {
+        let current_load = u32::try_from(self.current_load).map_err(|_| {
+            CoreError::InternalError(format!(
+                "Invalid data plane load value: {}",
+                self.current_load
+            ))
+        })?;
+        let organisations = self.organisations.into_iter().map(OrganisationId).collect();
+        let dataplane = DataPlaneRow {
+            id: self.id,
+            mode: self.mode,
+            region: self.region,
+            status: self.status,
+            capacity: self.capacity,
+        }
+        .into_dataplane()?;
+
+        Ok(PlacementCandidate {
+            dataplane,
+            current_load,
+            organisations,
+        })
+    }
+}
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 pub struct PostgresDataPlaneRepository<'e, 't> {
     executor: PgExecutor<'e, 't>,
@@ -338,6 +377,74 @@ impl DataPlaneRepository for PostgresDataPlaneRepository<'_, '_> {
         rows.into_iter().map(|row| row.into_dataplane()).collect()
     }
 
+    async fn list_placement_candidates(&self) -> Result<Vec<PlacementCandidate>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    PlacementCandidateRow,
+                    r#"
+                    SELECT dp.id,
+                           dp.mode,
+                           dp.region,
+                           dp.status,
+                           dp.capacity,
+                           COUNT(d.id)::BIGINT AS "current_load!",
+                           COALESCE(
+                               ARRAY_AGG(DISTINCT d.organisation_id)
+                                   FILTER (WHERE d.organisation_id IS NOT NULL),
+                               '{}'
+                           ) AS "organisations!"
+                    FROM data_planes dp
+                    LEFT JOIN deployments d
+                      ON d.dataplane_id = dp.id
+                     AND d.deleted_at IS NULL
+                    GROUP BY dp.id, dp.mode, dp.region, dp.status, dp.capacity
+                    ORDER BY dp.region ASC, dp.id ASC
+                    "#
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    PlacementCandidateRow,
+                    r#"
+                    SELECT dp.id,
+                           dp.mode,
+                           dp.region,
+                           dp.status,
+                           dp.capacity,
+                           COUNT(d.id)::BIGINT AS "current_load!",
+                           COALESCE(
+                               ARRAY_AGG(DISTINCT d.organisation_id)
+                                   FILTER (WHERE d.organisation_id IS NOT NULL),
+                               '{}'
+                           ) AS "organisations!"
+                    FROM data_planes dp
+                    LEFT JOIN deployments d
+                      ON d.dataplane_id = dp.id
+                     AND d.deleted_at IS NULL
+                    GROUP BY dp.id, dp.mode, dp.region, dp.status, dp.capacity
+                    ORDER BY dp.region ASC, dp.id ASC
+                    "#
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to list data plane placement candidates: {}", e),
+        })?;
+
+        rows.into_iter()
+            .map(PlacementCandidateRow::into_candidate)
+            .collect()
+    }
+
     async fn current_load(&self, id: &DataPlaneId) -> Result<u32, CoreError> {
         let count: i64 = match &self.executor {
             PgExecutor::Pool(pool) => {
@@ -1,4 +1,7 @@
+mod dataplane_agent_repository;
 mod dataplane_repository;
 
+#[allow(unused_imports)]
+pub use dataplane_agent_repository::PostgresDataPlaneAgentRepository;
 #[allow(unused_imports)]
 pub use dataplane_repository::PostgresDataPlaneRepository;
@@ -7,13 +7,15 @@ use aether_domain::{
     dataplane::value_objects::DataPlaneId,
     deployments::{
         Deployment, DeploymentId, DeploymentKind, DeploymentName, DeploymentStatus,
-        DeploymentVersion, ports::DeploymentRepository,
+        DeploymentStatusChange, DeploymentVersion, ports::DeploymentRepository,
     },
     organisation::OrganisationId,
     user::UserId,
 };
 use aether_persistence::{PgExecutor, PgTransaction};
 
+use crate::action::{parse_source, source_to_row};
+
 #[derive(FromRow)]
 struct DeploymentRow {
     id: Uuid,
@@ -29,6 +31,7 @@ struct DeploymentRow {
     updated_at: DateTime<Utc>,
     deployed_at: Option<DateTime<Utc>>,
     deleted_at: Option<DateTime<Utc>>,
+    purged_at: Option<DateTime<Utc>>,
 }
 
 impl DeploymentRow {
@@ -51,6 +54,46 @@ impl DeploymentRow {
             updated_at: self.updated_at,
             deployed_at: self.deployed_at,
             deleted_at: self.deleted_at,
+            purged_at: self.purged_at,
+        })
+    }
+}
+
+#[derive(FromRow)]
+struct DeploymentStatusChangeRow {
+    id: Uuid,
+    deployment_id: Uuid,
+    from_status: Option<String>,
+    to_status: String,
+    actor_type: String,
+    actor_user_id: Option<Uuid>,
+    actor_client_id: Option<String>,
+    reason: Option<String>,
+    changed_at: DateTime<Utc>,
+}
+
+impl DeploymentStatusChangeRow {
+    fn into_status_change(self) -> Result<DeploymentStatusChange, CoreError> {
+        let from_status = self
+            .from_status
+            .as_deref()
+            .map(DeploymentStatus::try_from)
+            .transpose()?;
+        let to_status = DeploymentStatus::try_from(self.to_status.as_str())?;
+        let actor = parse_source(
+            &self.actor_type,
+            self.actor_user_id,
+            self.actor_client_id.as_deref(),
+        )?;
+
+        Ok(DeploymentStatusChange {
+            id: self.id,
+            deployment_id: DeploymentId(self.deployment_id),
+            from_status,
+            to_status,
+            actor,
+            reason: self.reason,
+            changed_at: self.changed_at,
         })
     }
 }
@@ -189,7 +232,8 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
                            created_at,
                            updated_at,
                            deployed_at,
-                           deleted_at
+                           deleted_at,
+                           purged_at
                     FROM deployments
                     WHERE id = $1
                     "#,
@@ -218,7 +262,8 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
                            created_at,
                            updated_at,
                            deployed_at,
-                           deleted_at
+                           deleted_at,
+                           purged_at
                     FROM deployments
                     WHERE id = $1
                     "#,
@@ -235,9 +280,81 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
         row.map(|r| r.into_deployment()).transpose()
     }
 
+    async fn get_by_id_for_update(
+        &self,
+        deployment_id: DeploymentId,
+    ) -> Result<Option<Deployment>, CoreError> {
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    DeploymentRow,
+                    r#"
+                    SELECT id,
+                           organisation_id,
+                           dataplane_id,
+                           name,
+                           kind,
+                           status,
+                           namespace,
+                           version,
+                           created_by,
+                           created_at,
+                           updated_at,
+                           deployed_at,
+                           deleted_at,
+                           purged_at
+                    FROM deployments
+                    WHERE id = $1
+                    FOR UPDATE
+                    "#,
+                    deployment_id.0
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    DeploymentRow,
+                    r#"
+                    SELECT id,
+                           organisation_id,
+                           dataplane_id,
+                           name,
+                           kind,
+                           status,
+                           namespace,
+                           version,
+                           created_by,
+                           created_at,
+                           updated_at,
+                           deployed_at,
+                           deleted_at,
+                           purged_at
+                    FROM deployments
+                    WHERE id = $1
+                    FOR UPDATE
+                    "#,
+                    deployment_id.0
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to lock deployment: {}", e),
+        })?;
+
+        row.map(|r| r.into_deployment()).transpose()
+    }
+
     async fn list_by_organisation(
         &self,
         organisation_id: OrganisationId,
+        include_deleted: bool,
     ) -> Result<Vec<Deployment>, CoreError> {
         let rows = match &self.executor {
             PgExecutor::Pool(pool) => {
@@ -256,12 +373,15 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
                            created_at,
                            updated_at,
                            deployed_at,
-                           deleted_at
+                           deleted_at,
+                           purged_at
                     FROM deployments
                     WHERE organisation_id = $1
+                      AND ($2 OR deleted_at IS NULL)
                     ORDER BY created_at DESC
                     "#,
-                    organisation_id.0
+                    organisation_id.0,
+                    include_deleted
                 )
                 .fetch_all(*pool)
                 .await
@@ -286,12 +406,15 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
                            created_at,
                            updated_at,
                            deployed_at,
-                           deleted_at
+                           deleted_at,
+                           purged_at
                     FROM deployments
                     WHERE organisation_id = $1
+                      AND ($2 OR deleted_at IS NULL)
                     ORDER BY created_at DESC
                     "#,
-                    organisation_id.0
+                    organisation_id.0,
+                    include_deleted
                 )
                 .fetch_all(transaction.as_mut())
                 .await
@@ -317,7 +440,8 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
                         version = $6,
                         updated_at = $7,
                         deployed_at = $8,
-                        deleted_at = $9
+                        deleted_at = $9,
+                        purged_at = $10
                     WHERE id = $1
                     "#,
                     deployment.id.0,
@@ -329,6 +453,7 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
                     deployment.updated_at,
                     deployment.deployed_at,
                     deployment.deleted_at,
+                    deployment.purged_at,
                 )
                 .execute(*pool)
                 .await
@@ -348,7 +473,8 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
                         version = $6,
                         updated_at = $7,
                         deployed_at = $8,
-                        deleted_at = $9
+                        deleted_at = $9,
+                        purged_at = $10
                     WHERE id = $1
                     "#,
                     deployment.id.0,
@@ -360,6 +486,7 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
                     deployment.updated_at,
                     deployment.deployed_at,
                     deployment.deleted_at,
+                    deployment.purged_at,
                 )
                 .execute(transaction.as_mut())
                 .await
@@ -437,7 +564,8 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
                            created_at,
                            updated_at,
                            deployed_at,
-                           deleted_at
+                           deleted_at,
+                           purged_at
                     FROM deployments
                     WHERE dataplane_id = $1
                     ORDER BY created_at DESC
@@ -472,7 +600,8 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
                            created_at,
                            updated_at,
                            deployed_at,
-                           deleted_at
+                           deleted_at,
+                           purged_at
                     FROM deployments
                     WHERE dataplane_id = $1
                     ORDER BY created_at DESC
@@ -489,6 +618,267 @@ impl DeploymentRepository for PostgresDeploymentRepository<'_, '_> {
             }
         }
     }
+
+    async fn count_live_by_organisation(
+        &self,
+        organisation_id: OrganisationId,
+    ) -> Result<usize, CoreError> {
+        let count = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT COUNT(*) AS "count!"
+                    FROM deployments
+                    WHERE organisation_id = $1
+                      AND deleted_at IS NULL
+                    "#,
+                    organisation_id.0
+                )
+                .fetch_one(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT COUNT(*) AS "count!"
+                    FROM deployments
+                    WHERE organisation_id = $1
+                      AND deleted_at IS NULL
+                    "#,
+                    organisation_id.0
+                )
+                .fetch_one(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to count deployments by organisation: {}", e),
+        })?;
+
+        Ok(count as usize)
+    }
+
+    async fn mark_purged(
+        &self,
+        deleted_before: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<Deployment>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    DeploymentRow,
+                    r#"
+                    WITH purgeable AS (
+                        SELECT id
+                        FROM deployments
+                        WHERE deleted_at < $1
+                          AND purged_at IS NULL
+                        ORDER BY deleted_at
+                        LIMIT $2
+                        FOR UPDATE SKIP LOCKED
+                    )
+                    UPDATE deployments
+                    SET purged_at = NOW(),
+                        updated_at = NOW()
+                    FROM purgeable
+                    WHERE deployments.id = purgeable.id
+                    RETURNING deployments.id,
+                              deployments.organisation_id,
+                              deployments.dataplane_id,
+                              deployments.name,
+                              deployments.kind,
+                              deployments.status,
+                              deployments.namespace,
+                              deployments.version,
+                              deployments.created_by,
+                              deployments.created_at,
+                              deployments.updated_at,
+                              deployments.deployed_at,
+                              deployments.deleted_at,
+                              deployments.purged_at
+                    "#,
+                    deleted_before,
+                    limit as i64
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    DeploymentRow,
+                    r#"
+                    WITH purgeable AS (
+                        SELECT id
+                        FROM deployments
+                        WHERE deleted_at < $1
+                          AND purged_at IS NULL
+                        ORDER BY deleted_at
+                        LIMIT $2
+                        FOR UPDATE SKIP LOCKED
+                    )
+                    UPDATE deployments
+                    SET purged_at = NOW(),
+                        updated_at = NOW()
+                    FROM purgeable
+                    WHERE deployments.id = purgeable.id
+                    RETURNING deployments.id,
+                              deployments.organisation_id,
+                              deployments.dataplane_id,
+                              deployments.name,
+                              deployments.kind,
+                              deployments.status,
+                              deployments.namespace,
+                              deployments.version,
+                              deployments.created_by,
+                              deployments.created_at,
+                              deployments.updated_at,
+                              deployments.deployed_at,
+                              deployments.deleted_at,
+                              deployments.purged_at
+                    "#,
+                    deleted_before,
+                    limit as i64
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to mark deployments as purged: {}", e),
+        })?;
+
+        rows.into_iter().map(|r| r.into_deployment()).collect()
+    }
+
+    async fn record_status_change(&self, change: DeploymentStatusChange) -> Result<(), CoreError> {
+        let (actor_type, actor_user_id, actor_client_id) = source_to_row(&change.actor);
+        let from_status = change.from_status.as_ref().map(|s| s.to_string());
+
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO deployment_status_history (
+                        id, deployment_id, from_status, to_status,
+                        actor_type, actor_user_id, actor_client_id, reason, changed_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                    "#,
+                    change.id,
+                    change.deployment_id.0,
+                    from_status,
+                    change.to_status.to_string(),
+                    actor_type,
+                    actor_user_id,
+                    actor_client_id,
+                    change.reason,
+                    change.changed_at,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    INSERT INTO deployment_status_history (
+                        id, deployment_id, from_status, to_status,
+                        actor_type, actor_user_id, actor_client_id, reason, changed_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                    "#,
+                    change.id,
+                    change.deployment_id.0,
+                    from_status,
+                    change.to_status.to_string(),
+                    actor_type,
+                    actor_user_id,
+                    actor_client_id,
+                    change.reason,
+                    change.changed_at,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to record deployment status change: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn list_status_history(
+        &self,
+        deployment_id: DeploymentId,
+    ) -> Result<Vec<DeploymentStatusChange>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    DeploymentStatusChangeRow,
+                    r#"
+                    SELECT id,
+                           deployment_id,
+                           from_status,
+                           to_status,
+                           actor_type,
+                           actor_user_id,
+                           actor_client_id,
+                           reason,
+                           changed_at
+                    FROM deployment_status_history
+                    WHERE deployment_id = $1
+                    ORDER BY changed_at ASC, id ASC
+                    "#,
+                    deployment_id.0
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    DeploymentStatusChangeRow,
+                    r#"
+                    SELECT id,
+                           deployment_id,
+                           from_status,
+                           to_status,
+                           actor_type,
+                           actor_user_id,
+                           actor_client_id,
+                           reason,
+                           changed_at
+                    FROM deployment_status_history
+                    WHERE deployment_id = $1
+                    ORDER BY changed_at ASC, id ASC
+                    "#,
+                    deployment_id.0
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to list deployment status history: {}", e),
+        })?;
+
+        rows.into_iter().map(|r| r.into_status_change()).collect()
+    }
 }
 
 #[cfg(test)]
@@ -517,6 +907,7 @@ mod tests {
             updated_at: sample_time(),
             deployed_at: Some(sample_time()),
             deleted_at: None,
+            purged_at: None,
         }
     }
 
@@ -552,6 +943,32 @@ mod tests {
         assert!(deployment.deleted_at.is_none());
     }
 
+    #[test]
+    fn status_change_row_into_status_change_maps_actor() {
+        let user_id = Uuid::new_v4();
+        let row = DeploymentStatusChangeRow {
+            id: Uuid::new_v4(),
+            deployment_id: Uuid::new_v4(),
+            from_status: Some("in_progress".to_string()),
+            to_status: "successful".to_string(),
+            actor_type: "user".to_string(),
+            actor_user_id: Some(user_id),
+            actor_client_id: None,
+            reason: Some("rollout finished".to_string()),
+            changed_at: sample_time(),
+        };
+
+        let change = row.into_status_change().unwrap();
+
+        assert_eq!(change.from_status, Some(DeploymentStatus::InProgress));
+        assert_eq!(change.to_status, DeploymentStatus::Successful);
+        assert_eq!(
+            change.actor,
+            aether_domain::action::ActionSource::User { user_id }
+        );
+        assert_eq!(change.reason.as_deref(), Some("rollout finished"));
+    }
+
     #[test]
     fn deployment_row_into_deployment_rejects_invalid_kind() {
         let mut row = sample_row();
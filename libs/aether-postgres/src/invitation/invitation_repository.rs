@@ -0,0 +1,431 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use aether_domain::{
+    CoreError,
+    invitation::{Invitation, InvitationId, ports::InvitationRepository},
+    organisation::OrganisationId,
+    role::RoleId,
+    user::UserId,
+};
+use aether_persistence::{PgExecutor, PgTransaction};
+
+#[derive(FromRow)]
+struct InvitationRow {
+    id: Uuid,
+    organisation_id: Uuid,
+    email: String,
+    token_hash: String,
+    role_ids: Vec<Uuid>,
+    status: String,
+    invited_by: Uuid,
+    expires_at: DateTime<Utc>,
+    accepted_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl InvitationRow {
+    fn into_invitation(self) -> Result<Invitation, CoreError> {
+        Ok(Invitation {
+            id: InvitationId(self.id),
+            organisation_id: OrganisationId(self.organisation_id),
+            email: self.email,
+            role_ids: self.role_ids.into_iter().map(RoleId).collect(),
+            status: self.status.parse()?,
+            invited_by: UserId(self.invited_by),
+            token_hash: self.token_hash,
+            expires_at: self.expires_at,
+            accepted_at: self.accepted_at,
+            revoked_at: self.revoked_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub struct PostgresInvitationRepository<'e, 't> {
+    executor: PgExecutor<'e, 't>,
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<'e, 't> PostgresInvitationRepository<'e, 't> {
+    pub fn new(executor: PgExecutor<'e, 't>) -> Self {
+        Self { executor }
+    }
+
+    pub fn from_tx(tx: &'e PgTransaction<'t>) -> Self {
+        Self::new(PgExecutor::from_tx(tx))
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<'e> PostgresInvitationRepository<'e, 'e> {
+    pub fn from_pool(pool: &'e sqlx::PgPool) -> Self {
+        Self::new(PgExecutor::from_pool(pool))
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl InvitationRepository for PostgresInvitationRepository<'_, '_> {
+    async fn insert(&self, invitation: &Invitation) -> Result<(), CoreError> {
+        let role_ids: Vec<Uuid> = invitation.role_ids.iter().map(|id| id.0).collect();
+
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO invitations (
+                        id, organisation_id, email, token_hash, role_ids, status, invited_by,
+                        expires_at, accepted_at, revoked_at, created_at, updated_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                    "#,
+                    invitation.id.0,
+                    invitation.organisation_id.0,
+                    invitation.email,
+                    invitation.token_hash,
+                    &role_ids,
+                    invitation.status.to_string(),
+                    invitation.invited_by.0,
+                    invitation.expires_at,
+                    invitation.accepted_at,
+                    invitation.revoked_at,
+                    invitation.created_at,
+                    invitation.updated_at,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    INSERT INTO invitations (
+                        id, organisation_id, email, token_hash, role_ids, status, invited_by,
+                        expires_at, accepted_at, revoked_at, created_at, updated_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                    "#,
+                    invitation.id.0,
+                    invitation.organisation_id.0,
+                    invitation.email,
+                    invitation.token_hash,
+                    &role_ids,
+                    invitation.status.to_string(),
+                    invitation.invited_by.0,
+                    invitation.expires_at,
+                    invitation.accepted_at,
+                    invitation.revoked_at,
+                    invitation.created_at,
+                    invitation.updated_at,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to insert invitation: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        organisation_id: &OrganisationId,
+        invitation_id: &InvitationId,
+    ) -> Result<Option<Invitation>, CoreError> {
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    InvitationRow,
+                    r#"
+                    SELECT id, organisation_id, email, token_hash, role_ids, status, invited_by,
+                           expires_at, accepted_at, revoked_at, created_at, updated_at
+                    FROM invitations
+                    WHERE organisation_id = $1 AND id = $2
+                    "#,
+                    organisation_id.0,
+                    invitation_id.0,
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    InvitationRow,
+                    r#"
+                    SELECT id, organisation_id, email, token_hash, role_ids, status, invited_by,
+                           expires_at, accepted_at, revoked_at, created_at, updated_at
+                    FROM invitations
+                    WHERE organisation_id = $1 AND id = $2
+                    "#,
+                    organisation_id.0,
+                    invitation_id.0,
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to find invitation: {}", e),
+        })?;
+
+        row.map(InvitationRow::into_invitation).transpose()
+    }
+
+    async fn find_by_token_hash_for_update(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<Invitation>, CoreError> {
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    InvitationRow,
+                    r#"
+                    SELECT id, organisation_id, email, token_hash, role_ids, status, invited_by,
+                           expires_at, accepted_at, revoked_at, created_at, updated_at
+                    FROM invitations
+                    WHERE token_hash = $1
+                    FOR UPDATE
+                    "#,
+                    token_hash,
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    InvitationRow,
+                    r#"
+                    SELECT id, organisation_id, email, token_hash, role_ids, status, invited_by,
+                           expires_at, accepted_at, revoked_at, created_at, updated_at
+                    FROM invitations
+                    WHERE token_hash = $1
+                    FOR UPDATE
+                    "#,
+                    token_hash,
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to find invitation by token: {}", e),
+        })?;
+
+        row.map(InvitationRow::into_invitation).transpose()
+    }
+
+    async fn find_pending_by_email(
+        &self,
+        organisation_id: &OrganisationId,
+        email: &str,
+    ) -> Result<Option<Invitation>, CoreError> {
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    InvitationRow,
+                    r#"
+                    SELECT id, organisation_id, email, token_hash, role_ids, status, invited_by,
+                           expires_at, accepted_at, revoked_at, created_at, updated_at
+                    FROM invitations
+                    WHERE organisation_id = $1 AND LOWER(email) = LOWER($2) AND status = 'pending'
+                    "#,
+                    organisation_id.0,
+                    email,
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    InvitationRow,
+                    r#"
+                    SELECT id, organisation_id, email, token_hash, role_ids, status, invited_by,
+                           expires_at, accepted_at, revoked_at, created_at, updated_at
+                    FROM invitations
+                    WHERE organisation_id = $1 AND LOWER(email) = LOWER($2) AND status = 'pending'
+                    "#,
+                    organisation_id.0,
+                    email,
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to find pending invitation: {}", e),
+        })?;
+
+        row.map(InvitationRow::into_invitation).transpose()
+    }
+
+    async fn list_by_organisation(
+        &self,
+        organisation_id: &OrganisationId,
+    ) -> Result<Vec<Invitation>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    InvitationRow,
+                    r#"
+                    SELECT id, organisation_id, email, token_hash, role_ids, status, invited_by,
+                           expires_at, accepted_at, revoked_at, created_at, updated_at
+                    FROM invitations
+                    WHERE organisation_id = $1
+                    ORDER BY created_at DESC
+                    "#,
+                    organisation_id.0,
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    InvitationRow,
+                    r#"
+                    SELECT id, organisation_id, email, token_hash, role_ids, status, invited_by,
+                           expires_at, accepted_at, revoked_at, created_at, updated_at
+                    FROM invitations
+                    WHERE organisation_id = $1
+                    ORDER BY created_at DESC
+                    "#,
+                    organisation_id.0,
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to list invitations: {}", e),
+        })?;
+
+        rows.into_iter()
+            .map(InvitationRow::into_invitation)
+            .collect()
+    }
+
+    async fn update(&self, invitation: &Invitation) -> Result<(), CoreError> {
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    UPDATE invitations
+                    SET status = $2, accepted_at = $3, revoked_at = $4, updated_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    invitation.id.0,
+                    invitation.status.to_string(),
+                    invitation.accepted_at,
+                    invitation.revoked_at,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    UPDATE invitations
+                    SET status = $2, accepted_at = $3, revoked_at = $4, updated_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    invitation.id.0,
+                    invitation.status.to_string(),
+                    invitation.accepted_at,
+                    invitation.revoked_at,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to update invitation: {}", e),
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aether_domain::invitation::InvitationStatus;
+
+    fn sample_time() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn sample_row() -> InvitationRow {
+        InvitationRow {
+            id: Uuid::parse_str("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa").unwrap(),
+            organisation_id: Uuid::parse_str("dddddddd-dddd-dddd-dddd-dddddddddddd").unwrap(),
+            email: "invitee@example.com".to_string(),
+            token_hash: "hash".to_string(),
+            role_ids: vec![Uuid::parse_str("bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb").unwrap()],
+            status: "pending".to_string(),
+            invited_by: Uuid::parse_str("eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee").unwrap(),
+            expires_at: sample_time(),
+            accepted_at: None,
+            revoked_at: None,
+            created_at: sample_time(),
+            updated_at: sample_time(),
+        }
+    }
+
+    #[test]
+    fn invitation_row_into_invitation_maps_fields() {
+        let invitation = sample_row().into_invitation().unwrap();
+
+        assert_eq!(invitation.email, "invitee@example.com");
+        assert_eq!(invitation.status, InvitationStatus::Pending);
+        assert_eq!(
+            invitation.role_ids,
+            vec![RoleId(
+                Uuid::parse_str("bbbbbbbb-bbbb-bbbb-bbbb-bbbbbbbbbbbb").unwrap()
+            )]
+        );
+        assert_eq!(invitation.token_hash, "hash");
+        assert_eq!(invitation.expires_at, sample_time());
+    }
+
+    #[test]
+    fn invitation_row_into_invitation_rejects_invalid_status() {
+        let mut row = sample_row();
+        row.status = "unknown".to_string();
+
+        assert!(matches!(
+            row.into_invitation(),
+            Err(CoreError::InvalidInvitationStatus { .. })
+        ));
+    }
+}
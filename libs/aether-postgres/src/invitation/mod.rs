@@ -0,0 +1,3 @@
+mod invitation_repository;
+
+pub use invitation_repository::PostgresInvitationRepository;
@@ -3,12 +3,18 @@
 #[cfg_attr(coverage_nightly, coverage(off))]
 pub mod action;
 
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub mod api_key;
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 pub mod deployments;
 
 #[cfg_attr(coverage_nightly, coverage(off))]
 pub mod dataplane;
 
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub mod invitation;
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 pub mod organisation;
 
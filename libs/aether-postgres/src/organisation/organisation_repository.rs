@@ -6,17 +6,22 @@ use uuid::Uuid;
 use aether_domain::{
     CoreError,
     organisation::{
-        Organisation, OrganisationId,
+        Organisation, OrganisationId, OrganisationStatusChange, OwnershipTransfer,
         commands::CreateOrganisationData,
+        member::{Member, MemberId},
+        plan::PlanChange,
         ports::OrganisationRepository,
         value_objects::{
             OrganisationLimits, OrganisationName, OrganisationSlug, OrganisationStatus,
         },
     },
+    role::RoleId,
     user::UserId,
 };
 use aether_persistence::{PgExecutor, PgTransaction};
 
+use crate::action::{parse_source, source_to_row};
+
 /// Database row representation for organisations table
 ///
 /// This struct maps directly to the database schema and is used with sqlx's `query_as!()` macro.
@@ -36,6 +41,72 @@ struct OrganisationRow {
     deleted_at: Option<DateTime<Utc>>,
 }
 
+/// Database row for a member joined with its user and aggregated role assignments
+#[derive(FromRow)]
+struct MemberRow {
+    id: Uuid,
+    organisation_id: Uuid,
+    user_id: Uuid,
+    email: String,
+    name: String,
+    role_ids: Vec<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<MemberRow> for Member {
+    fn from(row: MemberRow) -> Self {
+        Self {
+            id: MemberId(row.id),
+            organisation_id: OrganisationId(row.organisation_id),
+            user_id: UserId(row.user_id),
+            email: row.email,
+            name: row.name,
+            role_ids: row.role_ids.into_iter().map(RoleId).collect(),
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// Database row for an entry in the organisation plan history
+#[derive(FromRow)]
+struct PlanChangeRow {
+    id: Uuid,
+    organisation_id: Uuid,
+    from_plan: String,
+    to_plan: String,
+    max_instances: i32,
+    max_users: i32,
+    max_storage_gb: i32,
+    actor_type: String,
+    actor_user_id: Option<Uuid>,
+    actor_client_id: Option<String>,
+    changed_at: DateTime<Utc>,
+}
+
+impl PlanChangeRow {
+    fn into_plan_change(self) -> Result<PlanChange, CoreError> {
+        let actor = parse_source(
+            &self.actor_type,
+            self.actor_user_id,
+            self.actor_client_id.as_deref(),
+        )?;
+
+        Ok(PlanChange {
+            id: self.id,
+            organisation_id: OrganisationId(self.organisation_id),
+            from_plan: self.from_plan.parse()?,
+            to_plan: self.to_plan.parse()?,
+            limits: OrganisationLimits::custom(
+                self.max_instances as usize,
+                self.max_users as usize,
+                self.max_storage_gb as usize,
+            ),
+            actor,
+            changed_at: self.changed_at,
+        })
+    }
+}
+
 impl OrganisationRow {
     /// Converts a database row into a domain Organisation entity
     fn into_organisation(self) -> Result<Organisation, CoreError> {
@@ -290,6 +361,55 @@ impl OrganisationRepository for PostgresOrganisationRepository<'_, '_> {
         row.map(|r| r.into_organisation()).transpose()
     }
 
+    async fn find_by_id_for_update(
+        &self,
+        id: &OrganisationId,
+    ) -> Result<Option<Organisation>, CoreError> {
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    OrganisationRow,
+                    r#"
+                    SELECT id, name, slug, owner_id, status, plan,
+                           max_instances, max_users, max_storage_gb,
+                           created_at, updated_at, deleted_at
+                    FROM organisations
+                    WHERE id = $1
+                    FOR UPDATE
+                    "#,
+                    id.0
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    OrganisationRow,
+                    r#"
+                    SELECT id, name, slug, owner_id, status, plan,
+                           max_instances, max_users, max_storage_gb,
+                           created_at, updated_at, deleted_at
+                    FROM organisations
+                    WHERE id = $1
+                    FOR UPDATE
+                    "#,
+                    id.0
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to lock organisation by id: {}", e),
+        })?;
+
+        row.map(|r| r.into_organisation()).transpose()
+    }
+
     async fn find_by_slug(
         &self,
         slug: &OrganisationSlug,
@@ -528,7 +648,8 @@ impl OrganisationRepository for PostgresOrganisationRepository<'_, '_> {
                         max_users = $7,
                         max_storage_gb = $8,
                         updated_at = $9,
-                        deleted_at = $10
+                        deleted_at = $10,
+                        owner_id = $11
                     WHERE id = $1
                     "#,
                     organisation.id.0,
@@ -541,6 +662,7 @@ impl OrganisationRepository for PostgresOrganisationRepository<'_, '_> {
                     organisation.limits.max_storage_gb as i32,
                     now,
                     organisation.deleted_at,
+                    organisation.owner_id.0,
                 )
                 .execute(*pool)
                 .await
@@ -561,7 +683,8 @@ impl OrganisationRepository for PostgresOrganisationRepository<'_, '_> {
                         max_users = $7,
                         max_storage_gb = $8,
                         updated_at = $9,
-                        deleted_at = $10
+                        deleted_at = $10,
+                        owner_id = $11
                     WHERE id = $1
                     "#,
                     organisation.id.0,
@@ -574,6 +697,7 @@ impl OrganisationRepository for PostgresOrganisationRepository<'_, '_> {
                     organisation.limits.max_storage_gb as i32,
                     now,
                     organisation.deleted_at,
+                    organisation.owner_id.0,
                 )
                 .execute(transaction.as_mut())
                 .await
@@ -736,6 +860,570 @@ impl OrganisationRepository for PostgresOrganisationRepository<'_, '_> {
 
         Ok(count as usize)
     }
+
+    async fn list_members(
+        &self,
+        organisation_id: &OrganisationId,
+    ) -> Result<Vec<Member>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    MemberRow,
+                    r#"
+                    SELECT m.id, m.organisation_id, m.user_id, u.email, u.name, m.created_at,
+                           COALESCE(
+                               ARRAY_AGG(mr.role_id ORDER BY mr.created_at)
+                                   FILTER (WHERE mr.role_id IS NOT NULL),
+                               '{}'
+                           ) AS "role_ids!"
+                    FROM members m
+                    INNER JOIN users u ON u.id = m.user_id
+                    LEFT JOIN member_roles mr ON mr.member_id = m.id
+                    WHERE m.organisation_id = $1
+                    GROUP BY m.id, u.email, u.name
+                    ORDER BY m.created_at
+                    "#,
+                    organisation_id.0,
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    MemberRow,
+                    r#"
+                    SELECT m.id, m.organisation_id, m.user_id, u.email, u.name, m.created_at,
+                           COALESCE(
+                               ARRAY_AGG(mr.role_id ORDER BY mr.created_at)
+                                   FILTER (WHERE mr.role_id IS NOT NULL),
+                               '{}'
+                           ) AS "role_ids!"
+                    FROM members m
+                    INNER JOIN users u ON u.id = m.user_id
+                    LEFT JOIN member_roles mr ON mr.member_id = m.id
+                    WHERE m.organisation_id = $1
+                    GROUP BY m.id, u.email, u.name
+                    ORDER BY m.created_at
+                    "#,
+                    organisation_id.0,
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to list organisation members: {}", e),
+        })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_member(
+        &self,
+        organisation_id: &OrganisationId,
+        member_id: &MemberId,
+    ) -> Result<Option<Member>, CoreError> {
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    MemberRow,
+                    r#"
+                    SELECT m.id, m.organisation_id, m.user_id, u.email, u.name, m.created_at,
+                           COALESCE(
+                               ARRAY_AGG(mr.role_id ORDER BY mr.created_at)
+                                   FILTER (WHERE mr.role_id IS NOT NULL),
+                               '{}'
+                           ) AS "role_ids!"
+                    FROM members m
+                    INNER JOIN users u ON u.id = m.user_id
+                    LEFT JOIN member_roles mr ON mr.member_id = m.id
+                    WHERE m.organisation_id = $1 AND m.id = $2
+                    GROUP BY m.id, u.email, u.name
+                    "#,
+                    organisation_id.0,
+                    member_id.0,
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    MemberRow,
+                    r#"
+                    SELECT m.id, m.organisation_id, m.user_id, u.email, u.name, m.created_at,
+                           COALESCE(
+                               ARRAY_AGG(mr.role_id ORDER BY mr.created_at)
+                                   FILTER (WHERE mr.role_id IS NOT NULL),
+                               '{}'
+                           ) AS "role_ids!"
+                    FROM members m
+                    INNER JOIN users u ON u.id = m.user_id
+                    LEFT JOIN member_roles mr ON mr.member_id = m.id
+                    WHERE m.organisation_id = $1 AND m.id = $2
+                    GROUP BY m.id, u.email, u.name
+                    "#,
+                    organisation_id.0,
+                    member_id.0,
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to find organisation member: {}", e),
+        })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_member_by_user(
+        &self,
+        organisation_id: &OrganisationId,
+        user_id: &UserId,
+    ) -> Result<Option<Member>, CoreError> {
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    MemberRow,
+                    r#"
+                    SELECT m.id, m.organisation_id, m.user_id, u.email, u.name, m.created_at,
+                           COALESCE(
+                               ARRAY_AGG(mr.role_id ORDER BY mr.created_at)
+                                   FILTER (WHERE mr.role_id IS NOT NULL),
+                               '{}'
+                           ) AS "role_ids!"
+                    FROM members m
+                    INNER JOIN users u ON u.id = m.user_id
+                    LEFT JOIN member_roles mr ON mr.member_id = m.id
+                    WHERE m.organisation_id = $1 AND m.user_id = $2
+                    GROUP BY m.id, u.email, u.name
+                    "#,
+                    organisation_id.0,
+                    user_id.0,
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    MemberRow,
+                    r#"
+                    SELECT m.id, m.organisation_id, m.user_id, u.email, u.name, m.created_at,
+                           COALESCE(
+                               ARRAY_AGG(mr.role_id ORDER BY mr.created_at)
+                                   FILTER (WHERE mr.role_id IS NOT NULL),
+                               '{}'
+                           ) AS "role_ids!"
+                    FROM members m
+                    INNER JOIN users u ON u.id = m.user_id
+                    LEFT JOIN member_roles mr ON mr.member_id = m.id
+                    WHERE m.organisation_id = $1 AND m.user_id = $2
+                    GROUP BY m.id, u.email, u.name
+                    "#,
+                    organisation_id.0,
+                    user_id.0,
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to find organisation member by user: {}", e),
+        })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn count_members(&self, organisation_id: &OrganisationId) -> Result<usize, CoreError> {
+        let count = match &self.executor {
+            PgExecutor::Pool(pool) => sqlx::query!(
+                r#"
+                    SELECT COUNT(*) as "count!" FROM members WHERE organisation_id = $1
+                    "#,
+                organisation_id.0,
+            )
+            .fetch_one(*pool)
+            .await
+            .map(|row| row.count),
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    SELECT COUNT(*) as "count!" FROM members WHERE organisation_id = $1
+                    "#,
+                    organisation_id.0,
+                )
+                .fetch_one(transaction.as_mut())
+                .await
+                .map(|row| row.count)
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to count organisation members: {}", e),
+        })?;
+
+        Ok(count as usize)
+    }
+
+    async fn delete_member(&self, member_id: &MemberId) -> Result<(), CoreError> {
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    WITH removed AS (
+                        DELETE FROM members WHERE id = $1
+                        RETURNING user_id, organisation_id
+                    )
+                    UPDATE api_keys
+                    SET revoked_at = NOW()
+                    FROM removed
+                    WHERE api_keys.organisation_id = removed.organisation_id
+                      AND api_keys.user_id = removed.user_id
+                      AND api_keys.revoked_at IS NULL
+                    "#,
+                    member_id.0,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    WITH removed AS (
+                        DELETE FROM members WHERE id = $1
+                        RETURNING user_id, organisation_id
+                    )
+                    UPDATE api_keys
+                    SET revoked_at = NOW()
+                    FROM removed
+                    WHERE api_keys.organisation_id = removed.organisation_id
+                      AND api_keys.user_id = removed.user_id
+                      AND api_keys.revoked_at IS NULL
+                    "#,
+                    member_id.0,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to delete organisation member: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn set_member_roles(
+        &self,
+        member_id: &MemberId,
+        role_ids: Vec<RoleId>,
+    ) -> Result<(), CoreError> {
+        let role_ids = role_ids.into_iter().map(|id| id.0).collect::<Vec<Uuid>>();
+
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    WITH removed AS (
+                        DELETE FROM member_roles
+                        WHERE member_id = $1 AND role_id <> ALL($2::uuid[])
+                    )
+                    INSERT INTO member_roles (member_id, role_id)
+                    SELECT $1, role_id FROM UNNEST($2::uuid[]) AS role_id
+                    ON CONFLICT (member_id, role_id) DO NOTHING
+                    "#,
+                    member_id.0,
+                    &role_ids,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    WITH removed AS (
+                        DELETE FROM member_roles
+                        WHERE member_id = $1 AND role_id <> ALL($2::uuid[])
+                    )
+                    INSERT INTO member_roles (member_id, role_id)
+                    SELECT $1, role_id FROM UNNEST($2::uuid[]) AS role_id
+                    ON CONFLICT (member_id, role_id) DO NOTHING
+                    "#,
+                    member_id.0,
+                    &role_ids,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to set member roles: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn record_status_change(
+        &self,
+        change: &OrganisationStatusChange,
+    ) -> Result<(), CoreError> {
+        let (actor_type, actor_user_id, actor_client_id) = source_to_row(&change.actor);
+
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO organisation_status_history (
+                        id, organisation_id, from_status, to_status,
+                        actor_type, actor_user_id, actor_client_id, reason, changed_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                    "#,
+                    change.id,
+                    change.organisation_id.0,
+                    change.from_status.to_string(),
+                    change.to_status.to_string(),
+                    actor_type,
+                    actor_user_id,
+                    actor_client_id,
+                    change.reason,
+                    change.changed_at,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    INSERT INTO organisation_status_history (
+                        id, organisation_id, from_status, to_status,
+                        actor_type, actor_user_id, actor_client_id, reason, changed_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                    "#,
+                    change.id,
+                    change.organisation_id.0,
+                    change.from_status.to_string(),
+                    change.to_status.to_string(),
+                    actor_type,
+                    actor_user_id,
+                    actor_client_id,
+                    change.reason,
+                    change.changed_at,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to record organisation status change: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn record_ownership_transfer(
+        &self,
+        transfer: &OwnershipTransfer,
+    ) -> Result<(), CoreError> {
+        let (actor_type, actor_user_id, actor_client_id) = source_to_row(&transfer.actor);
+
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO organisation_ownership_history (
+                        id, organisation_id, from_owner_id, to_owner_id,
+                        actor_type, actor_user_id, actor_client_id, transferred_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                    "#,
+                    transfer.id,
+                    transfer.organisation_id.0,
+                    transfer.from_owner_id.0,
+                    transfer.to_owner_id.0,
+                    actor_type,
+                    actor_user_id,
+                    actor_client_id,
+                    transfer.transferred_at,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    INSERT INTO organisation_ownership_history (
+                        id, organisation_id, from_owner_id, to_owner_id,
+                        actor_type, actor_user_id, actor_client_id, transferred_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                    "#,
+                    transfer.id,
+                    transfer.organisation_id.0,
+                    transfer.from_owner_id.0,
+                    transfer.to_owner_id.0,
+                    actor_type,
+                    actor_user_id,
+                    actor_client_id,
+                    transfer.transferred_at,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to record organisation ownership transfer: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn record_plan_change(&self, change: &PlanChange) -> Result<(), CoreError> {
+        let (actor_type, actor_user_id, actor_client_id) = source_to_row(&change.actor);
+
+        match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO organisation_plan_history (
+                        id, organisation_id, from_plan, to_plan,
+                        max_instances, max_users, max_storage_gb,
+                        actor_type, actor_user_id, actor_client_id, changed_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                    "#,
+                    change.id,
+                    change.organisation_id.0,
+                    change.from_plan.to_string(),
+                    change.to_plan.to_string(),
+                    change.limits.max_instances as i32,
+                    change.limits.max_users as i32,
+                    change.limits.max_storage_gb as i32,
+                    actor_type,
+                    actor_user_id,
+                    actor_client_id,
+                    change.changed_at,
+                )
+                .execute(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query!(
+                    r#"
+                    INSERT INTO organisation_plan_history (
+                        id, organisation_id, from_plan, to_plan,
+                        max_instances, max_users, max_storage_gb,
+                        actor_type, actor_user_id, actor_client_id, changed_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                    "#,
+                    change.id,
+                    change.organisation_id.0,
+                    change.from_plan.to_string(),
+                    change.to_plan.to_string(),
+                    change.limits.max_instances as i32,
+                    change.limits.max_users as i32,
+                    change.limits.max_storage_gb as i32,
+                    actor_type,
+                    actor_user_id,
+                    actor_client_id,
+                    change.changed_at,
+                )
+                .execute(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to record organisation plan change: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn list_plan_history(
+        &self,
+        organisation_id: &OrganisationId,
+    ) -> Result<Vec<PlanChange>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    PlanChangeRow,
+                    r#"
+                    SELECT id, organisation_id, from_plan, to_plan,
+                           max_instances, max_users, max_storage_gb,
+                           actor_type, actor_user_id, actor_client_id, changed_at
+                    FROM organisation_plan_history
+                    WHERE organisation_id = $1
+                    ORDER BY changed_at ASC, id ASC
+                    "#,
+                    organisation_id.0
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    PlanChangeRow,
+                    r#"
+                    SELECT id, organisation_id, from_plan, to_plan,
+                           max_instances, max_users, max_storage_gb,
+                           actor_type, actor_user_id, actor_client_id, changed_at
+                    FROM organisation_plan_history
+                    WHERE organisation_id = $1
+                    ORDER BY changed_at ASC, id ASC
+                    "#,
+                    organisation_id.0
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to list organisation plan history: {}", e),
+        })?;
+
+        rows.into_iter().map(|r| r.into_plan_change()).collect()
+    }
 }
 
 #[cfg(test)]
@@ -808,4 +1496,34 @@ mod tests {
         let err = row.into_organisation().unwrap_err();
         assert!(matches!(err, CoreError::InvalidOrganisationSlug { .. }));
     }
+
+    #[test]
+    fn plan_change_row_into_plan_change_maps_fields() {
+        let row = PlanChangeRow {
+            id: Uuid::new_v4(),
+            organisation_id: Uuid::parse_str("dddddddd-dddd-dddd-dddd-dddddddddddd").unwrap(),
+            from_plan: "starter".to_string(),
+            to_plan: "enterprise".to_string(),
+            max_instances: 500,
+            max_users: 200,
+            max_storage_gb: 1000,
+            actor_type: "api".to_string(),
+            actor_user_id: None,
+            actor_client_id: Some("billing-service".to_string()),
+            changed_at: sample_time(),
+        };
+
+        let change = row.into_plan_change().unwrap();
+
+        assert_eq!(change.from_plan.to_string(), "starter");
+        assert_eq!(change.to_plan.to_string(), "enterprise");
+        assert_eq!(change.limits, OrganisationLimits::custom(500, 200, 1000));
+        assert_eq!(
+            change.actor,
+            aether_domain::action::ActionSource::Api {
+                client_id: "billing-service".to_string()
+            }
+        );
+        assert_eq!(change.changed_at, sample_time());
+    }
 }
@@ -6,6 +6,7 @@ use aether_domain::{
     CoreError,
     organisation::OrganisationId,
     role::{Role, RoleId, ports::RoleRepository},
+    user::UserId,
 };
 use aether_persistence::{PgExecutor, PgTransaction};
 
@@ -248,6 +249,137 @@ impl RoleRepository for PostgresRoleRepository<'_, '_> {
         Ok(rows.into_iter().map(RoleRow::into_role).collect())
     }
 
+    async fn list_granted_to_subject(
+        &self,
+        organisation_id: OrganisationId,
+        sub: String,
+        role_names: Vec<String>,
+    ) -> Result<Vec<Role>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    RoleRow,
+                    r#"
+                    SELECT id, name, permissions, organisation_id, color, created_at
+                    FROM roles
+                    WHERE (organisation_id = $1 AND name = ANY($3))
+                       OR id IN (
+                           SELECT member_roles.role_id
+                           FROM member_roles
+                           JOIN members ON members.id = member_roles.member_id
+                           JOIN users ON users.id = members.user_id
+                           WHERE members.organisation_id = $1
+                             AND users.sub = $2
+                       )
+                    ORDER BY created_at DESC
+                    "#,
+                    organisation_id.0,
+                    sub,
+                    &role_names
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    RoleRow,
+                    r#"
+                    SELECT id, name, permissions, organisation_id, color, created_at
+                    FROM roles
+                    WHERE (organisation_id = $1 AND name = ANY($3))
+                       OR id IN (
+                           SELECT member_roles.role_id
+                           FROM member_roles
+                           JOIN members ON members.id = member_roles.member_id
+                           JOIN users ON users.id = members.user_id
+                           WHERE members.organisation_id = $1
+                             AND users.sub = $2
+                       )
+                    ORDER BY created_at DESC
+                    "#,
+                    organisation_id.0,
+                    sub,
+                    &role_names
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to list roles granted to user: {}", e),
+        })?;
+
+        Ok(rows.into_iter().map(RoleRow::into_role).collect())
+    }
+
+    async fn list_granted_to_user(
+        &self,
+        organisation_id: OrganisationId,
+        user_id: UserId,
+    ) -> Result<Vec<Role>, CoreError> {
+        let rows = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    RoleRow,
+                    r#"
+                    SELECT id, name, permissions, organisation_id, color, created_at
+                    FROM roles
+                    WHERE (organisation_id = $1
+                           AND name = ANY(SELECT unnest(users.roles) FROM users WHERE users.id = $2))
+                       OR id IN (
+                           SELECT member_roles.role_id
+                           FROM member_roles
+                           JOIN members ON members.id = member_roles.member_id
+                           WHERE members.organisation_id = $1
+                             AND members.user_id = $2
+                       )
+                    ORDER BY created_at DESC
+                    "#,
+                    organisation_id.0,
+                    user_id.0
+                )
+                .fetch_all(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    RoleRow,
+                    r#"
+                    SELECT id, name, permissions, organisation_id, color, created_at
+                    FROM roles
+                    WHERE (organisation_id = $1
+                           AND name = ANY(SELECT unnest(users.roles) FROM users WHERE users.id = $2))
+                       OR id IN (
+                           SELECT member_roles.role_id
+                           FROM member_roles
+                           JOIN members ON members.id = member_roles.member_id
+                           WHERE members.organisation_id = $1
+                             AND members.user_id = $2
+                       )
+                    ORDER BY created_at DESC
+                    "#,
+                    organisation_id.0,
+                    user_id.0
+                )
+                .fetch_all(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to list roles granted to user: {}", e),
+        })?;
+
+        Ok(rows.into_iter().map(RoleRow::into_role).collect())
+    }
+
     async fn update(&self, role: Role) -> Result<(), CoreError> {
         match &self.executor {
             PgExecutor::Pool(pool) => {
@@ -14,6 +14,7 @@ struct UserRow {
     email: String,
     name: String,
     sub: String,
+    roles: Vec<String>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -25,7 +26,7 @@ impl From<UserRow> for User {
             email: row.email,
             name: row.name,
             sub: row.sub,
-
+            roles: row.roles,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
@@ -57,28 +58,35 @@ impl<'e> PostgresUserRepository<'e, 'e> {
 
 #[cfg_attr(coverage_nightly, coverage(off))]
 impl UserRepository for PostgresUserRepository<'_, '_> {
-    async fn upsert_by_email(&self, user: &User) -> Result<User, CoreError> {
+    async fn upsert_by_email(&self, user: &User) -> Result<Option<User>, CoreError> {
         let now = Utc::now();
 
+        // Subjects are namespaced as `<issuer>#<sub>` (see `Identity::namespaced`), so an
+        // account is only handed to a new subject of the issuer that registered it
+
         let row = match &self.executor {
             PgExecutor::Pool(pool) => {
                 sqlx::query_as!(
                     UserRow,
                     r#"
-                    INSERT INTO users (id, email, name, sub, created_at, updated_at)
-                    VALUES ($1, $2, $3, $4, $5, $6)
+                    INSERT INTO users (id, email, name, sub, roles, created_at, updated_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
                     ON CONFLICT (email)
-                    DO UPDATE SET name = EXCLUDED.name, sub = EXCLUDED.sub, updated_at = EXCLUDED.updated_at
-                    RETURNING id, email, name, sub, created_at, updated_at
+                    DO UPDATE SET name = EXCLUDED.name, sub = EXCLUDED.sub, roles = EXCLUDED.roles,
+                                  updated_at = EXCLUDED.updated_at
+                    WHERE substring(users.sub FROM '^[^#]*#')
+                          IS NOT DISTINCT FROM substring(EXCLUDED.sub FROM '^[^#]*#')
+                    RETURNING id, email, name, sub, roles, created_at, updated_at
                     "#,
                     user.id.0,
                     user.email,
                     user.name,
                     user.sub,
+                    &user.roles,
                     now,
                     now,
                 )
-                .fetch_one(*pool)
+                .fetch_optional(*pool)
                 .await
             }
             PgExecutor::Tx(tx) => {
@@ -89,20 +97,24 @@ impl UserRepository for PostgresUserRepository<'_, '_> {
                 sqlx::query_as!(
                     UserRow,
                     r#"
-                    INSERT INTO users (id, email, name, sub, created_at, updated_at)
-                    VALUES ($1, $2, $3, $4, $5, $6)
+                    INSERT INTO users (id, email, name, sub, roles, created_at, updated_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
                     ON CONFLICT (email)
-                    DO UPDATE SET name = EXCLUDED.name, sub = EXCLUDED.sub, updated_at = EXCLUDED.updated_at
-                    RETURNING id, email, name, sub, created_at, updated_at
+                    DO UPDATE SET name = EXCLUDED.name, sub = EXCLUDED.sub, roles = EXCLUDED.roles,
+                                  updated_at = EXCLUDED.updated_at
+                    WHERE substring(users.sub FROM '^[^#]*#')
+                          IS NOT DISTINCT FROM substring(EXCLUDED.sub FROM '^[^#]*#')
+                    RETURNING id, email, name, sub, roles, created_at, updated_at
                     "#,
                     user.id.0,
                     user.email,
                     user.name,
                     user.sub,
+                    &user.roles,
                     now,
                     now,
                 )
-                .fetch_one(transaction.as_mut())
+                .fetch_optional(transaction.as_mut())
                 .await
             }
         }
@@ -110,7 +122,63 @@ impl UserRepository for PostgresUserRepository<'_, '_> {
             message: format!("Failed to upsert user: {}", e),
         })?;
 
-        Ok(row.into())
+        Ok(row.map(User::from))
+    }
+
+    async fn insert(&self, user: &User) -> Result<Option<User>, CoreError> {
+        let now = Utc::now();
+
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    UserRow,
+                    r#"
+                    INSERT INTO users (id, email, name, sub, roles, created_at, updated_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                    ON CONFLICT DO NOTHING
+                    RETURNING id, email, name, sub, roles, created_at, updated_at
+                    "#,
+                    user.id.0,
+                    user.email,
+                    user.name,
+                    user.sub,
+                    &user.roles,
+                    now,
+                    now,
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    UserRow,
+                    r#"
+                    INSERT INTO users (id, email, name, sub, roles, created_at, updated_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                    ON CONFLICT DO NOTHING
+                    RETURNING id, email, name, sub, roles, created_at, updated_at
+                    "#,
+                    user.id.0,
+                    user.email,
+                    user.name,
+                    user.sub,
+                    &user.roles,
+                    now,
+                    now,
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to insert user: {}", e),
+        })?;
+
+        Ok(row.map(User::from))
     }
 
     async fn find_by_sub(&self, sub: &str) -> Result<Option<User>, CoreError> {
@@ -119,7 +187,7 @@ impl UserRepository for PostgresUserRepository<'_, '_> {
                 sqlx::query_as!(
                     UserRow,
                     r#"
-                    SELECT id, email, name, sub, created_at, updated_at
+                    SELECT id, email, name, sub, roles, created_at, updated_at
                     FROM users
                     WHERE sub = $1
                     "#,
@@ -136,7 +204,7 @@ impl UserRepository for PostgresUserRepository<'_, '_> {
                 sqlx::query_as!(
                     UserRow,
                     r#"
-                    SELECT id, email, name, sub, created_at, updated_at
+                    SELECT id, email, name, sub, roles, created_at, updated_at
                     FROM users
                     WHERE sub = $1
                     "#,
@@ -152,4 +220,44 @@ impl UserRepository for PostgresUserRepository<'_, '_> {
 
         Ok(row.map(Into::into))
     }
+
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, CoreError> {
+        let row = match &self.executor {
+            PgExecutor::Pool(pool) => {
+                sqlx::query_as!(
+                    UserRow,
+                    r#"
+                    SELECT id, email, name, sub, roles, created_at, updated_at
+                    FROM users
+                    WHERE id = $1
+                    "#,
+                    id.0
+                )
+                .fetch_optional(*pool)
+                .await
+            }
+            PgExecutor::Tx(tx) => {
+                let mut guard = tx.lock().await;
+                let transaction = guard
+                    .as_mut()
+                    .ok_or_else(|| CoreError::InternalError("Transaction missing".to_string()))?;
+                sqlx::query_as!(
+                    UserRow,
+                    r#"
+                    SELECT id, email, name, sub, roles, created_at, updated_at
+                    FROM users
+                    WHERE id = $1
+                    "#,
+                    id.0
+                )
+                .fetch_optional(transaction.as_mut())
+                .await
+            }
+        }
+        .map_err(|e| CoreError::DatabaseError {
+            message: format!("Failed to find user by id: {}", e),
+        })?;
+
+        Ok(row.map(Into::into))
+    }
 }
@@ -0,0 +1,360 @@
+use aether_domain::action::{
+    Action, ActionConstraints, ActionFailureReason, ActionId, ActionMetadata, ActionPayload,
+    ActionSource, ActionStatus, ActionTarget, ActionType, ActionVersion, TargetKind,
+    ports::ActionRepository,
+};
+use aether_domain::dataplane::value_objects::DataPlaneId;
+use aether_domain::deployments::DeploymentId;
+use aether_postgres::action::PostgresActionRepository;
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Barrier;
+use uuid::Uuid;
+
+async fn seed_deployment(pool: &PgPool) -> (DeploymentId, DataPlaneId) {
+    let user_id = Uuid::new_v4();
+    let organisation_id = Uuid::new_v4();
+    let dataplane_id = Uuid::new_v4();
+    let deployment_id = Uuid::new_v4();
+
+    sqlx::query("INSERT INTO users (id, email, name, sub) VALUES ($1, 'owner@example.com', 'Owner', 'owner')")
+        .bind(user_id)
+        .execute(pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO organisations (id, name, slug, owner_id, status, plan, max_instances, max_users, max_storage_gb)
+         VALUES ($1, 'Acme', 'acme', $2, 'active', 'free', 1, 1, 1)",
+    )
+    .bind(organisation_id)
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO data_planes (id, mode, region, status, capacity)
+         VALUES ($1, 'shared', 'eu', 'active', 10)",
+    )
+    .bind(dataplane_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO deployments (id, organisation_id, dataplane_id, name, kind, status, namespace, created_by)
+         VALUES ($1, $2, $3, 'app', 'keycloak', 'pending', 'default', $4)",
+    )
+    .bind(deployment_id)
+    .bind(organisation_id)
+    .bind(dataplane_id)
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .unwrap();
+
+    (DeploymentId(deployment_id), DataPlaneId(dataplane_id))
+}
+
+async fn append_pending(
+    repository: &PostgresActionRepository<'_, '_>,
+    deployment_id: DeploymentId,
+    dataplane_id: DataPlaneId,
+    priority: Option<u8>,
+    waited_seconds: i64,
+) -> ActionId {
+    append_for_target(
+        repository,
+        deployment_id,
+        dataplane_id,
+        Uuid::new_v4(),
+        priority,
+        waited_seconds,
+    )
+    .await
+}
+
+async fn append_for_target(
+    repository: &PostgresActionRepository<'_, '_>,
+    deployment_id: DeploymentId,
+    dataplane_id: DataPlaneId,
+    target_id: Uuid,
+    priority: Option<u8>,
+    waited_seconds: i64,
+) -> ActionId {
+    let action = Action {
+        id: ActionId(Uuid::new_v4()),
+        deployment_id,
+        dataplane_id,
+        action_type: ActionType("deployment.update".to_string()),
+        target: ActionTarget {
+            kind: TargetKind::Deployment,
+            id: target_id,
+        },
+        payload: ActionPayload {
+            data: serde_json::json!({}),
+        },
+        version: ActionVersion(1),
+        status: ActionStatus::Pending,
+        metadata: ActionMetadata {
+            source: ActionSource::System,
+            created_at: Utc::now() - Duration::seconds(waited_seconds),
+            constraints: ActionConstraints {
+                not_after: None,
+                priority,
+            },
+        },
+        leased_until: None,
+    };
+    let id = action.id;
+    repository.append(action).await.unwrap();
+    id
+}
+
+async fn claim_ids(
+    repository: &PostgresActionRepository<'_, '_>,
+    deployment_id: DeploymentId,
+    dataplane_id: DataPlaneId,
+) -> Vec<ActionId> {
+    repository
+        .claim_pending(
+            deployment_id,
+            dataplane_id,
+            "herald".to_string(),
+            10,
+            Utc::now() + Duration::seconds(30),
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|action| action.id)
+        .collect()
+}
+
+#[sqlx::test(migrations = "../aether-core/migrations")]
+#[ignore = "needs a Postgres server in DATABASE_URL"]
+async fn claim_pending_orders_by_priority_then_age(pool: PgPool) {
+    let (deployment_id, dataplane_id) = seed_deployment(&pool).await;
+    let repository = PostgresActionRepository::from_pool(&pool);
+
+    let newer_default = append_pending(&repository, deployment_id, dataplane_id, None, 10).await;
+    let bulk = append_pending(&repository, deployment_id, dataplane_id, Some(200), 60).await;
+    let older_default = append_pending(&repository, deployment_id, dataplane_id, None, 120).await;
+    let urgent = append_pending(&repository, deployment_id, dataplane_id, Some(0), 5).await;
+
+    assert_eq!(
+        claim_ids(&repository, deployment_id, dataplane_id).await,
+        vec![urgent, older_default, newer_default, bulk]
+    );
+}
+
+#[sqlx::test(migrations = "../aether-core/migrations")]
+#[ignore = "needs a Postgres server in DATABASE_URL"]
+async fn claim_pending_promotes_starved_low_priority_action(pool: PgPool) {
+    let (deployment_id, dataplane_id) = seed_deployment(&pool).await;
+    let repository = PostgresActionRepository::from_pool(&pool);
+    let aging = ActionConstraints::PRIORITY_AGING_SECONDS;
+
+    let urgent = append_pending(&repository, deployment_id, dataplane_id, Some(1), 0).await;
+    let starved = append_pending(
+        &repository,
+        deployment_id,
+        dataplane_id,
+        Some(200),
+        aging * 200,
+    )
+    .await;
+
+    assert_eq!(
+        claim_ids(&repository, deployment_id, dataplane_id).await,
+        vec![starved, urgent]
+    );
+}
+
+async fn settle(
+    repository: &PostgresActionRepository<'_, '_>,
+    deployment_id: DeploymentId,
+    action_id: ActionId,
+    status: ActionStatus,
+) {
+    repository
+        .transition_status(
+            deployment_id,
+            action_id,
+            ActionStatus::Pulled {
+                agent_id: "herald".to_string(),
+                at: Utc::now(),
+            },
+            status,
+            "herald".to_string(),
+        )
+        .await
+        .unwrap()
+        .expect("the action should be pulled");
+}
+
+#[sqlx::test(migrations = "../aether-core/migrations")]
+#[ignore = "needs a Postgres server in DATABASE_URL"]
+async fn claim_pending_withholds_next_action_of_a_target_until_the_head_settles(pool: PgPool) {
+    let (deployment_id, dataplane_id) = seed_deployment(&pool).await;
+    let repository = PostgresActionRepository::from_pool(&pool);
+    let target = Uuid::new_v4();
+
+    let create =
+        append_for_target(&repository, deployment_id, dataplane_id, target, None, 30).await;
+    let update = append_for_target(
+        &repository,
+        deployment_id,
+        dataplane_id,
+        target,
+        Some(0),
+        20,
+    )
+    .await;
+    let delete = append_for_target(
+        &repository,
+        deployment_id,
+        dataplane_id,
+        target,
+        Some(0),
+        10,
+    )
+    .await;
+
+    assert_eq!(
+        claim_ids(&repository, deployment_id, dataplane_id).await,
+        vec![create]
+    );
+    assert!(
+        claim_ids(&repository, deployment_id, dataplane_id)
+            .await
+            .is_empty()
+    );
+
+    settle(
+        &repository,
+        deployment_id,
+        create,
+        ActionStatus::Published { at: Utc::now() },
+    )
+    .await;
+    assert_eq!(
+        claim_ids(&repository, deployment_id, dataplane_id).await,
+        vec![update]
+    );
+
+    settle(
+        &repository,
+        deployment_id,
+        update,
+        ActionStatus::Failed {
+            reason: ActionFailureReason::InvalidPayload,
+            at: Utc::now(),
+        },
+    )
+    .await;
+    assert_eq!(
+        claim_ids(&repository, deployment_id, dataplane_id).await,
+        vec![delete]
+    );
+}
+
+/// What the claimers of the concurrency test observed
+#[derive(Default)]
+struct ClaimLog {
+    claimed: HashSet<ActionId>,
+    settled: HashSet<(Uuid, i64)>,
+    violations: Vec<String>,
+}
+
+#[sqlx::test(migrations = "../aether-core/migrations")]
+#[ignore = "needs a Postgres server in DATABASE_URL"]
+async fn concurrent_claims_hand_out_each_action_once_and_in_target_order(pool: PgPool) {
+    const TARGETS: usize = 100;
+    const ACTIONS_PER_TARGET: i64 = 3;
+    const CLAIMERS: usize = 16;
+
+    let (deployment_id, dataplane_id) = seed_deployment(&pool).await;
+    let repository = PostgresActionRepository::from_pool(&pool);
+
+    let mut queues: HashMap<ActionId, (Uuid, i64)> = HashMap::new();
+    for _ in 0..TARGETS {
+        let target = Uuid::new_v4();
+        for position in 0..ACTIONS_PER_TARGET {
+            let waited = (ACTIONS_PER_TARGET - position) * 10;
+            let id = append_for_target(
+                &repository,
+                deployment_id,
+                dataplane_id,
+                target,
+                None,
+                waited,
+            )
+            .await;
+            queues.insert(id, (target, position));
+        }
+    }
+
+    let total = queues.len();
+    let queues = Arc::new(queues);
+    let log = Arc::new(Mutex::new(ClaimLog::default()));
+    let barrier = Arc::new(Barrier::new(CLAIMERS));
+
+    // Every claimer settles what it claimed right away, so claims keep overlapping commits
+    let claimers: Vec<_> = (0..CLAIMERS)
+        .map(|_| {
+            let pool = pool.clone();
+            let queues = queues.clone();
+            let log = log.clone();
+            let barrier = barrier.clone();
+            tokio::spawn(async move {
+                let repository = PostgresActionRepository::from_pool(&pool);
+                barrier.wait().await;
+
+                for _ in 0..500 {
+                    if log.lock().unwrap().settled.len() == total {
+                        break;
+                    }
+
+                    for id in claim_ids(&repository, deployment_id, dataplane_id).await {
+                        let (target, position) = queues[&id];
+                        {
+                            let mut log = log.lock().unwrap();
+                            if !log.claimed.insert(id) {
+                                log.violations.push(format!("{id:?} was claimed twice"));
+                            }
+                            if position > 0 && !log.settled.contains(&(target, position - 1)) {
+                                log.violations
+                                    .push(format!("{id:?} was claimed before its predecessor"));
+                            }
+                            // Recorded before the commit, which is what releases the next action
+                            log.settled.insert((target, position));
+                        }
+
+                        repository
+                            .transition_status(
+                                deployment_id,
+                                id,
+                                ActionStatus::Pulled {
+                                    agent_id: "herald".to_string(),
+                                    at: Utc::now(),
+                                },
+                                ActionStatus::Published { at: Utc::now() },
+                                "herald".to_string(),
+                            )
+                            .await
+                            .unwrap();
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for claimer in claimers {
+        claimer.await.unwrap();
+    }
+
+    let log = log.lock().unwrap();
+    assert!(log.violations.is_empty(), "{:?}", log.violations);
+    assert_eq!(log.claimed.len(), total);
+}
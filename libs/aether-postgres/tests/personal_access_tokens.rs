@@ -0,0 +1,194 @@
+use aether_domain::api_key::{ApiKey, ApiKeyOwner, ports::ApiKeyRepository};
+use aether_domain::organisation::{
+    OrganisationId, member::MemberId, ports::OrganisationRepository,
+};
+use aether_domain::role::ports::RoleRepository;
+use aether_domain::user::UserId;
+use aether_postgres::api_key::PostgresApiKeyRepository;
+use aether_postgres::organisation::PostgresOrganisationRepository;
+use aether_postgres::role::PostgresRoleRepository;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+struct Seed {
+    organisation_id: OrganisationId,
+    owner_id: UserId,
+    member_user_id: UserId,
+    member_id: MemberId,
+}
+
+async fn seed(pool: &PgPool) -> Seed {
+    let owner_id = Uuid::new_v4();
+    let member_user_id = Uuid::new_v4();
+    let organisation_id = Uuid::new_v4();
+    let member_id = Uuid::new_v4();
+    let role_id = Uuid::new_v4();
+
+    sqlx::query(
+        "INSERT INTO users (id, email, name, sub)
+         VALUES ($1, 'owner@example.com', 'Owner', 'owner'), ($2, 'jane@example.com', 'Jane', 'jane')",
+    )
+    .bind(owner_id)
+    .bind(member_user_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO organisations (id, name, slug, owner_id, status, plan, max_instances, max_users, max_storage_gb)
+         VALUES ($1, 'Acme', 'acme', $2, 'active', 'free', 1, 2, 1)",
+    )
+    .bind(organisation_id)
+    .bind(owner_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO members (id, user_id, organisation_id) VALUES ($1, $2, $3)")
+        .bind(member_id)
+        .bind(member_user_id)
+        .bind(organisation_id)
+        .execute(pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO roles (id, name, permissions, organisation_id) VALUES ($1, 'viewer', 1, $2)",
+    )
+    .bind(role_id)
+    .bind(organisation_id)
+    .execute(pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO member_roles (member_id, role_id) VALUES ($1, $2)")
+        .bind(member_id)
+        .bind(role_id)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    Seed {
+        organisation_id: OrganisationId(organisation_id),
+        owner_id: UserId(owner_id),
+        member_user_id: UserId(member_user_id),
+        member_id: MemberId(member_id),
+    }
+}
+
+#[sqlx::test(migrations = "../aether-core/migrations")]
+#[ignore = "needs a Postgres server in DATABASE_URL"]
+async fn list_granted_to_user_combines_stored_and_assigned_roles(pool: PgPool) {
+    let seed = seed(&pool).await;
+    sqlx::query(
+        "INSERT INTO roles (id, name, permissions, organisation_id) VALUES ($1, 'billing', 4096, $2)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(seed.organisation_id.0)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query("UPDATE users SET roles = ARRAY['billing'] WHERE id = $1")
+        .bind(seed.member_user_id.0)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let repository = PostgresRoleRepository::from_pool(&pool);
+
+    let roles = repository
+        .list_granted_to_user(seed.organisation_id, seed.member_user_id)
+        .await
+        .unwrap();
+    let mut names = roles
+        .iter()
+        .map(|role| role.name.as_str())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["billing", "viewer"]);
+
+    let not_member = repository
+        .list_granted_to_user(seed.organisation_id, seed.owner_id)
+        .await
+        .unwrap();
+    assert!(not_member.is_empty());
+}
+
+#[sqlx::test(migrations = "../aether-core/migrations")]
+#[ignore = "needs a Postgres server in DATABASE_URL"]
+async fn list_granted_to_subject_combines_named_and_assigned_roles(pool: PgPool) {
+    let seed = seed(&pool).await;
+    sqlx::query(
+        "INSERT INTO roles (id, name, permissions, organisation_id) VALUES ($1, 'billing', 4096, $2)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(seed.organisation_id.0)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let repository = PostgresRoleRepository::from_pool(&pool);
+
+    let roles = repository
+        .list_granted_to_subject(
+            seed.organisation_id,
+            "jane".to_string(),
+            vec!["billing".to_string()],
+        )
+        .await
+        .unwrap();
+    let mut names = roles
+        .iter()
+        .map(|role| role.name.as_str())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["billing", "viewer"]);
+
+    let not_member = repository
+        .list_granted_to_subject(seed.organisation_id, "owner".to_string(), vec![])
+        .await
+        .unwrap();
+    assert!(not_member.is_empty());
+}
+
+#[sqlx::test(migrations = "../aether-core/migrations")]
+#[ignore = "needs a Postgres server in DATABASE_URL"]
+async fn delete_member_revokes_personal_access_tokens(pool: PgPool) {
+    let seed = seed(&pool).await;
+    let api_keys = PostgresApiKeyRepository::from_pool(&pool);
+
+    let personal = ApiKey::new(
+        seed.organisation_id,
+        ApiKeyOwner::User {
+            user_id: seed.member_user_id,
+        },
+        "laptop".to_string(),
+        "ak_personal",
+        1,
+        seed.member_user_id,
+        None,
+    );
+    let shared = ApiKey::new(
+        seed.organisation_id,
+        ApiKeyOwner::Organisation,
+        "ci".to_string(),
+        "ak_shared",
+        1,
+        seed.member_user_id,
+        None,
+    );
+    api_keys.insert(&personal).await.unwrap();
+    api_keys.insert(&shared).await.unwrap();
+
+    PostgresOrganisationRepository::from_pool(&pool)
+        .delete_member(&seed.member_id)
+        .await
+        .unwrap();
+
+    let personal = api_keys
+        .find_by_id(&seed.organisation_id, &personal.id)
+        .await
+        .unwrap()
+        .unwrap();
+    let shared = api_keys
+        .find_by_id(&seed.organisation_id, &shared.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(personal.revoked_at.is_some());
+    assert!(shared.revoked_at.is_none());
+}
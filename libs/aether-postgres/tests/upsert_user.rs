@@ -0,0 +1,67 @@
+use aether_domain::user::{User, UserId, ports::UserRepository};
+use aether_postgres::user::PostgresUserRepository;
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+fn user(sub: &str, name: &str) -> User {
+    User {
+        id: UserId(Uuid::new_v4()),
+        email: "jane@example.com".to_string(),
+        name: name.to_string(),
+        sub: sub.to_string(),
+        roles: vec![],
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+#[sqlx::test(migrations = "../aether-core/migrations")]
+#[ignore = "needs a Postgres server in DATABASE_URL"]
+async fn upsert_by_email_refreshes_subject_of_the_same_issuer(pool: PgPool) {
+    let repository = PostgresUserRepository::from_pool(&pool);
+
+    let registered = repository
+        .upsert_by_email(&user("https://staff.test#old", "Jane"))
+        .await
+        .unwrap()
+        .unwrap();
+    let refreshed = repository
+        .upsert_by_email(&user("https://staff.test#new", "Jane Doe"))
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(refreshed.id, registered.id);
+    assert_eq!(refreshed.sub, "https://staff.test#new");
+    assert_eq!(refreshed.name, "Jane Doe");
+}
+
+#[sqlx::test(migrations = "../aether-core/migrations")]
+#[ignore = "needs a Postgres server in DATABASE_URL"]
+async fn upsert_by_email_keeps_account_of_another_issuer(pool: PgPool) {
+    let repository = PostgresUserRepository::from_pool(&pool);
+
+    let registered = repository
+        .upsert_by_email(&user("user-123", "Jane"))
+        .await
+        .unwrap()
+        .unwrap();
+
+    for sub in [
+        "https://customers.test#user-123",
+        "https://staff.test#other",
+    ] {
+        assert!(
+            repository
+                .upsert_by_email(&user(sub, "Mallory"))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    let kept = repository.find_by_sub("user-123").await.unwrap().unwrap();
+    assert_eq!(kept.id, registered.id);
+    assert_eq!(kept.name, "Jane");
+}
@@ -4,11 +4,16 @@ use crate::domain::ports::EventHandler;
 use std::sync::Arc;
 use tracing::{debug, warn};
 
-/// Routes an incoming [`ActionEvent`] to the first handler whose routing key matches.
+/// Routes an incoming [`ActionEvent`] to every handler whose routing key pattern matches.
 ///
-/// Matching rules (in order):
-/// 1. Exact match on `routing_key` (e.g. `"deployment.create"`)
-/// 2. Wildcard `"*"` matches any event
+/// Patterns follow AMQP topic exchange semantics on `.`-separated words:
+/// - `*` matches exactly one word (`deployment.*` matches `deployment.create`)
+/// - `#` matches zero or more words (`#.delete` matches `deployment.delete`)
+///
+/// A bare `*` is kept as a catch-all for compatibility with existing handlers.
+///
+/// Every matching handler runs even if an earlier one fails; failures are aggregated
+/// into the returned error.
 pub struct EventDispatcher {
     handlers: Vec<Arc<dyn EventHandler>>,
 }
@@ -18,25 +23,243 @@ impl EventDispatcher {
         Self { handlers }
     }
 
+    /// Binding keys a queue needs on the topic exchange to receive every event one of the
+    /// handlers matches. The bare `*` catch-all becomes `#`.
+    pub fn binding_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .handlers
+            .iter()
+            .map(|h| match h.routing_key() {
+                "*" => "#".to_string(),
+                pattern => pattern.to_string(),
+            })
+            .collect();
+        keys.sort();
+        keys.dedup();
+
+        keys
+    }
+
     pub async fn dispatch(&self, event: ActionEvent) -> Result<(), GenesisError> {
-        let matched = self.handlers.iter().find(|h| {
-            let key = h.routing_key();
-            key == "*" || key == event.routing_key
-        });
-
-        match matched {
-            Some(handler) => {
-                debug!(
+        let matched: Vec<_> = self
+            .handlers
+            .iter()
+            .filter(|h| {
+                let pattern = h.routing_key();
+                pattern == "*" || topic_matches(pattern, &event.routing_key)
+            })
+            .collect();
+
+        if matched.is_empty() {
+            warn!(routing_key = %event.routing_key, "no handler registered for routing key");
+            return Ok(());
+        }
+
+        let mut errors = Vec::new();
+
+        for handler in matched {
+            debug!(
+                routing_key = %event.routing_key,
+                pattern = %handler.routing_key(),
+                action_id = %event.action_id,
+                "dispatching event"
+            );
+
+            if let Err(e) = handler.handle(event.clone()).await {
+                warn!(
                     routing_key = %event.routing_key,
+                    pattern = %handler.routing_key(),
                     action_id = %event.action_id,
-                    "dispatching event"
+                    error = %e,
+                    "handler failed"
                 );
-                handler.handle(event).await
+                errors.push(e);
             }
-            None => {
-                warn!(routing_key = %event.routing_key, "no handler registered for routing key");
-                Ok(())
+        }
+
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(GenesisError::Multiple { errors }),
+        }
+    }
+}
+
+/// Returns whether `routing_key` matches the AMQP topic `pattern`.
+pub fn topic_matches(pattern: &str, routing_key: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split('.').collect();
+    let key: Vec<&str> = routing_key.split('.').collect();
+
+    words_match(&pattern, &key)
+}
+
+fn words_match(pattern: &[&str], key: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => key.is_empty(),
+        Some((&"#", rest)) => (0..=key.len()).any(|skip| words_match(rest, &key[skip..])),
+        Some((&word, rest)) => match key.split_first() {
+            Some((&first, key_rest)) => {
+                (word == "*" || word == first) && words_match(rest, key_rest)
             }
+            None => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ports::BoxFuture;
+    use chrono::Utc;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use uuid::Uuid;
+
+    struct RecordingHandler {
+        routing_key: String,
+        calls: AtomicUsize,
+        fail: bool,
+    }
+
+    impl RecordingHandler {
+        fn new(routing_key: &str, fail: bool) -> Arc<Self> {
+            Arc::new(Self {
+                routing_key: routing_key.to_string(),
+                calls: AtomicUsize::new(0),
+                fail,
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    impl EventHandler for RecordingHandler {
+        fn routing_key(&self) -> &str {
+            &self.routing_key
         }
+
+        fn handle<'a>(&'a self, _event: ActionEvent) -> BoxFuture<'a, Result<(), GenesisError>> {
+            Box::pin(async move {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                if self.fail {
+                    return Err(GenesisError::Handler {
+                        message: format!("{} failed", self.routing_key),
+                    });
+                }
+                Ok(())
+            })
+        }
+    }
+
+    fn event(routing_key: &str) -> ActionEvent {
+        ActionEvent {
+            action_id: Uuid::new_v4(),
+            routing_key: routing_key.to_string(),
+            payload: json!({}),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn topic_matching_follows_amqp_rules() {
+        assert!(topic_matches("deployment.create", "deployment.create"));
+        assert!(!topic_matches("deployment.create", "deployment.delete"));
+
+        assert!(topic_matches("deployment.*", "deployment.create"));
+        assert!(!topic_matches("deployment.*", "deployment"));
+        assert!(!topic_matches("deployment.*", "deployment.realm.create"));
+
+        assert!(topic_matches("#.delete", "deployment.delete"));
+        assert!(topic_matches("#.delete", "delete"));
+        assert!(topic_matches("#.delete", "deployment.realm.delete"));
+        assert!(!topic_matches("#.delete", "deployment.create"));
+
+        assert!(topic_matches("#", "deployment.create"));
+        assert!(topic_matches("deployment.#", "deployment"));
+        assert!(topic_matches("*.*", "deployment.create"));
+    }
+
+    #[test]
+    fn binding_keys_cover_every_handler_once() {
+        let dispatcher = EventDispatcher::new(vec![
+            RecordingHandler::new("deployment.create", false),
+            RecordingHandler::new("deployment.create", false),
+            RecordingHandler::new("#.delete", false),
+            RecordingHandler::new("*", false),
+        ]);
+
+        assert_eq!(
+            dispatcher.binding_keys(),
+            vec!["#", "#.delete", "deployment.create"]
+        );
+    }
+
+    #[tokio::test]
+    async fn dispatches_to_every_matching_handler() {
+        let executor = RecordingHandler::new("deployment.create", false);
+        let audit = RecordingHandler::new("#", false);
+        let other = RecordingHandler::new("deployment.delete", false);
+        let dispatcher = EventDispatcher::new(vec![executor.clone(), audit.clone(), other.clone()]);
+
+        dispatcher
+            .dispatch(event("deployment.create"))
+            .await
+            .unwrap();
+
+        assert_eq!(executor.calls(), 1);
+        assert_eq!(audit.calls(), 1);
+        assert_eq!(other.calls(), 0);
+    }
+
+    #[tokio::test]
+    async fn bare_wildcard_matches_any_event() {
+        let catch_all = RecordingHandler::new("*", false);
+        let dispatcher = EventDispatcher::new(vec![catch_all.clone()]);
+
+        dispatcher
+            .dispatch(event("deployment.create"))
+            .await
+            .unwrap();
+
+        assert_eq!(catch_all.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn aggregates_failures_without_short_circuiting() {
+        let first = RecordingHandler::new("deployment.*", true);
+        let second = RecordingHandler::new("#.create", true);
+        let third = RecordingHandler::new("#", false);
+        let dispatcher = EventDispatcher::new(vec![first.clone(), second.clone(), third.clone()]);
+
+        let result = dispatcher.dispatch(event("deployment.create")).await;
+
+        assert!(matches!(result, Err(GenesisError::Multiple { ref errors }) if errors.len() == 2));
+        assert_eq!(first.calls(), 1);
+        assert_eq!(second.calls(), 1);
+        assert_eq!(third.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn single_failure_is_returned_as_is() {
+        let failing = RecordingHandler::new("deployment.create", true);
+        let audit = RecordingHandler::new("#", false);
+        let dispatcher = EventDispatcher::new(vec![failing, audit.clone()]);
+
+        let result = dispatcher.dispatch(event("deployment.create")).await;
+
+        assert!(matches!(result, Err(GenesisError::Handler { .. })));
+        assert_eq!(audit.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn unmatched_event_is_ignored() {
+        let handler = RecordingHandler::new("deployment.create", false);
+        let dispatcher = EventDispatcher::new(vec![handler.clone()]);
+
+        dispatcher.dispatch(event("realm.create")).await.unwrap();
+
+        assert_eq!(handler.calls(), 0);
     }
 }
@@ -1,38 +1,339 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use aether_crds::common::constants::{
+    DEFAULT_CPU_LIMIT, DEFAULT_CPU_REQUEST, DEFAULT_MEMORY_LIMIT, DEFAULT_MEMORY_REQUEST,
+    DEFAULT_REPLICAS,
+};
+use aether_crds::common::types::{ResourceList, ResourceRequirements};
+use aether_crds::v1alpha::identity_instance::{
+    DatabaseConfig, DatabaseMode, IdentityInstance, IdentityInstanceSpec, ManagedClusterConfig,
+    ManagedClusterStorage,
+};
+use tracing::info;
+
 use crate::domain::entities::action_event::ActionEvent;
+use crate::domain::entities::deployment_payload::DeploymentPayload;
 use crate::domain::error::GenesisError;
-use crate::domain::ports::{BoxFuture, EventHandler};
-use tracing::info;
+use crate::domain::ports::{BoxFuture, EventHandler, IdentityInstanceRepository};
 
-/// Handles events with routing key `deployment.<kind>`.
-pub struct DeploymentEventHandler {
+pub const DEFAULT_BASE_DOMAIN: &str = "aether.local";
+pub const DEFAULT_DATABASE_STORAGE_SIZE: &str = "10Gi";
+
+pub const LABEL_DEPLOYMENT_ID: &str = "aether.dev/deployment-id";
+pub const LABEL_ORGANISATION_ID: &str = "aether.dev/organisation-id";
+pub const LABEL_MANAGED_BY: &str = "app.kubernetes.io/managed-by";
+
+/// Handles events with routing key `deployment.<kind>` by creating, patching, suspending,
+/// resuming or deleting the matching `IdentityInstance` custom resource.
+pub struct DeploymentEventHandler<R> {
     routing_key: String,
+    identity_instances: Arc<R>,
+    base_domain: String,
 }
 
-impl DeploymentEventHandler {
-    pub fn new(kind: impl Into<String>) -> Self {
+impl<R> DeploymentEventHandler<R>
+where
+    R: IdentityInstanceRepository,
+{
+    pub fn new(kind: impl Into<String>, identity_instances: Arc<R>) -> Self {
         Self {
             routing_key: format!("deployment.{}", kind.into()),
+            identity_instances,
+            base_domain: DEFAULT_BASE_DOMAIN.to_string(),
         }
     }
+
+    /// Domain under which instance hostnames are allocated (`<name>.<base_domain>`).
+    pub fn with_base_domain(mut self, base_domain: impl Into<String>) -> Self {
+        self.base_domain = base_domain.into();
+        self
+    }
+
+    fn build_instance(&self, payload: &DeploymentPayload) -> IdentityInstance {
+        let spec = IdentityInstanceSpec {
+            organisation_id: payload.organisation_id.to_string(),
+            provider: payload.kind.clone(),
+            version: payload.version.clone(),
+            hostname: format!("{}.{}", payload.name, self.base_domain),
+            database: DatabaseConfig {
+                mode: DatabaseMode::ManagedCluster,
+                managed_cluster: ManagedClusterConfig {
+                    instances: DEFAULT_REPLICAS,
+                    storage: ManagedClusterStorage {
+                        size: DEFAULT_DATABASE_STORAGE_SIZE.to_string(),
+                        storage_class: None,
+                    },
+                    resources: ResourceRequirements {
+                        requests: Some(ResourceList {
+                            cpu: Some(DEFAULT_CPU_REQUEST.to_string()),
+                            memory: Some(DEFAULT_MEMORY_REQUEST.to_string()),
+                        }),
+                        limits: Some(ResourceList {
+                            cpu: Some(DEFAULT_CPU_LIMIT.to_string()),
+                            memory: Some(DEFAULT_MEMORY_LIMIT.to_string()),
+                        }),
+                    },
+                },
+            },
+            ferriskey: None,
+            ingress: None,
+            suspended: None,
+        };
+
+        let mut instance = IdentityInstance::new(&payload.name, spec);
+        instance.metadata.namespace = Some(payload.namespace.clone());
+        instance.metadata.labels = Some(BTreeMap::from([
+            (
+                LABEL_DEPLOYMENT_ID.to_string(),
+                payload.deployment_id.to_string(),
+            ),
+            (
+                LABEL_ORGANISATION_ID.to_string(),
+                payload.organisation_id.to_string(),
+            ),
+            (LABEL_MANAGED_BY.to_string(), "genesis".to_string()),
+        ]));
+
+        instance
+    }
 }
 
-impl EventHandler for DeploymentEventHandler {
+impl<R> EventHandler for DeploymentEventHandler<R>
+where
+    R: IdentityInstanceRepository + 'static,
+{
     fn routing_key(&self) -> &str {
         &self.routing_key
     }
 
     fn handle<'a>(&'a self, event: ActionEvent) -> BoxFuture<'a, Result<(), GenesisError>> {
         Box::pin(async move {
+            let payload = DeploymentPayload::try_from(&event.payload)?;
+
             info!(
                 action_id = %event.action_id,
                 routing_key = %event.routing_key,
-                payload = %event.payload,
+                deployment_id = %payload.deployment_id,
+                name = %payload.name,
+                namespace = %payload.namespace,
                 "handling deployment event"
             );
 
-            // TODO: implement business logic (e.g. trigger Kubernetes reconciliation)
+            match event.kind() {
+                "create" => {
+                    self.identity_instances
+                        .create(self.build_instance(&payload))
+                        .await
+                }
+                "update" => {
+                    self.identity_instances
+                        .patch(self.build_instance(&payload))
+                        .await
+                }
+                "suspend" | "resume" => {
+                    let mut instance = self.build_instance(&payload);
+                    instance.spec.suspended = Some(event.kind() == "suspend");
+                    self.identity_instances.patch(instance).await
+                }
+                "delete" => {
+                    self.identity_instances
+                        .delete(&payload.namespace, &payload.name)
+                        .await
+                }
+                other => Err(GenesisError::Handler {
+                    message: format!("unsupported deployment event kind '{other}'"),
+                }),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aether_crds::v1alpha::identity_instance::IdentityProvider;
+    use chrono::Utc;
+    use serde_json::{Value, json};
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    /// In-memory stand-in for the Kubernetes API, keyed by `(namespace, name)`.
+    #[derive(Default)]
+    struct FakeIdentityInstances {
+        instances: Mutex<BTreeMap<(String, String), IdentityInstance>>,
+    }
+
+    impl FakeIdentityInstances {
+        fn get(&self, namespace: &str, name: &str) -> Option<IdentityInstance> {
+            self.instances
+                .lock()
+                .unwrap()
+                .get(&(namespace.to_string(), name.to_string()))
+                .cloned()
+        }
+
+        fn key(instance: &IdentityInstance) -> (String, String) {
+            (
+                instance.metadata.namespace.clone().unwrap_or_default(),
+                instance.metadata.name.clone().unwrap_or_default(),
+            )
+        }
+    }
+
+    impl IdentityInstanceRepository for FakeIdentityInstances {
+        async fn create(&self, instance: IdentityInstance) -> Result<(), GenesisError> {
+            self.instances
+                .lock()
+                .unwrap()
+                .entry(Self::key(&instance))
+                .or_insert(instance);
+            Ok(())
+        }
+
+        async fn patch(&self, instance: IdentityInstance) -> Result<(), GenesisError> {
+            let mut instances = self.instances.lock().unwrap();
+            let existing = instances.get_mut(&Self::key(&instance)).ok_or_else(|| {
+                GenesisError::Kubernetes {
+                    message: "identityinstances not found".to_string(),
+                }
+            })?;
+            existing.spec = instance.spec;
+            existing.metadata.labels = instance.metadata.labels;
+            Ok(())
+        }
 
+        async fn delete(&self, namespace: &str, name: &str) -> Result<(), GenesisError> {
+            self.instances
+                .lock()
+                .unwrap()
+                .remove(&(namespace.to_string(), name.to_string()));
             Ok(())
+        }
+    }
+
+    fn payload(version: &str) -> Value {
+        json!({
+            "deployment_id": "6f1c1d1e-8f43-4c38-9d0c-3f1c2b7d4a10",
+            "dataplane_id": Uuid::new_v4(),
+            "organisation_id": "0b6f0c7a-2d0e-4a43-a9a5-5a0d8e3c9f21",
+            "name": "acme-auth",
+            "kind": "keycloak",
+            "version": version,
+            "namespace": "org-acme",
+            "created_by": Uuid::new_v4(),
         })
     }
+
+    fn event(routing_key: &str, payload: Value) -> ActionEvent {
+        ActionEvent {
+            action_id: Uuid::new_v4(),
+            routing_key: routing_key.to_string(),
+            payload,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn handler(
+        kind: &str,
+        fake: &Arc<FakeIdentityInstances>,
+    ) -> DeploymentEventHandler<FakeIdentityInstances> {
+        DeploymentEventHandler::new(kind, fake.clone()).with_base_domain("auth.example.com")
+    }
+
+    #[tokio::test]
+    async fn create_builds_identity_instance_from_payload() {
+        let fake = Arc::new(FakeIdentityInstances::default());
+
+        handler("create", &fake)
+            .handle(event("deployment.create", payload("26.0.0")))
+            .await
+            .unwrap();
+
+        let instance = fake.get("org-acme", "acme-auth").expect("instance created");
+        assert_eq!(
+            instance.spec.organisation_id,
+            "0b6f0c7a-2d0e-4a43-a9a5-5a0d8e3c9f21"
+        );
+        assert_eq!(instance.spec.provider, IdentityProvider::Keycloak);
+        assert_eq!(instance.spec.version, "26.0.0");
+        assert_eq!(instance.spec.hostname, "acme-auth.auth.example.com");
+        assert_eq!(
+            instance
+                .metadata
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.get(LABEL_DEPLOYMENT_ID))
+                .map(String::as_str),
+            Some("6f1c1d1e-8f43-4c38-9d0c-3f1c2b7d4a10")
+        );
+    }
+
+    #[tokio::test]
+    async fn update_patches_existing_identity_instance() {
+        let fake = Arc::new(FakeIdentityInstances::default());
+        handler("create", &fake)
+            .handle(event("deployment.create", payload("26.0.0")))
+            .await
+            .unwrap();
+
+        handler("update", &fake)
+            .handle(event("deployment.update", payload("26.1.0")))
+            .await
+            .unwrap();
+
+        let instance = fake.get("org-acme", "acme-auth").unwrap();
+        assert_eq!(instance.spec.version, "26.1.0");
+    }
+
+    #[tokio::test]
+    async fn suspend_and_resume_toggle_suspended_flag() {
+        let fake = Arc::new(FakeIdentityInstances::default());
+        handler("create", &fake)
+            .handle(event("deployment.create", payload("26.0.0")))
+            .await
+            .unwrap();
+
+        handler("suspend", &fake)
+            .handle(event("deployment.suspend", payload("26.0.0")))
+            .await
+            .unwrap();
+        let instance = fake.get("org-acme", "acme-auth").unwrap();
+        assert_eq!(instance.spec.suspended, Some(true));
+
+        handler("resume", &fake)
+            .handle(event("deployment.resume", payload("26.0.0")))
+            .await
+            .unwrap();
+        let instance = fake.get("org-acme", "acme-auth").unwrap();
+        assert_eq!(instance.spec.suspended, Some(false));
+    }
+
+    #[tokio::test]
+    async fn delete_removes_identity_instance() {
+        let fake = Arc::new(FakeIdentityInstances::default());
+        handler("create", &fake)
+            .handle(event("deployment.create", payload("26.0.0")))
+            .await
+            .unwrap();
+
+        handler("delete", &fake)
+            .handle(event("deployment.delete", payload("26.0.0")))
+            .await
+            .unwrap();
+
+        assert!(fake.get("org-acme", "acme-auth").is_none());
+    }
+
+    #[tokio::test]
+    async fn invalid_payload_is_rejected() {
+        let fake = Arc::new(FakeIdentityInstances::default());
+
+        let result = handler("create", &fake)
+            .handle(event("deployment.create", json!({"name": "acme-auth"})))
+            .await;
+
+        assert!(matches!(result, Err(GenesisError::InvalidPayload { .. })));
+        assert!(fake.get("org-acme", "acme-auth").is_none());
+    }
 }
@@ -0,0 +1,74 @@
+use aether_crds::v1alpha::identity_instance::IdentityProvider;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::domain::error::GenesisError;
+
+/// Payload of the `deployment.*` actions recorded by the control plane.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeploymentPayload {
+    pub deployment_id: Uuid,
+    pub organisation_id: Uuid,
+    pub name: String,
+    /// Deployment kind, which maps one-to-one onto the identity provider
+    pub kind: IdentityProvider,
+    pub version: String,
+    pub namespace: String,
+}
+
+impl TryFrom<&Value> for DeploymentPayload {
+    type Error = GenesisError;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        Self::deserialize(value).map_err(|e| GenesisError::InvalidPayload {
+            message: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_control_plane_payload() {
+        let deployment_id = Uuid::new_v4();
+        let organisation_id = Uuid::new_v4();
+        let payload = json!({
+            "deployment_id": deployment_id,
+            "dataplane_id": Uuid::new_v4(),
+            "organisation_id": organisation_id,
+            "name": "acme-auth",
+            "kind": "keycloak",
+            "version": "26.0.0",
+            "namespace": "org-acme",
+            "created_by": Uuid::new_v4(),
+        });
+
+        let parsed = DeploymentPayload::try_from(&payload).unwrap();
+
+        assert_eq!(parsed.deployment_id, deployment_id);
+        assert_eq!(parsed.organisation_id, organisation_id);
+        assert_eq!(parsed.kind, IdentityProvider::Keycloak);
+        assert_eq!(parsed.namespace, "org-acme");
+    }
+
+    #[test]
+    fn rejects_unknown_kind() {
+        let payload = json!({
+            "deployment_id": Uuid::new_v4(),
+            "organisation_id": Uuid::new_v4(),
+            "name": "acme-auth",
+            "kind": "okta",
+            "version": "1.0.0",
+            "namespace": "org-acme",
+        });
+
+        assert!(matches!(
+            DeploymentPayload::try_from(&payload),
+            Err(GenesisError::InvalidPayload { .. })
+        ));
+    }
+}
@@ -1 +1,2 @@
 pub mod action_event;
+pub mod deployment_payload;
@@ -8,9 +8,82 @@ pub enum GenesisError {
     #[error("Message bus error: {message}")]
     MessageBus { message: String },
 
+    #[error("Invalid action payload: {message}")]
+    InvalidPayload { message: String },
+
+    #[error("Kubernetes API error: {message}")]
+    Kubernetes { message: String },
+
     #[error("Handler error: {message}")]
     Handler { message: String },
 
     #[error("Internal error: {message}")]
     Internal { message: String },
+
+    #[error("{} handlers failed: {}", errors.len(), join_errors(errors))]
+    Multiple { errors: Vec<GenesisError> },
+}
+
+impl GenesisError {
+    /// Whether handling the same message again may succeed.
+    ///
+    /// Malformed messages and payloads are permanent failures and go straight to the
+    /// dead-letter queue; broker and Kubernetes errors are usually transient.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Deserialization { .. } | Self::InvalidPayload { .. } => false,
+            Self::MessageBus { .. } | Self::Kubernetes { .. } => true,
+            Self::Handler { .. } | Self::Internal { .. } => false,
+            Self::Multiple { errors } => errors.iter().any(Self::is_retryable),
+        }
+    }
+}
+
+fn join_errors(errors: &[GenesisError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_input_is_permanent() {
+        let error = GenesisError::InvalidPayload {
+            message: "missing field `name`".to_string(),
+        };
+        assert!(!error.is_retryable());
+
+        let error = GenesisError::Deserialization {
+            message: "expected value".to_string(),
+        };
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn infrastructure_errors_are_retryable() {
+        let error = GenesisError::Kubernetes {
+            message: "connection refused".to_string(),
+        };
+        assert!(error.is_retryable());
+    }
+
+    #[test]
+    fn aggregate_is_retryable_if_any_failure_is() {
+        let error = GenesisError::Multiple {
+            errors: vec![
+                GenesisError::Handler {
+                    message: "audit failed".to_string(),
+                },
+                GenesisError::Kubernetes {
+                    message: "timeout".to_string(),
+                },
+            ],
+        };
+        assert!(error.is_retryable());
+    }
 }
@@ -1,5 +1,6 @@
 use crate::domain::entities::action_event::ActionEvent;
 use crate::domain::error::GenesisError;
+use aether_crds::v1alpha::identity_instance::IdentityInstance;
 use std::future::Future;
 use std::pin::Pin;
 
@@ -19,3 +20,26 @@ pub trait EventConsumer: Send + Sync {
     /// Start consuming messages, dispatching each one to the registered handlers.
     fn run(&self) -> impl Future<Output = Result<(), GenesisError>> + Send;
 }
+
+/// Writes `IdentityInstance` custom resources to the cluster the operator watches.
+pub trait IdentityInstanceRepository: Send + Sync {
+    /// Create the resource. Creating a resource that already exists is not an error,
+    /// so a redelivered event converges instead of failing.
+    fn create(
+        &self,
+        instance: IdentityInstance,
+    ) -> impl Future<Output = Result<(), GenesisError>> + Send;
+
+    /// Merge the spec and labels of `instance` into the existing resource.
+    fn patch(
+        &self,
+        instance: IdentityInstance,
+    ) -> impl Future<Output = Result<(), GenesisError>> + Send;
+
+    /// Delete the resource. Deleting a resource that no longer exists is not an error.
+    fn delete(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> impl Future<Output = Result<(), GenesisError>> + Send;
+}
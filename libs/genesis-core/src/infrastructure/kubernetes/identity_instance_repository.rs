@@ -0,0 +1,100 @@
+use aether_crds::v1alpha::identity_instance::IdentityInstance;
+use kube::api::{DeleteParams, Patch, PatchParams, PostParams};
+use kube::{Api, Client};
+use serde_json::json;
+use tracing::{debug, info};
+
+use crate::domain::error::GenesisError;
+use crate::domain::ports::IdentityInstanceRepository;
+
+pub struct KubeIdentityInstanceRepository {
+    client: Client,
+}
+
+impl KubeIdentityInstanceRepository {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    fn api(
+        &self,
+        instance: &IdentityInstance,
+    ) -> Result<(Api<IdentityInstance>, String), GenesisError> {
+        let name = instance
+            .metadata
+            .name
+            .clone()
+            .ok_or_else(|| GenesisError::Internal {
+                message: "IdentityInstance has no name".to_string(),
+            })?;
+        let namespace =
+            instance
+                .metadata
+                .namespace
+                .as_deref()
+                .ok_or_else(|| GenesisError::Internal {
+                    message: format!("IdentityInstance '{name}' has no namespace"),
+                })?;
+
+        Ok((Api::namespaced(self.client.clone(), namespace), name))
+    }
+}
+
+fn kube_error(action: &str, name: &str, error: kube::Error) -> GenesisError {
+    GenesisError::Kubernetes {
+        message: format!("failed to {action} IdentityInstance '{name}': {error}"),
+    }
+}
+
+fn is_status(error: &kube::Error, code: u16) -> bool {
+    matches!(error, kube::Error::Api(response) if response.code == code)
+}
+
+impl IdentityInstanceRepository for KubeIdentityInstanceRepository {
+    async fn create(&self, instance: IdentityInstance) -> Result<(), GenesisError> {
+        let (api, name) = self.api(&instance)?;
+
+        match api.create(&PostParams::default(), &instance).await {
+            Ok(_) => {
+                info!(%name, "created IdentityInstance");
+                Ok(())
+            }
+            Err(error) if is_status(&error, 409) => {
+                debug!(%name, "IdentityInstance already exists");
+                Ok(())
+            }
+            Err(error) => Err(kube_error("create", &name, error)),
+        }
+    }
+
+    async fn patch(&self, instance: IdentityInstance) -> Result<(), GenesisError> {
+        let (api, name) = self.api(&instance)?;
+        let patch = json!({
+            "metadata": { "labels": instance.metadata.labels },
+            "spec": instance.spec,
+        });
+
+        api.patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .map_err(|error| kube_error("patch", &name, error))?;
+
+        info!(%name, "patched IdentityInstance");
+        Ok(())
+    }
+
+    async fn delete(&self, namespace: &str, name: &str) -> Result<(), GenesisError> {
+        let api: Api<IdentityInstance> = Api::namespaced(self.client.clone(), namespace);
+
+        match api.delete(name, &DeleteParams::default()).await {
+            Ok(_) => {
+                info!(%name, %namespace, "deleted IdentityInstance");
+                Ok(())
+            }
+            Err(error) if is_status(&error, 404) => {
+                debug!(%name, %namespace, "IdentityInstance already deleted");
+                Ok(())
+            }
+            Err(error) => Err(kube_error("delete", name, error)),
+        }
+    }
+}
@@ -1 +1,2 @@
+pub mod kubernetes;
 pub mod rabbitmq;
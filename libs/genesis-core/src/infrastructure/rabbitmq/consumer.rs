@@ -2,35 +2,79 @@ use crate::application::dispatcher::EventDispatcher;
 use crate::domain::entities::action_event::ActionEvent;
 use crate::domain::error::GenesisError;
 use crate::domain::ports::EventConsumer;
+use crate::infrastructure::rabbitmq::retry::{
+    DEATH_REASON_HEADER, Disposition, RETRY_COUNT_HEADER, RetryPolicy, retry_count,
+};
 use lapin::{
-    Channel, Connection, ConnectionProperties,
-    options::{BasicAckOptions, BasicConsumeOptions, BasicNackOptions, QueueDeclareOptions},
-    types::FieldTable,
+    BasicProperties, Channel, Connection, ConnectionProperties, ExchangeKind,
+    message::Delivery,
+    options::{
+        BasicAckOptions, BasicConsumeOptions, BasicNackOptions, BasicPublishOptions,
+        ConfirmSelectOptions, ExchangeDeclareOptions, QueueBindOptions, QueueDeclareOptions,
+    },
+    publisher_confirm::Confirmation,
+    types::{AMQPValue, FieldTable, LongString},
 };
 use serde_json::from_slice;
 use std::sync::Arc;
 use tokio_stream::StreamExt;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
+/// Consumes action events from a durable queue.
+///
+/// The queue is bound to the topic exchange herald publishes to, with one binding per
+/// routing key pattern of the registered handlers.
+///
+/// Failed messages are never requeued in place. Retryable failures are republished to
+/// the `<queue>.retry` exchange, which routes them to one delay queue per attempt
+/// (`<queue>.retry.<n>`, with an exponentially growing TTL) that dead-letters back into
+/// the main queue. Permanent failures and messages out of attempts are parked in
+/// `<queue>.dlq`, with the last error in the `x-death-reason` header.
 pub struct RabbitMqConsumer {
     amqp_url: String,
+    exchange: String,
     queue: String,
     dispatcher: Arc<EventDispatcher>,
+    retry_policy: RetryPolicy,
 }
 
 impl RabbitMqConsumer {
     pub fn new(
         amqp_url: impl Into<String>,
+        exchange: impl Into<String>,
         queue: impl Into<String>,
         dispatcher: Arc<EventDispatcher>,
     ) -> Self {
         Self {
             amqp_url: amqp_url.into(),
+            exchange: exchange.into(),
             queue: queue.into(),
             dispatcher,
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    fn retry_exchange(&self) -> String {
+        format!("{}.retry", self.queue)
+    }
+
+    fn retry_queue(&self, attempt: u32) -> String {
+        format!("{}.retry.{attempt}", self.queue)
+    }
+
+    fn dead_letter_exchange(&self) -> String {
+        format!("{}.dlx", self.queue)
+    }
+
+    fn dead_letter_queue(&self) -> String {
+        format!("{}.dlq", self.queue)
+    }
+
     async fn connect(&self) -> Result<Channel, GenesisError> {
         let conn = Connection::connect(&self.amqp_url, ConnectionProperties::default())
             .await
@@ -46,26 +90,223 @@ impl RabbitMqConsumer {
             })?;
 
         channel
-            .queue_declare(
-                &self.queue,
-                QueueDeclareOptions {
-                    durable: true,
-                    ..Default::default()
-                },
-                FieldTable::default(),
-            )
+            .confirm_select(ConfirmSelectOptions::default())
             .await
             .map_err(|e| GenesisError::MessageBus {
-                message: format!("failed to declare queue '{}': {e}", self.queue),
+                message: format!("failed to enable publisher confirms: {e}"),
             })?;
 
+        declare_queue(&channel, &self.queue, FieldTable::default()).await?;
+        self.bind_to_actions_exchange(&channel).await?;
+        self.declare_retry_topology(&channel).await?;
+        self.declare_dead_letter_topology(&channel).await?;
+
         Ok(channel)
     }
+
+    async fn bind_to_actions_exchange(&self, channel: &Channel) -> Result<(), GenesisError> {
+        declare_exchange(channel, &self.exchange, ExchangeKind::Topic).await?;
+
+        for routing_key in self.dispatcher.binding_keys() {
+            bind_queue(channel, &self.queue, &self.exchange, &routing_key).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn declare_retry_topology(&self, channel: &Channel) -> Result<(), GenesisError> {
+        let exchange = self.retry_exchange();
+        declare_exchange(channel, &exchange, ExchangeKind::Direct).await?;
+
+        for attempt in 1..=self.retry_policy.max_retries() {
+            let queue = self.retry_queue(attempt);
+            let delay_ms =
+                u32::try_from(self.retry_policy.delay(attempt).as_millis()).unwrap_or(u32::MAX);
+
+            let mut arguments = FieldTable::default();
+            arguments.insert("x-message-ttl".into(), AMQPValue::LongUInt(delay_ms));
+            arguments.insert(
+                "x-dead-letter-exchange".into(),
+                AMQPValue::LongString(LongString::from("")),
+            );
+            arguments.insert(
+                "x-dead-letter-routing-key".into(),
+                AMQPValue::LongString(LongString::from(self.queue.as_str())),
+            );
+
+            declare_queue(channel, &queue, arguments).await?;
+            bind_queue(channel, &queue, &exchange, &attempt.to_string()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn declare_dead_letter_topology(&self, channel: &Channel) -> Result<(), GenesisError> {
+        let exchange = self.dead_letter_exchange();
+        let queue = self.dead_letter_queue();
+
+        declare_exchange(channel, &exchange, ExchangeKind::Fanout).await?;
+        declare_queue(channel, &queue, FieldTable::default()).await?;
+        bind_queue(channel, &queue, &exchange, "").await
+    }
+
+    /// Settles a delivery whose handling failed: schedules a delayed retry or parks it
+    /// in the dead-letter queue, then acks the original. Falls back to an in-place
+    /// requeue only if the broker refuses the republish, so the message is never lost.
+    async fn settle_failure(&self, channel: &Channel, delivery: Delivery, failure: GenesisError) {
+        let retries = retry_count(delivery.properties.headers().as_ref());
+        let mut headers = delivery.properties.headers().clone().unwrap_or_default();
+
+        let (exchange, routing_key) = match self.retry_policy.disposition(retries, &failure) {
+            Disposition::Retry { attempt, delay } => {
+                warn!(
+                    attempt,
+                    delay_ms = delay.as_millis() as u64,
+                    error = %failure,
+                    "handler failed, scheduling retry"
+                );
+                headers.insert(RETRY_COUNT_HEADER.into(), AMQPValue::LongUInt(attempt));
+                (self.retry_exchange(), attempt.to_string())
+            }
+            Disposition::DeadLetter => {
+                error!(
+                    retries,
+                    retryable = failure.is_retryable(),
+                    error = %failure,
+                    "handler failed, moving message to dead-letter queue"
+                );
+                headers.insert(
+                    DEATH_REASON_HEADER.into(),
+                    AMQPValue::LongString(LongString::from(failure.to_string())),
+                );
+                (self.dead_letter_exchange(), String::new())
+            }
+        };
+
+        let properties = delivery.properties.clone().with_headers(headers);
+
+        match publish(channel, &exchange, &routing_key, &delivery.data, properties).await {
+            Ok(()) => {
+                delivery.ack(BasicAckOptions::default()).await.ok();
+            }
+            Err(e) => {
+                error!("failed to reroute message, requeueing it: {e}");
+                delivery
+                    .nack(BasicNackOptions {
+                        requeue: true,
+                        ..Default::default()
+                    })
+                    .await
+                    .ok();
+            }
+        }
+    }
+}
+
+async fn declare_exchange(
+    channel: &Channel,
+    exchange: &str,
+    kind: ExchangeKind,
+) -> Result<(), GenesisError> {
+    channel
+        .exchange_declare(
+            exchange,
+            kind,
+            ExchangeDeclareOptions {
+                durable: true,
+                ..Default::default()
+            },
+            FieldTable::default(),
+        )
+        .await
+        .map_err(|e| GenesisError::MessageBus {
+            message: format!("failed to declare exchange '{exchange}': {e}"),
+        })
+}
+
+async fn declare_queue(
+    channel: &Channel,
+    queue: &str,
+    arguments: FieldTable,
+) -> Result<(), GenesisError> {
+    channel
+        .queue_declare(
+            queue,
+            QueueDeclareOptions {
+                durable: true,
+                ..Default::default()
+            },
+            arguments,
+        )
+        .await
+        .map_err(|e| GenesisError::MessageBus {
+            message: format!("failed to declare queue '{queue}': {e}"),
+        })?;
+
+    Ok(())
+}
+
+async fn bind_queue(
+    channel: &Channel,
+    queue: &str,
+    exchange: &str,
+    routing_key: &str,
+) -> Result<(), GenesisError> {
+    channel
+        .queue_bind(
+            queue,
+            exchange,
+            routing_key,
+            QueueBindOptions::default(),
+            FieldTable::default(),
+        )
+        .await
+        .map_err(|e| GenesisError::MessageBus {
+            message: format!("failed to bind queue '{queue}' to '{exchange}': {e}"),
+        })
+}
+
+async fn publish(
+    channel: &Channel,
+    exchange: &str,
+    routing_key: &str,
+    payload: &[u8],
+    properties: BasicProperties,
+) -> Result<(), GenesisError> {
+    let confirmation = channel
+        .basic_publish(
+            exchange,
+            routing_key,
+            BasicPublishOptions::default(),
+            payload,
+            properties,
+        )
+        .await
+        .map_err(|e| GenesisError::MessageBus {
+            message: format!("failed to publish to '{exchange}': {e}"),
+        })?
+        .await
+        .map_err(|e| GenesisError::MessageBus {
+            message: format!("failed to confirm publish to '{exchange}': {e}"),
+        })?;
+
+    if let Confirmation::Nack(_) = confirmation {
+        return Err(GenesisError::MessageBus {
+            message: format!("broker rejected publish to '{exchange}'"),
+        });
+    }
+
+    Ok(())
 }
 
 impl EventConsumer for RabbitMqConsumer {
     async fn run(&self) -> Result<(), GenesisError> {
-        info!(queue = %self.queue, "starting RabbitMQ consumer");
+        info!(
+            exchange = %self.exchange,
+            queue = %self.queue,
+            max_attempts = self.retry_policy.max_attempts,
+            "starting RabbitMQ consumer"
+        );
 
         let channel = self.connect().await?;
 
@@ -93,15 +334,10 @@ impl EventConsumer for RabbitMqConsumer {
             let event: ActionEvent = match from_slice(&delivery.data) {
                 Ok(e) => e,
                 Err(e) => {
-                    error!("failed to deserialize event: {e}");
-                    delivery
-                        .nack(BasicNackOptions {
-                            requeue: false,
-                            ..Default::default()
-                        })
-                        .await
-                        .ok();
-
+                    let failure = GenesisError::Deserialization {
+                        message: e.to_string(),
+                    };
+                    self.settle_failure(&channel, delivery, failure).await;
                     continue;
                 }
             };
@@ -111,14 +347,7 @@ impl EventConsumer for RabbitMqConsumer {
                     delivery.ack(BasicAckOptions::default()).await.ok();
                 }
                 Err(e) => {
-                    error!("handler error {e}");
-                    delivery
-                        .nack(BasicNackOptions {
-                            requeue: true,
-                            ..Default::default()
-                        })
-                        .await
-                        .ok();
+                    self.settle_failure(&channel, delivery, e).await;
                 }
             }
         }
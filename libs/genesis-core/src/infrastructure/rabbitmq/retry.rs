@@ -0,0 +1,156 @@
+use std::time::Duration;
+
+use lapin::types::{AMQPValue, FieldTable};
+
+use crate::domain::error::GenesisError;
+
+/// Header carrying how many times a message has already been retried
+pub const RETRY_COUNT_HEADER: &str = "x-retry-count";
+
+/// Header carrying the error that sent a message to the dead-letter queue
+pub const DEATH_REASON_HEADER: &str = "x-death-reason";
+
+/// Exponential backoff applied to messages whose handlers failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of deliveries, including the first one
+    pub max_attempts: u32,
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+}
+
+/// What to do with a message whose handlers failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disposition {
+    /// Redeliver after `delay`; `attempt` is the (1-based) retry number
+    Retry { attempt: u32, delay: Duration },
+    /// Park the message in the dead-letter queue
+    DeadLetter,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, initial_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_delay,
+            max_delay,
+        }
+    }
+
+    /// Delay before the given (1-based) retry
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+
+    /// Number of retries, and therefore of delay tiers, this policy allows
+    pub fn max_retries(&self) -> u32 {
+        self.max_attempts - 1
+    }
+
+    /// Decides the fate of a message that already went through `retries` retries
+    pub fn disposition(&self, retries: u32, error: &GenesisError) -> Disposition {
+        if !error.is_retryable() || retries >= self.max_retries() {
+            return Disposition::DeadLetter;
+        }
+
+        let attempt = retries + 1;
+        Disposition::Retry {
+            attempt,
+            delay: self.delay(attempt),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(5, Duration::from_secs(1), Duration::from_secs(60))
+    }
+}
+
+/// Reads the retry counter of a delivery, treating a missing or malformed header as zero
+pub fn retry_count(headers: Option<&FieldTable>) -> u32 {
+    let Some(value) = headers.and_then(|headers| headers.inner().get(RETRY_COUNT_HEADER)) else {
+        return 0;
+    };
+
+    match value {
+        AMQPValue::ShortShortUInt(v) => u32::from(*v),
+        AMQPValue::ShortUInt(v) => u32::from(*v),
+        AMQPValue::LongUInt(v) => *v,
+        AMQPValue::ShortShortInt(v) => u32::try_from(*v).unwrap_or(0),
+        AMQPValue::ShortInt(v) => u32::try_from(*v).unwrap_or(0),
+        AMQPValue::LongInt(v) => u32::try_from(*v).unwrap_or(0),
+        AMQPValue::LongLongInt(v) => u32::try_from(*v).unwrap_or(0),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn retryable() -> GenesisError {
+        GenesisError::Kubernetes {
+            message: "connection refused".to_string(),
+        }
+    }
+
+    #[test]
+    fn delay_doubles_until_cap() {
+        let policy = RetryPolicy::new(10, Duration::from_secs(1), Duration::from_secs(5));
+
+        assert_eq!(policy.delay(1), Duration::from_secs(1));
+        assert_eq!(policy.delay(2), Duration::from_secs(2));
+        assert_eq!(policy.delay(3), Duration::from_secs(4));
+        assert_eq!(policy.delay(4), Duration::from_secs(5));
+        assert_eq!(policy.delay(40), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn retryable_error_is_retried_until_attempts_run_out() {
+        let policy = RetryPolicy::new(3, Duration::from_secs(1), Duration::from_secs(60));
+
+        assert_eq!(
+            policy.disposition(0, &retryable()),
+            Disposition::Retry {
+                attempt: 1,
+                delay: Duration::from_secs(1)
+            }
+        );
+        assert_eq!(
+            policy.disposition(1, &retryable()),
+            Disposition::Retry {
+                attempt: 2,
+                delay: Duration::from_secs(2)
+            }
+        );
+        assert_eq!(policy.disposition(2, &retryable()), Disposition::DeadLetter);
+    }
+
+    #[test]
+    fn permanent_error_is_dead_lettered_immediately() {
+        let policy = RetryPolicy::default();
+        let error = GenesisError::InvalidPayload {
+            message: "missing field `name`".to_string(),
+        };
+
+        assert_eq!(policy.disposition(0, &error), Disposition::DeadLetter);
+    }
+
+    #[test]
+    fn retry_count_reads_header() {
+        let mut headers = FieldTable::default();
+        assert_eq!(retry_count(Some(&headers)), 0);
+
+        headers.insert(RETRY_COUNT_HEADER.into(), AMQPValue::LongUInt(3));
+        assert_eq!(retry_count(Some(&headers)), 3);
+
+        headers.insert(RETRY_COUNT_HEADER.into(), AMQPValue::LongInt(-1));
+        assert_eq!(retry_count(Some(&headers)), 0);
+
+        assert_eq!(retry_count(None), 0);
+    }
+}